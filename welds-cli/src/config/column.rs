@@ -12,6 +12,8 @@ pub struct Column {
     pub primary_key: bool,
     #[serde(default)]
     pub writeable: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
 }
 
 impl Column {
@@ -24,6 +26,7 @@ impl Column {
             is_null: def.null(),
             primary_key: def.primary_key(),
             writeable: def.updatable(),
+            comment: def.comment().map(|x| x.to_owned()),
         }
     }
 
@@ -33,5 +36,6 @@ impl Column {
         self.is_null = def.null();
         self.primary_key = def.primary_key();
         self.writeable = def.updatable();
+        self.comment = def.comment().map(|x| x.to_owned());
     }
 }
@@ -9,16 +9,19 @@ pub struct Table {
     pub name: String,              // Table name
     pub manual_update: bool,       // Tell welds to ignore this Def when scanning the database
     model: Option<String>,         // value Default to singularized version of table name
-    pub r#type: String,            // This could be a table or view
+    pub r#type: String,            // This could be a table, view, or materialized_view
     pub columns: Vec<Column>,      // What are the columns on this table
     pub belongs_to: Vec<Relation>, // list of objects this object belongs to
     pub has_many: Vec<Relation>,   // what objects this object has many of
     pub database: DbProvider,      // what DB this object was scanned from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>, // The comment on the table in the database
 }
 
 fn type_str(ty: DataType) -> &'static str {
     match ty {
         DataType::View => "view",
+        DataType::MaterializedView => "materialized_view",
         DataType::Table => "table",
     }
 }
@@ -35,6 +38,7 @@ impl Table {
             belongs_to: table_def.belongs_to().iter().map(|x| x.into()).collect(),
             has_many: table_def.has_many().iter().map(|x| x.into()).collect(),
             database: provider,
+            comment: table_def.comment().map(|x| x.to_owned()),
         };
         t.update_cols_from(table_def.columns());
         t
@@ -51,6 +55,7 @@ impl Table {
         self.has_many = table_def.has_many().iter().map(|x| x.into()).collect();
         self.update_cols_from(table_def.columns());
         self.database = provider;
+        self.comment = table_def.comment().map(|x| x.to_owned());
     }
 
     fn update_cols_from(&mut self, cols: &[ColumnDef]) {
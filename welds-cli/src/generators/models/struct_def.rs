@@ -21,6 +21,7 @@ pub(crate) fn generate(
 
     let struct_name = format_ident!("{}", table.struct_name());
 
+    let doc = build_doc(&table.comment);
     let weldstable = build_welds_table(table);
     let relations = build_relations(table, all);
     let fields = build_fields(table, table.database, hide_unknown_types);
@@ -28,6 +29,7 @@ pub(crate) fn generate(
     let code = quote! {
         use welds::WeldsModel;
 
+        #doc
         #[derive(Debug, WeldsModel)]
         #weldstable
         #relations
@@ -43,13 +45,29 @@ pub(crate) fn generate(
     Ok(())
 }
 
+/// Carries a database comment over as a doc comment
+fn build_doc(comment: &Option<String>) -> TokenStream {
+    match comment {
+        Some(comment) => {
+            let comment = format!(" {}", comment.trim());
+            quote! { #[doc = #comment] }
+        }
+        None => quote! {},
+    }
+}
+
 fn build_welds_table(table: &Table) -> TokenStream {
     let schema = match &table.schema {
         Some(s) => quote! { schema = #s, },
         None => quote! {},
     };
     let tablename = table.name.as_str();
-    quote! { #[welds( #schema table=#tablename )] }
+    // views can't be written to
+    let readonly = match table.r#type.as_str() {
+        "table" => quote! {},
+        _ => quote! { , readonly },
+    };
+    quote! { #[welds( #schema table=#tablename #readonly )] }
 }
 
 fn build_relations(table: &Table, all: &[Table]) -> TokenStream {
@@ -102,7 +120,7 @@ fn build_fields(table: &Table, db: DbProvider, hide_unknown_types: bool) -> Toke
 }
 
 fn build_field(column: &Column, db: DbProvider, hide_unknown_types: bool) -> Option<TokenStream> {
-    let mut parts = Vec::default();
+    let mut parts = vec![build_doc(&column.comment)];
     if column.primary_key {
         parts.push(quote! { #[welds(primary_key)]});
     }
@@ -0,0 +1,173 @@
+// ******************************************************************************************
+// Lets a rust type of your own be used as a column, stored as one of the built in types.
+// The derive writes (or leaves to you) the WeldsType impl and calls __welds_type! which
+// writes the encoding for each database this crate was built with
+// ******************************************************************************************
+
+/// A rust type of your own stored in a single column as one of the built in types.
+///
+/// Lets a newtype like `EmailAddress(String)` or `Cents(i64)` be a field of a model.
+/// `#[derive(WeldsType)]` implements it for a struct with a single field.
+/// With `#[welds(custom)]` implement it yourself, to encode and decode the value your own way
+pub trait WeldsType: Sized {
+    /// The built in type the value is stored as. I.E. `String` or `i64`
+    type Stored;
+    /// The value written to the database
+    fn to_stored(&self) -> Self::Stored;
+    /// The value read back from the database, an Err fails the read
+    fn from_stored(stored: Self::Stored) -> Result<Self, Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// Writes the impls to read and write the type on all the enabled databases
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __welds_type {
+    ($ty:ty) => {
+        $crate::__welds_type_sqlite!($ty);
+        $crate::__welds_type_postgres!($ty);
+        $crate::__welds_type_mysql!($ty);
+        $crate::__welds_type_mssql!($ty);
+    };
+}
+
+#[cfg(feature = "sqlite")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __welds_type_sqlite {
+    ($ty:ty) => {
+        $crate::__welds_type_sqlx!($crate::__sqlx::Sqlite, $ty);
+    };
+}
+
+#[cfg(not(feature = "sqlite"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __welds_type_sqlite {
+    ($ty:ty) => {};
+}
+
+#[cfg(feature = "postgres")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __welds_type_postgres {
+    ($ty:ty) => {
+        $crate::__welds_type_sqlx!($crate::__sqlx::Postgres, $ty);
+    };
+}
+
+#[cfg(not(feature = "postgres"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __welds_type_postgres {
+    ($ty:ty) => {};
+}
+
+#[cfg(feature = "mysql")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __welds_type_mysql {
+    ($ty:ty) => {
+        $crate::__welds_type_sqlx!($crate::__sqlx::MySql, $ty);
+    };
+}
+
+#[cfg(not(feature = "mysql"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __welds_type_mysql {
+    ($ty:ty) => {};
+}
+
+/// The value is encoded and decoded as its stored type
+#[cfg(any(feature = "sqlite", feature = "postgres", feature = "mysql"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __welds_type_sqlx {
+    ($db:ty, $ty:ty) => {
+        impl $crate::__sqlx::Type<$db> for $ty {
+            fn type_info() -> <$db as $crate::__sqlx::Database>::TypeInfo {
+                <<$ty as $crate::WeldsType>::Stored as $crate::__sqlx::Type<$db>>::type_info()
+            }
+            fn compatible(ty: &<$db as $crate::__sqlx::Database>::TypeInfo) -> bool {
+                <<$ty as $crate::WeldsType>::Stored as $crate::__sqlx::Type<$db>>::compatible(ty)
+            }
+        }
+
+        impl<'q> $crate::__sqlx::Encode<'q, $db> for $ty {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <$db as $crate::__sqlx::Database>::ArgumentBuffer<'q>,
+            ) -> ::std::result::Result<
+                $crate::__sqlx::encode::IsNull,
+                $crate::__sqlx::error::BoxDynError,
+            > {
+                let stored = <$ty as $crate::WeldsType>::to_stored(self);
+                <<$ty as $crate::WeldsType>::Stored as $crate::__sqlx::Encode<'q, $db>>::encode(
+                    stored, buf,
+                )
+            }
+        }
+
+        impl<'r> $crate::__sqlx::Decode<'r, $db> for $ty {
+            fn decode(
+                value: <$db as $crate::__sqlx::Database>::ValueRef<'r>,
+            ) -> ::std::result::Result<Self, $crate::__sqlx::error::BoxDynError> {
+                let stored = <<$ty as $crate::WeldsType>::Stored as $crate::__sqlx::Decode<
+                    'r,
+                    $db,
+                >>::decode(value)?;
+                <$ty as $crate::WeldsType>::from_stored(stored)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "mssql")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __welds_type_mssql {
+    ($ty:ty) => {
+        impl $crate::__tiberius::ToSql for $ty {
+            fn to_sql(&self) -> $crate::__tiberius::ColumnData<'_> {
+                let stored = <$ty as $crate::WeldsType>::to_stored(self);
+                $crate::__tiberius::IntoSql::into_sql(stored)
+            }
+        }
+
+        impl $crate::__tiberius::FromSqlOwned for $ty {
+            fn from_sql_owned(
+                value: $crate::__tiberius::ColumnData<'static>,
+            ) -> $crate::__tiberius::Result<Option<Self>> {
+                let stored: Option<<$ty as $crate::WeldsType>::Stored> =
+                    $crate::__tiberius::FromSqlOwned::from_sql_owned(value)?;
+                let stored = match stored {
+                    Some(stored) => stored,
+                    None => return Ok(None),
+                };
+                match <$ty as $crate::WeldsType>::from_stored(stored) {
+                    Ok(value) => Ok(Some(value)),
+                    Err(err) => Err($crate::__tiberius::error::Error::Conversion(
+                        format!("Invalid {}: {}", stringify!($ty), err).into(),
+                    )),
+                }
+            }
+        }
+
+        impl $crate::row::TiberiusDecode for $ty {
+            fn read(
+                col: &$crate::__tiberius::Column,
+                value: $crate::__tiberius::ColumnData<'static>,
+            ) -> $crate::errors::Result<Self> {
+                let v = $crate::__tiberius::FromSqlOwned::from_sql_owned(value)?;
+                v.ok_or_else(|| $crate::Error::UnexpectedNoneInColumn(col.name().to_owned()))
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "mssql"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __welds_type_mssql {
+    ($ty:ty) => {};
+}
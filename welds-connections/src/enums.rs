@@ -0,0 +1,188 @@
+// ******************************************************************************************
+// Lets a plain rust enum be used as a column, stored as the text of the variant or a number.
+// The derive writes the WeldsEnum impl and calls __welds_enum! which writes the
+// encoding for each database this crate was built with
+// ******************************************************************************************
+
+/// A rust enum stored in a single column, as the name of its variant or as a number.
+///
+/// Implemented with `#[derive(WeldsEnum)]`
+pub trait WeldsEnum: Sized {
+    /// The text the variant is stored as
+    fn as_text(&self) -> &'static str;
+    /// The variant stored as this text
+    fn from_text(text: &str) -> Option<Self>;
+    /// The number the variant is stored as
+    fn as_int(&self) -> i32;
+    /// The variant stored as this number
+    fn from_int(value: i32) -> Option<Self>;
+}
+
+/// Writes the impls to read and write the enum on all the enabled databases
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __welds_enum {
+    ($ty:ty, $store:ident) => {
+        $crate::__welds_enum_sqlite!($ty, $store);
+        $crate::__welds_enum_postgres!($ty, $store);
+        $crate::__welds_enum_mysql!($ty, $store);
+        $crate::__welds_enum_mssql!($ty, $store);
+    };
+}
+
+#[cfg(feature = "sqlite")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __welds_enum_sqlite {
+    ($ty:ty, $store:ident) => {
+        $crate::__welds_enum_sqlx!($crate::__sqlx::Sqlite, $ty, $store);
+    };
+}
+
+#[cfg(not(feature = "sqlite"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __welds_enum_sqlite {
+    ($ty:ty, $store:ident) => {};
+}
+
+#[cfg(feature = "postgres")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __welds_enum_postgres {
+    ($ty:ty, $store:ident) => {
+        $crate::__welds_enum_sqlx!($crate::__sqlx::Postgres, $ty, $store);
+    };
+}
+
+#[cfg(not(feature = "postgres"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __welds_enum_postgres {
+    ($ty:ty, $store:ident) => {};
+}
+
+#[cfg(feature = "mysql")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __welds_enum_mysql {
+    ($ty:ty, $store:ident) => {
+        $crate::__welds_enum_sqlx!($crate::__sqlx::MySql, $ty, $store);
+    };
+}
+
+#[cfg(not(feature = "mysql"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __welds_enum_mysql {
+    ($ty:ty, $store:ident) => {};
+}
+
+/// The stored value is encoded and decoded as a &str or i32, then mapped to the variant
+#[cfg(any(feature = "sqlite", feature = "postgres", feature = "mysql"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __welds_enum_sqlx {
+    ($db:ty, $ty:ty, text) => {
+        $crate::__welds_enum_sqlx!($db, $ty, &str, as_text, from_text);
+    };
+    ($db:ty, $ty:ty, int) => {
+        $crate::__welds_enum_sqlx!($db, $ty, i32, as_int, from_int);
+    };
+    ($db:ty, $ty:ty, $stored:ty, $to:ident, $from:ident) => {
+        impl $crate::__sqlx::Type<$db> for $ty {
+            fn type_info() -> <$db as $crate::__sqlx::Database>::TypeInfo {
+                <$stored as $crate::__sqlx::Type<$db>>::type_info()
+            }
+            fn compatible(ty: &<$db as $crate::__sqlx::Database>::TypeInfo) -> bool {
+                <$stored as $crate::__sqlx::Type<$db>>::compatible(ty)
+            }
+        }
+
+        impl<'q> $crate::__sqlx::Encode<'q, $db> for $ty {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <$db as $crate::__sqlx::Database>::ArgumentBuffer<'q>,
+            ) -> ::std::result::Result<
+                $crate::__sqlx::encode::IsNull,
+                $crate::__sqlx::error::BoxDynError,
+            > {
+                let value = <$ty as $crate::WeldsEnum>::$to(self);
+                <$stored as $crate::__sqlx::Encode<'q, $db>>::encode(value, buf)
+            }
+        }
+
+        impl<'r> $crate::__sqlx::Decode<'r, $db> for $ty {
+            fn decode(
+                value: <$db as $crate::__sqlx::Database>::ValueRef<'r>,
+            ) -> ::std::result::Result<Self, $crate::__sqlx::error::BoxDynError> {
+                let value = <$stored as $crate::__sqlx::Decode<'r, $db>>::decode(value)?;
+                <$ty as $crate::WeldsEnum>::$from(value).ok_or_else(|| {
+                    format!("{} is not a variant of {}", value, stringify!($ty)).into()
+                })
+            }
+        }
+    };
+}
+
+#[cfg(feature = "mssql")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __welds_enum_mssql {
+    ($ty:ty, text) => {
+        impl $crate::__tiberius::ToSql for $ty {
+            fn to_sql(&self) -> $crate::__tiberius::ColumnData<'_> {
+                let text = <$ty as $crate::WeldsEnum>::as_text(self);
+                $crate::__tiberius::ColumnData::String(Some(::std::borrow::Cow::Borrowed(text)))
+            }
+        }
+        $crate::__welds_enum_mssql!($ty, String, as_str, from_text);
+    };
+    ($ty:ty, int) => {
+        impl $crate::__tiberius::ToSql for $ty {
+            fn to_sql(&self) -> $crate::__tiberius::ColumnData<'_> {
+                let value = <$ty as $crate::WeldsEnum>::as_int(self);
+                $crate::__tiberius::ColumnData::I32(Some(value))
+            }
+        }
+        $crate::__welds_enum_mssql!($ty, i32, clone, from_int);
+    };
+    ($ty:ty, $stored:ty, $read:ident, $from:ident) => {
+        impl $crate::__tiberius::FromSqlOwned for $ty {
+            #[allow(clippy::clone_on_copy)]
+            fn from_sql_owned(
+                value: $crate::__tiberius::ColumnData<'static>,
+            ) -> $crate::__tiberius::Result<Option<Self>> {
+                let value: Option<$stored> =
+                    $crate::__tiberius::FromSqlOwned::from_sql_owned(value)?;
+                let value = match value {
+                    Some(value) => value,
+                    None => return Ok(None),
+                };
+                match <$ty as $crate::WeldsEnum>::$from(value.$read()) {
+                    Some(variant) => Ok(Some(variant)),
+                    None => Err($crate::__tiberius::error::Error::Conversion(
+                        format!("{} is not a variant of {}", value, stringify!($ty)).into(),
+                    )),
+                }
+            }
+        }
+
+        impl $crate::row::TiberiusDecode for $ty {
+            fn read(
+                col: &$crate::__tiberius::Column,
+                value: $crate::__tiberius::ColumnData<'static>,
+            ) -> $crate::errors::Result<Self> {
+                let v = $crate::__tiberius::FromSqlOwned::from_sql_owned(value)?;
+                v.ok_or_else(|| $crate::Error::UnexpectedNoneInColumn(col.name().to_owned()))
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "mssql"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __welds_enum_mssql {
+    ($ty:ty, $store:ident) => {};
+}
@@ -19,6 +19,47 @@ pub enum Error {
 
 impl std::error::Error for Error {}
 
+impl Error {
+    /// True when the database stopped a statement because it ran out of time.
+    /// I.E. it went over the limit of `fetch_many_with_timeout`
+    ///
+    /// NOTE: on SQLite this is any SQLITE_BUSY. It is only a timeout for the statements run with a limit
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            #[cfg(any(feature = "mysql", feature = "sqlite", feature = "postgres"))]
+            Error::Sqlx(sqlx::Error::Database(err)) => sqlx_is_timeout(err.as_ref()),
+            // Lock request time out period exceeded
+            #[cfg(feature = "mssql")]
+            Error::Tiberius(err) => err.code() == Some(1222),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(any(feature = "mysql", feature = "sqlite", feature = "postgres"))]
+fn sqlx_is_timeout(err: &dyn sqlx::error::DatabaseError) -> bool {
+    // canceling statement due to statement timeout
+    #[cfg(feature = "postgres")]
+    if let Some(err) = err.try_downcast_ref::<sqlx::postgres::PgDatabaseError>() {
+        return err.code() == "57014";
+    }
+    // Query execution was interrupted, maximum statement execution time exceeded
+    #[cfg(feature = "mysql")]
+    if let Some(err) = err.try_downcast_ref::<sqlx::mysql::MySqlDatabaseError>() {
+        return err.number() == 3024;
+    }
+    // SQLITE_BUSY, and its extended codes
+    #[cfg(feature = "sqlite")]
+    if err
+        .try_downcast_ref::<sqlx::sqlite::SqliteError>()
+        .is_some()
+    {
+        let code: Option<i32> = err.code().and_then(|c| c.parse().ok());
+        return code.map(|c| c & 0xff == 5).unwrap_or_default();
+    }
+    false
+}
+
 impl Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let message = match self {
@@ -0,0 +1,114 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// A value stored in the database as JSON.
+///
+/// Written and read for `#[welds(json)]` fields, the field itself stays a plain rust type.
+/// Stored as json/jsonb on Postgres, JSON on MySQL, and text on Sqlite and MSSQL.
+/// A value written as JSON `null` is stored as NULL
+#[repr(transparent)]
+pub struct AsJson<T>(pub T);
+
+impl<T> AsJson<T> {
+    /// Borrows a value as JSON, so it can be bound without a copy
+    pub fn from_ref(value: &T) -> &AsJson<T> {
+        // SAFETY: AsJson is repr(transparent), it has the same layout as T
+        unsafe { &*(value as *const T as *const AsJson<T>) }
+    }
+}
+
+impl<T: Serialize> AsJson<T> {
+    /// The JSON of the value, None if it is null
+    fn to_json(&self) -> serde_json::Result<Option<serde_json::Value>> {
+        let json = serde_json::to_value(&self.0)?;
+        Ok(Some(json).filter(|j| !j.is_null()))
+    }
+}
+
+impl<T: DeserializeOwned> AsJson<T> {
+    /// reads the value out of the JSON text, NULL is read as JSON `null`
+    #[cfg(feature = "mssql")]
+    fn from_text(text: Option<&str>) -> serde_json::Result<Self> {
+        Ok(AsJson(serde_json::from_str(text.unwrap_or("null"))?))
+    }
+}
+
+#[cfg(any(feature = "sqlite", feature = "postgres", feature = "mysql"))]
+mod sqlx_json {
+    use super::*;
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::types::Json;
+    use sqlx::{Database, Decode, Encode, Type, ValueRef};
+
+    macro_rules! impl_sqlx_json {
+        ($db:ty) => {
+            impl<T> Type<$db> for AsJson<T> {
+                fn type_info() -> <$db as Database>::TypeInfo {
+                    <Json<serde_json::Value> as Type<$db>>::type_info()
+                }
+                fn compatible(ty: &<$db as Database>::TypeInfo) -> bool {
+                    <Json<serde_json::Value> as Type<$db>>::compatible(ty)
+                        || <&str as Type<$db>>::compatible(ty)
+                }
+            }
+
+            impl<'q, T: Serialize> Encode<'q, $db> for AsJson<T> {
+                fn encode_by_ref(
+                    &self,
+                    buf: &mut <$db as Database>::ArgumentBuffer<'q>,
+                ) -> Result<IsNull, BoxDynError> {
+                    match self.to_json()? {
+                        Some(json) => {
+                            <Json<serde_json::Value> as Encode<'q, $db>>::encode(Json(json), buf)
+                        }
+                        None => Ok(IsNull::Yes),
+                    }
+                }
+            }
+
+            impl<'r, T: 'r + DeserializeOwned> Decode<'r, $db> for AsJson<T> {
+                fn decode(value: <$db as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                    if value.is_null() {
+                        return Ok(AsJson(serde_json::from_str("null")?));
+                    }
+                    let json = <Json<T> as Decode<'r, $db>>::decode(value)?;
+                    Ok(AsJson(json.0))
+                }
+            }
+        };
+    }
+
+    #[cfg(feature = "sqlite")]
+    impl_sqlx_json!(sqlx::Sqlite);
+    #[cfg(feature = "postgres")]
+    impl_sqlx_json!(sqlx::Postgres);
+    #[cfg(feature = "mysql")]
+    impl_sqlx_json!(sqlx::MySql);
+}
+
+#[cfg(feature = "mssql")]
+mod mssql_json {
+    use super::*;
+    use crate::errors::Result;
+    use crate::row::TiberiusDecode;
+    use std::borrow::Cow;
+    use tiberius::{Column, ColumnData, FromSqlOwned, ToSql};
+
+    impl<T: Serialize + Send + Sync> ToSql for AsJson<T> {
+        fn to_sql(&self) -> ColumnData<'_> {
+            // ToSql can't fail, a value that can't be written as JSON is written as NULL
+            let json = self.to_json().ok().flatten();
+            ColumnData::String(json.map(|j| Cow::Owned(j.to_string())))
+        }
+    }
+
+    impl<T: DeserializeOwned> TiberiusDecode for AsJson<T> {
+        fn read(_col: &Column, value: ColumnData<'static>) -> Result<Self> {
+            let text: Option<String> = FromSqlOwned::from_sql_owned(value)?;
+            AsJson::from_text(text.as_deref()).map_err(|err| {
+                tiberius::error::Error::Conversion(format!("Invalid JSON: {}", err).into()).into()
+            })
+        }
+    }
+}
@@ -1,8 +1,24 @@
 pub use crate::errors::Error;
 use crate::errors::Result;
 use async_trait::async_trait;
+#[cfg(any(
+    feature = "sqlite",
+    feature = "postgres",
+    feature = "mysql",
+    feature = "mssql"
+))]
+pub use row::Decodable;
 pub use row::Row;
+use std::time::Duration;
 pub use transaction::Transaction;
+mod custom;
+pub use custom::WeldsType;
+mod enums;
+pub use enums::WeldsEnum;
+#[cfg(feature = "serde")]
+mod json;
+#[cfg(feature = "serde")]
+pub use json::AsJson;
 pub mod errors;
 pub mod row;
 pub mod transaction;
@@ -18,11 +34,22 @@ pub mod postgres;
 #[cfg(feature = "sqlite")]
 pub mod sqlite;
 
+// used by the code written by `#[derive(WeldsEnum)]` and `#[derive(WeldsType)]`
+#[cfg(any(feature = "sqlite", feature = "postgres", feature = "mysql"))]
+#[doc(hidden)]
+pub use sqlx as __sqlx;
+#[cfg(feature = "mssql")]
+#[doc(hidden)]
+pub use tiberius as __tiberius;
+
 pub struct Fetch<'s, 'args, 't> {
     pub sql: &'s str,
     pub params: &'args [&'t (dyn Param + Sync)],
 }
 
+/// The rows of a query, read as the database sends them
+pub type RowStream<'s> = futures_core::stream::BoxStream<'s, Result<Row>>;
+
 #[async_trait]
 /// The common trait for database connections and transactions.
 pub trait Client: Sync + Send {
@@ -38,6 +65,29 @@ pub trait Client: Sync + Send {
         args: &[Fetch<'s, 'args, 't>],
     ) -> Result<Vec<Vec<Row>>>;
 
+    /// Run several `fetch_rows` command on the same connection, with a time limit on each of them.
+    ///
+    /// The limit is taken back off the connection after, even when a command fails.
+    /// Connections that can't limit their commands run them without a limit
+    async fn fetch_many_with_timeout<'s, 'args, 't>(
+        &self,
+        args: &[Fetch<'s, 'args, 't>],
+        _timeout: Duration,
+    ) -> Result<Vec<Vec<Row>>> {
+        self.fetch_many(args).await
+    }
+
+    /// Runs SQL and streams back the rows without collecting them into memory.
+    ///
+    /// Returns None when the connection can't stream, the rows should be paged through instead
+    fn stream_rows<'s>(
+        &'s self,
+        _sql: &'s str,
+        _params: &'s [&'s (dyn Param + Sync)],
+    ) -> Option<RowStream<'s>> {
+        None
+    }
+
     // Returns what syntax (dialect) of SQL the backend is expecting
     fn syntax(&self) -> Syntax;
 }
@@ -99,6 +149,27 @@ pub trait TransactStart {
 // This code is scripted out cuz writing it for all the features to be to much
 mod params;
 pub use params::Param;
+#[cfg(any(
+    feature = "sqlite",
+    feature = "postgres",
+    feature = "mysql",
+    feature = "mssql"
+))]
+mod literal;
+#[cfg(any(
+    feature = "sqlite",
+    feature = "postgres",
+    feature = "mysql",
+    feature = "mssql"
+))]
+pub use literal::debug_literal;
+#[cfg(any(
+    feature = "sqlite",
+    feature = "postgres",
+    feature = "mysql",
+    feature = "mssql"
+))]
+mod timeout;
 
 pub struct ExecuteResult {
     pub(crate) rows_affected: u64,
@@ -111,6 +182,7 @@ impl ExecuteResult {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Syntax {
     Mysql,
     Postgres,
@@ -0,0 +1,199 @@
+use crate::{Param, Syntax};
+
+// ******************************************************************************************
+// Writes params as SQL literals so a statement can be read with its values in place.
+// This is for debugging only, the params are always sent to the database on their own
+// ******************************************************************************************
+
+/// Writes a param the way it would be typed into SQL. I.E. `'bob'` or `42`
+///
+/// Returns None if the value can't be written out for the syntax,
+/// or the connection for the syntax isn't enabled.
+pub fn debug_literal(syntax: Syntax, param: &(dyn Param + Sync)) -> Option<String> {
+    match syntax {
+        #[cfg(feature = "sqlite")]
+        Syntax::Sqlite => crate::sqlite::SqliteParam::debug_literal(param),
+        #[cfg(feature = "postgres")]
+        Syntax::Postgres => crate::postgres::PostgresParam::debug_literal(param),
+        #[cfg(feature = "mysql")]
+        Syntax::Mysql => crate::mysql::MysqlParam::debug_literal(param),
+        #[cfg(feature = "mssql")]
+        Syntax::Mssql => crate::mssql::MssqlParam::debug_literal(param),
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
+
+pub(crate) const NULL: &str = "NULL";
+
+/// quotes text, doubling up the quotes inside it
+pub(crate) fn quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
+
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect()
+}
+
+/// writes the date `days` after 1970-01-01 as `YYYY-MM-DD`
+#[cfg(feature = "postgres")]
+pub(crate) fn date(days: i64) -> String {
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// writes the date and time `micros` after 1970-01-01 00:00:00 as `YYYY-MM-DD HH:MM:SS.ffffff`
+#[cfg(feature = "postgres")]
+pub(crate) fn datetime(micros: i64) -> String {
+    let secs = micros.div_euclid(1_000_000);
+    let fraction = micros.rem_euclid(1_000_000);
+    let time = secs.rem_euclid(86_400);
+    format!(
+        "{} {:02}:{:02}:{:02}.{:06}",
+        date(secs.div_euclid(86_400)),
+        time / 3600,
+        time % 3600 / 60,
+        time % 60,
+        fraction
+    )
+}
+
+/// The number of days from 1970-01-01 to 2000-01-01, where postgres counts its dates from
+#[cfg(feature = "postgres")]
+const PG_EPOCH_DAYS: i64 = 10_957;
+
+/// Reads a value out of the binary format postgres is sent params in
+#[cfg(feature = "postgres")]
+pub(crate) fn postgres(type_name: &str, bytes: &[u8]) -> Option<String> {
+    let literal = match type_name {
+        "BOOL" => match bytes.first()? {
+            0 => "false".to_owned(),
+            _ => "true".to_owned(),
+        },
+        "INT2" => i16::from_be_bytes(bytes.try_into().ok()?).to_string(),
+        "INT4" => i32::from_be_bytes(bytes.try_into().ok()?).to_string(),
+        "INT8" => i64::from_be_bytes(bytes.try_into().ok()?).to_string(),
+        "FLOAT4" => f32::from_be_bytes(bytes.try_into().ok()?).to_string(),
+        "FLOAT8" => f64::from_be_bytes(bytes.try_into().ok()?).to_string(),
+        "TEXT" | "VARCHAR" | "BPCHAR" | "NAME" | "JSON" => quote(std::str::from_utf8(bytes).ok()?),
+        // JSONB is sent with a version number in front of the text
+        "JSONB" => quote(std::str::from_utf8(bytes.get(1..)?).ok()?),
+        "BYTEA" => format!("'\\x{}'", hex(bytes).to_lowercase()),
+        "UUID" => quote(&uuid(bytes)?),
+        "DATE" => {
+            let days = i32::from_be_bytes(bytes.try_into().ok()?) as i64;
+            quote(&date(days + PG_EPOCH_DAYS))
+        }
+        "TIMESTAMP" | "TIMESTAMPTZ" => {
+            let micros = i64::from_be_bytes(bytes.try_into().ok()?);
+            let text = datetime(micros + PG_EPOCH_DAYS * 86_400_000_000);
+            match type_name {
+                "TIMESTAMPTZ" => quote(&format!("{}+00", text)),
+                _ => quote(&text),
+            }
+        }
+        _ => return None,
+    };
+    Some(literal)
+}
+
+#[cfg(feature = "postgres")]
+fn uuid(bytes: &[u8]) -> Option<String> {
+    if bytes.len() != 16 {
+        return None;
+    }
+    let hex = hex(bytes).to_lowercase();
+    Some(format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    ))
+}
+
+/// Reads a value out of the binary format mysql is sent params in
+#[cfg(feature = "mysql")]
+pub(crate) fn mysql(type_name: &str, bytes: &[u8]) -> Option<String> {
+    let literal = match type_name {
+        "BOOLEAN" => match bytes.first()? {
+            0 => "false".to_owned(),
+            _ => "true".to_owned(),
+        },
+        "TINYINT" => i8::from_le_bytes(bytes.try_into().ok()?).to_string(),
+        "SMALLINT" => i16::from_le_bytes(bytes.try_into().ok()?).to_string(),
+        "INT" => i32::from_le_bytes(bytes.try_into().ok()?).to_string(),
+        "BIGINT" => i64::from_le_bytes(bytes.try_into().ok()?).to_string(),
+        "TINYINT UNSIGNED" => u8::from_le_bytes(bytes.try_into().ok()?).to_string(),
+        "SMALLINT UNSIGNED" => u16::from_le_bytes(bytes.try_into().ok()?).to_string(),
+        "INT UNSIGNED" => u32::from_le_bytes(bytes.try_into().ok()?).to_string(),
+        "BIGINT UNSIGNED" => u64::from_le_bytes(bytes.try_into().ok()?).to_string(),
+        "FLOAT" => f32::from_le_bytes(bytes.try_into().ok()?).to_string(),
+        "DOUBLE" => f64::from_le_bytes(bytes.try_into().ok()?).to_string(),
+        "CHAR" | "VARCHAR" | "TEXT" | "TINYTEXT" | "MEDIUMTEXT" | "LONGTEXT" | "JSON"
+        | "DECIMAL" | "ENUM" => quote(std::str::from_utf8(lenenc(bytes)?).ok()?),
+        "BINARY" | "VARBINARY" | "TINYBLOB" | "BLOB" | "MEDIUMBLOB" | "LONGBLOB" => {
+            format!("X'{}'", hex(lenenc(bytes)?))
+        }
+        "DATE" | "DATETIME" | "TIMESTAMP" => quote(&mysql_datetime(bytes)?),
+        _ => return None,
+    };
+    Some(literal)
+}
+
+/// reads the value out from behind its length
+#[cfg(feature = "mysql")]
+fn lenenc(bytes: &[u8]) -> Option<&[u8]> {
+    let (len, start) = match *bytes.first()? {
+        0xfc => (
+            u16::from_le_bytes(bytes.get(1..3)?.try_into().ok()?) as usize,
+            3,
+        ),
+        0xfd => {
+            let mut len = [0; 4];
+            len[..3].copy_from_slice(bytes.get(1..4)?);
+            (u32::from_le_bytes(len) as usize, 4)
+        }
+        0xfe => (
+            u64::from_le_bytes(bytes.get(1..9)?.try_into().ok()?) as usize,
+            9,
+        ),
+        len => (len as usize, 1),
+    };
+    bytes.get(start..start + len)
+}
+
+/// dates are sent as their length followed by each part of the date
+#[cfg(feature = "mysql")]
+fn mysql_datetime(bytes: &[u8]) -> Option<String> {
+    let len = *bytes.first()? as usize;
+    let parts = bytes.get(1..1 + len)?;
+    let year = u16::from_le_bytes(parts.get(0..2)?.try_into().ok()?);
+    let date = format!("{:04}-{:02}-{:02}", year, parts.get(2)?, parts.get(3)?);
+    if len == 4 {
+        return Some(date);
+    }
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        parts.get(4)?,
+        parts.get(5)?,
+        parts.get(6)?
+    );
+    match parts.get(7..11) {
+        Some(micros) => {
+            let micros = u32::from_le_bytes(micros.try_into().ok()?);
+            Some(format!("{} {}.{:06}", date, time, micros))
+        }
+        None => Some(format!("{} {}", date, time)),
+    }
+}
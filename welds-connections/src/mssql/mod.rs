@@ -3,14 +3,17 @@ use super::Row;
 use super::TransactStart;
 use super::{Client, Param};
 use crate::errors::Result;
+use crate::timeout;
 use crate::ExecuteResult;
 use async_trait::async_trait;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tokio::sync::oneshot;
 
+use crate::literal;
 use bb8::Pool;
 use bb8_tiberius::ConnectionManager;
-use tiberius::ToSql;
+use tiberius::{ColumnData, ToSql};
 
 pub(crate) mod transaction;
 
@@ -110,27 +113,29 @@ impl Client for MssqlClient {
         &self,
         args: &[crate::Fetch<'s, 'args, 't>],
     ) -> Result<Vec<Vec<Row>>> {
-        let mut resultset = Vec::default();
         let mut conn = self.pool.get().await?;
-        for fetch in args {
-            let sql = fetch.sql;
-            let params = fetch.params;
-            let mut args: Vec<&dyn ToSql> = Vec::new();
-            for &p in params {
-                args = MssqlParam::add_param(p, args);
-            }
-            log::debug!("MSSQL_QUERY: {}", sql);
-            let stream = conn.query(sql, &args).await?;
-            let mssql_rows = stream.into_results().await?;
-            let mut all = Vec::default();
-            for batch in mssql_rows {
-                for r in batch {
-                    all.push(Row::from(r))
-                }
+        fetch_many_on(&mut conn, args).await
+    }
+
+    async fn fetch_many_with_timeout<'s, 'args, 't>(
+        &self,
+        args: &[crate::Fetch<'s, 'args, 't>],
+        timeout: Duration,
+    ) -> Result<Vec<Vec<Row>>> {
+        let mut conn = self.pool.get().await?;
+        let limit = timeout::limit(crate::Syntax::Mssql, timeout);
+        conn.execute(limit, &[]).await?;
+        let resultset = fetch_many_on(&mut conn, args).await;
+        let reset = timeout::reset(crate::Syntax::Mssql, None);
+        if conn.execute(reset, &[]).await.is_err() {
+            // never hand a connection that still has the limit back to the pool.
+            // bb8 can't take a connection out of the pool, a new one takes its place
+            match self.pool.dedicated_connection().await {
+                Ok(fresh) => *conn = fresh,
+                Err(err) => log::warn!("MSSQL: could not replace a timed out connection: {}", err),
             }
-            resultset.push(all)
         }
-        Ok(resultset)
+        resultset
     }
 
     fn syntax(&self) -> crate::Syntax {
@@ -138,10 +143,40 @@ impl Client for MssqlClient {
     }
 }
 
+/// runs the fetches one after the other on a single connection
+async fn fetch_many_on(
+    conn: &mut DbConn,
+    args: &[crate::Fetch<'_, '_, '_>],
+) -> Result<Vec<Vec<Row>>> {
+    let mut resultset = Vec::default();
+    for fetch in args {
+        let sql = fetch.sql;
+        let params = fetch.params;
+        let mut args: Vec<&dyn ToSql> = Vec::new();
+        for &p in params {
+            args = MssqlParam::add_param(p, args);
+        }
+        log::debug!("MSSQL_QUERY: {}", sql);
+        let stream = conn.query(sql, &args).await?;
+        let mssql_rows = stream.into_results().await?;
+        let mut all = Vec::default();
+        for batch in mssql_rows {
+            for r in batch {
+                all.push(Row::from(r))
+            }
+        }
+        resultset.push(all)
+    }
+    Ok(resultset)
+}
+
 //impl<T> Param for T where T: MssqlParam {}
 
 pub trait MssqlParam {
     fn add_param<'a>(&'a self, args: Vec<&'a dyn ToSql>) -> Vec<&'a dyn ToSql>;
+
+    /// The value written as a SQL literal, for debugging
+    fn debug_literal(&self) -> Option<String>;
 }
 
 impl<T> MssqlParam for T
@@ -152,4 +187,22 @@ where
         args.push(self);
         args
     }
+
+    fn debug_literal(&self) -> Option<String> {
+        let literal = match self.to_sql() {
+            ColumnData::U8(x) => x.map(|x| x.to_string()),
+            ColumnData::I16(x) => x.map(|x| x.to_string()),
+            ColumnData::I32(x) => x.map(|x| x.to_string()),
+            ColumnData::I64(x) => x.map(|x| x.to_string()),
+            ColumnData::F32(x) => x.map(|x| x.to_string()),
+            ColumnData::F64(x) => x.map(|x| x.to_string()),
+            ColumnData::Bit(x) => x.map(|x| u8::from(x).to_string()),
+            ColumnData::String(x) => x.map(|x| format!("N{}", literal::quote(&x))),
+            ColumnData::Guid(x) => x.map(|x| literal::quote(&x.to_string())),
+            ColumnData::Binary(x) => x.map(|x| format!("0x{}", literal::hex(&x))),
+            ColumnData::Numeric(x) => x.map(|x| x.to_string()),
+            _ => return None,
+        };
+        Some(literal.unwrap_or_else(|| literal::NULL.to_owned()))
+    }
 }
@@ -160,7 +160,7 @@ impl<'t> Client for MssqlTransaction<'t> {
     }
 }
 
-async fn fetch_rows_inner<'t>(
+async fn fetch_rows_inner(
     conn: &mut DbConn,
     sql: &str,
     params: &[&(dyn Param + Sync)],
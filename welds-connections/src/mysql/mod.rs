@@ -1,14 +1,21 @@
 use super::transaction::{TransT, Transaction};
 use super::Row;
 use super::TransactStart;
-use super::{Client, Param};
+use super::{Client, Param, RowStream};
 use crate::errors::Result;
+use crate::literal;
+use crate::timeout;
 use crate::ExecuteResult;
 use async_trait::async_trait;
+use futures_util::StreamExt;
+use sqlx::encode::IsNull;
 use sqlx::mysql::MySqlArguments;
 use sqlx::query::Query;
+use sqlx::Executor;
+use sqlx::TypeInfo;
 use sqlx::{MySql, MySqlPool};
 use std::sync::Arc;
+use std::time::Duration;
 
 pub struct MysqlClient {
     pool: Arc<MySqlPool>,
@@ -74,20 +81,38 @@ impl Client for MysqlClient {
         &self,
         fetches: &[crate::Fetch<'s, 'args, 't>],
     ) -> Result<Vec<Vec<Row>>> {
-        let mut datasets = Vec::default();
         let mut conn = self.pool.acquire().await?;
-        for fetch in fetches {
-            let sql = fetch.sql;
-            let params = fetch.params;
-            let mut query = sqlx::query::<MySql>(sql);
-            for param in params {
-                query = MysqlParam::add_param(*param, query);
-            }
-            let mut raw_rows = query.fetch_all(&mut *conn).await?;
-            let rows: Vec<Row> = raw_rows.drain(..).map(Row::from).collect();
-            datasets.push(rows);
+        fetch_many_on(&mut conn, fetches).await
+    }
+
+    async fn fetch_many_with_timeout<'s, 'args, 't>(
+        &self,
+        fetches: &[crate::Fetch<'s, 'args, 't>],
+        timeout: Duration,
+    ) -> Result<Vec<Vec<Row>>> {
+        let mut conn = self.pool.acquire().await?;
+        let limit = timeout::limit(crate::Syntax::Mysql, timeout);
+        conn.execute(limit.as_str()).await?;
+        let datasets = fetch_many_on(&mut conn, fetches).await;
+        let reset = timeout::reset(crate::Syntax::Mysql, None);
+        if conn.execute(reset.as_str()).await.is_err() {
+            // never hand a connection that still has the limit back to the pool
+            drop(conn.detach());
+        }
+        datasets
+    }
+
+    fn stream_rows<'s>(
+        &'s self,
+        sql: &'s str,
+        params: &'s [&'s (dyn Param + Sync)],
+    ) -> Option<RowStream<'s>> {
+        let mut query = sqlx::query::<MySql>(sql);
+        for param in params {
+            query = MysqlParam::add_param(*param, query);
         }
-        Ok(datasets)
+        let rows = query.fetch(&*self.pool).map(|row| Ok(Row::from(row?)));
+        Some(Box::pin(rows))
     }
 
     fn syntax(&self) -> crate::Syntax {
@@ -95,11 +120,34 @@ impl Client for MysqlClient {
     }
 }
 
+/// runs the fetches one after the other on a single connection
+async fn fetch_many_on(
+    conn: &mut sqlx::MySqlConnection,
+    fetches: &[crate::Fetch<'_, '_, '_>],
+) -> Result<Vec<Vec<Row>>> {
+    let mut datasets = Vec::default();
+    for fetch in fetches {
+        let sql = fetch.sql;
+        let params = fetch.params;
+        let mut query = sqlx::query::<MySql>(sql);
+        for param in params {
+            query = MysqlParam::add_param(*param, query);
+        }
+        let mut raw_rows = query.fetch_all(&mut *conn).await?;
+        let rows: Vec<Row> = raw_rows.drain(..).map(Row::from).collect();
+        datasets.push(rows);
+    }
+    Ok(datasets)
+}
+
 pub trait MysqlParam {
     fn add_param<'q>(
         &'q self,
         query: Query<'q, MySql, MySqlArguments>,
     ) -> Query<'q, MySql, MySqlArguments>;
+
+    /// The value written as a SQL literal, for debugging
+    fn debug_literal(&self) -> Option<String>;
 }
 
 impl<T> MysqlParam for T
@@ -113,4 +161,13 @@ where
     ) -> Query<'q, MySql, MySqlArguments> {
         query.bind(self)
     }
+
+    fn debug_literal(&self) -> Option<String> {
+        let mut buf = Vec::default();
+        let ty = Encode::<MySql>::produces(self).unwrap_or_else(T::type_info);
+        if let IsNull::Yes = Encode::<MySql>::encode_by_ref(self, &mut buf).ok()? {
+            return Some(literal::NULL.to_owned());
+        }
+        literal::mysql(ty.name(), &buf)
+    }
 }
@@ -1,9 +1,11 @@
 use super::Row;
 use super::{Client, Param};
 use crate::errors::Result;
+use crate::timeout;
 use crate::{ExecuteResult, Syntax};
 use async_trait::async_trait;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 // This is a version of a client that does nothing.
 // It is used for testing
@@ -96,6 +98,34 @@ impl Client for NoopClient {
         Ok(Vec::default())
     }
 
+    async fn fetch_many_with_timeout<'s, 'args, 'i>(
+        &self,
+        fetches: &[crate::Fetch<'s, 'args, 'i>],
+        timeout: Duration,
+    ) -> Result<Vec<Vec<Row>>> {
+        let mut total = 0;
+        let mut sqls = Vec::default();
+        // the noop has no limit to read, it is reset to the default
+        sqls.push(timeout::limit(self.syntax, timeout));
+        for fetch in fetches {
+            sqls.push(fetch.sql.to_string());
+            total += fetch.params.len()
+        }
+        sqls.push(timeout::reset(self.syntax, None));
+
+        // save off the sql
+        let lock = self.last_sql.clone();
+        let mut mutex = lock.lock().unwrap();
+        *mutex = Some(sqls.join(";"));
+
+        // save off the args count
+        let lock = self.args_count.clone();
+        let mut mutex = lock.lock().unwrap();
+        *mutex = Some(total as u64);
+
+        Ok(Vec::default())
+    }
+
     fn syntax(&self) -> crate::Syntax {
         self.syntax
     }
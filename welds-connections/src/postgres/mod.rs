@@ -1,14 +1,21 @@
 use super::transaction::{TransT, Transaction};
 use super::Row;
 use super::TransactStart;
-use super::{Client, Param};
+use super::{Client, Param, RowStream};
 use crate::errors::Result;
+use crate::literal;
+use crate::timeout;
 use crate::ExecuteResult;
 use async_trait::async_trait;
-use sqlx::postgres::PgArguments;
+use futures_util::StreamExt;
+use sqlx::encode::IsNull;
+use sqlx::postgres::{PgArgumentBuffer, PgArguments};
 use sqlx::query::Query;
+use sqlx::Executor;
+use sqlx::TypeInfo;
 use sqlx::{PgPool, Postgres};
 use std::sync::Arc;
+use std::time::Duration;
 
 #[derive(Debug, Clone)]
 pub struct PostgresClient {
@@ -75,20 +82,38 @@ impl Client for PostgresClient {
         &self,
         fetches: &[crate::Fetch<'s, 'args, 't>],
     ) -> Result<Vec<Vec<Row>>> {
-        let mut datasets = Vec::default();
         let mut conn = self.pool.acquire().await?;
-        for fetch in fetches {
-            let sql = fetch.sql;
-            let params = fetch.params;
-            let mut query = sqlx::query::<Postgres>(sql);
-            for param in params {
-                query = PostgresParam::add_param(*param, query);
-            }
-            let mut raw_rows = query.fetch_all(&mut *conn).await?;
-            let rows: Vec<Row> = raw_rows.drain(..).map(Row::from).collect();
-            datasets.push(rows);
+        fetch_many_on(&mut conn, fetches).await
+    }
+
+    async fn fetch_many_with_timeout<'s, 'args, 't>(
+        &self,
+        fetches: &[crate::Fetch<'s, 'args, 't>],
+        timeout: Duration,
+    ) -> Result<Vec<Vec<Row>>> {
+        let mut conn = self.pool.acquire().await?;
+        let limit = timeout::limit(crate::Syntax::Postgres, timeout);
+        conn.execute(limit.as_str()).await?;
+        let datasets = fetch_many_on(&mut conn, fetches).await;
+        let reset = timeout::reset(crate::Syntax::Postgres, None);
+        if conn.execute(reset.as_str()).await.is_err() {
+            // never hand a connection that still has the limit back to the pool
+            drop(conn.detach());
+        }
+        datasets
+    }
+
+    fn stream_rows<'s>(
+        &'s self,
+        sql: &'s str,
+        params: &'s [&'s (dyn Param + Sync)],
+    ) -> Option<RowStream<'s>> {
+        let mut query = sqlx::query::<Postgres>(sql);
+        for param in params {
+            query = PostgresParam::add_param(*param, query);
         }
-        Ok(datasets)
+        let rows = query.fetch(&*self.pool).map(|row| Ok(Row::from(row?)));
+        Some(Box::pin(rows))
     }
 
     fn syntax(&self) -> crate::Syntax {
@@ -96,11 +121,34 @@ impl Client for PostgresClient {
     }
 }
 
+/// runs the fetches one after the other on a single connection
+async fn fetch_many_on(
+    conn: &mut sqlx::PgConnection,
+    fetches: &[crate::Fetch<'_, '_, '_>],
+) -> Result<Vec<Vec<Row>>> {
+    let mut datasets = Vec::default();
+    for fetch in fetches {
+        let sql = fetch.sql;
+        let params = fetch.params;
+        let mut query = sqlx::query::<Postgres>(sql);
+        for param in params {
+            query = PostgresParam::add_param(*param, query);
+        }
+        let mut raw_rows = query.fetch_all(&mut *conn).await?;
+        let rows: Vec<Row> = raw_rows.drain(..).map(Row::from).collect();
+        datasets.push(rows);
+    }
+    Ok(datasets)
+}
+
 pub trait PostgresParam {
     fn add_param<'q>(
         &'q self,
         query: Query<'q, Postgres, PgArguments>,
     ) -> Query<'q, Postgres, PgArguments>;
+
+    /// The value written as a SQL literal, for debugging
+    fn debug_literal(&self) -> Option<String>;
 }
 
 impl<T> PostgresParam for T
@@ -114,4 +162,13 @@ where
     ) -> Query<'q, Postgres, PgArguments> {
         query.bind(self)
     }
+
+    fn debug_literal(&self) -> Option<String> {
+        let mut buf = PgArgumentBuffer::default();
+        let ty = Encode::<Postgres>::produces(self).unwrap_or_else(T::type_info);
+        if let IsNull::Yes = Encode::<Postgres>::encode_by_ref(self, &mut buf).ok()? {
+            return Some(literal::NULL.to_owned());
+        }
+        literal::postgres(ty.name(), &buf)
+    }
 }
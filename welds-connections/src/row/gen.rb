@@ -42,6 +42,13 @@ impl Row {
     }
 }
 
+/// A value that can be read out of a `Row` with `get`
+#{cfg}
+pub trait Decodable: #{wheres} {}
+
+#{cfg}
+impl<T> Decodable for T where T: #{wheres} {}
+
 |
 
 
@@ -22,7 +22,7 @@ use tiberius::Row as MssqlRow;
 #[cfg(feature = "mssql")]
 mod mssql_row_wrapper;
 #[cfg(feature = "mssql")]
-pub use mssql_row_wrapper::MssqlRowWrapper;
+pub use mssql_row_wrapper::{MssqlRowWrapper, TiberiusDecode};
 
 /// all kinds of rows
 pub enum RowInner {
@@ -112,8 +112,12 @@ impl Row {
     }
 }
 
-#[cfg(feature = "mssql")]
-use mssql_row_wrapper::TiberiusDecode;
-
 // This code is scripted out cuz writing it for all the features to be to much
 mod row_gen;
+#[cfg(any(
+    feature = "sqlite",
+    feature = "postgres",
+    feature = "mysql",
+    feature = "mssql"
+))]
+pub use row_gen::Decodable;
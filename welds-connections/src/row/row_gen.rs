@@ -43,6 +43,23 @@ impl Row {
     }
 }
 
+/// A value that can be read out of a `Row` with `get`
+#[cfg(all(
+    feature = "sqlite",
+    not(feature = "postgres"),
+    not(feature = "mysql"),
+    not(feature = "mssql")
+))]
+pub trait Decodable: for<'r> Decode<'r, sqlx::Sqlite> + Type<sqlx::Sqlite> {}
+
+#[cfg(all(
+    feature = "sqlite",
+    not(feature = "postgres"),
+    not(feature = "mysql"),
+    not(feature = "mssql")
+))]
+impl<T> Decodable for T where T: for<'r> Decode<'r, sqlx::Sqlite> + Type<sqlx::Sqlite> {}
+
 #[cfg(all(
     feature = "postgres",
     not(feature = "sqlite"),
@@ -83,6 +100,23 @@ impl Row {
     }
 }
 
+/// A value that can be read out of a `Row` with `get`
+#[cfg(all(
+    feature = "postgres",
+    not(feature = "sqlite"),
+    not(feature = "mysql"),
+    not(feature = "mssql")
+))]
+pub trait Decodable: for<'r> Decode<'r, sqlx::Postgres> + Type<sqlx::Postgres> {}
+
+#[cfg(all(
+    feature = "postgres",
+    not(feature = "sqlite"),
+    not(feature = "mysql"),
+    not(feature = "mssql")
+))]
+impl<T> Decodable for T where T: for<'r> Decode<'r, sqlx::Postgres> + Type<sqlx::Postgres> {}
+
 #[cfg(all(
     feature = "mysql",
     not(feature = "sqlite"),
@@ -123,6 +157,23 @@ impl Row {
     }
 }
 
+/// A value that can be read out of a `Row` with `get`
+#[cfg(all(
+    feature = "mysql",
+    not(feature = "sqlite"),
+    not(feature = "postgres"),
+    not(feature = "mssql")
+))]
+pub trait Decodable: for<'r> Decode<'r, sqlx::MySql> + Type<sqlx::MySql> {}
+
+#[cfg(all(
+    feature = "mysql",
+    not(feature = "sqlite"),
+    not(feature = "postgres"),
+    not(feature = "mssql")
+))]
+impl<T> Decodable for T where T: for<'r> Decode<'r, sqlx::MySql> + Type<sqlx::MySql> {}
+
 #[cfg(all(
     feature = "mssql",
     not(feature = "sqlite"),
@@ -163,6 +214,23 @@ impl Row {
     }
 }
 
+/// A value that can be read out of a `Row` with `get`
+#[cfg(all(
+    feature = "mssql",
+    not(feature = "sqlite"),
+    not(feature = "postgres"),
+    not(feature = "mysql")
+))]
+pub trait Decodable: TiberiusDecode {}
+
+#[cfg(all(
+    feature = "mssql",
+    not(feature = "sqlite"),
+    not(feature = "postgres"),
+    not(feature = "mysql")
+))]
+impl<T> Decodable for T where T: TiberiusDecode {}
+
 #[cfg(all(
     feature = "sqlite",
     feature = "postgres",
@@ -209,6 +277,35 @@ impl Row {
     }
 }
 
+/// A value that can be read out of a `Row` with `get`
+#[cfg(all(
+    feature = "sqlite",
+    feature = "postgres",
+    not(feature = "mysql"),
+    not(feature = "mssql")
+))]
+pub trait Decodable:
+    for<'r> Decode<'r, sqlx::Sqlite>
+    + Type<sqlx::Sqlite>
+    + for<'r> Decode<'r, sqlx::Postgres>
+    + Type<sqlx::Postgres>
+{
+}
+
+#[cfg(all(
+    feature = "sqlite",
+    feature = "postgres",
+    not(feature = "mysql"),
+    not(feature = "mssql")
+))]
+impl<T> Decodable for T where
+    T: for<'r> Decode<'r, sqlx::Sqlite>
+        + Type<sqlx::Sqlite>
+        + for<'r> Decode<'r, sqlx::Postgres>
+        + Type<sqlx::Postgres>
+{
+}
+
 #[cfg(all(
     feature = "sqlite",
     feature = "mysql",
@@ -255,6 +352,35 @@ impl Row {
     }
 }
 
+/// A value that can be read out of a `Row` with `get`
+#[cfg(all(
+    feature = "sqlite",
+    feature = "mysql",
+    not(feature = "postgres"),
+    not(feature = "mssql")
+))]
+pub trait Decodable:
+    for<'r> Decode<'r, sqlx::Sqlite>
+    + Type<sqlx::Sqlite>
+    + for<'r> Decode<'r, sqlx::MySql>
+    + Type<sqlx::MySql>
+{
+}
+
+#[cfg(all(
+    feature = "sqlite",
+    feature = "mysql",
+    not(feature = "postgres"),
+    not(feature = "mssql")
+))]
+impl<T> Decodable for T where
+    T: for<'r> Decode<'r, sqlx::Sqlite>
+        + Type<sqlx::Sqlite>
+        + for<'r> Decode<'r, sqlx::MySql>
+        + Type<sqlx::MySql>
+{
+}
+
 #[cfg(all(
     feature = "sqlite",
     feature = "mssql",
@@ -295,6 +421,29 @@ impl Row {
     }
 }
 
+/// A value that can be read out of a `Row` with `get`
+#[cfg(all(
+    feature = "sqlite",
+    feature = "mssql",
+    not(feature = "postgres"),
+    not(feature = "mysql")
+))]
+pub trait Decodable:
+    for<'r> Decode<'r, sqlx::Sqlite> + Type<sqlx::Sqlite> + TiberiusDecode
+{
+}
+
+#[cfg(all(
+    feature = "sqlite",
+    feature = "mssql",
+    not(feature = "postgres"),
+    not(feature = "mysql")
+))]
+impl<T> Decodable for T where
+    T: for<'r> Decode<'r, sqlx::Sqlite> + Type<sqlx::Sqlite> + TiberiusDecode
+{
+}
+
 #[cfg(all(
     feature = "postgres",
     feature = "mysql",
@@ -341,6 +490,35 @@ impl Row {
     }
 }
 
+/// A value that can be read out of a `Row` with `get`
+#[cfg(all(
+    feature = "postgres",
+    feature = "mysql",
+    not(feature = "sqlite"),
+    not(feature = "mssql")
+))]
+pub trait Decodable:
+    for<'r> Decode<'r, sqlx::Postgres>
+    + Type<sqlx::Postgres>
+    + for<'r> Decode<'r, sqlx::MySql>
+    + Type<sqlx::MySql>
+{
+}
+
+#[cfg(all(
+    feature = "postgres",
+    feature = "mysql",
+    not(feature = "sqlite"),
+    not(feature = "mssql")
+))]
+impl<T> Decodable for T where
+    T: for<'r> Decode<'r, sqlx::Postgres>
+        + Type<sqlx::Postgres>
+        + for<'r> Decode<'r, sqlx::MySql>
+        + Type<sqlx::MySql>
+{
+}
+
 #[cfg(all(
     feature = "postgres",
     feature = "mssql",
@@ -381,6 +559,29 @@ impl Row {
     }
 }
 
+/// A value that can be read out of a `Row` with `get`
+#[cfg(all(
+    feature = "postgres",
+    feature = "mssql",
+    not(feature = "sqlite"),
+    not(feature = "mysql")
+))]
+pub trait Decodable:
+    for<'r> Decode<'r, sqlx::Postgres> + Type<sqlx::Postgres> + TiberiusDecode
+{
+}
+
+#[cfg(all(
+    feature = "postgres",
+    feature = "mssql",
+    not(feature = "sqlite"),
+    not(feature = "mysql")
+))]
+impl<T> Decodable for T where
+    T: for<'r> Decode<'r, sqlx::Postgres> + Type<sqlx::Postgres> + TiberiusDecode
+{
+}
+
 #[cfg(all(
     feature = "mysql",
     feature = "mssql",
@@ -421,6 +622,24 @@ impl Row {
     }
 }
 
+/// A value that can be read out of a `Row` with `get`
+#[cfg(all(
+    feature = "mysql",
+    feature = "mssql",
+    not(feature = "sqlite"),
+    not(feature = "postgres")
+))]
+pub trait Decodable: for<'r> Decode<'r, sqlx::MySql> + Type<sqlx::MySql> + TiberiusDecode {}
+
+#[cfg(all(
+    feature = "mysql",
+    feature = "mssql",
+    not(feature = "sqlite"),
+    not(feature = "postgres")
+))]
+impl<T> Decodable for T where T: for<'r> Decode<'r, sqlx::MySql> + Type<sqlx::MySql> + TiberiusDecode
+{}
+
 #[cfg(all(
     feature = "sqlite",
     feature = "postgres",
@@ -471,6 +690,39 @@ impl Row {
     }
 }
 
+/// A value that can be read out of a `Row` with `get`
+#[cfg(all(
+    feature = "sqlite",
+    feature = "postgres",
+    feature = "mysql",
+    not(feature = "mssql")
+))]
+pub trait Decodable:
+    for<'r> Decode<'r, sqlx::Sqlite>
+    + Type<sqlx::Sqlite>
+    + for<'r> Decode<'r, sqlx::Postgres>
+    + Type<sqlx::Postgres>
+    + for<'r> Decode<'r, sqlx::MySql>
+    + Type<sqlx::MySql>
+{
+}
+
+#[cfg(all(
+    feature = "sqlite",
+    feature = "postgres",
+    feature = "mysql",
+    not(feature = "mssql")
+))]
+impl<T> Decodable for T where
+    T: for<'r> Decode<'r, sqlx::Sqlite>
+        + Type<sqlx::Sqlite>
+        + for<'r> Decode<'r, sqlx::Postgres>
+        + Type<sqlx::Postgres>
+        + for<'r> Decode<'r, sqlx::MySql>
+        + Type<sqlx::MySql>
+{
+}
+
 #[cfg(all(
     feature = "sqlite",
     feature = "postgres",
@@ -519,6 +771,37 @@ impl Row {
     }
 }
 
+/// A value that can be read out of a `Row` with `get`
+#[cfg(all(
+    feature = "sqlite",
+    feature = "postgres",
+    feature = "mssql",
+    not(feature = "mysql")
+))]
+pub trait Decodable:
+    for<'r> Decode<'r, sqlx::Sqlite>
+    + Type<sqlx::Sqlite>
+    + for<'r> Decode<'r, sqlx::Postgres>
+    + Type<sqlx::Postgres>
+    + TiberiusDecode
+{
+}
+
+#[cfg(all(
+    feature = "sqlite",
+    feature = "postgres",
+    feature = "mssql",
+    not(feature = "mysql")
+))]
+impl<T> Decodable for T where
+    T: for<'r> Decode<'r, sqlx::Sqlite>
+        + Type<sqlx::Sqlite>
+        + for<'r> Decode<'r, sqlx::Postgres>
+        + Type<sqlx::Postgres>
+        + TiberiusDecode
+{
+}
+
 #[cfg(all(
     feature = "sqlite",
     feature = "mysql",
@@ -567,6 +850,37 @@ impl Row {
     }
 }
 
+/// A value that can be read out of a `Row` with `get`
+#[cfg(all(
+    feature = "sqlite",
+    feature = "mysql",
+    feature = "mssql",
+    not(feature = "postgres")
+))]
+pub trait Decodable:
+    for<'r> Decode<'r, sqlx::Sqlite>
+    + Type<sqlx::Sqlite>
+    + for<'r> Decode<'r, sqlx::MySql>
+    + Type<sqlx::MySql>
+    + TiberiusDecode
+{
+}
+
+#[cfg(all(
+    feature = "sqlite",
+    feature = "mysql",
+    feature = "mssql",
+    not(feature = "postgres")
+))]
+impl<T> Decodable for T where
+    T: for<'r> Decode<'r, sqlx::Sqlite>
+        + Type<sqlx::Sqlite>
+        + for<'r> Decode<'r, sqlx::MySql>
+        + Type<sqlx::MySql>
+        + TiberiusDecode
+{
+}
+
 #[cfg(all(
     feature = "postgres",
     feature = "mysql",
@@ -615,6 +929,37 @@ impl Row {
     }
 }
 
+/// A value that can be read out of a `Row` with `get`
+#[cfg(all(
+    feature = "postgres",
+    feature = "mysql",
+    feature = "mssql",
+    not(feature = "sqlite")
+))]
+pub trait Decodable:
+    for<'r> Decode<'r, sqlx::Postgres>
+    + Type<sqlx::Postgres>
+    + for<'r> Decode<'r, sqlx::MySql>
+    + Type<sqlx::MySql>
+    + TiberiusDecode
+{
+}
+
+#[cfg(all(
+    feature = "postgres",
+    feature = "mysql",
+    feature = "mssql",
+    not(feature = "sqlite")
+))]
+impl<T> Decodable for T where
+    T: for<'r> Decode<'r, sqlx::Postgres>
+        + Type<sqlx::Postgres>
+        + for<'r> Decode<'r, sqlx::MySql>
+        + Type<sqlx::MySql>
+        + TiberiusDecode
+{
+}
+
 #[cfg(all(
     feature = "sqlite",
     feature = "postgres",
@@ -666,3 +1011,38 @@ impl Row {
         }
     }
 }
+
+/// A value that can be read out of a `Row` with `get`
+#[cfg(all(
+    feature = "sqlite",
+    feature = "postgres",
+    feature = "mysql",
+    feature = "mssql"
+))]
+pub trait Decodable:
+    for<'r> Decode<'r, sqlx::Sqlite>
+    + Type<sqlx::Sqlite>
+    + for<'r> Decode<'r, sqlx::Postgres>
+    + Type<sqlx::Postgres>
+    + for<'r> Decode<'r, sqlx::MySql>
+    + Type<sqlx::MySql>
+    + TiberiusDecode
+{
+}
+
+#[cfg(all(
+    feature = "sqlite",
+    feature = "postgres",
+    feature = "mysql",
+    feature = "mssql"
+))]
+impl<T> Decodable for T where
+    T: for<'r> Decode<'r, sqlx::Sqlite>
+        + Type<sqlx::Sqlite>
+        + for<'r> Decode<'r, sqlx::Postgres>
+        + Type<sqlx::Postgres>
+        + for<'r> Decode<'r, sqlx::MySql>
+        + Type<sqlx::MySql>
+        + TiberiusDecode
+{
+}
@@ -3,12 +3,17 @@ use super::Row;
 use super::TransactStart;
 use super::{Client, Param};
 use crate::errors::Result;
+use crate::literal;
+use crate::timeout;
 use crate::ExecuteResult;
 use async_trait::async_trait;
+use sqlx::encode::IsNull;
 use sqlx::query::Query;
-use sqlx::sqlite::SqliteArguments;
-use sqlx::{Acquire, Sqlite, SqlitePool};
+use sqlx::sqlite::{SqliteArgumentValue, SqliteArguments};
+use sqlx::Executor;
+use sqlx::{Sqlite, SqlitePool};
 use std::sync::Arc;
+use std::time::Duration;
 
 pub struct SqliteClient {
     pool: Arc<SqlitePool>,
@@ -74,20 +79,30 @@ impl Client for SqliteClient {
         &self,
         fetches: &[crate::Fetch<'s, 'args, 't>],
     ) -> Result<Vec<Vec<Row>>> {
-        let mut datasets = Vec::default();
         let mut conn = self.pool.acquire().await?;
-        for fetch in fetches {
-            let sql = fetch.sql;
-            let params = fetch.params;
-            let mut query = sqlx::query::<Sqlite>(sql);
-            for param in params {
-                query = SqliteParam::add_param(*param, query);
-            }
-            let mut raw_rows = query.fetch_all(&mut *conn).await?;
-            let rows: Vec<Row> = raw_rows.drain(..).map(Row::from).collect();
-            datasets.push(rows);
+        fetch_many_on(&mut conn, fetches).await
+    }
+
+    async fn fetch_many_with_timeout<'s, 'args, 't>(
+        &self,
+        fetches: &[crate::Fetch<'s, 'args, 't>],
+        timeout: Duration,
+    ) -> Result<Vec<Vec<Row>>> {
+        let mut conn = self.pool.acquire().await?;
+        // put back the busy_timeout the connection had, not the one sqlx connects with
+        let current: Option<i64> = match timeout::current(crate::Syntax::Sqlite) {
+            Some(sql) => Some(sqlx::query_scalar(sql).fetch_one(&mut *conn).await?),
+            None => None,
+        };
+        let limit = timeout::limit(crate::Syntax::Sqlite, timeout);
+        conn.execute(limit.as_str()).await?;
+        let datasets = fetch_many_on(&mut conn, fetches).await;
+        let reset = timeout::reset(crate::Syntax::Sqlite, current);
+        if conn.execute(reset.as_str()).await.is_err() {
+            // never hand a connection that still has the limit back to the pool
+            drop(conn.detach());
         }
-        Ok(datasets)
+        datasets
     }
 
     fn syntax(&self) -> crate::Syntax {
@@ -95,11 +110,34 @@ impl Client for SqliteClient {
     }
 }
 
+/// runs the fetches one after the other on a single connection
+async fn fetch_many_on(
+    conn: &mut sqlx::SqliteConnection,
+    fetches: &[crate::Fetch<'_, '_, '_>],
+) -> Result<Vec<Vec<Row>>> {
+    let mut datasets = Vec::default();
+    for fetch in fetches {
+        let sql = fetch.sql;
+        let params = fetch.params;
+        let mut query = sqlx::query::<Sqlite>(sql);
+        for param in params {
+            query = SqliteParam::add_param(*param, query);
+        }
+        let mut raw_rows = query.fetch_all(&mut *conn).await?;
+        let rows: Vec<Row> = raw_rows.drain(..).map(Row::from).collect();
+        datasets.push(rows);
+    }
+    Ok(datasets)
+}
+
 pub trait SqliteParam {
     fn add_param<'q>(
         &'q self,
         query: Query<'q, Sqlite, SqliteArguments<'q>>,
     ) -> Query<'q, Sqlite, SqliteArguments<'q>>;
+
+    /// The value written as a SQL literal, for debugging
+    fn debug_literal(&self) -> Option<String>;
 }
 
 impl<T> SqliteParam for T
@@ -113,4 +151,20 @@ where
     ) -> Query<'q, Sqlite, SqliteArguments<'q>> {
         query.bind(self)
     }
+
+    fn debug_literal(&self) -> Option<String> {
+        let mut buf = Vec::default();
+        if let IsNull::Yes = Encode::<Sqlite>::encode_by_ref(self, &mut buf).ok()? {
+            return Some(literal::NULL.to_owned());
+        }
+        let literal = match buf.pop()? {
+            SqliteArgumentValue::Null => literal::NULL.to_owned(),
+            SqliteArgumentValue::Text(text) => literal::quote(&text),
+            SqliteArgumentValue::Blob(bytes) => format!("X'{}'", literal::hex(&bytes)),
+            SqliteArgumentValue::Double(x) => x.to_string(),
+            SqliteArgumentValue::Int(x) => x.to_string(),
+            SqliteArgumentValue::Int64(x) => x.to_string(),
+        };
+        Some(literal)
+    }
 }
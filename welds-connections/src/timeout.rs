@@ -0,0 +1,37 @@
+use crate::Syntax;
+use std::time::Duration;
+
+// ******************************************************************************************
+// The statements that put a time limit on the statements of a connection, and take it off.
+// The limit is set on the connection, it must always be reset before it goes back to the pool
+// ******************************************************************************************
+
+/// Sets the time limit. A zero limit is made 1ms, zero turns the limit off on most databases
+pub(crate) fn limit(syntax: Syntax, timeout: Duration) -> String {
+    let ms = timeout.as_millis().max(1);
+    match syntax {
+        Syntax::Postgres => format!("SET statement_timeout = {}", ms),
+        // NOTE: only SELECTs are stopped by max_execution_time
+        Syntax::Mysql => format!("SET SESSION max_execution_time = {}", ms),
+        Syntax::Mssql => format!("SET LOCK_TIMEOUT {}", ms),
+        Syntax::Sqlite => format!("PRAGMA busy_timeout = {}", ms),
+    }
+}
+
+/// Reads the limit the connection has now, for the databases without a DEFAULT to go back to
+pub(crate) fn current(syntax: Syntax) -> Option<&'static str> {
+    match syntax {
+        Syntax::Sqlite => Some("PRAGMA busy_timeout"),
+        _ => None,
+    }
+}
+
+/// Puts the connection back to its default limit, or to the `current` limit it had before
+pub(crate) fn reset(syntax: Syntax, current: Option<i64>) -> String {
+    match syntax {
+        Syntax::Postgres => "SET statement_timeout = DEFAULT".to_owned(),
+        Syntax::Mysql => "SET SESSION max_execution_time = DEFAULT".to_owned(),
+        Syntax::Mssql => "SET LOCK_TIMEOUT -1".to_owned(),
+        Syntax::Sqlite => format!("PRAGMA busy_timeout = {}", current.unwrap_or_default()),
+    }
+}
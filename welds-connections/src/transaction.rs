@@ -1,9 +1,11 @@
 use super::Row;
 use super::{Client, Param};
 use crate::errors::Result;
+use crate::timeout;
 use crate::{ExecuteResult, Syntax};
 use async_trait::async_trait;
 use std::sync::Mutex;
+use std::time::Duration;
 
 #[cfg(feature = "mssql")]
 use crate::mssql::transaction::MssqlTransaction;
@@ -142,21 +144,64 @@ impl<'t> Client for Transaction<'t> {
     ) -> Result<Vec<Vec<Row>>> {
         // transactions are already locked to a single connection.
         // Just run the batch of fetches
-        let mut datasets = Vec::default();
         let mut inner = self.take_conn();
-        for fetch in fetches {
-            let sql = fetch.sql;
-            let params = fetch.params;
-            let r = fetch_rows_inner(&mut inner, sql, params).await;
-            let is_err = r.is_err();
-            datasets.push(r);
-            if is_err {
-                break;
-            }
-        }
+        let datasets = fetch_many_inner(&mut inner, fetches).await;
+        self.return_conn(inner);
+        datasets
+    }
+
+    async fn fetch_many_with_timeout<'s, 'args, 'i>(
+        &self,
+        fetches: &[crate::Fetch<'s, 'args, 'i>],
+        timeout: Duration,
+    ) -> Result<Vec<Vec<Row>>> {
+        let mut inner = self.take_conn();
+        let datasets =
+            fetch_many_with_timeout_inner(&mut inner, self.syntax, fetches, timeout).await;
         self.return_conn(inner);
-        datasets.drain(..).collect()
+        datasets
+    }
+}
+
+async fn fetch_many_with_timeout_inner<'t>(
+    inner: &mut TransT<'t>,
+    syntax: Syntax,
+    fetches: &[crate::Fetch<'_, '_, '_>],
+    timeout: Duration,
+) -> Result<Vec<Vec<Row>>> {
+    let current = match timeout::current(syntax) {
+        Some(sql) => {
+            let rows = fetch_rows_inner(inner, sql, &[]).await?;
+            let row = rows.first().ok_or(crate::Error::RowNowFound)?;
+            Some(row.get_by_position::<i64>(0)?)
+        }
+        None => None,
+    };
+    let limit = timeout::limit(syntax, timeout);
+    execute_inner(inner, &limit, &[]).await?;
+    let datasets = fetch_many_inner(inner, fetches).await;
+    // a failed fetch can end the transaction, rolling back the limit with it
+    let reset = timeout::reset(syntax, current);
+    let _ = execute_inner(inner, &reset, &[]).await;
+    datasets
+}
+
+async fn fetch_many_inner<'t>(
+    inner: &mut TransT<'t>,
+    fetches: &[crate::Fetch<'_, '_, '_>],
+) -> Result<Vec<Vec<Row>>> {
+    let mut datasets = Vec::default();
+    for fetch in fetches {
+        let sql = fetch.sql;
+        let params = fetch.params;
+        let r = fetch_rows_inner(inner, sql, params).await;
+        let is_err = r.is_err();
+        datasets.push(r);
+        if is_err {
+            break;
+        }
     }
+    datasets.drain(..).collect()
 }
 
 async fn execute_inner<'t>(
@@ -4,6 +4,7 @@ use crate::{
     column::Column,
     hook::{Hook, HookKind},
     relation::Relation,
+    scope::Scope,
 };
 use proc_macro2::{Ident, Span};
 use syn::{Attribute, Field, Type};
@@ -26,12 +27,40 @@ pub(crate) fn get_columns(ast: &syn::DeriveInput) -> Vec<Column> {
             let is_option = field_type.is_some();
             let field_type = field_type.unwrap_or(&f.ty).clone();
             let field = f.ident.as_ref().unwrap().clone();
+            let (index, index_name) = read_index(&f.attrs);
+            let unique = read_unique(&f.attrs);
+            let default = read_default(f);
+            let collation = read_collation(f);
+            let max_length = read_field_int(f, "max_length");
+            let precision = read_field_int(f, "precision");
+            let scale = read_field_int(f, "scale");
+            let check_ignore = read_check_ignore(f);
+            let db_default = read_db_default(&f.attrs);
+            let (created_at, updated_at) = read_timestamp(&f.attrs);
+            let version = read_version(&f.attrs);
+            let json = read_json(&f.attrs);
+            let uuid = read_uuid(&f.attrs);
             Column {
                 field,
                 ignore,
                 dbname,
                 field_type,
                 is_option,
+                index,
+                index_name,
+                unique,
+                default,
+                collation,
+                max_length,
+                precision,
+                scale,
+                check_ignore,
+                db_default,
+                created_at,
+                updated_at,
+                version,
+                json,
+                uuid,
             }
         })
         .collect()
@@ -56,12 +85,36 @@ pub(crate) fn get_pks(ast: &syn::DeriveInput) -> Vec<Column> {
             let is_option = field_type.is_some();
             let field_type = field_type.unwrap_or(&f.ty).clone();
             let field = f.ident.as_ref().unwrap().clone();
+            let (index, index_name) = read_index(&f.attrs);
+            let unique = read_unique(&f.attrs);
+            let default = read_default(f);
+            let collation = read_collation(f);
+            let max_length = read_field_int(f, "max_length");
+            let precision = read_field_int(f, "precision");
+            let scale = read_field_int(f, "scale");
+            let check_ignore = read_check_ignore(f);
+            let db_default = read_db_default(&f.attrs);
             Column {
                 field,
                 ignore: false,
                 dbname,
                 field_type,
                 is_option,
+                index,
+                index_name,
+                unique,
+                default,
+                collation,
+                max_length,
+                precision,
+                scale,
+                check_ignore,
+                db_default,
+                created_at: false,
+                updated_at: false,
+                version: false,
+                json: read_json(&f.attrs),
+                uuid: read_uuid(&f.attrs),
             }
         })
         .collect()
@@ -88,6 +141,146 @@ fn read_rename(field: &Field) -> Option<String> {
     db_col_name
 }
 
+/// reads `#[welds(default = "expr")]` off of a field
+fn read_default(field: &Field) -> Option<String> {
+    read_field_str(field, "default")
+}
+
+/// reads `#[welds(collation = "xyz")]` off of a field
+fn read_collation(field: &Field) -> Option<String> {
+    read_field_str(field, "collation")
+}
+
+/// reads the first `#[welds(name = 123)]` off of a field
+fn read_field_int(field: &Field, name: &str) -> Option<i64> {
+    let metas = welds_meta(&field.attrs);
+    // Read out the inner meta from [welds(this, and_this)]
+    let inners: Vec<&syn::Meta> = metas.iter().flat_map(as_metalist_nested_meta).collect();
+    inners
+        .iter()
+        .filter_map(|m| as_meta_namevalue_ref(m))
+        .filter(|m| m.path.is_ident(name))
+        .filter_map(|nv| lit_as_i64(&nv.lit))
+        .next()
+}
+
+/// reads the first `#[welds(name = "value")]` off of a field
+fn read_field_str(field: &Field, name: &str) -> Option<String> {
+    read_attrs_str(&field.attrs, name)
+}
+
+fn read_attrs_str(attrs: &[Attribute], name: &str) -> Option<String> {
+    let metas = welds_meta(attrs);
+    // Read out the inner meta from [welds(this, and_this)]
+    let inners: Vec<&syn::Meta> = metas.iter().flat_map(as_metalist_nested_meta).collect();
+    inners
+        .iter()
+        .filter_map(|m| as_meta_namevalue_ref(m))
+        .filter(|m| m.path.is_ident(name))
+        .filter_map(|nv| lit_as_litstr(&nv.lit))
+        .map(|x| x.value())
+        .next()
+}
+
+/// reads `#[welds(check = "ignore")]` off of a field
+fn read_check_ignore(field: &Field) -> bool {
+    let metas = welds_meta(&field.attrs);
+    // Read out the inner meta from [welds(this, and_this)]
+    let inners: Vec<&syn::Meta> = metas.iter().flat_map(as_metalist_nested_meta).collect();
+    inners
+        .iter()
+        .filter_map(|m| as_meta_namevalue_ref(m))
+        .filter(|m| m.path.is_ident("check"))
+        .filter_map(|nv| lit_as_litstr(&nv.lit))
+        .any(|x| x.value() == "ignore")
+}
+
+/// reads `#[welds(db_default)]` off of a field, a column the database fills in on insert
+fn read_db_default(attrs: &[Attribute]) -> bool {
+    let metas = welds_meta(attrs);
+    // Read out the inner meta from [welds(this, and_this)]
+    let inners: Vec<&syn::Meta> = metas.iter().flat_map(as_metalist_nested_meta).collect();
+    inners.iter().any(|m| m.path().is_ident("db_default"))
+}
+
+/// reads `#[welds(created_at)]` and `#[welds(updated_at)]` off of a field
+fn read_timestamp(attrs: &[Attribute]) -> (bool, bool) {
+    let metas = welds_meta(attrs);
+    // Read out the inner meta from [welds(this, and_this)]
+    let inners: Vec<&syn::Meta> = metas.iter().flat_map(as_metalist_nested_meta).collect();
+    let created_at = inners.iter().any(|m| m.path().is_ident("created_at"));
+    let updated_at = inners.iter().any(|m| m.path().is_ident("updated_at"));
+    (created_at, updated_at)
+}
+
+/// reads `#[welds(version)]` off of a field, the version of the row used for optimistic locking
+fn read_version(attrs: &[Attribute]) -> bool {
+    let metas = welds_meta(attrs);
+    // Read out the inner meta from [welds(this, and_this)]
+    let inners: Vec<&syn::Meta> = metas.iter().flat_map(as_metalist_nested_meta).collect();
+    inners.iter().any(|m| m.path().is_ident("version"))
+}
+
+/// reads `#[welds(json)]` off of a field, the value is stored as JSON
+fn read_json(attrs: &[Attribute]) -> bool {
+    let metas = welds_meta(attrs);
+    // Read out the inner meta from [welds(this, and_this)]
+    let inners: Vec<&syn::Meta> = metas.iter().flat_map(as_metalist_nested_meta).collect();
+    inners.iter().any(|m| m.path().is_ident("json"))
+}
+
+/// reads `#[welds(uuid_v4)]` or `#[welds(uuid_v7)]` off of a primary key,
+/// returns the fn of UuidKey used to make its keys
+fn read_uuid(attrs: &[Attribute]) -> Option<&'static str> {
+    let metas = welds_meta(attrs);
+    // Read out the inner meta from [welds(this, and_this)]
+    let inners: Vec<&syn::Meta> = metas.iter().flat_map(as_metalist_nested_meta).collect();
+    inners.iter().find_map(|m| match m.path() {
+        p if p.is_ident("uuid_v4") => Some("new_v4"),
+        p if p.is_ident("uuid_v7") => Some("new_v7"),
+        _ => None,
+    })
+}
+
+/// only a primary key can have its uuid made by welds
+pub(crate) fn check_uuid(columns: &[Column], pks: &[Column]) -> Result<()> {
+    for col in columns.iter().filter(|c| c.uuid.is_some()) {
+        if !pks.iter().any(|pk| pk.field == col.field) {
+            return Err(format!(
+                "Expected the uuid field {} to be a primary_key",
+                col.field
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// reads `#[welds(index)]` or `#[welds(index = "name")]` off of a field
+/// returns if the field is indexed and the name given to the index
+fn read_index(attrs: &[Attribute]) -> (bool, Option<String>) {
+    let metas = welds_meta(attrs);
+    // Read out the inner meta from [welds(this, and_this)]
+    let inners: Vec<&syn::Meta> = metas.iter().flat_map(as_metalist_nested_meta).collect();
+    let index = inners.iter().any(|m| m.path().is_ident("index"));
+    // find the first index="name"
+    let index_name: Option<String> = inners
+        .iter()
+        .filter_map(|m| as_meta_namevalue_ref(m))
+        .filter(|m| m.path.is_ident("index"))
+        .filter_map(|nv| lit_as_litstr(&nv.lit))
+        .map(|x| x.value())
+        .next();
+    (index, index_name)
+}
+
+/// reads `#[welds(unique)]` off of a field
+fn read_unique(attrs: &[Attribute]) -> bool {
+    let metas = welds_meta(attrs);
+    // Read out the inner meta from [welds(this, and_this)]
+    let inners: Vec<&syn::Meta> = metas.iter().flat_map(as_metalist_nested_meta).collect();
+    inners.iter().any(|m| m.path().is_ident("unique"))
+}
+
 /// returns the `inner_type`:  the `T` type inside the `Option<T>`
 fn as_option_inner(ftype: &Type) -> Option<&Type> {
     let tp = as_typepath(ftype)?;
@@ -191,6 +384,21 @@ pub(crate) fn get_hooks(ast: &syn::DeriveInput) -> Result<Vec<Hook>> {
     Ok(hooks)
 }
 
+pub(crate) fn get_scopes(ast: &syn::DeriveInput) -> Result<Vec<Scope>> {
+    let metas = welds_meta(&ast.attrs);
+
+    // Read out the inner meta from [welds(this, and_this)]
+    let inners: Vec<&syn::Meta> = metas.iter().flat_map(as_metalist_nested_meta).collect();
+
+    let scopes: Result<Vec<_>> = inners
+        .iter()
+        .filter_map(|m| as_metalist_ref(m))
+        .filter(|m| m.path.is_ident("scope"))
+        .map(Scope::new)
+        .collect();
+    Ok(scopes?.drain(..).flatten().collect())
+}
+
 pub(crate) fn get_scructname(ast: &syn::DeriveInput) -> syn::Ident {
     ast.ident.clone()
 }
@@ -212,6 +420,57 @@ pub(crate) fn get_tablename(ast: &syn::DeriveInput) -> String {
     tablename.unwrap_or(structname)
 }
 
+/// reads `#[welds(soft_delete = "deleted_at")]`, finding the field it names
+/// a model can only have one version column, and it always has a value to compare against
+pub(crate) fn check_version(columns: &[Column]) -> Result<()> {
+    let versions: Vec<&Column> = columns.iter().filter(|c| c.version).collect();
+    if versions.len() > 1 {
+        return Err("Expected only one field with [welds(version)]".to_owned());
+    }
+    if let Some(col) = versions.first() {
+        if col.is_option {
+            return Err(format!(
+                "Expected the version field {} to not be an Option",
+                col.field
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn get_soft_delete(
+    ast: &syn::DeriveInput,
+    columns: &[Column],
+) -> Result<Option<Column>> {
+    let metas = welds_meta(&ast.attrs);
+    // Read out the inner meta from [welds(this, and_this)]
+    let inners: Vec<&syn::Meta> = metas.iter().flat_map(as_metalist_nested_meta).collect();
+    let name: Option<String> = inners
+        .iter()
+        .filter_map(|m| as_meta_namevalue_ref(m))
+        .filter(|m| m.path.is_ident("soft_delete"))
+        .filter_map(|nv| lit_as_litstr(&nv.lit))
+        .map(|x| x.value())
+        .next();
+    let name = match name {
+        Some(name) => name,
+        None => return Ok(None),
+    };
+    let col = columns
+        .iter()
+        .filter(|c| !c.ignore)
+        .find(|c| c.field == name || c.dbname == name)
+        .ok_or_else(|| format!("Could not find the field {} to use for soft_delete", name))?;
+    // restoring a row clears the time it was deleted
+    if !col.is_option {
+        return Err(format!(
+            "Expected the soft_delete field {} to be an Option",
+            name
+        ));
+    }
+    Ok(Some(col.clone()))
+}
+
 pub(crate) fn get_schemaname(ast: &syn::DeriveInput) -> Option<String> {
     let metas = welds_meta(&ast.attrs);
     // Read out the inner meta from [welds(this, and_this)]
@@ -238,6 +497,38 @@ pub(crate) fn get_welds_path(ast: &syn::DeriveInput) -> syn::Path {
     })
 }
 
+/// reads `#[welds(store = "int")]` off of a WeldsEnum, how its variants are stored
+pub(crate) fn get_enum_store(ast: &syn::DeriveInput) -> Option<String> {
+    read_attrs_str(&ast.attrs, "store")
+}
+
+/// `#[welds(custom)]` on a WeldsType, the user writes the impl of WeldsType
+pub(crate) fn get_custom(ast: &syn::DeriveInput) -> bool {
+    let metas = welds_meta(&ast.attrs);
+    // Read out the inner meta from [welds(this, and_this)]
+    let inners: Vec<&syn::Meta> = metas.iter().flat_map(as_metalist_nested_meta).collect();
+    inners.iter().any(|&m| m.path().is_ident("custom"))
+}
+
+/// reads `#[welds(rename = "xyz")]` off of a variant of a WeldsEnum
+pub(crate) fn read_variant_rename(variant: &syn::Variant) -> Option<String> {
+    read_attrs_str(&variant.attrs, "rename")
+}
+
+pub(crate) fn get_dto_model(ast: &syn::DeriveInput) -> Option<syn::Path> {
+    let metas = welds_meta(&ast.attrs);
+    // Read out the inner meta from [welds(this, and_this)]
+    let inners: Vec<&syn::Meta> = metas.iter().flat_map(as_metalist_nested_meta).collect();
+    // find the first model(Path)
+    inners
+        .iter()
+        .filter_map(|m| as_metalist_ref(m))
+        .filter(|m| m.path.is_ident("model"))
+        .flat_map(as_metalist_nested_meta)
+        .map(|m| m.path().clone())
+        .next()
+}
+
 pub(crate) fn get_readonly(ast: &syn::DeriveInput) -> bool {
     let metas = welds_meta(&ast.attrs);
     // Read out the inner meta from [welds(this, and_this)]
@@ -246,6 +537,50 @@ pub(crate) fn get_readonly(ast: &syn::DeriveInput) -> bool {
     inners.iter().any(|&m| m.path().is_ident("readonly"))
 }
 
+pub(crate) fn get_timestamps(ast: &syn::DeriveInput) -> bool {
+    let metas = welds_meta(&ast.attrs);
+    // Read out the inner meta from [welds(this, and_this)]
+    let inners: Vec<&syn::Meta> = metas.iter().flat_map(as_metalist_nested_meta).collect();
+    inners.iter().any(|&m| m.path().is_ident("timestamps"))
+}
+
+/// `#[welds(timestamps)]` uses the fields named `created_at` and `updated_at`
+pub(crate) fn use_timestamp_fields(columns: &mut [Column]) -> Result<()> {
+    let mut found = false;
+    for col in columns.iter_mut().filter(|c| !c.ignore) {
+        match col.field.to_string().as_str() {
+            "created_at" => col.created_at = true,
+            "updated_at" => col.updated_at = true,
+            _ => continue,
+        }
+        found = true;
+    }
+    if !found {
+        return Err(
+            "Expected a created_at or updated_at field to use for\n[ welds(timestamps) ]"
+                .to_owned(),
+        );
+    }
+    Ok(())
+}
+
+pub(crate) fn get_check_extra_columns(ast: &syn::DeriveInput) -> bool {
+    let metas = welds_meta(&ast.attrs);
+    // Read out the inner meta from [welds(this, and_this)]
+    let inners: Vec<&syn::Meta> = metas.iter().flat_map(as_metalist_nested_meta).collect();
+    // find the first check_extra_columns=bool
+    inners
+        .iter()
+        .filter_map(|m| as_meta_namevalue_ref(m))
+        .filter(|m| m.path.is_ident("check_extra_columns"))
+        .filter_map(|nv| match &nv.lit {
+            syn::Lit::Bool(b) => Some(b.value),
+            _ => None,
+        })
+        .next()
+        .unwrap_or(true)
+}
+
 fn as_metalist(meta: syn::Meta) -> Option<syn::MetaList> {
     match meta {
         syn::Meta::List(inner) => Some(inner),
@@ -278,6 +613,13 @@ fn as_metalist_nested_meta(metalist: &syn::MetaList) -> Vec<&syn::Meta> {
         .collect()
 }
 
+fn lit_as_i64(lit: &syn::Lit) -> Option<i64> {
+    match lit {
+        syn::Lit::Int(i) => i.base10_parse().ok(),
+        _ => None,
+    }
+}
+
 fn lit_as_litstr(lit: &syn::Lit) -> Option<&syn::LitStr> {
     match lit {
         syn::Lit::Str(s) => Some(s),
@@ -43,13 +43,17 @@ fn def_field(info: &Info, col: &Column) -> TokenStream {
     let wp = &info.welds_path;
     let type_inner = &col.field_type;
     let ty = quote! { #type_inner };
-    let clause = get_clause(type_inner, col.is_option);
+    let clause = get_clause(col);
     let full_type = quote! { #wp::query::clause::#clause<#ty> };
     quote! { pub #name: #full_type }
 }
 
-fn get_clause(ty: &syn::Type, nullable: bool) -> TokenStream {
-    let clasename = crate::utils::get_clause(ty, nullable);
+fn get_clause(col: &Column) -> TokenStream {
+    let clasename = match col.json {
+        true if col.is_option => "JsonOpt".to_owned(),
+        true => "Json".to_owned(),
+        false => crate::utils::get_clause(&col.field_type, col.is_option),
+    };
     let id = Ident::new(clasename.as_str(), Span::call_site());
     quote! { #id }
 }
@@ -57,8 +61,7 @@ fn get_clause(ty: &syn::Type, nullable: bool) -> TokenStream {
 fn default_fields(info: &Info, col: &Column) -> TokenStream {
     let wp = &info.welds_path;
     let name = &col.field;
-    let type_inner = &col.field_type;
-    let clause = get_clause(type_inner, col.is_option);
+    let clause = get_clause(col);
     let dbname = col.dbname.as_str();
     let fieldname: String = col.field.to_string();
     quote! { #name: #wp::query::clause::#clause::new(#dbname, #fieldname) }
@@ -0,0 +1,72 @@
+use crate::column::Column;
+use crate::info::Info;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub(crate) fn write(info: &Info) -> TokenStream {
+    let def = &info.defstruct;
+    let wp = &info.welds_path;
+
+    let fields: Vec<_> = info
+        .columns
+        .iter()
+        .filter(|x| !x.ignore)
+        .map(|col| setfield(wp, col))
+        .collect();
+
+    // fields not connected to the database are left as their default
+    let defaults = if info.columns.iter().any(|x| x.ignore) {
+        quote! { ..Default::default() }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        impl #wp::model_traits::FromPrefixedRow for #def {
+            fn from_prefixed_row(row: &#wp::Row, prefix: &str) -> #wp::errors::Result<Self> {
+                Ok(#def {
+                    #(#fields)*
+                    #defaults
+                })
+            }
+        }
+    }
+}
+
+fn setfield(wp: &syn::Path, col: &Column) -> TokenStream {
+    let dbname = col.dbname.as_str();
+    let field = &col.field;
+    let value = col.row_get(wp, quote! { &format!("{}{}", prefix, #dbname) });
+    quote! { #field: #value, }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_read_the_columns_with_a_prefix() {
+        let info = Info::mock()
+            .add_pk("id", "i64")
+            .add_column("name", "String", true);
+        let ts = write(&info);
+        let code = ts.to_string();
+
+        let expected: &str = r#"
+            impl welds::model_traits::FromPrefixedRow for Mock {
+                fn from_prefixed_row(row: &welds::Row, prefix: &str) -> welds::errors::Result<Self> {
+                    Ok(Mock {
+                        id: row.get(&format!("{}{}", prefix, "id"))?,
+                        name: row.get(&format!("{}{}", prefix, "name"))?,
+                    })
+                }
+            }
+        "#;
+
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
+    fn cleaned(input: &str) -> String {
+        input.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+}
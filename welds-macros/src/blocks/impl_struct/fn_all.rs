@@ -6,6 +6,12 @@ pub(crate) fn write(info: &Info) -> TokenStream {
     let schema = &info.schemastruct;
     let wp = &info.welds_path;
 
+    // a soft deleted model hides its deleted rows by default
+    let soft_delete = info.soft_delete.as_ref().map(|col| {
+        let column = &col.dbname;
+        quote! { .soft_delete(#column) }
+    });
+
     quote! {
 
         pub fn all() -> #wp::query::builder::QueryBuilder<Self>
@@ -13,7 +19,7 @@ pub(crate) fn write(info: &Info) -> TokenStream {
             #schema: #wp::model_traits::TableColumns,
             Self: Send
             {
-                #wp::query::builder::QueryBuilder::new()
+                #wp::query::builder::QueryBuilder::new()#soft_delete
             }
 
     }
@@ -10,8 +10,15 @@ pub(crate) fn write(info: &Info) -> TokenStream {
         return quote!();
     }
 
-    let id_params: Vec<_> = pks.iter().map(id_param).collect();
-    let id_params = quote! { #(#id_params),* };
+    // a composite key is found with a tuple of its values, in the order of the fields
+    let id_params = match pks {
+        [pk] => id_param(pk),
+        _ => {
+            let names: Vec<_> = pks.iter().map(|pk| &pk.field).collect();
+            let types: Vec<_> = pks.iter().map(|pk| &pk.field_type).collect();
+            quote! { ( #(#names),* ): ( #(impl Into<#types>),* ) }
+        }
+    };
 
     let converts: Vec<_> = pks.iter().map(convert).collect();
     let converts = quote! {#(#converts)* };
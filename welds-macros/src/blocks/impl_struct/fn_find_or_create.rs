@@ -0,0 +1,28 @@
+use crate::info::Info;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub(crate) fn write(info: &Info) -> TokenStream {
+    // A readonly model can't be created
+    if info.readonly {
+        return quote!();
+    }
+    let schema = &info.schemastruct;
+    let wp = &info.welds_path;
+
+    quote! {
+
+    pub async fn find_or_create(
+        conn: &dyn #wp::Client,
+        lam: impl Fn(#schema) -> Box<dyn #wp::query::clause::ClauseAdder>,
+        create: impl FnOnce() -> Self,
+    ) -> #wp::errors::Result<#wp::state::DbState<Self>>
+    where
+        <Self as #wp::model_traits::HasSchema>::Schema: #wp::model_traits::TableColumns,
+        Self: Send + TryFrom<#wp::Row>,
+    {
+        Self::where_col(lam).first_or_insert(conn, create).await
+    }
+
+    }
+}
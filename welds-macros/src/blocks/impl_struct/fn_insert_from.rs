@@ -0,0 +1,20 @@
+use crate::info::Info;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub(crate) fn write(info: &Info) -> TokenStream {
+    let wp = &info.welds_path;
+
+    quote! {
+
+        pub fn insert_from<S>(
+            select: #wp::query::select_cols::SelectBuilder<S>,
+        ) -> #wp::query::insert::InsertSelectBuilder<Self, S>
+        where
+            S: Send + #wp::model_traits::HasSchema,
+        {
+            #wp::query::insert::InsertSelectBuilder::new(select)
+        }
+
+    }
+}
@@ -0,0 +1,30 @@
+use crate::info::Info;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub(crate) fn write(info: &Info) -> TokenStream {
+    let schema = &info.schemastruct;
+    let wp = &info.welds_path;
+
+    let parts: Vec<_> = info
+        .scopes
+        .iter()
+        .map(|scope| {
+            let name = &scope.name;
+            let sql = &scope.sql;
+            let doc = format!("All the rows matching the `{}` scope: `{}`", name, sql);
+            quote! {
+                #[doc = #doc]
+                pub fn #name() -> #wp::query::builder::QueryBuilder<Self>
+                where
+                    #schema: #wp::model_traits::TableColumns,
+                    Self: Send
+                {
+                    Self::all().where_raw(#sql, &[])
+                }
+            }
+        })
+        .collect();
+
+    quote! { #(#parts)* }
+}
@@ -15,7 +15,7 @@ pub(crate) fn write(info: &Info) -> TokenStream {
             #schema: #wp::model_traits::TableColumns,
             Self: Send,
         {
-            let qb = #wp::query::builder::QueryBuilder::new();
+            let qb = Self::all();
             qb.select(lam)
         }
 
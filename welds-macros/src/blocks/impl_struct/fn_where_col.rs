@@ -17,7 +17,7 @@ pub(crate) fn write(info: &Info) -> TokenStream {
         #schema: #wp::model_traits::TableColumns,
         Self: Send
     {
-        let select = #wp::query::builder::QueryBuilder::new();
+        let select = Self::all();
         select.where_col(lam)
     }
 
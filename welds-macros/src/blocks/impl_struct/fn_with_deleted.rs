@@ -0,0 +1,33 @@
+use crate::info::Info;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub(crate) fn write(info: &Info) -> TokenStream {
+    if info.soft_delete.is_none() {
+        return quote!();
+    }
+    let schema = &info.schemastruct;
+    let wp = &info.welds_path;
+
+    quote! {
+
+        /// All the rows, including the rows that have been soft deleted
+        pub fn with_deleted() -> #wp::query::builder::QueryBuilder<Self>
+        where
+            #schema: #wp::model_traits::TableColumns,
+            Self: Send
+        {
+            Self::all().with_deleted()
+        }
+
+        /// Only the rows that have been soft deleted
+        pub fn only_deleted() -> #wp::query::builder::QueryBuilder<Self>
+        where
+            #schema: #wp::model_traits::TableColumns,
+            Self: Send
+        {
+            Self::all().only_deleted()
+        }
+
+    }
+}
@@ -4,10 +4,14 @@ use quote::quote;
 
 pub(crate) mod fn_all;
 pub(crate) mod fn_find_by_id;
+pub(crate) mod fn_find_or_create;
 pub(crate) mod fn_from_raw_sql;
+pub(crate) mod fn_insert_from;
 pub(crate) mod fn_new;
+pub(crate) mod fn_scopes;
 pub(crate) mod fn_select;
 pub(crate) mod fn_where_col;
+pub(crate) mod fn_with_deleted;
 
 pub(crate) fn write(infos: &Info) -> TokenStream {
     let defstruct = &infos.defstruct;
@@ -18,6 +22,10 @@ pub(crate) fn write(infos: &Info) -> TokenStream {
     let p4 = fn_find_by_id::write(infos);
     let p5 = fn_from_raw_sql::write(infos);
     let p6 = fn_select::write(infos);
+    let p7 = fn_insert_from::write(infos);
+    let p8 = fn_scopes::write(infos);
+    let p9 = fn_find_or_create::write(infos);
+    let p10 = fn_with_deleted::write(infos);
 
     quote! {
 
@@ -28,6 +36,10 @@ pub(crate) fn write(infos: &Info) -> TokenStream {
             #p4
             #p5
             #p6
+            #p7
+            #p8
+            #p9
+            #p10
         }
 
     }
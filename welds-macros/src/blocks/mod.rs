@@ -1,27 +1,41 @@
 mod define_schema;
+mod from_prefixed_row;
 mod has_schema;
 mod impl_struct;
+mod primary_key_values;
 mod relations;
+mod soft_delete;
+mod table_check;
 mod table_columns;
+mod table_indexes;
 mod table_info;
+mod table_relations;
 mod unique_identifier;
 //mod write_bulk_array_to_args;
 mod try_from_row;
 mod update_from_row;
+mod versioned;
 mod write_col_default_check;
 mod write_hooks;
 mod write_to_args;
 
 pub(crate) use define_schema::write as define_schema;
+pub(crate) use from_prefixed_row::write as from_prefixed_row;
 pub(crate) use has_schema::write as has_schema;
 pub(crate) use impl_struct::write as impl_struct;
+pub(crate) use primary_key_values::write as primary_key_values;
 pub(crate) use relations::write as relations;
+pub(crate) use soft_delete::write as soft_delete;
+pub(crate) use table_check::write as table_check;
 pub(crate) use table_columns::write as table_columns;
+pub(crate) use table_indexes::write as table_indexes;
 pub(crate) use table_info::write as table_info;
+pub(crate) use table_relations::write as table_relations;
 pub(crate) use unique_identifier::write as unique_identifier;
 //pub(crate) use write_bulk_array_to_args::write as write_bulk_array_to_args;
 pub(crate) use try_from_row::write as try_from_row;
 pub(crate) use update_from_row::write as update_from_row;
+pub(crate) use versioned::write as versioned;
 pub(crate) use write_col_default_check::write as write_col_default_check;
 pub(crate) use write_hooks::write as write_hooks;
 pub(crate) use write_to_args::write as write_to_args;
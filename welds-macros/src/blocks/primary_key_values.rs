@@ -0,0 +1,41 @@
+use crate::info::Info;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub(crate) fn write(info: &Info) -> TokenStream {
+    // the values are read as a tuple, tuples of up to 4 are supported
+    if info.pks.is_empty() || info.pks.len() > 4 {
+        return quote!();
+    }
+    let wp = &info.welds_path;
+    let def = &info.defstruct;
+    let types: Vec<_> = info.pks.iter().map(|pk| &pk.field_type).collect();
+
+    quote! {
+        impl #wp::model_traits::PrimaryKeyValues for #def {
+            type Values = ( #(#types,)* );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_able_to_write_primary_key_values() {
+        let info = Info::mock().add_pk("id", "i64").add_pk("tenant", "i32");
+        let ts = write(&info);
+        let code = ts.to_string();
+        let expected: &str = r#"
+            impl welds::model_traits::PrimaryKeyValues for Mock {
+                type Values = (i64, i32,);
+            }
+        "#;
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
+    fn cleaned(input: &str) -> String {
+        input.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+}
@@ -0,0 +1,97 @@
+use crate::info::Info;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub(crate) fn write(info: &Info) -> TokenStream {
+    let wp = &info.welds_path;
+    let def = &info.defstruct;
+
+    let col = match &info.soft_delete {
+        Some(col) => col,
+        None => return quote! { impl #wp::model_traits::SoftDelete for #def {} },
+    };
+    let column = &col.dbname;
+
+    // If this is a readonly model it can't be deleted, its deleted rows are still hidden
+    if info.readonly {
+        return quote! {
+            impl #wp::model_traits::SoftDelete for #def {
+                fn soft_delete_column() -> Option<&'static str> {
+                    Some(#column)
+                }
+            }
+        };
+    }
+    let field = &col.field;
+    let ty = &col.field_type;
+
+    quote! {
+        impl #wp::model_traits::SoftDelete for #def {
+            fn soft_delete_column() -> Option<&'static str> {
+                Some(#column)
+            }
+            fn set_deleted(&mut self, deleted: bool) {
+                self.#field = match deleted {
+                    true => Some(<#ty as #wp::model_traits::Timestamp>::now()),
+                    false => None,
+                };
+            }
+            fn deleted_now() -> Option<Box<dyn #wp::connections::Param + Send + Sync>> {
+                Some(Box::new(<#ty as #wp::model_traits::Timestamp>::now()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_set_the_soft_delete_column() {
+        let info = Info::mock()
+            .add_pk("id", "i64")
+            .add_column("deleted_at", "Stamp", true)
+            .with_soft_delete();
+        let code = write(&info).to_string();
+        let expected: &str = r#"
+            impl welds::model_traits::SoftDelete for Mock {
+                fn soft_delete_column() -> Option<&'static str> {
+                    Some("deleted_at")
+                }
+                fn set_deleted(&mut self, deleted: bool) {
+                    self.deleted_at = match deleted {
+                        true => Some(<Stamp as welds::model_traits::Timestamp>::now()),
+                        false => None,
+                    };
+                }
+                fn deleted_now() -> Option<Box<dyn welds::connections::Param + Send + Sync>> {
+                    Some(Box::new(<Stamp as welds::model_traits::Timestamp>::now()))
+                }
+            }
+        "#;
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
+    #[test]
+    fn should_only_hide_the_deleted_rows_of_a_readonly_model() {
+        let mut info = Info::mock()
+            .add_pk("id", "i64")
+            .add_column("deleted_at", "Stamp", true)
+            .with_soft_delete();
+        info.readonly = true;
+        let code = write(&info).to_string();
+        let expected: &str = r#"
+            impl welds::model_traits::SoftDelete for Mock {
+                fn soft_delete_column() -> Option<&'static str> {
+                    Some("deleted_at")
+                }
+            }
+        "#;
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
+    fn cleaned(input: &str) -> String {
+        input.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+}
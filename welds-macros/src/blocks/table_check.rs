@@ -0,0 +1,93 @@
+use crate::info::Info;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub(crate) fn write(info: &Info) -> TokenStream {
+    let wp = &info.welds_path;
+    let def = &info.schemastruct;
+    let ignored: Vec<_> = info
+        .columns
+        .iter()
+        .filter(|x| x.check_ignore)
+        .map(|x| x.dbname.as_str())
+        .collect();
+    let check_extra_columns = info.check_extra_columns;
+    let readonly = info.readonly;
+
+    quote! {
+
+        impl #wp::model_traits::TableCheck for #def {
+            fn ignored_columns() -> &'static [&'static str] {
+                &[ #(#ignored),* ]
+            }
+            fn check_extra_columns() -> bool {
+                #check_extra_columns
+            }
+            fn readonly() -> bool {
+                #readonly
+            }
+        }
+
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_write_default_check_settings() {
+        let info = Info::mock().add_pk("id", "i64");
+        let ts = write(&info);
+        let code = ts.to_string();
+
+        let expected: &str = r#"
+            impl welds::model_traits::TableCheck for MockSchema {
+                fn ignored_columns() -> &'static [&'static str] {
+                    &[]
+                }
+                fn check_extra_columns() -> bool {
+                    true
+                }
+                fn readonly() -> bool {
+                    false
+                }
+            }
+        "#;
+
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
+    #[test]
+    fn should_write_ignored_columns() {
+        let mut info =
+            Info::mock()
+                .add_pk("id", "i64")
+                .add_column("touched_by_trigger", "String", true);
+        info.columns[1].check_ignore = true;
+        info.check_extra_columns = false;
+        info.readonly = true;
+        let ts = write(&info);
+        let code = ts.to_string();
+
+        let expected: &str = r#"
+            impl welds::model_traits::TableCheck for MockSchema {
+                fn ignored_columns() -> &'static [&'static str] {
+                    &["touched_by_trigger"]
+                }
+                fn check_extra_columns() -> bool {
+                    false
+                }
+                fn readonly() -> bool {
+                    true
+                }
+            }
+        "#;
+
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
+    fn cleaned(input: &str) -> String {
+        input.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+}
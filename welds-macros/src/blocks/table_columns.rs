@@ -1,3 +1,4 @@
+use crate::column::Column;
 use crate::info::Info;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -13,17 +14,7 @@ pub(crate) fn write_cols(info: &Info) -> TokenStream {
         .columns
         .iter()
         .filter(|x| !x.ignore)
-        .map(|c| {
-            let ft = &c.field_type;
-            let ty = quote! { #ft };
-            let nullable = c.is_option;
-            //if nullable {
-            //    ty = quote! { Option<#ty> };
-            //}
-            let dbname = c.dbname.as_str();
-            let rust_type = ty.to_string();
-            quote! { Column::new(#dbname, #rust_type, #nullable) }
-        })
+        .map(write_col)
         .collect();
     quote! { vec![ #(#parts),* ] }
 }
@@ -33,18 +24,46 @@ pub(crate) fn write_pks(info: &Info) -> TokenStream {
         .pks
         .iter()
         .filter(|x| !x.ignore)
-        .map(|c| {
-            let ft = &c.field_type;
-            let ty = quote! { #ft };
-            let nullable = c.is_option;
-            let dbname = c.dbname.as_str();
-            let rust_type = ty.to_string();
-            quote! { Column::new(#dbname, #rust_type, #nullable) }
-        })
+        .map(write_col)
         .collect();
     quote! { vec![ #(#parts),* ] }
 }
 
+fn write_col(c: &Column) -> TokenStream {
+    let ft = &c.field_type;
+    // check and migrations see the JSON the value is stored as
+    let ty = match c.json {
+        true => quote! { serde_json::Value },
+        false => quote! { #ft },
+    };
+    let nullable = c.is_option;
+    let dbname = c.dbname.as_str();
+    let rust_type = ty.to_string();
+    let mut col = quote! { Column::new(#dbname, #rust_type, #nullable) };
+    if let Some(default) = &c.default {
+        col = quote! { #col.with_default(#default) };
+    }
+    if let Some(collation) = &c.collation {
+        col = quote! { #col.with_collation(#collation) };
+    }
+    if let Some(max_length) = c.max_length {
+        col = quote! { #col.with_max_length(#max_length) };
+    }
+    if let Some(precision) = c.precision {
+        col = quote! { #col.with_precision(#precision) };
+    }
+    if let Some(scale) = c.scale {
+        col = quote! { #col.with_scale(#scale) };
+    }
+    if c.db_default {
+        col = quote! { #col.with_db_default() };
+    }
+    if c.created_at {
+        col = quote! { #col.with_created_at() };
+    }
+    col
+}
+
 pub(crate) fn write_for_db(info: &Info, pks: &TokenStream, columns: &TokenStream) -> TokenStream {
     let wp = &info.welds_path;
     let def = &info.schemastruct;
@@ -94,6 +113,90 @@ mod tests {
         assert_eq!(cleaned(&code), cleaned(expected));
     }
 
+    #[test]
+    fn should_write_the_default_of_a_column() {
+        let info = Info::mock()
+            .add_pk("id", "i64")
+            .add_column("created_at", "String", false)
+            .with_default("now()");
+        let ts = write_cols(&info);
+        let code = ts.to_string();
+
+        let expected: &str = r#"
+            vec![Column::new("id", "i64", false), Column::new("created_at", "String", false).with_default("now()")]
+        "#;
+
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
+    #[test]
+    fn should_write_a_column_the_database_fills_in() {
+        let info = Info::mock()
+            .add_pk("id", "i64")
+            .add_column("created_at", "String", false)
+            .with_db_default();
+        let ts = write_cols(&info);
+        let code = ts.to_string();
+
+        let expected: &str = r#"
+            vec![Column::new("id", "i64", false), Column::new("created_at", "String", false).with_db_default()]
+        "#;
+
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
+    #[test]
+    fn should_write_the_column_the_row_was_created_at() {
+        let info = Info::mock()
+            .add_pk("id", "i64")
+            .add_column("created_at", "String", false)
+            .with_created_at();
+        let ts = write_cols(&info);
+        let code = ts.to_string();
+
+        let expected: &str = r#"
+            vec![Column::new("id", "i64", false), Column::new("created_at", "String", false).with_created_at()]
+        "#;
+
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
+    #[test]
+    fn should_write_the_collation_of_a_column() {
+        let info = Info::mock()
+            .add_pk("id", "i64")
+            .add_column("email", "String", false)
+            .with_collation("utf8mb4_general_ci");
+        let ts = write_cols(&info);
+        let code = ts.to_string();
+
+        let expected: &str = r#"
+            vec![Column::new("id", "i64", false), Column::new("email", "String", false).with_collation("utf8mb4_general_ci")]
+        "#;
+
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
+    #[test]
+    fn should_write_the_sizes_of_a_column() {
+        let info = Info::mock()
+            .add_column("code", "String", false)
+            .with_max_length(50)
+            .add_column("price", "Decimal", false)
+            .with_precision(10, 2);
+        let ts = write_cols(&info);
+        let code = ts.to_string();
+
+        let expected: &str = r#"
+            vec![
+                Column::new("code", "String", false).with_max_length(50i64),
+                Column::new("price", "Decimal", false).with_precision(10i64).with_scale(2i64)
+            ]
+        "#;
+
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
     fn cleaned(input: &str) -> String {
         input.chars().filter(|c| !c.is_whitespace()).collect()
     }
@@ -0,0 +1,119 @@
+use crate::info::Info;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub(crate) fn write(info: &Info) -> TokenStream {
+    let wp = &info.welds_path;
+    let def = &info.schemastruct;
+    let indexes = write_indexes(info);
+
+    quote! {
+
+        impl #wp::model_traits::TableIndexes for #def {
+            fn indexes() -> Vec<#wp::model_traits::Index> {
+                #indexes
+            }
+        }
+
+    }
+}
+
+pub(crate) fn write_indexes(info: &Info) -> TokenStream {
+    let wp = &info.welds_path;
+    let parts: Vec<_> = info
+        .columns
+        .iter()
+        .filter(|x| !x.ignore)
+        .filter(|x| x.index || x.unique)
+        .map(|c| {
+            let dbname = c.dbname.as_str();
+            let mut index = quote! { #wp::model_traits::Index::new(&[#dbname]) };
+            if let Some(name) = &c.index_name {
+                index = quote! { #index.with_name(#name) };
+            }
+            if c.unique {
+                index = quote! { #index.with_unique() };
+            }
+            index
+        })
+        .collect();
+    quote! { vec![ #(#parts),* ] }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_write_no_indexes_when_none_are_defined() {
+        let info = Info::mock().add_pk("id", "i64");
+        let ts = write(&info);
+        let code = ts.to_string();
+
+        let expected: &str = r#"
+            impl welds::model_traits::TableIndexes for MockSchema {
+                fn indexes() -> Vec<welds::model_traits::Index> {
+                    vec![]
+                }
+            }
+        "#;
+
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
+    #[test]
+    fn should_write_named_and_unnamed_indexes() {
+        let info = Info::mock()
+            .add_pk("id", "i64")
+            .add_column("num", "f32", true)
+            .with_index(None)
+            .add_column("name", "String", false)
+            .with_index(Some("ix_name"));
+        let ts = write(&info);
+        let code = ts.to_string();
+
+        let expected: &str = r#"
+            impl welds::model_traits::TableIndexes for MockSchema {
+                fn indexes() -> Vec<welds::model_traits::Index> {
+                    vec![
+                        welds::model_traits::Index::new(&["num"]),
+                        welds::model_traits::Index::new(&["name"]).with_name("ix_name")
+                    ]
+                }
+            }
+        "#;
+
+        eprintln!("CODE: \n{}\n", code);
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
+    #[test]
+    fn should_write_unique_indexes() {
+        let info = Info::mock()
+            .add_pk("id", "i64")
+            .add_column("email", "String", false)
+            .with_unique()
+            .add_column("code", "String", false)
+            .with_index(Some("ux_code"))
+            .with_unique();
+        let ts = write(&info);
+        let code = ts.to_string();
+
+        let expected: &str = r#"
+            impl welds::model_traits::TableIndexes for MockSchema {
+                fn indexes() -> Vec<welds::model_traits::Index> {
+                    vec![
+                        welds::model_traits::Index::new(&["email"]).with_unique(),
+                        welds::model_traits::Index::new(&["code"]).with_name("ux_code").with_unique()
+                    ]
+                }
+            }
+        "#;
+
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
+    fn cleaned(input: &str) -> String {
+        input.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{info::Info, relation::Relation};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub(crate) fn write(info: &Info) -> TokenStream {
+    let wp = &info.welds_path;
+    let def = &info.schemastruct;
+    let parts: Vec<_> = info
+        .relations
+        .iter()
+        .map(|x| write_relation(info, x))
+        .collect();
+
+    quote! {
+
+        impl #wp::model_traits::TableRelations for #def {
+            fn relations() -> Vec<#wp::model_traits::RelationInfo> {
+                vec![ #(#parts),* ]
+            }
+        }
+
+    }
+}
+
+fn write_relation(info: &Info, relation: &Relation) -> TokenStream {
+    let wp = &info.welds_path;
+    let kind = &relation.kind;
+    let field = relation.field.to_string();
+    let other = &relation.foreign_struct;
+    let fk = &relation.foreign_key;
+    quote! {
+        #wp::model_traits::RelationInfo::new(
+            #wp::model_traits::RelationKind::#kind,
+            #field,
+            <<#other as #wp::model_traits::HasSchema>::Schema as #wp::model_traits::TableInfo>::identifier(),
+            #fk
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_write_no_relations_when_none_are_defined() {
+        let info = Info::mock().add_pk("id", "i64");
+        let ts = write(&info);
+        let code = ts.to_string();
+
+        let expected: &str = r#"
+            impl welds::model_traits::TableRelations for MockSchema {
+                fn relations() -> Vec<welds::model_traits::RelationInfo> {
+                    vec![]
+                }
+            }
+        "#;
+
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
+    #[test]
+    fn should_write_relation_info_with_the_other_tables_identifier() {
+        let info = Info::mock().add_pk("id", "i64").add_relation(
+            "BelongsTo",
+            "product",
+            "super::Product",
+            "product_id",
+        );
+        let ts = write(&info);
+        let code = ts.to_string();
+
+        let expected: &str = r#"
+            impl welds::model_traits::TableRelations for MockSchema {
+                fn relations() -> Vec<welds::model_traits::RelationInfo> {
+                    vec![
+                        welds::model_traits::RelationInfo::new(
+                            welds::model_traits::RelationKind::BelongsTo,
+                            "product",
+                            <<super::Product as welds::model_traits::HasSchema>::Schema as welds::model_traits::TableInfo>::identifier(),
+                            "product_id"
+                        )
+                    ]
+                }
+            }
+        "#;
+
+        eprintln!("CODE: \n{}\n", code);
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
+    fn cleaned(input: &str) -> String {
+        input.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+}
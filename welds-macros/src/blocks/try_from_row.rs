@@ -8,7 +8,7 @@ pub(crate) fn write(info: &Info) -> TokenStream {
         .columns
         .iter()
         .filter(|x| !x.ignore)
-        .map(setfield)
+        .map(|col| setfield(&info.welds_path, col))
         .collect();
     let fields = quote! { #(#fields)* };
 
@@ -18,10 +18,11 @@ pub(crate) fn write(info: &Info) -> TokenStream {
     write_for_db(info, &fields, &ignored)
 }
 
-pub(crate) fn setfield(col: &Column) -> TokenStream {
+pub(crate) fn setfield(wp: &syn::Path, col: &Column) -> TokenStream {
     let dbname = col.dbname.as_str();
     let field = &col.field;
-    quote! { #field: row.get(#dbname)?, }
+    let value = col.row_get(wp, quote! { #dbname });
+    quote! { #field: #value, }
 }
 
 pub(crate) fn write_for_db(
@@ -83,6 +84,30 @@ mod tests {
         assert_eq!(cleaned(&code), cleaned(expected));
     }
 
+    #[test]
+    fn should_read_json_columns_from_json() {
+        let info = Info::mock()
+            .add_pk("id", "i64")
+            .add_column("settings", "Settings", false)
+            .with_json();
+        let ts = write(&info);
+        let code = ts.to_string();
+
+        let expected: &str = r#"
+            impl TryFrom<welds::Row> for Mock {
+                type Error = welds::WeldsError;
+                fn try_from(row: welds::Row) -> std::result::Result<Self, Self::Error> {
+                    Ok(Mock {
+                        id: row.get("id")?,
+                        settings: row.get::<welds::connections::AsJson<_>>("settings")?.0,
+                    })
+                }
+            }
+        "#;
+
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
     fn cleaned(input: &str) -> String {
         input.chars().filter(|c| !c.is_whitespace()).collect()
     }
@@ -8,20 +8,40 @@ pub(crate) fn write(info: &Info) -> TokenStream {
         .columns
         .iter()
         .filter(|x| !x.ignore)
-        .map(setfield)
+        .map(|col| setfield(&info.welds_path, col))
         .collect();
     let fields = quote! { #(#fields)* };
 
-    write_for_db(info, &fields)
+    let matches: Vec<_> = info
+        .columns
+        .iter()
+        .filter(|x| !x.ignore)
+        .map(|col| setfield_match(&info.welds_path, col))
+        .collect();
+    let matches = quote! { #(#matches)* };
+
+    write_for_db(info, &fields, &matches)
+}
+
+pub(crate) fn setfield(wp: &syn::Path, col: &Column) -> TokenStream {
+    let dbname = col.dbname.as_str();
+    let field = &col.field;
+    let value = col.row_get(wp, quote! { #dbname });
+    quote! { self.#field = #value; }
 }
 
-pub(crate) fn setfield(col: &Column) -> TokenStream {
+pub(crate) fn setfield_match(wp: &syn::Path, col: &Column) -> TokenStream {
     let dbname = col.dbname.as_str();
     let field = &col.field;
-    quote! { self.#field = row.get(#dbname)?; }
+    let value = col.row_get(wp, quote! { #dbname });
+    quote! { #dbname => self.#field = #value, }
 }
 
-pub(crate) fn write_for_db(info: &Info, fieldsets: &TokenStream) -> TokenStream {
+pub(crate) fn write_for_db(
+    info: &Info,
+    fieldsets: &TokenStream,
+    matches: &TokenStream,
+) -> TokenStream {
     let def = &info.defstruct;
     let wp = &info.welds_path;
 
@@ -32,6 +52,20 @@ pub(crate) fn write_for_db(info: &Info, fieldsets: &TokenStream) -> TokenStream
                 Ok(())
             }
           }
+
+          impl #wp::model_traits::UpdateColumnFromRow for #def {
+            fn update_column_from_row(&mut self, column: &str, row: &mut #wp::Row) -> #wp::errors::Result<()> {
+                match column {
+                    #matches
+                    _ => {
+                        return Err(#wp::errors::WeldsError::MissingDbColumn(
+                            column.to_owned(),
+                        ))
+                    }
+                }
+                Ok(())
+            }
+          }
     }
 }
 
@@ -55,6 +89,18 @@ mod tests {
                   Ok(())
               }
             }
+            impl welds::model_traits::UpdateColumnFromRow for Mock {
+              fn update_column_from_row(&mut self, column: &str, row: &mut welds::Row) -> welds::errors::Result<()> {
+                  match column {
+                      "id" => self.id = row.get("id")?,
+                      "name" => self.name = row.get("name")?,
+                      _ => {
+                          return Err(welds::errors::WeldsError::MissingDbColumn(column.to_owned(),))
+                      }
+                  }
+                  Ok(())
+              }
+            }
         "#;
 
         assert_eq!(cleaned(&code), cleaned(expected));
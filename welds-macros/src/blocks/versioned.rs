@@ -0,0 +1,59 @@
+use crate::info::Info;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub(crate) fn write(info: &Info) -> TokenStream {
+    // If this is a readonly model it can't be updated
+    if info.readonly {
+        return quote!();
+    }
+    let wp = &info.welds_path;
+    let def = &info.defstruct;
+
+    let col = match info.columns.iter().find(|c| c.version && !c.ignore) {
+        Some(col) => col,
+        None => return quote! { impl #wp::model_traits::Versioned for #def {} },
+    };
+    let column = &col.dbname;
+    let field = &col.field;
+
+    quote! {
+        impl #wp::model_traits::Versioned for #def {
+            fn version_column() -> Option<&'static str> {
+                Some(#column)
+            }
+            fn next_version(&mut self) {
+                self.#field += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_move_to_the_next_version() {
+        let info = Info::mock()
+            .add_pk("id", "i64")
+            .add_column("lock_version", "i32", false)
+            .with_version();
+        let code = write(&info).to_string();
+        let expected: &str = r#"
+            impl welds::model_traits::Versioned for Mock {
+                fn version_column() -> Option<&'static str> {
+                    Some("lock_version")
+                }
+                fn next_version(&mut self) {
+                    self.lock_version += 1;
+                }
+            }
+        "#;
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
+    fn cleaned(input: &str) -> String {
+        input.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+}
@@ -29,6 +29,10 @@ pub(crate) fn col_switch(col: &Column) -> TokenStream {
     if col.is_option {
         return quote! { #dbname => self.#field.is_none(), };
     }
+    // the value is only known as JSON, it isn't looked at
+    if col.json {
+        return quote! { #dbname => false, };
+    }
     if is_generic_type(field_type) {
         return quote! { #dbname => true, };
     }
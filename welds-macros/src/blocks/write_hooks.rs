@@ -1,3 +1,4 @@
+use crate::column::Column;
 use crate::hook::HookKind;
 use crate::info::Info;
 use proc_macro2::TokenStream;
@@ -46,9 +47,15 @@ pub(crate) fn write_before_create(info: &Info) -> TokenStream {
         .collect();
     let hook_calls = quote! { #(#hook_calls)* };
 
+    // the keys and timestamps are set first so the hooks see them
+    let keys = write_uuids(info);
+    let stamps = write_timestamps(info, |c| c.created_at || c.updated_at);
+
     quote! {
         impl #wp::model_traits::hooks::BeforeCreate for #def {
             fn before(&mut self) -> #wp::errors::Result<()> {
+                #keys
+                #stamps
                 #hook_calls
                 Ok(())
             }
@@ -81,6 +88,55 @@ pub(crate) fn write_after_create(info: &Info) -> TokenStream {
     }
 }
 
+/// makes a new uuid for each of the primary keys that haven't been given one
+fn write_uuids(info: &Info) -> TokenStream {
+    let wp = &info.welds_path;
+    let sets: Vec<_> = info
+        .pks
+        .iter()
+        .filter_map(|c| Some((c, c.uuid?)))
+        .map(|(c, new)| {
+            let field = &c.field;
+            let ty = &c.field_type;
+            let new = syn::Ident::new(new, proc_macro2::Span::call_site());
+            let key = quote! { <#ty as #wp::model_traits::UuidKey>::#new() };
+            match c.is_option {
+                true => quote! {
+                    if self.#field.is_none() {
+                        self.#field = Some(#key);
+                    }
+                },
+                false => quote! {
+                    if self.#field == <#ty as Default>::default() {
+                        self.#field = #key;
+                    }
+                },
+            }
+        })
+        .collect();
+    quote! { #(#sets)* }
+}
+
+/// sets each of the timestamp fields to now
+fn write_timestamps(info: &Info, filter: impl Fn(&&Column) -> bool) -> TokenStream {
+    let wp = &info.welds_path;
+    let sets: Vec<_> = info
+        .columns
+        .iter()
+        .filter(filter)
+        .map(|c| {
+            let field = &c.field;
+            let ty = &c.field_type;
+            let now = quote! { <#ty as #wp::model_traits::Timestamp>::now() };
+            match c.is_option {
+                true => quote! { self.#field = Some(#now); },
+                false => quote! { self.#field = #now; },
+            }
+        })
+        .collect();
+    quote! { #(#sets)* }
+}
+
 // Update
 
 pub(crate) fn write_before_update(info: &Info) -> TokenStream {
@@ -99,9 +155,12 @@ pub(crate) fn write_before_update(info: &Info) -> TokenStream {
         .collect();
     let hook_calls = quote! { #(#hook_calls)* };
 
+    let stamps = write_timestamps(info, |c| c.updated_at);
+
     quote! {
         impl #wp::model_traits::hooks::BeforeUpdate for #def {
             fn before(&mut self) -> #wp::errors::Result<()> {
+                #stamps
                 #hook_calls
                 Ok(())
             }
@@ -186,3 +245,63 @@ pub(crate) fn write_after_delete(info: &Info) -> TokenStream {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_set_the_timestamps_before_saving() {
+        let info = Info::mock()
+            .add_pk("id", "i64")
+            .add_column("created_at", "Stamp", false)
+            .with_created_at()
+            .add_column("updated_at", "Stamp", true)
+            .with_updated_at();
+
+        let code = write_before_create(&info).to_string();
+        let expected: &str = r#"
+            impl welds::model_traits::hooks::BeforeCreate for Mock {
+                fn before(&mut self) -> welds::errors::Result<()> {
+                    self.created_at = <Stamp as welds::model_traits::Timestamp>::now();
+                    self.updated_at = Some(<Stamp as welds::model_traits::Timestamp>::now());
+                    Ok(())
+                }
+            }
+        "#;
+        assert_eq!(cleaned(&code), cleaned(expected));
+
+        let code = write_before_update(&info).to_string();
+        let expected: &str = r#"
+            impl welds::model_traits::hooks::BeforeUpdate for Mock {
+                fn before(&mut self) -> welds::errors::Result<()> {
+                    self.updated_at = Some(<Stamp as welds::model_traits::Timestamp>::now());
+                    Ok(())
+                }
+            }
+        "#;
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
+    #[test]
+    fn should_make_the_uuid_key_before_creating() {
+        let info = Info::mock().add_pk("id", "Uuid").with_uuid_v4();
+
+        let code = write_before_create(&info).to_string();
+        let expected: &str = r#"
+            impl welds::model_traits::hooks::BeforeCreate for Mock {
+                fn before(&mut self) -> welds::errors::Result<()> {
+                    if self.id == <Uuid as Default>::default() {
+                        self.id = <Uuid as welds::model_traits::UuidKey>::new_v4();
+                    }
+                    Ok(())
+                }
+            }
+        "#;
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
+    fn cleaned(input: &str) -> String {
+        input.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+}
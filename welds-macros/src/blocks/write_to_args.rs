@@ -13,16 +13,19 @@ pub(crate) fn write(info: &Info) -> TokenStream {
         .columns
         .iter()
         .filter(|x| !x.ignore)
-        .map(write_col_normal)
+        .map(|col| write_col_normal(&info.welds_path, col))
         .collect();
     let fields = quote! { #(#fields)* };
 
     write_for_db(info, &fields)
 }
 
-pub(crate) fn write_col_normal(col: &Column) -> TokenStream {
+pub(crate) fn write_col_normal(wp: &syn::Path, col: &Column) -> TokenStream {
     let dbname = col.dbname.as_str();
     let field = &col.field;
+    if col.json {
+        return quote! { #dbname => args.push(#wp::connections::AsJson::from_ref(&self.#field)), };
+    }
     quote! { #dbname => args.push(&self.#field), }
 }
 
@@ -95,6 +98,18 @@ mod tests {
         assert_eq!(cleaned(&code), cleaned(expected));
     }
 
+    #[test]
+    fn should_bind_json_columns_as_json() {
+        let info = Info::mock()
+            .add_column("settings", "Settings", false)
+            .with_json();
+        let col = &info.columns[0];
+        let code = write_col_normal(&info.welds_path, col).to_string();
+        let expected =
+            r#""settings" => args.push(welds::connections::AsJson::from_ref(&self.settings)),"#;
+        assert_eq!(cleaned(&code), cleaned(expected));
+    }
+
     fn cleaned(input: &str) -> String {
         input.chars().filter(|c| !c.is_whitespace()).collect()
     }
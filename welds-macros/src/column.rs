@@ -1,3 +1,5 @@
+use proc_macro2::TokenStream;
+use quote::quote;
 use syn::Ident;
 use syn::Type;
 
@@ -8,4 +10,30 @@ pub(crate) struct Column {
     pub(crate) dbname: String,
     pub(crate) field_type: Type,
     pub(crate) is_option: bool,
+    pub(crate) index: bool,
+    pub(crate) index_name: Option<String>,
+    pub(crate) unique: bool,
+    pub(crate) default: Option<String>,
+    pub(crate) collation: Option<String>,
+    pub(crate) max_length: Option<i64>,
+    pub(crate) precision: Option<i64>,
+    pub(crate) scale: Option<i64>,
+    pub(crate) check_ignore: bool,
+    pub(crate) db_default: bool,
+    pub(crate) created_at: bool,
+    pub(crate) updated_at: bool,
+    pub(crate) version: bool,
+    pub(crate) json: bool,
+    /// the fn of UuidKey used to make a new key. I.E. `new_v4`
+    pub(crate) uuid: Option<&'static str>,
+}
+
+impl Column {
+    /// reads the value of the column out of `row`, by the name written in `name`
+    pub(crate) fn row_get(&self, wp: &syn::Path, name: TokenStream) -> TokenStream {
+        if self.json {
+            return quote! { row.get::<#wp::connections::AsJson<_>>(#name)?.0 };
+        }
+        quote! { row.get(#name)? }
+    }
 }
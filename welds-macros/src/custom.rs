@@ -0,0 +1,72 @@
+use crate::attributes;
+use crate::errors::Result;
+use proc_macro::TokenStream;
+use quote::quote;
+
+/// writes the code for a `#[derive(WeldsType)]`
+///
+/// A struct with a single field is stored as that field.
+/// With `#[welds(custom)]` the WeldsType impl is left to the user, only the encoding is written.
+pub(crate) fn write(input: TokenStream) -> Result<TokenStream> {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    let def = &ast.ident;
+    let wp = attributes::get_welds_path(&ast);
+
+    let welds_type = match attributes::get_custom(&ast) {
+        true => quote!(),
+        false => write_welds_type(&ast)?,
+    };
+
+    let q = quote! {
+
+        #welds_type
+
+        #wp::connections::__welds_type!(#def);
+
+    };
+    Ok(q.into())
+}
+
+/// stores the struct as its only field
+fn write_welds_type(ast: &syn::DeriveInput) -> Result<proc_macro2::TokenStream> {
+    let def = &ast.ident;
+    let wp = attributes::get_welds_path(ast);
+    let fields = match &ast.data {
+        syn::Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(format!(
+                "WeldsType can only store a struct with a single field, add #[welds(custom)] and impl WeldsType: {}",
+                def
+            ))
+        }
+    };
+    let field = match fields.iter().collect::<Vec<_>>().as_slice() {
+        [field] => *field,
+        _ => {
+            return Err(format!(
+                "WeldsType can only store a struct with a single field, add #[welds(custom)] and impl WeldsType: {}",
+                def
+            ))
+        }
+    };
+    let ty = &field.ty;
+    let (get, make) = match &field.ident {
+        Some(ident) => (quote!(self.#ident), quote!(Self { #ident: stored })),
+        None => (quote!(self.0), quote!(Self(stored))),
+    };
+
+    Ok(quote! {
+        impl #wp::connections::WeldsType for #def {
+            type Stored = #ty;
+            #[allow(clippy::clone_on_copy)]
+            fn to_stored(&self) -> Self::Stored {
+                #get.clone()
+            }
+            fn from_stored(
+                stored: Self::Stored,
+            ) -> ::std::result::Result<Self, Box<dyn ::std::error::Error + Send + Sync>> {
+                Ok(#make)
+            }
+        }
+    })
+}
@@ -0,0 +1,110 @@
+use crate::attributes;
+use crate::column::Column;
+use crate::errors::Result;
+use proc_macro::TokenStream;
+use quote::quote;
+
+/// writes the code for a `#[derive(WeldsDto)]`
+///
+/// The fields of the Dto are looked up on the Schema of the model,
+/// so a field missing from the model (or of a different type) fails to compile.
+pub(crate) fn write(input: TokenStream) -> Result<TokenStream> {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    let def = attributes::get_scructname(&ast);
+    let wp = attributes::get_welds_path(&ast);
+    let model = attributes::get_dto_model(&ast).ok_or_else(|| {
+        format!(
+            "WeldsDto requires the model it selects from: #[welds(model(YourModel))] on {}",
+            def
+        )
+    })?;
+    let columns = attributes::get_columns(&ast);
+
+    let selects: Vec<_> = columns
+        .iter()
+        .filter(|x| !x.ignore)
+        .map(|col| write_select(&wp, col))
+        .collect();
+
+    // the columns are selected as the name of the field
+    let try_from = write_try_from(&def, &wp, &columns, |col| col.field.to_string());
+
+    let q = quote! {
+
+        impl #wp::model_traits::Dto for #def {
+            type Model = #model;
+            fn select(
+                sb: #wp::query::select_cols::SelectBuilder<#model>,
+            ) -> #wp::query::select_cols::SelectBuilder<#model> {
+                sb #(#selects)*
+            }
+        }
+
+        #try_from
+
+    };
+    Ok(q.into())
+}
+
+/// writes the code for a `#[derive(WeldsRow)]`
+///
+/// Reads the columns of any row into the struct by name. Use `#[welds(rename = "...")]`
+/// when the column has a different name than the field.
+pub(crate) fn write_row(input: TokenStream) -> Result<TokenStream> {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    let def = attributes::get_scructname(&ast);
+    let wp = attributes::get_welds_path(&ast);
+    let columns = attributes::get_columns(&ast);
+    let try_from = write_try_from(&def, &wp, &columns, |col| col.dbname.clone());
+    Ok(try_from.into())
+}
+
+fn write_try_from(
+    def: &syn::Ident,
+    wp: &syn::Path,
+    columns: &[Column],
+    row_name: impl Fn(&Column) -> String,
+) -> proc_macro2::TokenStream {
+    let fields: Vec<_> = columns
+        .iter()
+        .filter(|x| !x.ignore)
+        .map(|col| {
+            let field = &col.field;
+            let name = row_name(col);
+            let value = col.row_get(wp, quote! { #name });
+            quote! { #field: #value, }
+        })
+        .collect();
+
+    // fields not read from the row are left as default
+    let defaults = if columns.iter().any(|x| x.ignore) {
+        quote! { ..Default::default() }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        impl TryFrom<#wp::Row> for #def {
+            type Error = #wp::WeldsError;
+            fn try_from(row: #wp::Row) -> std::result::Result<Self, Self::Error> {
+                Ok(#def {
+                    #(#fields)*
+                    #defaults
+                })
+            }
+        }
+    }
+}
+
+fn write_select(wp: &syn::Path, col: &Column) -> proc_macro2::TokenStream {
+    let field = &col.field;
+    let ty = &col.field_type;
+    quote! {
+        .select(|x| {
+            let field = x.#field;
+            // the field on the Dto must have the same type as the model
+            let _: &dyn #wp::query::clause::AsFieldName<#ty> = &field;
+            field
+        })
+    }
+}
@@ -0,0 +1,118 @@
+use crate::attributes;
+use crate::errors::Result;
+use proc_macro::TokenStream;
+use proc_macro2::Literal;
+use quote::quote;
+
+/// writes the code for a `#[derive(WeldsEnum)]`
+///
+/// Each variant is stored as its name, or `#[welds(rename = "...")]`.
+/// With `#[welds(store = "int")]` the variants are stored as their discriminant instead.
+pub(crate) fn write(input: TokenStream) -> Result<TokenStream> {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    let def = &ast.ident;
+    let wp = attributes::get_welds_path(&ast);
+    let data = match &ast.data {
+        syn::Data::Enum(data) => data,
+        _ => return Err(format!("WeldsEnum can only be derived on an enum: {}", def)),
+    };
+    let store = match attributes::get_enum_store(&ast).as_deref() {
+        None | Some("text") => quote!(text),
+        Some("int") => quote!(int),
+        Some(other) => {
+            return Err(format!(
+                "Unknown store \"{}\" on {}, expected \"text\" or \"int\"",
+                other, def
+            ))
+        }
+    };
+    if data.variants.is_empty() {
+        return Err(format!("WeldsEnum requires at least one variant: {}", def));
+    }
+
+    let mut idents = Vec::default();
+    let mut names: Vec<String> = Vec::default();
+    let mut ints: Vec<i32> = Vec::default();
+    // like rust, a variant without a discriminant is one more than the variant before it
+    let mut next: i64 = 0;
+    for variant in &data.variants {
+        let ident = &variant.ident;
+        if !matches!(variant.fields, syn::Fields::Unit) {
+            return Err(format!(
+                "The variants of a WeldsEnum can't hold values: {}::{}",
+                def, ident
+            ));
+        }
+        let value = match &variant.discriminant {
+            Some((_, expr)) => read_int(expr).ok_or_else(|| {
+                format!(
+                    "Expected the discriminant of {}::{} to be a number",
+                    def, ident
+                )
+            })?,
+            None => next,
+        };
+        next = value + 1;
+        let int = i32::try_from(value)
+            .map_err(|_| format!("The discriminant of {}::{} must fit in an i32", def, ident))?;
+        let name = attributes::read_variant_rename(variant).unwrap_or_else(|| ident.to_string());
+        if names.contains(&name) {
+            return Err(format!(
+                "{} has more than one variant stored as \"{}\"",
+                def, name
+            ));
+        }
+        idents.push(ident);
+        names.push(name);
+        ints.push(int);
+    }
+    let ints: Vec<Literal> = ints.into_iter().map(Literal::i32_unsuffixed).collect();
+
+    let q = quote! {
+
+        impl #wp::connections::WeldsEnum for #def {
+            fn as_text(&self) -> &'static str {
+                match self {
+                    #( Self::#idents => #names, )*
+                }
+            }
+            fn from_text(text: &str) -> Option<Self> {
+                match text {
+                    #( #names => Some(Self::#idents), )*
+                    _ => None,
+                }
+            }
+            fn as_int(&self) -> i32 {
+                match self {
+                    #( Self::#idents => #ints, )*
+                }
+            }
+            fn from_int(value: i32) -> Option<Self> {
+                match value {
+                    #( #ints => Some(Self::#idents), )*
+                    _ => None,
+                }
+            }
+        }
+
+        #wp::connections::__welds_enum!(#def, #store);
+
+    };
+    Ok(q.into())
+}
+
+/// reads a discriminant like `3` or `-1`
+fn read_int(expr: &syn::Expr) -> Option<i64> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(i),
+            ..
+        }) => i.base10_parse().ok(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => read_int(expr).map(|i| -i),
+        _ => None,
+    }
+}
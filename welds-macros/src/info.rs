@@ -3,6 +3,7 @@ use crate::column::Column;
 use crate::errors::Result;
 use crate::hook::Hook;
 use crate::relation::Relation;
+use crate::scope::Scope;
 use syn::Ident;
 
 pub(crate) struct Info {
@@ -12,10 +13,13 @@ pub(crate) struct Info {
     pub pks: Vec<Column>,
     pub relations: Vec<Relation>,
     pub hooks: Vec<Hook>,
+    pub scopes: Vec<Scope>,
     pub relations_struct: Ident,
     pub tablename: String,
     pub schemaname: Option<String>,
     pub readonly: bool,
+    pub soft_delete: Option<Column>,
+    pub check_extra_columns: bool,
     pub welds_path: syn::Path,
 }
 
@@ -23,6 +27,7 @@ impl Info {
     pub fn new(ast: &syn::DeriveInput) -> Result<Self> {
         let relations = attributes::get_relations(ast)?;
         let hooks = attributes::get_hooks(ast)?;
+        let scopes = attributes::get_scopes(ast)?;
         let defstruct = attributes::get_scructname(ast);
         let schemastruct_name = format!("{}Schema", defstruct);
         let schemastruct = Ident::new(&schemastruct_name, defstruct.span());
@@ -30,9 +35,16 @@ impl Info {
         let relations_struct = Ident::new(&relations_struct_name, defstruct.span());
         let tablename = attributes::get_tablename(ast);
         let schemaname = attributes::get_schemaname(ast);
-        let columns = attributes::get_columns(ast);
+        let mut columns = attributes::get_columns(ast);
+        if attributes::get_timestamps(ast) {
+            attributes::use_timestamp_fields(&mut columns)?;
+        }
         let pks = attributes::get_pks(ast);
         let readonly = attributes::get_readonly(ast);
+        let soft_delete = attributes::get_soft_delete(ast, &columns)?;
+        attributes::check_version(&columns)?;
+        attributes::check_uuid(&columns, &pks)?;
+        let check_extra_columns = attributes::get_check_extra_columns(ast);
         let welds_path = attributes::get_welds_path(ast);
 
         Ok(Self {
@@ -41,11 +53,14 @@ impl Info {
             defstruct,
             relations,
             hooks,
+            scopes,
             schemastruct,
             relations_struct,
             tablename,
             schemaname,
             readonly,
+            soft_delete,
+            check_extra_columns,
             welds_path,
         })
     }
@@ -68,10 +83,13 @@ mod tests {
                 pks: Vec::default(),
                 relations: Vec::default(),
                 hooks: Vec::default(),
+                scopes: Vec::default(),
                 relations_struct: Ident::new("MockRelationships", Span::call_site()),
                 tablename: "datables".to_string(),
                 schemaname: Some("daschema".to_string()),
                 readonly: false,
+                soft_delete: None,
+                check_extra_columns: true,
                 welds_path: Ident::new("welds", Span::call_site()).into(),
             }
         }
@@ -92,6 +110,21 @@ mod tests {
                 dbname: name,
                 field_type,
                 is_option: null,
+                index: false,
+                index_name: None,
+                unique: false,
+                default: None,
+                collation: None,
+                max_length: None,
+                precision: None,
+                scale: None,
+                check_ignore: false,
+                db_default: false,
+                created_at: false,
+                updated_at: false,
+                version: false,
+                json: false,
+                uuid: None,
             };
             self.columns.push(col);
             self
@@ -109,10 +142,133 @@ mod tests {
                 dbname: name,
                 field_type,
                 is_option: false,
+                index: false,
+                index_name: None,
+                unique: false,
+                default: None,
+                collation: None,
+                max_length: None,
+                precision: None,
+                scale: None,
+                check_ignore: false,
+                db_default: false,
+                created_at: false,
+                updated_at: false,
+                version: false,
+                json: false,
+                uuid: None,
             };
             self.columns.push(col.clone());
             self.pks.push(col);
             self
         }
+
+        pub(crate) fn add_relation(
+            mut self,
+            kind: &str,
+            field: &str,
+            other: &str,
+            foreign_key: &str,
+        ) -> Info {
+            self.relations.push(crate::relation::Relation {
+                kind: Ident::new(kind, Span::call_site()),
+                field: Ident::new(field, Span::call_site()),
+                foreign_struct: syn::parse_str(other).unwrap(),
+                foreign_key: foreign_key.to_string(),
+            });
+            self
+        }
+
+        /// sets the default on the last column added
+        pub(crate) fn with_default(mut self, default: &str) -> Info {
+            let col = self.columns.last_mut().unwrap();
+            col.default = Some(default.to_string());
+            self
+        }
+
+        /// sets the collation on the last column added
+        pub(crate) fn with_collation(mut self, collation: &str) -> Info {
+            let col = self.columns.last_mut().unwrap();
+            col.collation = Some(collation.to_string());
+            self
+        }
+
+        /// marks the last column added as set to now when the model is created
+        pub(crate) fn with_created_at(mut self) -> Info {
+            let col = self.columns.last_mut().unwrap();
+            col.created_at = true;
+            self
+        }
+
+        /// marks the last column added as set to now every time the model is saved
+        pub(crate) fn with_updated_at(mut self) -> Info {
+            let col = self.columns.last_mut().unwrap();
+            col.updated_at = true;
+            self
+        }
+
+        /// makes the keys of the last pk added with UuidKey::new_v4
+        pub(crate) fn with_uuid_v4(mut self) -> Info {
+            self.pks.last_mut().unwrap().uuid = Some("new_v4");
+            self.columns.last_mut().unwrap().uuid = Some("new_v4");
+            self
+        }
+
+        /// stores the last column added as JSON
+        pub(crate) fn with_json(mut self) -> Info {
+            let col = self.columns.last_mut().unwrap();
+            col.json = true;
+            self
+        }
+
+        /// marks the last column added as the version of the row
+        pub(crate) fn with_version(mut self) -> Info {
+            let col = self.columns.last_mut().unwrap();
+            col.version = true;
+            self
+        }
+
+        /// soft deletes the model with the last column added
+        pub(crate) fn with_soft_delete(mut self) -> Info {
+            self.soft_delete = self.columns.last().cloned();
+            self
+        }
+
+        /// marks the last column added as filled in by the database
+        pub(crate) fn with_db_default(mut self) -> Info {
+            let col = self.columns.last_mut().unwrap();
+            col.db_default = true;
+            self
+        }
+
+        /// marks the last column added as unique
+        pub(crate) fn with_unique(mut self) -> Info {
+            let col = self.columns.last_mut().unwrap();
+            col.unique = true;
+            self
+        }
+
+        /// sets the max_length on the last column added
+        pub(crate) fn with_max_length(mut self, max_length: i64) -> Info {
+            let col = self.columns.last_mut().unwrap();
+            col.max_length = Some(max_length);
+            self
+        }
+
+        /// sets the precision and scale on the last column added
+        pub(crate) fn with_precision(mut self, precision: i64, scale: i64) -> Info {
+            let col = self.columns.last_mut().unwrap();
+            col.precision = Some(precision);
+            col.scale = Some(scale);
+            self
+        }
+
+        /// marks the last column added as indexed
+        pub(crate) fn with_index(mut self, name: Option<&str>) -> Info {
+            let col = self.columns.last_mut().unwrap();
+            col.index = true;
+            col.index_name = name.map(|x| x.to_string());
+            self
+        }
     }
 }
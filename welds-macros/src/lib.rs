@@ -4,10 +4,14 @@ use quote::quote;
 pub(crate) mod attributes;
 pub(crate) mod blocks;
 pub(crate) mod column;
+pub(crate) mod custom;
+pub(crate) mod dto;
+pub(crate) mod enums;
 pub(crate) mod errors;
 pub(crate) mod hook;
 pub(crate) mod info;
 pub(crate) mod relation;
+pub(crate) mod scope;
 pub(crate) mod utils;
 
 use info::Info;
@@ -20,6 +24,38 @@ pub fn model_gen(input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro_derive(WeldsDto, attributes(welds, welds_path))]
+pub fn dto_gen(input: TokenStream) -> TokenStream {
+    match dto::write(input) {
+        Ok(q) => q,
+        Err(err) => quote! { std::compile_error!(#err); }.into(),
+    }
+}
+
+#[proc_macro_derive(WeldsEnum, attributes(welds, welds_path))]
+pub fn enum_gen(input: TokenStream) -> TokenStream {
+    match enums::write(input) {
+        Ok(q) => q,
+        Err(err) => quote! { std::compile_error!(#err); }.into(),
+    }
+}
+
+#[proc_macro_derive(WeldsType, attributes(welds, welds_path))]
+pub fn type_gen(input: TokenStream) -> TokenStream {
+    match custom::write(input) {
+        Ok(q) => q,
+        Err(err) => quote! { std::compile_error!(#err); }.into(),
+    }
+}
+
+#[proc_macro_derive(WeldsRow, attributes(welds, welds_path))]
+pub fn row_gen(input: TokenStream) -> TokenStream {
+    match dto::write_row(input) {
+        Ok(q) => q,
+        Err(err) => quote! { std::compile_error!(#err); }.into(),
+    }
+}
+
 fn model_gen_inner(input: TokenStream) -> errors::Result<TokenStream> {
     // Gather the Info needed to build all the code snipits
     let ast: syn::DeriveInput = syn::parse(input).unwrap();
@@ -39,6 +75,13 @@ fn model_gen_inner(input: TokenStream) -> errors::Result<TokenStream> {
     let p11 = blocks::update_from_row(&info);
     let p12 = blocks::write_col_default_check(&info);
     let p13 = blocks::write_hooks(&info);
+    let p14 = blocks::table_indexes(&info);
+    let p15 = blocks::table_relations(&info);
+    let p16 = blocks::table_check(&info);
+    let p17 = blocks::primary_key_values(&info);
+    let p18 = blocks::from_prefixed_row(&info);
+    let p19 = blocks::soft_delete(&info);
+    let p20 = blocks::versioned(&info);
 
     let q = quote! {
         #p1
@@ -54,6 +97,13 @@ fn model_gen_inner(input: TokenStream) -> errors::Result<TokenStream> {
         #p11
         #p12
         #p13
+        #p14
+        #p15
+        #p16
+        #p17
+        #p18
+        #p19
+        #p20
     };
 
     //  // Want to see what the macros generate?
@@ -0,0 +1,36 @@
+use crate::errors::Result;
+use syn::Ident;
+use syn::MetaList;
+
+/// A named filter declared on the model, `scope(active = "$.deleted_at IS NULL")`
+#[derive(Debug)]
+pub(crate) struct Scope {
+    pub(crate) name: Ident,
+    pub(crate) sql: String,
+}
+
+impl Scope {
+    pub(crate) fn new(list: &MetaList) -> Result<Vec<Self>> {
+        let mut scopes = Vec::default();
+        for inner in &list.nested {
+            let nv = match inner {
+                syn::NestedMeta::Meta(syn::Meta::NameValue(nv)) => nv,
+                _ => return Err(FORMAT_ERR_SCOPE.to_owned()),
+            };
+            let name = match nv.path.get_ident() {
+                Some(name) => name.clone(),
+                None => return Err(FORMAT_ERR_SCOPE.to_owned()),
+            };
+            let sql = match &nv.lit {
+                syn::Lit::Str(s) => s.value(),
+                _ => return Err(FORMAT_ERR_SCOPE.to_owned()),
+            };
+            scopes.push(Scope { name, sql });
+        }
+        Ok(scopes)
+    }
+}
+
+const FORMAT_ERR_SCOPE: &str = "Invalid Format For scope:
+scope should be in for format of
+[ welds(scope(name = \"sql\", other_name = \"sql\") )]";
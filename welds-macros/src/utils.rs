@@ -38,12 +38,35 @@ fn get_clause_typepath(ty: &TypePath) -> Option<&'static str> {
         "f64" => "Numeric",
         "String" => "Text",
         "chrono" => "Numeric",
+        "DateTime" => "Numeric",
+        "NaiveDate" => "Numeric",
+        "NaiveDateTime" => "Numeric",
+        "NaiveTime" => "Numeric",
         "PgMoney" => "Numeric",
+        "serde_json" => "Json",
+        "Json" => "Json",
+        "Vec" if !is_bytes(ty) => "Array",
         _ => return None,
     };
     Some(clause)
 }
 
+/// Vec<u8> is binary data, not an array
+fn is_bytes(ty: &TypePath) -> bool {
+    let last = match ty.path.segments.last() {
+        Some(x) => x,
+        None => return false,
+    };
+    let args = match &last.arguments {
+        syn::PathArguments::AngleBracketed(args) => args,
+        _ => return false,
+    };
+    matches!(
+        args.args.first(),
+        Some(syn::GenericArgument::Type(syn::Type::Path(inner))) if inner.path.is_ident("u8")
+    )
+}
+
 pub(crate) fn as_typepath(ty: &syn::Type) -> Option<&syn::TypePath> {
     match ty {
         syn::Type::Path(tp) => Some(tp),
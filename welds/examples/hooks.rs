@@ -11,7 +11,6 @@ use welds::prelude::*;
 #[welds(AfterUpdate(after_update))]
 #[welds(BeforeDelete(before_delete))]
 #[welds(AfterDelete(after_delete))]
-
 pub struct Product {
     #[welds(primary_key)]
     #[welds(rename = "product_id")]
@@ -57,6 +57,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Simple basic struct to put our data in
     #[derive(Debug)]
+    #[allow(dead_code)]
     struct View {
         name: String,
         price: Option<f32>,
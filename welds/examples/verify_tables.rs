@@ -58,5 +58,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    println!();
+    // Or check all your models at once
+    println!("All Models:");
+    let models = welds::models![NotInDb, Order];
+    for table in welds::check::schema_all(client.as_ref(), &models).await? {
+        println!("{}: {} issues", table.ident, table.issues.len());
+    }
+
+    println!();
+    // Find the tables in the database that no model is using
+    println!("Tables without a model:");
+    for table in welds::check::unmapped_tables(client.as_ref(), &models).await? {
+        println!("{}", table.ident());
+    }
+
     Ok(())
 }
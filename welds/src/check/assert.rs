@@ -0,0 +1,112 @@
+use super::{schema_all, Issue, Registration};
+use crate::errors::{Result, WeldsError};
+use crate::Client;
+
+/// Checks every registered model against the database and fails if any of them would cause
+/// queries to error. Intended to be called on startup so a service refuses to run against
+/// a database it isn't compatible with.
+///
+/// ```rust,ignore
+/// let models = welds::models![Product, Order, Seller];
+/// welds::check::assert_schema_all(client, &models).await?;
+/// ```
+///
+/// The error returned is a `WeldsError::SchemaMismatch` with a table of every error found
+pub async fn assert_schema_all(client: &dyn Client, models: &[Registration]) -> Result<()> {
+    let tables = schema_all(client, models).await?;
+    let errors: Vec<&Issue> = tables
+        .iter()
+        .flat_map(|t| t.issues.iter())
+        .filter(|i| i.is_error())
+        .collect();
+    if errors.is_empty() {
+        return Ok(());
+    }
+    Err(WeldsError::SchemaMismatch(format_table(&errors)))
+}
+
+/// Writes out the issues as a plain text table
+fn format_table(issues: &[&Issue]) -> String {
+    let headers = ["TABLE", "SEVERITY", "ISSUE"];
+    let rows: Vec<[String; 3]> = issues
+        .iter()
+        .map(|i| {
+            [
+                i.ident.to_string(),
+                format!("{:?}", i.severity),
+                i.kind.to_string(),
+            ]
+        })
+        .collect();
+
+    let mut widths = headers.map(|h| h.len());
+    for row in &rows {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(cell.len());
+        }
+    }
+
+    let line = |cells: [&str; 3]| {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .map(|(c, w)| format!("{:<w$}", c, w = w))
+            .collect();
+        padded.join(" | ").trim_end().to_string()
+    };
+
+    let mut out = vec![line(headers)];
+    out.push(widths.map(|w| "-".repeat(w)).join("-+-"));
+    for row in &rows {
+        out.push(line([&row[0], &row[1], &row[2]]));
+    }
+    out.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Syntax;
+    use crate::WeldsModel;
+
+    #[derive(Debug, Default, WeldsModel)]
+    #[welds(schema = "shop", table = "cars")]
+    #[welds_path(crate)] // needed only within the welds crate.
+    struct Car {
+        #[welds(primary_key)]
+        id: i32,
+    }
+
+    #[test]
+    fn should_format_errors_as_a_table() {
+        let missing = Issue::missing_table(Some("shop"), "cars");
+        let table = format_table(&[&missing]);
+        let expected = "\
+TABLE     | SEVERITY | ISSUE
+----------+----------+----------------------------------------
+shop.cars | Error    | The Table was not found in the database";
+        assert_eq!(table, expected);
+    }
+
+    #[test]
+    fn should_fail_when_a_table_is_missing() {
+        let models = crate::models![Car];
+        let result = futures::executor::block_on(async move {
+            let client = welds_connections::noop::build(Syntax::Postgres);
+            assert_schema_all(&client, &models).await
+        });
+        match result {
+            Err(WeldsError::SchemaMismatch(table)) => assert!(table.contains("shop.cars")),
+            other => panic!("expected a SchemaMismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_pass_with_no_models() {
+        let result = futures::executor::block_on(async move {
+            let client = welds_connections::noop::build(Syntax::Postgres);
+            assert_schema_all(&client, &[]).await
+        });
+        assert!(result.is_ok());
+    }
+}
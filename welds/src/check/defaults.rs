@@ -0,0 +1,126 @@
+/// Rewrites a column default into a form that can be compared across databases.
+///
+/// Each database reports the same default differently.
+/// E.g. `'abc'::character varying` (postgres), `('abc')` (mssql), `abc` (mysql)
+pub(crate) fn normalize(raw: &str) -> String {
+    let mut value: String = raw.trim().to_lowercase();
+    loop {
+        let before = value.clone();
+        value = strip_parens(&value).trim().to_string();
+        value = strip_cast(&value).trim().to_string();
+        value = strip_national(&value).to_string();
+        if before == value {
+            break;
+        }
+    }
+    let value = unquote(&value);
+    alias(&value).to_string()
+}
+
+/// (expr) => expr, only when the outer parens wrap the whole expression
+fn strip_parens(value: &str) -> &str {
+    if !value.starts_with('(') || !value.ends_with(')') {
+        return value;
+    }
+    let mut depth = 0;
+    for (i, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => {}
+        }
+        if depth == 0 && i != value.len() - 1 {
+            return value;
+        }
+    }
+    &value[1..value.len() - 1]
+}
+
+/// expr::type => expr, only for casts that are not nested inside the expression
+fn strip_cast(value: &str) -> &str {
+    let mut depth = 0;
+    let mut quoted = false;
+    let bytes = value.as_bytes();
+    for i in 0..bytes.len() {
+        match bytes[i] {
+            b'\'' => quoted = !quoted,
+            b'(' if !quoted => depth += 1,
+            b')' if !quoted => depth -= 1,
+            b':' if !quoted && depth == 0 && bytes.get(i + 1) == Some(&b':') => {
+                let ty = &value[i + 2..];
+                let is_type = ty
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || " _[](),".contains(c));
+                if is_type {
+                    return &value[..i];
+                }
+            }
+            _ => {}
+        }
+    }
+    value
+}
+
+/// N'abc' => 'abc'
+fn strip_national(value: &str) -> &str {
+    if value.starts_with("n'") && value.ends_with('\'') {
+        return &value[1..];
+    }
+    value
+}
+
+/// 'abc' => abc
+fn unquote(value: &str) -> String {
+    if value.len() >= 2 && value.starts_with('\'') && value.ends_with('\'') {
+        return value[1..value.len() - 1].replace("''", "'");
+    }
+    value.to_string()
+}
+
+/// expressions that mean the same thing in different databases
+fn alias(value: &str) -> &str {
+    match value {
+        "now()" | "current_timestamp()" | "getdate()" | "sysdatetime()" | "datetime('now')" => {
+            "current_timestamp"
+        }
+        "gen_random_uuid()" | "uuid_generate_v4()" | "newid()" | "uuid()" => "uuid()",
+        "true" => "1",
+        "false" => "0",
+        _ => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_strip_postgres_casts() {
+        assert_eq!(normalize("'abc'::character varying"), "abc");
+        assert_eq!(normalize("'{}'::jsonb"), "{}");
+        assert_eq!(
+            normalize("nextval('cars_id_seq'::regclass)"),
+            "nextval('cars_id_seq'::regclass)"
+        );
+    }
+
+    #[test]
+    fn should_strip_mssql_parens() {
+        assert_eq!(normalize("((0))"), "0");
+        assert_eq!(normalize("(N'abc')"), "abc");
+        assert_eq!(normalize("(getdate())"), "current_timestamp");
+        assert_eq!(normalize("(1) + (2)"), "(1) + (2)");
+    }
+
+    #[test]
+    fn should_treat_timestamps_and_uuids_as_the_same() {
+        assert_eq!(normalize("now()"), normalize("CURRENT_TIMESTAMP"));
+        assert_eq!(normalize("gen_random_uuid()"), normalize("(newid())"));
+    }
+
+    #[test]
+    fn should_treat_bools_and_ints_as_the_same() {
+        assert_eq!(normalize("true"), normalize("'1'"));
+        assert_eq!(normalize("FALSE"), normalize("((0))"));
+    }
+}
@@ -0,0 +1,448 @@
+use super::{AutoIncrementDiff, Diff, Issue, Kind, Missing, MissingIndex};
+use crate::model_traits::TableIdent;
+use crate::writers::types::{get_pairs, recommended_db_type};
+use crate::Syntax;
+
+impl Issue {
+    /// Returns the SQL that would change the database to line up with the welds struct.
+    ///
+    /// None is returned if there isn't a safe/known way to fix this issue with a single statement
+    /// (missing tables, primary keys, foreign keys, views, and most column changes in sqlite)
+    pub fn fix_sql(&self, syntax: Syntax) -> Option<String> {
+        let table = &self.ident;
+        match &self.kind {
+            Kind::MissingTable => None,
+            Kind::InOtherSchema(_) => None,
+            Kind::InDbNotModel(missing) => Some(drop_column(table, missing)),
+            Kind::OnModelNotDb(missing) => add_column(syntax, table, missing),
+            Kind::Changed(diff) => alter_column(syntax, table, diff),
+            Kind::PrimaryKeyChanged(_) => None,
+            Kind::AutoIncrementChanged(diff) => add_auto_increment(syntax, table, diff),
+            Kind::MissingForeignKey(_) => None,
+            Kind::BrokenForeignKey(_) => None,
+            Kind::MissingIndex(index) => Some(create_index(table, index)),
+            Kind::ExtraIndex(index) => drop_index(syntax, table, index),
+            Kind::ViewOnly => None,
+            Kind::MissingUniqueConstraint(index) => Some(create_unique_index(table, index)),
+        }
+    }
+}
+
+/// Returns a script with the SQL to fix all the issues that can be fixed.
+/// Issues that can't be fixed are left out.
+pub fn fix_script(issues: &[Issue], syntax: Syntax) -> String {
+    issues
+        .iter()
+        .filter_map(|x| x.fix_sql(syntax))
+        .map(|x| format!("{x};\n"))
+        .collect()
+}
+
+fn drop_column(table: &TableIdent, missing: &Missing) -> String {
+    let col = sanitize(&missing.column);
+    format!("ALTER TABLE {table} DROP COLUMN {col}")
+}
+
+fn add_column(syntax: Syntax, table: &TableIdent, missing: &Missing) -> Option<String> {
+    let col = sanitize(&missing.column);
+    let ty = db_type_for_rust(syntax, &missing.ty)?;
+    let null = null_sql(missing.nullable);
+    Some(match syntax {
+        Syntax::Mssql => format!("ALTER TABLE {table} ADD {col} {ty} {null}"),
+        _ => format!("ALTER TABLE {table} ADD COLUMN {col} {ty} {null}"),
+    })
+}
+
+fn alter_column(syntax: Syntax, table: &TableIdent, diff: &Diff) -> Option<String> {
+    let col = sanitize(&diff.column);
+    let ty = match diff.type_changed {
+        true => db_type_for_rust(syntax, &diff.welds_type)?,
+        false => sized_db_type(syntax, &diff.db_type),
+    };
+    let ty = match diff.size_changed {
+        true => resized_db_type(&ty, diff),
+        false => ty,
+    };
+    let null = null_sql(diff.welds_nullable);
+    let default = diff.welds_default.as_ref().filter(|_| diff.default_changed);
+    let collate = match diff
+        .welds_collation
+        .as_ref()
+        .filter(|_| diff.collation_changed)
+    {
+        Some(collation) if syntax == Syntax::Postgres => format!(" COLLATE \"{collation}\""),
+        Some(collation) => format!(" COLLATE {collation}"),
+        None => String::default(),
+    };
+
+    match syntax {
+        // sqlite can only change a column by rebuilding the table.
+        Syntax::Sqlite => None,
+        Syntax::Postgres => {
+            let mut parts = Vec::default();
+            if diff.type_changed {
+                parts.push(format!(
+                    "ALTER COLUMN {col} TYPE {ty}{collate} USING {col}::{ty}"
+                ));
+            } else if diff.collation_changed || diff.size_changed {
+                parts.push(format!("ALTER COLUMN {col} TYPE {ty}{collate}"));
+            }
+            if diff.nullable_changed() {
+                match diff.welds_nullable {
+                    true => parts.push(format!("ALTER COLUMN {col} DROP NOT NULL")),
+                    false => parts.push(format!("ALTER COLUMN {col} SET NOT NULL")),
+                }
+            }
+            if let Some(default) = default {
+                parts.push(format!("ALTER COLUMN {col} SET DEFAULT {default}"));
+            }
+            if parts.is_empty() {
+                return None;
+            }
+            Some(format!("ALTER TABLE {table} {}", parts.join(", ")))
+        }
+        // MODIFY COLUMN rewrites the whole column, the default is written again so it isn't dropped
+        Syntax::Mysql => match mysql_default(diff) {
+            Some(default) => Some(format!(
+                "ALTER TABLE {table} MODIFY COLUMN {col} {ty}{collate} {null} DEFAULT {default}"
+            )),
+            None => Some(format!(
+                "ALTER TABLE {table} MODIFY COLUMN {col} {ty}{collate} {null}"
+            )),
+        },
+        // NOTE: mssql defaults are named constraints, they are not changed here
+        Syntax::Mssql => {
+            if !diff.type_changed
+                && !diff.nullable_changed()
+                && !diff.collation_changed
+                && !diff.size_changed
+            {
+                return None;
+            }
+            Some(format!(
+                "ALTER TABLE {table} ALTER COLUMN {col} {ty}{collate} {null}"
+            ))
+        }
+    }
+}
+
+/// The default the column should have, the one on the model or else the one already in the DB
+fn mysql_default(diff: &Diff) -> Option<String> {
+    if let Some(default) = &diff.welds_default {
+        return Some(default.to_owned());
+    }
+    let raw = diff.db_default.as_deref()?.trim();
+    // mysql reports literals without their quotes. I.E. abc for 'abc'
+    let lower = raw.to_lowercase();
+    let keywords = [
+        "current_timestamp",
+        "now(",
+        "localtime",
+        "current_date",
+        "current_time",
+    ];
+    let quoted = raw.len() >= 2 && raw.starts_with('\'') && raw.ends_with('\'');
+    if quoted || raw.parse::<f64>().is_ok() || keywords.iter().any(|k| lower.starts_with(k)) {
+        return Some(raw.to_string());
+    }
+    // an expression default. I.E. (uuid())
+    if raw.contains('(') {
+        return Some(format!("({raw})"));
+    }
+    Some(format!(
+        "'{}'",
+        raw.replace('\\', "\\\\").replace('\'', "''")
+    ))
+}
+
+/// only adding auto increment is supported, and only where the column can be changed in place
+fn add_auto_increment(
+    syntax: Syntax,
+    table: &TableIdent,
+    diff: &AutoIncrementDiff,
+) -> Option<String> {
+    if !diff.welds_auto_increment {
+        return None;
+    }
+    let col = sanitize(&diff.column);
+    match syntax {
+        Syntax::Postgres => Some(format!(
+            "ALTER TABLE {table} ALTER COLUMN {col} ADD GENERATED BY DEFAULT AS IDENTITY"
+        )),
+        Syntax::Mysql => Some(format!(
+            "ALTER TABLE {table} MODIFY COLUMN {col} {} NOT NULL AUTO_INCREMENT",
+            diff.db_type
+        )),
+        Syntax::Mssql | Syntax::Sqlite => None,
+    }
+}
+
+fn create_index(table: &TableIdent, index: &MissingIndex) -> String {
+    let cols: Vec<String> = index.columns.iter().map(|x| sanitize(x)).collect();
+    let name = match &index.name {
+        Some(n) => n.to_owned(),
+        None => format!("ix_{}_{}", table.name, cols.join("_")),
+    };
+    format!("CREATE INDEX {name} ON {table} ( {} )", cols.join(", "))
+}
+
+fn create_unique_index(table: &TableIdent, index: &MissingIndex) -> String {
+    let cols: Vec<String> = index.columns.iter().map(|x| sanitize(x)).collect();
+    let name = match &index.name {
+        Some(n) => n.to_owned(),
+        None => format!("ux_{}_{}", table.name, cols.join("_")),
+    };
+    format!(
+        "CREATE UNIQUE INDEX {name} ON {table} ( {} )",
+        cols.join(", ")
+    )
+}
+
+fn drop_index(syntax: Syntax, table: &TableIdent, index: &MissingIndex) -> Option<String> {
+    let name = index.name.as_ref()?;
+    Some(match syntax {
+        Syntax::Postgres => match &table.schema {
+            Some(schema) => format!("DROP INDEX {schema}.{name}"),
+            None => format!("DROP INDEX {name}"),
+        },
+        Syntax::Sqlite => format!("DROP INDEX {name}"),
+        Syntax::Mysql | Syntax::Mssql => format!("DROP INDEX {name} ON {table}"),
+    })
+}
+
+fn null_sql(nullable: bool) -> &'static str {
+    if nullable {
+        "NULL"
+    } else {
+        "NOT NULL"
+    }
+}
+
+/// The DB type to use for a rust type, sized if the DB requires it
+fn db_type_for_rust(syntax: Syntax, rust_type: &str) -> Option<String> {
+    let pair = recommended_db_type(syntax, rust_type)?;
+    match pair.default_size() {
+        Some(size) if pair.db_sized() => Some(format!("{}({})", pair.db_type(), size)),
+        _ => Some(pair.db_type()),
+    }
+}
+
+/// adds a size to a db type if the DB requires one
+fn sized_db_type(syntax: Syntax, db_type: &str) -> String {
+    let pairs = get_pairs(syntax);
+    let found = pairs
+        .iter()
+        .find(|&p| p.db_type().eq_ignore_ascii_case(db_type));
+    match found.and_then(|f| f.default_size()) {
+        Some(size) => format!("{}({})", db_type, size),
+        None => db_type.to_string(),
+    }
+}
+
+/// replaces the size of a db type with the size the model expects
+fn resized_db_type(db_type: &str, diff: &Diff) -> String {
+    let base = match db_type.find('(') {
+        Some(index) => &db_type[..index],
+        None => db_type,
+    };
+    let precision = diff.welds_precision.or(diff.db_precision);
+    let scale = diff.welds_scale.or(diff.db_scale);
+    if let Some(precision) = precision {
+        return match scale {
+            Some(scale) => format!("{base}({precision},{scale})"),
+            None => format!("{base}({precision})"),
+        };
+    }
+    match diff.welds_max_length.or(diff.db_max_length) {
+        Some(len) => format!("{base}({len})"),
+        None => db_type.to_string(),
+    }
+}
+
+/// Make sure this string is a valid column name
+fn sanitize(input: &str) -> String {
+    input
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == '_')
+        .collect::<String>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check::{Level, Severity};
+
+    fn changed(diff: Diff) -> Issue {
+        Issue::changed(Some("public"), "cars", diff)
+    }
+
+    fn diff() -> Diff {
+        Diff {
+            column: "name".to_string(),
+            db_type: "TEXT".to_string(),
+            db_nullable: true,
+            welds_type: "String".to_string(),
+            welds_nullable: false,
+            type_changed: false,
+            db_default: None,
+            welds_default: None,
+            default_changed: false,
+            db_collation: None,
+            welds_collation: None,
+            collation_changed: false,
+            db_max_length: None,
+            welds_max_length: None,
+            db_precision: None,
+            welds_precision: None,
+            db_scale: None,
+            welds_scale: None,
+            size_changed: false,
+            suggested_rust_type: None,
+        }
+    }
+
+    #[test]
+    fn should_write_add_column_for_each_syntax() {
+        let missing = Missing {
+            column: "name".to_string(),
+            ty: "String".to_string(),
+            nullable: true,
+        };
+        let kind = Kind::OnModelNotDb(missing);
+        let issue = Issue {
+            ident: TableIdent::parse("cars"),
+            level: Level::Critical,
+            severity: Severity::classify(&kind),
+            kind,
+        };
+        assert_eq!(
+            issue.fix_sql(Syntax::Postgres).unwrap(),
+            "ALTER TABLE cars ADD COLUMN name TEXT NULL"
+        );
+        assert_eq!(
+            issue.fix_sql(Syntax::Mssql).unwrap(),
+            "ALTER TABLE cars ADD name NVARCHAR(MAX) NULL"
+        );
+    }
+
+    #[test]
+    fn should_write_pg_null_change() {
+        let sql = changed(diff()).fix_sql(Syntax::Postgres).unwrap();
+        assert_eq!(
+            sql,
+            "ALTER TABLE public.cars ALTER COLUMN name SET NOT NULL"
+        );
+    }
+
+    #[test]
+    fn should_write_pg_type_and_default_change() {
+        let mut diff = diff();
+        diff.db_nullable = false;
+        diff.db_type = "INT4".to_string();
+        diff.type_changed = true;
+        diff.welds_default = Some("'bob'".to_string());
+        diff.default_changed = true;
+        let sql = changed(diff).fix_sql(Syntax::Postgres).unwrap();
+        assert_eq!(
+            sql,
+            "ALTER TABLE public.cars ALTER COLUMN name TYPE TEXT USING name::TEXT, ALTER COLUMN name SET DEFAULT 'bob'"
+        );
+    }
+
+    #[test]
+    fn should_write_mysql_collation_change() {
+        let mut diff = diff();
+        diff.db_nullable = false;
+        diff.db_type = "varchar".to_string();
+        diff.welds_collation = Some("utf8mb4_bin".to_string());
+        diff.collation_changed = true;
+        let sql = changed(diff).fix_sql(Syntax::Mysql).unwrap();
+        assert_eq!(
+            sql,
+            "ALTER TABLE public.cars MODIFY COLUMN name varchar(255) COLLATE utf8mb4_bin NOT NULL"
+        );
+    }
+
+    #[test]
+    fn should_write_size_changes() {
+        let mut diff = diff();
+        diff.db_nullable = false;
+        diff.db_type = "NUMERIC".to_string();
+        diff.db_precision = Some(10);
+        diff.db_scale = Some(2);
+        diff.welds_precision = Some(12);
+        diff.welds_scale = Some(4);
+        diff.size_changed = true;
+        let sql = changed(diff.clone()).fix_sql(Syntax::Postgres).unwrap();
+        assert_eq!(
+            sql,
+            "ALTER TABLE public.cars ALTER COLUMN name TYPE NUMERIC(12,4)"
+        );
+
+        diff.db_type = "varchar".to_string();
+        diff.db_precision = None;
+        diff.db_scale = None;
+        diff.welds_precision = None;
+        diff.welds_scale = None;
+        diff.db_max_length = Some(255);
+        diff.welds_max_length = Some(50);
+        let sql = changed(diff).fix_sql(Syntax::Mysql).unwrap();
+        assert_eq!(
+            sql,
+            "ALTER TABLE public.cars MODIFY COLUMN name varchar(50) NOT NULL"
+        );
+    }
+
+    #[test]
+    fn should_keep_the_mysql_default_when_only_nullability_changed() {
+        let mut diff = diff();
+        diff.db_type = "varchar".to_string();
+        diff.db_default = Some("bob's".to_string());
+        let sql = changed(diff.clone()).fix_sql(Syntax::Mysql).unwrap();
+        assert_eq!(
+            sql,
+            "ALTER TABLE public.cars MODIFY COLUMN name varchar(255) NOT NULL DEFAULT 'bob''s'"
+        );
+
+        diff.db_default = Some("0".to_string());
+        let sql = changed(diff.clone()).fix_sql(Syntax::Mysql).unwrap();
+        assert_eq!(
+            sql,
+            "ALTER TABLE public.cars MODIFY COLUMN name varchar(255) NOT NULL DEFAULT 0"
+        );
+
+        diff.db_default = Some("uuid()".to_string());
+        let sql = changed(diff.clone()).fix_sql(Syntax::Mysql).unwrap();
+        assert_eq!(
+            sql,
+            "ALTER TABLE public.cars MODIFY COLUMN name varchar(255) NOT NULL DEFAULT (uuid())"
+        );
+
+        // the default on the model is used when it has one
+        diff.welds_default = Some("'alice'".to_string());
+        let sql = changed(diff).fix_sql(Syntax::Mysql).unwrap();
+        assert_eq!(
+            sql,
+            "ALTER TABLE public.cars MODIFY COLUMN name varchar(255) NOT NULL DEFAULT 'alice'"
+        );
+    }
+
+    #[test]
+    fn should_not_fix_column_changes_in_sqlite() {
+        assert_eq!(changed(diff()).fix_sql(Syntax::Sqlite), None);
+    }
+
+    #[test]
+    fn should_write_a_script_of_fixable_issues() {
+        let issues = vec![
+            Issue::missing_table(None, "cars"),
+            changed(diff()),
+            changed(diff()),
+        ];
+        let script = fix_script(&issues, Syntax::Mysql);
+        assert_eq!(
+            script,
+            "ALTER TABLE public.cars MODIFY COLUMN name TEXT NOT NULL;\nALTER TABLE public.cars MODIFY COLUMN name TEXT NOT NULL;\n"
+        );
+    }
+}
@@ -1,12 +1,13 @@
 use crate::{
-    detect::ColumnDef,
-    model_traits::{Column, TableIdent},
+    detect::{ColumnDef, IndexDef},
+    model_traits::{Column, Index, TableIdent},
 };
 use colored::Colorize;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Diff {
     pub column: String,
     pub db_type: String,
@@ -14,6 +15,21 @@ pub struct Diff {
     pub welds_type: String,
     pub welds_nullable: bool,
     pub type_changed: bool,
+    pub db_default: Option<String>,
+    pub welds_default: Option<String>,
+    pub default_changed: bool,
+    pub db_collation: Option<String>,
+    pub welds_collation: Option<String>,
+    pub collation_changed: bool,
+    pub db_max_length: Option<i64>,
+    pub welds_max_length: Option<i64>,
+    pub db_precision: Option<i64>,
+    pub welds_precision: Option<i64>,
+    pub db_scale: Option<i64>,
+    pub welds_scale: Option<i64>,
+    pub size_changed: bool,
+    /// The rust type welds recommends for the database column. I.E. `Option<i32>`
+    pub suggested_rust_type: Option<String>,
 }
 
 impl Display for Diff {
@@ -26,6 +42,10 @@ impl Display for Diff {
             f.write_str(&self.db_type)?;
             f.write_str(" welds_type: ")?;
             f.write_str(&self.welds_type)?;
+            if let Some(suggested) = &self.suggested_rust_type {
+                f.write_str(" suggested_type: ")?;
+                f.write_str(suggested)?;
+            }
         }
         if self.db_nullable != self.welds_nullable {
             f.write_str(" db_null: ")?;
@@ -33,6 +53,33 @@ impl Display for Diff {
             f.write_str(" welds_null: ")?;
             Display::fmt(&self.welds_nullable, f)?;
         }
+        if self.default_changed {
+            f.write_str(" db_default: ")?;
+            f.write_str(self.db_default.as_deref().unwrap_or("None"))?;
+            f.write_str(" welds_default: ")?;
+            f.write_str(self.welds_default.as_deref().unwrap_or("None"))?;
+        }
+        if self.collation_changed {
+            f.write_str(" db_collation: ")?;
+            f.write_str(self.db_collation.as_deref().unwrap_or("None"))?;
+            f.write_str(" welds_collation: ")?;
+            f.write_str(self.welds_collation.as_deref().unwrap_or("None"))?;
+        }
+        if self.size_changed {
+            f.write_str(" db_size: ")?;
+            f.write_str(&size_str(
+                self.db_max_length,
+                self.db_precision,
+                self.db_scale,
+            ))?;
+            f.write_str(" welds_size: ")?;
+            let welds = size_str(
+                self.welds_max_length,
+                self.welds_precision,
+                self.welds_scale,
+            );
+            f.write_str(&welds)?;
+        }
         Ok(())
     }
 }
@@ -45,13 +92,40 @@ impl Diff {
     pub fn nullable_changed(&self) -> bool {
         self.welds_nullable != self.db_nullable
     }
+
+    pub fn default_changed(&self) -> bool {
+        self.default_changed
+    }
+
+    pub fn collation_changed(&self) -> bool {
+        self.collation_changed
+    }
+
+    /// true if the length, precision, or scale has changed
+    pub fn size_changed(&self) -> bool {
+        self.size_changed
+    }
+}
+
+/// writes out the size of a column. I.E. `(50)` or `(10,2)`
+fn size_str(max_length: Option<i64>, precision: Option<i64>, scale: Option<i64>) -> String {
+    let fmt = |x: Option<i64>| x.map(|x| x.to_string()).unwrap_or("None".to_string());
+    if precision.is_some() || scale.is_some() {
+        return format!("({},{})", fmt(precision), fmt(scale));
+    }
+    match max_length {
+        Some(len) => format!("({len})"),
+        None => "None".to_string(),
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Describes a potential problem or different between the welds struct and the database table
 pub struct Issue {
     pub ident: TableIdent,
     pub level: Level,
+    pub severity: Severity,
     pub kind: Kind,
 }
 
@@ -67,15 +141,42 @@ impl Display for Issue {
 }
 
 impl Issue {
+    /// returns true if this issue will cause queries with the model to fail
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
     pub(crate) fn missing_table(schemaname: Option<&str>, tablename: &str) -> Self {
         let ident = TableIdent {
             schema: schemaname.map(|x| x.to_string()),
             name: tablename.to_string(),
         };
+        let kind = Kind::MissingTable;
+        let severity = Severity::classify(&kind);
         Issue {
             ident,
             level: Level::Critical,
-            kind: Kind::MissingTable,
+            severity,
+            kind,
+        }
+    }
+
+    pub(crate) fn in_other_schema(
+        schemaname: Option<&str>,
+        tablename: &str,
+        found: Vec<TableIdent>,
+    ) -> Self {
+        let ident = TableIdent {
+            schema: schemaname.map(|x| x.to_string()),
+            name: tablename.to_string(),
+        };
+        let kind = Kind::InOtherSchema(found);
+        let severity = Severity::classify(&kind);
+        Issue {
+            ident,
+            level: Level::Critical,
+            severity,
+            kind,
         }
     }
 
@@ -88,14 +189,17 @@ impl Issue {
             schema: schemaname.map(|x| x.to_string()),
             name: tablename.to_string(),
         };
+        let kind = Kind::InDbNotModel(Missing {
+            column: col.name.to_string(),
+            ty: col.ty.to_string(),
+            nullable: col.null,
+        });
+        let severity = Severity::classify(&kind);
         Issue {
             ident,
             level: Level::Medium,
-            kind: Kind::InDbNotModel(Missing {
-                column: col.name.to_string(),
-                ty: col.ty.to_string(),
-                nullable: col.null,
-            }),
+            severity,
+            kind,
         }
     }
 
@@ -104,14 +208,17 @@ impl Issue {
             schema: schemaname.map(|x| x.to_string()),
             name: tablename.to_string(),
         };
+        let kind = Kind::OnModelNotDb(Missing {
+            column: col.name().to_string(),
+            ty: col.rust_type().to_string(),
+            nullable: col.nullable(),
+        });
+        let severity = Severity::classify(&kind);
         Issue {
             ident,
             level: Level::Critical,
-            kind: Kind::OnModelNotDb(Missing {
-                column: col.name().to_string(),
-                ty: col.rust_type().to_string(),
-                nullable: col.nullable(),
-            }),
+            severity,
+            kind,
         }
     }
 
@@ -127,15 +234,234 @@ impl Issue {
             Level::High
         };
 
+        let kind = Kind::Changed(diff);
+        let severity = Severity::classify(&kind);
+        Issue {
+            ident,
+            level,
+            severity,
+            kind,
+        }
+    }
+
+    pub(crate) fn primary_key_changed(
+        schemaname: Option<&str>,
+        tablename: &str,
+        diff: PrimaryKeyDiff,
+    ) -> Self {
+        let ident = TableIdent {
+            schema: schemaname.map(|x| x.to_string()),
+            name: tablename.to_string(),
+        };
+        let kind = Kind::PrimaryKeyChanged(diff);
+        let severity = Severity::classify(&kind);
+        Issue {
+            ident,
+            level: Level::High,
+            severity,
+            kind,
+        }
+    }
+
+    pub(crate) fn auto_increment_changed(
+        schemaname: Option<&str>,
+        tablename: &str,
+        diff: AutoIncrementDiff,
+    ) -> Self {
+        let ident = TableIdent {
+            schema: schemaname.map(|x| x.to_string()),
+            name: tablename.to_string(),
+        };
+        let kind = Kind::AutoIncrementChanged(diff);
+        let severity = Severity::classify(&kind);
+        Issue {
+            ident,
+            level: Level::High,
+            severity,
+            kind,
+        }
+    }
+
+    pub(crate) fn missing_foreign_key(
+        schemaname: Option<&str>,
+        tablename: &str,
+        fk: ForeignKeyIssue,
+    ) -> Self {
+        let ident = TableIdent {
+            schema: schemaname.map(|x| x.to_string()),
+            name: tablename.to_string(),
+        };
+        let kind = Kind::MissingForeignKey(fk);
+        let severity = Severity::classify(&kind);
+        Issue {
+            ident,
+            level: Level::Medium,
+            severity,
+            kind,
+        }
+    }
+
+    pub(crate) fn broken_foreign_key(
+        schemaname: Option<&str>,
+        tablename: &str,
+        fk: ForeignKeyIssue,
+    ) -> Self {
+        let ident = TableIdent {
+            schema: schemaname.map(|x| x.to_string()),
+            name: tablename.to_string(),
+        };
+        // joins will fail outright if the column isn't there
+        let level = match fk.db_references {
+            Some(_) => Level::High,
+            None => Level::Critical,
+        };
+        let kind = Kind::BrokenForeignKey(fk);
+        let severity = Severity::classify(&kind);
         Issue {
             ident,
             level,
-            kind: Kind::Changed(diff),
+            severity,
+            kind,
+        }
+    }
+
+    pub(crate) fn missing_index(schemaname: Option<&str>, tablename: &str, index: &Index) -> Self {
+        let ident = TableIdent {
+            schema: schemaname.map(|x| x.to_string()),
+            name: tablename.to_string(),
+        };
+        let kind = Kind::MissingIndex(MissingIndex {
+            name: index.name().map(|x| x.to_string()),
+            columns: index.columns().to_vec(),
+        });
+        let severity = Severity::classify(&kind);
+        Issue {
+            ident,
+            level: Level::Medium,
+            severity,
+            kind,
+        }
+    }
+
+    pub(crate) fn view_only(schemaname: Option<&str>, tablename: &str) -> Self {
+        let ident = TableIdent {
+            schema: schemaname.map(|x| x.to_string()),
+            name: tablename.to_string(),
+        };
+        let kind = Kind::ViewOnly;
+        let severity = Severity::classify(&kind);
+        Issue {
+            ident,
+            level: Level::High,
+            severity,
+            kind,
+        }
+    }
+
+    pub(crate) fn missing_unique_constraint(
+        schemaname: Option<&str>,
+        tablename: &str,
+        index: &Index,
+    ) -> Self {
+        let ident = TableIdent {
+            schema: schemaname.map(|x| x.to_string()),
+            name: tablename.to_string(),
+        };
+        let kind = Kind::MissingUniqueConstraint(MissingIndex {
+            name: index.name().map(|x| x.to_string()),
+            columns: index.columns().to_vec(),
+        });
+        let severity = Severity::classify(&kind);
+        Issue {
+            ident,
+            level: Level::Medium,
+            severity,
+            kind,
+        }
+    }
+
+    pub(crate) fn extra_index(schemaname: Option<&str>, tablename: &str, index: &IndexDef) -> Self {
+        let ident = TableIdent {
+            schema: schemaname.map(|x| x.to_string()),
+            name: tablename.to_string(),
+        };
+        let kind = Kind::ExtraIndex(MissingIndex {
+            name: Some(index.name().to_string()),
+            columns: index.columns().to_vec(),
+        });
+        let severity = Severity::classify(&kind);
+        Issue {
+            ident,
+            level: Level::Low,
+            severity,
+            kind,
+        }
+    }
+}
+
+/// How much an issue should matter to an app. Use this to decide to fail on startup/deploy
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Severity {
+    /// Queries with this model will fail or read bad data
+    Error,
+    /// The model works but might fail writing or be slow
+    Warning,
+    /// Differences that are safe to ignore
+    Info,
+}
+
+impl Severity {
+    /// The rules used to decide how bad an issue is
+    pub(crate) fn classify(kind: &Kind) -> Severity {
+        match kind {
+            Kind::MissingTable => Severity::Error,
+            Kind::InOtherSchema(_) => Severity::Error,
+            Kind::OnModelNotDb(_) => Severity::Error,
+            // inserts will fail if the unmapped column requires a value
+            Kind::InDbNotModel(missing) if missing.nullable => Severity::Info,
+            Kind::InDbNotModel(_) => Severity::Warning,
+            // reading a null into a non-null field will fail
+            Kind::Changed(diff) if diff.db_nullable && !diff.welds_nullable => Severity::Error,
+            Kind::Changed(diff) if diff.type_changed => Severity::Error,
+            Kind::Changed(diff) if diff.default_changed => Severity::Warning,
+            // lookups and sorting quietly behave differently
+            Kind::Changed(diff) if diff.collation_changed => Severity::Warning,
+            // values can be truncated or rejected
+            Kind::Changed(diff) if diff.size_changed => Severity::Warning,
+            // the model allows null but the database doesn't, reads are fine
+            Kind::Changed(_) => Severity::Info,
+            Kind::PrimaryKeyChanged(_) => Severity::Error,
+            // inserts will not get an id back
+            Kind::AutoIncrementChanged(diff) if diff.welds_auto_increment => Severity::Error,
+            // some databases refuse to insert a value into a generated column
+            Kind::AutoIncrementChanged(_) => Severity::Warning,
+            Kind::BrokenForeignKey(fk) if fk.db_references.is_none() => Severity::Error,
+            Kind::BrokenForeignKey(_) => Severity::Warning,
+            Kind::MissingForeignKey(_) => Severity::Warning,
+            Kind::MissingIndex(_) => Severity::Warning,
+            Kind::ExtraIndex(_) => Severity::Info,
+            // reads are fine, writes through the model will fail
+            Kind::ViewOnly => Severity::Warning,
+            // duplicates can be written that the app assumes can't exist
+            Kind::MissingUniqueConstraint(_) => Severity::Warning,
+        }
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => "Error".red().fmt(f)?,
+            Severity::Warning => "Warning".yellow().fmt(f)?,
+            Severity::Info => "Info".blue().fmt(f)?,
         }
+        Ok(())
     }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Level {
     /// Full on prevents this struct from being used.
     Critical,
@@ -160,6 +486,7 @@ impl Display for Level {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Missing {
     pub column: String,
     pub ty: String,
@@ -167,15 +494,112 @@ pub struct Missing {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrimaryKeyDiff {
+    pub db_columns: Vec<String>,
+    pub welds_columns: Vec<String>,
+}
+
+impl Display for PrimaryKeyDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("The Primary Key has changed, db_pk: (")?;
+        f.write_str(&self.db_columns.join(", "))?;
+        f.write_str(") welds_pk: (")?;
+        f.write_str(&self.welds_columns.join(", "))?;
+        f.write_str(")")?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AutoIncrementDiff {
+    pub column: String,
+    pub db_type: String,
+    /// true if the database generates the value on insert
+    pub db_auto_increment: bool,
+    /// true if the welds struct expects the database to generate the value on insert
+    pub welds_auto_increment: bool,
+}
+
+impl Display for AutoIncrementDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("The Primary Key `")?;
+        f.write_str(&self.column)?;
+        if self.welds_auto_increment {
+            f.write_str("` is expected to be generated by the database but is not")?;
+        } else {
+            f.write_str("` is generated by the database but the struct provides its own value")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ForeignKeyIssue {
+    /// The name of the relation on the welds struct
+    pub relation: String,
+    /// The table the foreign_key column is on
+    pub table: TableIdent,
+    pub foreign_key: String,
+    /// The table the welds struct expects the foreign_key to point at
+    pub welds_references: TableIdent,
+    /// The table the foreign_key points at in the database
+    pub db_references: Option<TableIdent>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MissingIndex {
+    pub name: Option<String>,
+    pub columns: Vec<String>,
+}
+
+impl Display for MissingIndex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("The Index ")?;
+        if let Some(name) = &self.name {
+            f.write_str("`")?;
+            f.write_str(name)?;
+            f.write_str("` ")?;
+        }
+        f.write_str("on (")?;
+        f.write_str(&self.columns.join(", "))?;
+        f.write_str(")")?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Kind {
     /// The table is missing in the database
     MissingTable,
+    /// The table is missing from its schema, but a table with the same name is in these other schemas
+    InOtherSchema(Vec<TableIdent>),
     /// The column is defined in the database but not the welds Struct
     InDbNotModel(Missing),
     /// The column is defined on the welds Struct but not in the database
     OnModelNotDb(Missing),
     /// How the model is defined in the database table is different than on the welds Struct
     Changed(Diff),
+    /// The columns making up the primary key in the database are different than on the welds Struct
+    PrimaryKeyChanged(PrimaryKeyDiff),
+    /// The welds Struct and the database disagree on who generates the primary key
+    AutoIncrementChanged(AutoIncrementDiff),
+    /// The relation is defined on the welds Struct but there is no foreign key for it in the database
+    MissingForeignKey(ForeignKeyIssue),
+    /// The foreign key column for the relation is missing or points at a different table
+    BrokenForeignKey(ForeignKeyIssue),
+    /// The index is defined on the welds Struct but not in the database
+    MissingIndex(MissingIndex),
+    /// The index is defined in the database but not on the welds Struct
+    ExtraIndex(MissingIndex),
+    /// The welds Struct is connected to a view but is not marked readonly
+    ViewOnly,
+    /// The welds Struct expects these columns to be unique but the database does not enforce it
+    MissingUniqueConstraint(MissingIndex),
 }
 
 impl Kind {
@@ -186,6 +610,34 @@ impl Kind {
         }
     }
 
+    pub fn as_in_other_schema(&self) -> Option<&[TableIdent]> {
+        match self {
+            Kind::InOtherSchema(found) => Some(found),
+            _ => None,
+        }
+    }
+
+    pub fn as_missing_unique_constraint(&self) -> Option<&MissingIndex> {
+        match self {
+            Kind::MissingUniqueConstraint(index) => Some(index),
+            _ => None,
+        }
+    }
+
+    pub fn as_auto_increment_changed(&self) -> Option<&AutoIncrementDiff> {
+        match self {
+            Kind::AutoIncrementChanged(diff) => Some(diff),
+            _ => None,
+        }
+    }
+
+    pub fn as_view_only(&self) -> Option<()> {
+        match self {
+            Kind::ViewOnly => Some(()),
+            _ => None,
+        }
+    }
+
     pub fn as_in_db_not_model(&self) -> Option<&Missing> {
         match self {
             Kind::InDbNotModel(missing) => Some(missing),
@@ -206,6 +658,41 @@ impl Kind {
             _ => None,
         }
     }
+
+    pub fn as_primary_key_changed(&self) -> Option<&PrimaryKeyDiff> {
+        match self {
+            Kind::PrimaryKeyChanged(diff) => Some(diff),
+            _ => None,
+        }
+    }
+
+    pub fn as_missing_foreign_key(&self) -> Option<&ForeignKeyIssue> {
+        match self {
+            Kind::MissingForeignKey(fk) => Some(fk),
+            _ => None,
+        }
+    }
+
+    pub fn as_broken_foreign_key(&self) -> Option<&ForeignKeyIssue> {
+        match self {
+            Kind::BrokenForeignKey(fk) => Some(fk),
+            _ => None,
+        }
+    }
+
+    pub fn as_missing_index(&self) -> Option<&MissingIndex> {
+        match self {
+            Kind::MissingIndex(index) => Some(index),
+            _ => None,
+        }
+    }
+
+    pub fn as_extra_index(&self) -> Option<&MissingIndex> {
+        match self {
+            Kind::ExtraIndex(index) => Some(index),
+            _ => None,
+        }
+    }
 }
 
 impl Display for Kind {
@@ -214,6 +701,11 @@ impl Display for Kind {
             Kind::MissingTable => {
                 f.write_str("The Table was not found in the database")?;
             }
+            Kind::InOtherSchema(found) => {
+                let found: Vec<String> = found.iter().map(|x| x.to_string()).collect();
+                f.write_str("The Table was not found, but exists in a different schema: ")?;
+                f.write_str(&found.join(", "))?;
+            }
             Kind::InDbNotModel(missing) => {
                 f.write_str("The Column `")?;
                 f.write_str(&missing.column)?;
@@ -225,6 +717,48 @@ impl Display for Kind {
                 f.write_str("` was defined on the struct but not in the database")?;
             }
             Kind::Changed(diff) => diff.fmt(f)?,
+            Kind::PrimaryKeyChanged(diff) => diff.fmt(f)?,
+            Kind::AutoIncrementChanged(diff) => diff.fmt(f)?,
+            Kind::MissingForeignKey(fk) => {
+                f.write_str("The Relation `")?;
+                f.write_str(&fk.relation)?;
+                f.write_str("` has no foreign key from `")?;
+                write!(f, "{}.{}", fk.table, fk.foreign_key)?;
+                f.write_str("` to `")?;
+                Display::fmt(&fk.welds_references, f)?;
+                f.write_str("` in the database")?;
+            }
+            Kind::BrokenForeignKey(fk) => {
+                f.write_str("The Relation `")?;
+                f.write_str(&fk.relation)?;
+                f.write_str("` expects `")?;
+                write!(f, "{}.{}", fk.table, fk.foreign_key)?;
+                f.write_str("` to point at `")?;
+                Display::fmt(&fk.welds_references, f)?;
+                match &fk.db_references {
+                    Some(other) => {
+                        f.write_str("` but it points at `")?;
+                        Display::fmt(other, f)?;
+                        f.write_str("`")?;
+                    }
+                    None => f.write_str("` but the column was not found in the database")?,
+                }
+            }
+            Kind::MissingIndex(index) => {
+                index.fmt(f)?;
+                f.write_str(" was defined on the struct but not in the database")?;
+            }
+            Kind::ExtraIndex(index) => {
+                index.fmt(f)?;
+                f.write_str(" was defined in the database but not the struct")?;
+            }
+            Kind::ViewOnly => {
+                f.write_str("The struct is connected to a view but is not marked readonly")?;
+            }
+            Kind::MissingUniqueConstraint(index) => {
+                index.fmt(f)?;
+                f.write_str(" is unique on the struct but not in the database")?;
+            }
         }
         Ok(())
     }
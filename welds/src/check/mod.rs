@@ -1,14 +1,63 @@
-use crate::detect::ColumnDef;
+use crate::detect::table_def::primary_keys;
+use crate::detect::{ColumnDef, DataType, IdentMatching, IndexDef, SchemaSnapshot};
 use crate::errors::Result;
-use crate::model_traits::Column;
-use crate::model_traits::{HasSchema, TableColumns, TableInfo};
-use crate::writers::types::{are_equivalent_types, get_pairs, Pair};
+use crate::model_traits::{Column, Index};
+use crate::model_traits::{HasSchema, TableCheck, TableColumns, TableIndexes, TableInfo};
+use crate::writers::types::{are_equivalent_types, get_pairs, recommended_rust_type_in, Pair};
 use crate::Client;
 use crate::Syntax;
 
+mod assert;
+pub use assert::assert_schema_all;
+mod defaults;
+mod fix;
+pub use fix::fix_script;
 mod issue;
+mod registry;
+pub use registry::{schema_all, Registration, TableIssues};
+mod relations;
+pub use relations::relations;
+mod report;
+pub use report::{Report, ReportIssue};
+#[cfg(test)]
+mod tests;
+mod unmapped;
 use crate::model_traits::TableIdent;
 pub use issue::*;
+pub use unmapped::unmapped_tables;
+
+/// Controls how strict check is when comparing a model to the database
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CheckOptions {
+    /// How column defaults are compared
+    pub defaults: DefaultMatching,
+    /// Which nullability differences are reported
+    pub nullability: Nullability,
+    /// How the table name of the model is matched to the tables in the database
+    pub identifiers: IdentMatching,
+}
+
+/// Which nullability differences between a model and the database are reported
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Nullability {
+    /// Any difference is reported
+    #[default]
+    Strict,
+    /// Only report when the database can return a null the model can't hold.
+    /// A not null column read into an `Option<T>` field is safe and not reported
+    ReadSafe,
+}
+
+/// How column defaults on the model are compared to the database
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DefaultMatching {
+    /// The default must be written exactly the same as the database reports it
+    Exact,
+    /// Ignore differences in how each database writes out the same expression.
+    /// casts, wrapping parens, quotes, `now()` vs `CURRENT_TIMESTAMP`, etc
+    #[default]
+    Relaxed,
+}
 
 /// Returns a list of differences in the current database schema
 /// and what the welds object was compiled against
@@ -17,45 +66,309 @@ pub use issue::*;
 pub async fn schema<T>(client: &dyn Client) -> Result<Vec<Issue>>
 where
     T: Send + HasSchema,
-    <T as HasSchema>::Schema: TableInfo + TableColumns,
+    <T as HasSchema>::Schema: TableInfo + TableColumns + TableIndexes + TableCheck,
+{
+    schema_with_options::<T>(client, &CheckOptions::default()).await
+}
+
+/// Same as `schema` but allows you to control how strict the checks are
+pub async fn schema_with_options<T>(
+    client: &dyn Client,
+    options: &CheckOptions,
+) -> Result<Vec<Issue>>
+where
+    T: Send + HasSchema,
+    <T as HasSchema>::Schema: TableInfo + TableColumns + TableIndexes + TableCheck,
+{
+    let syntax = client.syntax();
+    let (namespace, tablename) = model_table::<T>(syntax);
+
+    let matching = options.identifiers;
+    let tabledef = crate::detect::find_table_with(namespace, tablename, matching, client).await?;
+    let tabledef = match tabledef {
+        Some(x) => x,
+        None => {
+            let others = crate::detect::find_table_any_schema(tablename, client).await?;
+            let others = others.iter().map(|t| t.ident().clone()).collect();
+            return Ok(vec![missing_table(namespace, tablename, others)]);
+        }
+    };
+    // look up the indexes with the name the table actually has in the database
+    let found_ident = tabledef.ident();
+    let table_indexes =
+        crate::detect::find_indexes(found_ident.schema(), found_ident.name(), client).await?;
+
+    let found = FoundTable {
+        syntax,
+        ty: tabledef.ty(),
+        columns: tabledef.columns(),
+        indexes: &table_indexes,
+    };
+    Ok(diff_table::<T>(namespace, tablename, &found, options))
+}
+
+/// Same as `schema` but compares the model to a snapshot of a database instead of a live connection
+///
+/// Useful for running checks where the database can't be reached. I.E. CI
+pub fn schema_against_snapshot<T>(snapshot: &SchemaSnapshot) -> Vec<Issue>
+where
+    T: Send + HasSchema,
+    <T as HasSchema>::Schema: TableInfo + TableColumns + TableIndexes + TableCheck,
+{
+    schema_against_snapshot_with_options::<T>(snapshot, &CheckOptions::default())
+}
+
+/// Same as `schema_against_snapshot` but allows you to control how strict the checks are
+pub fn schema_against_snapshot_with_options<T>(
+    snapshot: &SchemaSnapshot,
+    options: &CheckOptions,
+) -> Vec<Issue>
+where
+    T: Send + HasSchema,
+    <T as HasSchema>::Schema: TableInfo + TableColumns + TableIndexes + TableCheck,
+{
+    let syntax = snapshot.syntax();
+    let (namespace, tablename) = model_table::<T>(syntax);
+
+    let ident = TableIdent::new(tablename, namespace);
+    let table = match snapshot.find_with(&ident, options.identifiers) {
+        Some(x) => x,
+        None => {
+            let others = snapshot
+                .tables()
+                .iter()
+                .map(|t| t.table().ident())
+                .filter(|t| options.identifiers.matches(tablename, t.name()))
+                .cloned()
+                .collect();
+            return vec![missing_table(namespace, tablename, others)];
+        }
+    };
+
+    let found = FoundTable {
+        syntax,
+        ty: table.table().ty(),
+        columns: table.table().columns(),
+        indexes: table.indexes(),
+    };
+    diff_table::<T>(namespace, tablename, &found, options)
+}
+
+/// The namespace and tablename the model is expected to be in
+/// the table is missing, but might be in a schema the model isn't looking in
+fn missing_table(namespace: Option<&str>, tablename: &str, others: Vec<TableIdent>) -> Issue {
+    let others: Vec<TableIdent> = others
+        .into_iter()
+        .filter(|t| namespace.is_some() && t.schema() != namespace)
+        .collect();
+    match others.is_empty() {
+        true => Issue::missing_table(namespace, tablename),
+        false => Issue::in_other_schema(namespace, tablename, others),
+    }
+}
+
+fn model_table<T>(syntax: Syntax) -> (Option<&'static str>, &'static str)
+where
+    T: HasSchema,
+    <T as HasSchema>::Schema: TableInfo,
 {
-    let mut problems = Vec::default();
     let identifier_parts: Vec<&str> = <T::Schema>::identifier().iter().rev().cloned().collect();
     let tablename = identifier_parts[0];
-    let syntax = client.syntax();
     let namespace = identifier_parts
         .get(1)
         .copied()
         .or(TableIdent::default_namespace(syntax));
+    (namespace, tablename)
+}
 
-    let tabledef = match crate::detect::find_table(namespace, tablename, client).await? {
-        Some(x) => x,
-        None => return Ok(vec![Issue::missing_table(namespace, tablename)]),
-    };
+/// What was found in the database for a model's table
+struct FoundTable<'a> {
+    syntax: Syntax,
+    ty: DataType,
+    columns: &'a [ColumnDef],
+    indexes: &'a [IndexDef],
+}
 
-    let table_cols = tabledef.columns();
-    let model_cols = <T::Schema>::columns();
-    let pairs = get_pairs(client.syntax());
+/// compares a model to what was found in the database
+fn diff_table<T>(
+    namespace: Option<&str>,
+    tablename: &str,
+    found: &FoundTable,
+    options: &CheckOptions,
+) -> Vec<Issue>
+where
+    T: HasSchema,
+    <T as HasSchema>::Schema: TableInfo + TableColumns + TableIndexes + TableCheck,
+{
+    let mut problems = Vec::default();
+
+    let ignored = <T::Schema>::ignored_columns();
+    let table_cols: Vec<ColumnDef> = found
+        .columns
+        .iter()
+        .filter(|c| !ignored.contains(&c.name()))
+        .cloned()
+        .collect();
+    let table_cols = table_cols.as_slice();
+    let model_cols: Vec<Column> = <T::Schema>::columns()
+        .drain(..)
+        .filter(|c| !ignored.contains(&c.name()))
+        .collect();
+    let pairs = get_pairs(found.syntax);
 
     struct_added(table_cols, &model_cols)
         .iter()
         .for_each(|x| problems.push(Issue::struct_added(namespace, tablename, x)));
 
-    build_diffs(&pairs, table_cols, &model_cols)
+    // views don't reliably report if a column can be null
+    let compare_nullable = found.ty == DataType::Table;
+
+    build_diffs(&pairs, options, compare_nullable, table_cols, &model_cols)
         .drain(..)
         .for_each(|x| problems.push(Issue::changed(namespace, tablename, x)));
 
-    struct_missing(table_cols, &model_cols)
+    if <T::Schema>::check_extra_columns() {
+        struct_missing(table_cols, &model_cols)
+            .iter()
+            .for_each(|x| problems.push(Issue::struct_missing(namespace, tablename, x)));
+    }
+
+    if found.ty != DataType::Table && !<T::Schema>::readonly() {
+        problems.push(Issue::view_only(namespace, tablename));
+    }
+
+    // views don't have a primary key in the database to compare against
+    if found.ty == DataType::Table {
+        let model_pks = <T::Schema>::primary_keys();
+        match build_pk_diff(table_cols, &model_pks) {
+            Some(diff) => problems.push(Issue::primary_key_changed(namespace, tablename, diff)),
+            None => {
+                if let Some(diff) = build_auto_increment_diff(table_cols, &model_pks) {
+                    problems.push(Issue::auto_increment_changed(namespace, tablename, diff));
+                }
+            }
+        }
+    }
+
+    let model_indexes = <T::Schema>::indexes();
+
+    index_missing(found.indexes, &model_indexes)
+        .iter()
+        .for_each(|x| problems.push(Issue::missing_index(namespace, tablename, x)));
+
+    unique_missing(found.indexes, &model_indexes)
+        .iter()
+        .for_each(|x| problems.push(Issue::missing_unique_constraint(namespace, tablename, x)));
+
+    index_extra(found.indexes, &model_indexes)
         .iter()
-        .for_each(|x| problems.push(Issue::struct_missing(namespace, tablename, x)));
+        .for_each(|x| problems.push(Issue::extra_index(namespace, tablename, x)));
 
-    Ok(problems)
+    problems
+}
+
+/// returns a diff if the primary key columns in the database are not the same as on the model
+fn build_pk_diff(table_cols: &[ColumnDef], model_pks: &[Column]) -> Option<PrimaryKeyDiff> {
+    // reported in key order, compared as sets
+    let db_columns: Vec<String> = primary_keys(table_cols)
+        .iter()
+        .map(|x| x.name.to_string())
+        .collect();
+    let welds_columns: Vec<String> = model_pks.iter().map(|x| x.name().to_string()).collect();
+    let mut db_set = db_columns.clone();
+    let mut welds_set = welds_columns.clone();
+    db_set.sort();
+    db_set.dedup();
+    welds_set.sort();
+    welds_set.dedup();
+    if db_set == welds_set {
+        return None;
+    }
+    Some(PrimaryKeyDiff {
+        db_columns,
+        welds_columns,
+    })
+}
+
+/// returns a diff if the model and database disagree on who generates the primary key.
+/// welds expects the database to generate a single integer primary key.
+/// Any other key is expected to be provided by the model
+fn build_auto_increment_diff(
+    table_cols: &[ColumnDef],
+    model_pks: &[Column],
+) -> Option<AutoIncrementDiff> {
+    let pk = match model_pks {
+        [pk] => pk,
+        _ => return None,
+    };
+    let dbcol = table_cols.iter().find(|x| x.name == pk.name())?;
+    let welds_auto_increment = is_integer_type(pk.rust_type());
+    if dbcol.auto_increment == welds_auto_increment {
+        return None;
+    }
+    Some(AutoIncrementDiff {
+        column: dbcol.name.to_string(),
+        db_type: dbcol.ty.to_string(),
+        db_auto_increment: dbcol.auto_increment,
+        welds_auto_increment,
+    })
+}
+
+fn is_integer_type(rust_type: &str) -> bool {
+    let ty = match rust_type.rfind(':') {
+        Some(index) => &rust_type[index + 1..],
+        None => rust_type,
+    };
+    matches!(
+        ty,
+        "i8" | "i16" | "i32" | "i64" | "isize" | "u8" | "u16" | "u32" | "u64" | "usize"
+    )
+}
+
+/// indexes defined on the model that could not be found in the database
+/// unique indexes are checked by `unique_missing`
+fn index_missing<'a>(table_indexes: &[IndexDef], model_indexes: &'a [Index]) -> Vec<&'a Index> {
+    let table_has = |cols: &[String]| table_indexes.iter().any(|x| x.columns() == cols);
+    model_indexes
+        .iter()
+        .filter(|mi| !mi.unique())
+        .filter(|mi| !table_has(mi.columns()))
+        .collect()
+}
+
+/// unique indexes defined on the model that the database does not enforce.
+/// the primary key counts as unique
+fn unique_missing<'a>(table_indexes: &[IndexDef], model_indexes: &'a [Index]) -> Vec<&'a Index> {
+    let table_has = |cols: &[String]| {
+        table_indexes
+            .iter()
+            .filter(|x| x.unique() || x.primary_key())
+            .any(|x| x.columns() == cols)
+    };
+    model_indexes
+        .iter()
+        .filter(|mi| mi.unique())
+        .filter(|mi| !table_has(mi.columns()))
+        .collect()
+}
+
+/// indexes in the database that are not defined on the model.
+/// the primary key's index is not included
+fn index_extra<'a>(table_indexes: &'a [IndexDef], model_indexes: &[Index]) -> Vec<&'a IndexDef> {
+    let model_has = |cols: &[String]| model_indexes.iter().any(|x| x.columns() == cols);
+    table_indexes
+        .iter()
+        .filter(|ti| !ti.primary_key())
+        .filter(|ti| !model_has(ti.columns()))
+        .collect()
 }
 
 fn struct_missing<'a>(table_cols: &'a [ColumnDef], model_cols: &[Column]) -> Vec<&'a ColumnDef> {
     let model_has = |name: &str| model_cols.iter().any(|x| x.name() == name);
+    // generated columns are computed by the database, the model doesn't need them
     table_cols
         .iter()
+        .filter(|tc| !tc.generated)
         .filter(|tc| !model_has(&tc.name))
         .collect()
 }
@@ -83,30 +396,104 @@ fn zip_by_name<'a>(
 }
 
 /// returns true if this db column and model field do not line up
-fn build_diff(pairs: &[Pair], dbcol: &ColumnDef, field: &Column) -> Option<Diff> {
+fn build_diff(
+    pairs: &[Pair],
+    options: &CheckOptions,
+    compare_nullable: bool,
+    dbcol: &ColumnDef,
+    field: &Column,
+) -> Option<Diff> {
     let type_changed = !are_equivalent_types(pairs, &dbcol.ty, field.rust_type());
 
-    let nullable_chagned = dbcol.null != field.nullable();
-    if type_changed || nullable_chagned {
+    let nullable_chagned = compare_nullable
+        && match options.nullability {
+            Nullability::Strict => dbcol.null != field.nullable(),
+            Nullability::ReadSafe => dbcol.null && !field.nullable(),
+        };
+
+    // only compare defaults when the model says what it expects
+    let default_changed = match field.default() {
+        Some(welds_default) => !same_default(options, dbcol.default(), welds_default),
+        None => false,
+    };
+
+    // only compare collations when the model says what it expects
+    let collation_changed = match field.collation() {
+        Some(welds_collation) => !dbcol
+            .collation()
+            .is_some_and(|x| x.eq_ignore_ascii_case(welds_collation.trim())),
+        None => false,
+    };
+
+    // only compare sizes when the model says what it expects
+    let size_differs = |welds: Option<i64>, db: Option<i64>| welds.is_some() && welds != db;
+    let size_changed = size_differs(field.max_length(), dbcol.max_length)
+        || size_differs(field.precision(), dbcol.precision)
+        || size_differs(field.scale(), dbcol.scale);
+
+    if type_changed || nullable_chagned || default_changed || collation_changed || size_changed {
         return Some(Diff {
             column: dbcol.name.to_string(),
             db_type: dbcol.ty.to_string(),
-            db_nullable: dbcol.null,
+            // when not compared, the db is assumed to match the model
+            db_nullable: match compare_nullable {
+                true => dbcol.null,
+                false => field.nullable(),
+            },
             welds_type: field.rust_type().to_string(),
             welds_nullable: field.nullable(),
             type_changed,
+            db_default: dbcol.default.clone(),
+            welds_default: field.default().map(|x| x.to_string()),
+            default_changed,
+            db_collation: dbcol.collation.clone(),
+            welds_collation: field.collation().map(|x| x.to_string()),
+            collation_changed,
+            db_max_length: dbcol.max_length,
+            welds_max_length: field.max_length(),
+            db_precision: dbcol.precision,
+            welds_precision: field.precision(),
+            db_scale: dbcol.scale,
+            welds_scale: field.scale(),
+            size_changed,
+            suggested_rust_type: suggested_rust_type(pairs, dbcol),
         });
     }
     None
 }
 
+/// the rust type (including the Option) that should be used for a db column
+fn suggested_rust_type(pairs: &[Pair], dbcol: &ColumnDef) -> Option<String> {
+    let pair = recommended_rust_type_in(pairs, &dbcol.ty)?;
+    let rust_type = pair.full_rust_type();
+    Some(match dbcol.null {
+        true => format!("Option<{}>", rust_type),
+        false => rust_type,
+    })
+}
+
+fn same_default(options: &CheckOptions, db_default: Option<&str>, welds_default: &str) -> bool {
+    let db_default = match db_default {
+        Some(x) => x,
+        None => return false,
+    };
+    match options.defaults {
+        DefaultMatching::Exact => db_default.trim() == welds_default.trim(),
+        DefaultMatching::Relaxed => {
+            defaults::normalize(db_default) == defaults::normalize(welds_default)
+        }
+    }
+}
+
 fn build_diffs<'a>(
     pairs: &[Pair],
+    options: &CheckOptions,
+    compare_nullable: bool,
     table_cols: &'a [ColumnDef],
     model_cols: &'a [Column],
 ) -> Vec<Diff> {
     zip_by_name(table_cols, model_cols)
         .into_iter()
-        .filter_map(|(d, m)| build_diff(pairs, d, m))
+        .filter_map(|(d, m)| build_diff(pairs, options, compare_nullable, d, m))
         .collect()
 }
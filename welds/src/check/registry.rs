@@ -0,0 +1,120 @@
+use super::{schema, Issue};
+use crate::errors::Result;
+use crate::model_traits::{
+    HasSchema, TableCheck, TableColumns, TableIdent, TableIndexes, TableInfo,
+};
+use crate::Client;
+use std::future::Future;
+use std::pin::Pin;
+
+type CheckFuture<'c> = Pin<Box<dyn Future<Output = Result<Vec<Issue>>> + Send + 'c>>;
+
+/// A model that has been registered to be checked against the database.
+///
+/// Build a list of these with the `welds::models!` macro
+/// ```rust,ignore
+/// let models = welds::models![Product, Order, Seller];
+/// let report = welds::check::schema_all(client, &models).await?;
+/// ```
+#[derive(Clone, Copy)]
+pub struct Registration {
+    identifier: fn() -> &'static [&'static str],
+    check: for<'c> fn(&'c dyn Client) -> CheckFuture<'c>,
+}
+
+impl Registration {
+    pub fn of<T>() -> Registration
+    where
+        T: Send + HasSchema + 'static,
+        <T as HasSchema>::Schema: TableInfo + TableColumns + TableIndexes + TableCheck,
+    {
+        Registration {
+            identifier: <T::Schema as TableInfo>::identifier,
+            check: check_model::<T>,
+        }
+    }
+
+    /// The table this model is connected to
+    pub fn ident(&self) -> TableIdent {
+        TableIdent::parse(&(self.identifier)().join("."))
+    }
+
+    /// returns the issues for this model. Same as `check::schema`
+    pub async fn schema(&self, client: &dyn Client) -> Result<Vec<Issue>> {
+        (self.check)(client).await
+    }
+}
+
+fn check_model<T>(client: &dyn Client) -> CheckFuture<'_>
+where
+    T: Send + HasSchema + 'static,
+    <T as HasSchema>::Schema: TableInfo + TableColumns + TableIndexes + TableCheck,
+{
+    Box::pin(schema::<T>(client))
+}
+
+/// The issues found for a single table
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableIssues {
+    pub ident: TableIdent,
+    pub issues: Vec<Issue>,
+}
+
+/// Checks every registered model against the database.
+/// Returns the issues grouped by table, in the order the models were registered
+pub async fn schema_all(client: &dyn Client, models: &[Registration]) -> Result<Vec<TableIssues>> {
+    let mut report = Vec::default();
+    for model in models {
+        let issues = model.schema(client).await?;
+        report.push(TableIssues {
+            ident: model.ident(),
+            issues,
+        });
+    }
+    Ok(report)
+}
+
+/// Builds a list of models to check with `welds::check::schema_all`
+#[macro_export]
+macro_rules! models {
+    ($($model:ty),* $(,)?) => {
+        vec![ $( $crate::check::Registration::of::<$model>() ),* ]
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Syntax;
+    use crate::WeldsModel;
+
+    #[derive(Debug, Default, WeldsModel)]
+    #[welds(schema = "shop", table = "cars")]
+    #[welds_path(crate)] // needed only within the welds crate.
+    struct Car {
+        #[welds(primary_key)]
+        id: i32,
+    }
+
+    #[derive(Debug, Default, WeldsModel)]
+    #[welds(table = "wheels")]
+    #[welds_path(crate)] // needed only within the welds crate.
+    struct Wheel {
+        #[welds(primary_key)]
+        id: i32,
+    }
+
+    #[test]
+    fn should_group_issues_by_registered_table() {
+        let models = crate::models![Car, Wheel];
+        let report = futures::executor::block_on(async move {
+            let client = welds_connections::noop::build(Syntax::Postgres);
+            schema_all(&client, &models).await.unwrap()
+        });
+        assert_eq!(report.len(), 2);
+        assert_eq!(report[0].ident, TableIdent::parse("shop.cars"));
+        assert_eq!(report[1].ident, TableIdent::parse("wheels"));
+        assert!(report[1].issues[0].kind.as_missing_table().is_some());
+    }
+}
@@ -0,0 +1,216 @@
+use super::{ForeignKeyIssue, Issue};
+use crate::detect::{find_tables, TableDef};
+use crate::errors::Result;
+use crate::model_traits::{HasSchema, RelationInfo, RelationKind, TableIdent};
+use crate::model_traits::{TableInfo, TableRelations};
+use crate::Client;
+
+/// Returns a list of problems with the foreign keys in the database
+/// backing the relations (BelongsTo/HasMany) defined on the welds object
+///
+/// Used to known if the relations on a model are going to line up with the database
+pub async fn relations<T>(client: &dyn Client) -> Result<Vec<Issue>>
+where
+    T: Send + HasSchema,
+    <T as HasSchema>::Schema: TableInfo + TableRelations,
+{
+    let identifier = <T::Schema>::identifier().join(".");
+    let me = TableIdent::parse(&identifier);
+    let namespace = me.schema();
+    let tablename = me.name();
+
+    let tables = find_tables(client).await?;
+    if find(&tables, &me).is_none() {
+        return Ok(vec![Issue::missing_table(namespace, tablename)]);
+    }
+
+    let problems = <T::Schema>::relations()
+        .iter()
+        .filter_map(|r| check_relation(&tables, &me, r))
+        .map(|(broken, x)| match broken {
+            true => Issue::broken_foreign_key(namespace, tablename, x),
+            false => Issue::missing_foreign_key(namespace, tablename, x),
+        })
+        .collect();
+
+    Ok(problems)
+}
+
+/// Looks up the foreign key backing a relation.
+/// Returns the problem if there is one, and if the foreign key is broken (vs missing)
+fn check_relation(
+    tables: &[TableDef],
+    me: &TableIdent,
+    relation: &RelationInfo,
+) -> Option<(bool, ForeignKeyIssue)> {
+    let other = relation.other();
+    let fk = relation.foreign_key();
+    // the table with the foreign_key column and the table it should point at
+    let (holder, target) = match relation.kind() {
+        RelationKind::BelongsTo => (me, other),
+        RelationKind::HasMany => (other, me),
+    };
+
+    let mut issue = ForeignKeyIssue {
+        relation: relation.field().to_string(),
+        table: holder.clone(),
+        foreign_key: fk.to_string(),
+        welds_references: target.clone(),
+        db_references: None,
+    };
+
+    let holder = match find(tables, holder) {
+        Some(x) => x,
+        None => return Some((true, issue)),
+    };
+    if !holder.columns().iter().any(|c| c.name() == fk) {
+        return Some((true, issue));
+    }
+
+    let constraints: Vec<_> = holder
+        .belongs_to()
+        .iter()
+        .filter(|x| x.foreign_key() == fk)
+        .collect();
+    if constraints
+        .iter()
+        .any(|x| same_table(x.other_table(), target))
+    {
+        return None;
+    }
+    match constraints.first() {
+        Some(wrong) => {
+            issue.db_references = Some(wrong.other_table().clone());
+            Some((true, issue))
+        }
+        None => Some((false, issue)),
+    }
+}
+
+fn find<'a>(tables: &'a [TableDef], ident: &TableIdent) -> Option<&'a TableDef> {
+    tables.iter().find(|t| same_table(t.ident(), ident))
+}
+
+/// true if the two idents are the same table.
+/// a missing schema is treated as the default schema
+pub(super) fn same_table(a: &TableIdent, b: &TableIdent) -> bool {
+    let schemas_match = match (a.schema(), b.schema()) {
+        (Some(x), Some(y)) => x == y,
+        _ => true,
+    };
+    schemas_match && a.name() == b.name()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::{ColumnDef, DataType, RelationDef};
+    use crate::Syntax;
+
+    fn table(name: &str, columns: &[&str], belongs_to: &[(&str, &str)]) -> TableDef {
+        TableDef {
+            ident: TableIdent::parse(name),
+            ty: DataType::Table,
+            columns: columns
+                .iter()
+                .map(|c| ColumnDef {
+                    name: c.to_string(),
+                    ty: "INT".to_string(),
+                    null: false,
+                    primary_key: *c == "id",
+                    primary_key_position: (*c == "id").then_some(1),
+                    updatable: true,
+                    default: None,
+                    collation: None,
+                    charset: None,
+                    max_length: None,
+                    precision: None,
+                    scale: None,
+                    auto_increment: false,
+                    generated: false,
+                    comment: None,
+                })
+                .collect(),
+            has_many: Vec::default(),
+            belongs_to: belongs_to
+                .iter()
+                .map(|(fk, other)| RelationDef::new(TableIdent::parse(other), fk, "id"))
+                .collect(),
+            foreign_keys: Vec::default(),
+            check_constraints: Vec::default(),
+            comment: None,
+            partitioning: None,
+            partition_of: None,
+            syntax: Syntax::Postgres,
+        }
+    }
+
+    fn db() -> Vec<TableDef> {
+        vec![
+            table("public.products", &["id"], &[]),
+            table("public.sellers", &["id"], &[]),
+            table(
+                "public.orders",
+                &["id", "product_id", "seller_id", "other_id"],
+                &[
+                    ("product_id", "public.products"),
+                    ("seller_id", "public.sellers"),
+                ],
+            ),
+        ]
+    }
+
+    #[test]
+    fn should_be_ok_when_fk_exists_for_belongs_to() {
+        let me = TableIdent::parse("orders");
+        let r = RelationInfo::new(
+            RelationKind::BelongsTo,
+            "product",
+            &["products"],
+            "product_id",
+        );
+        assert!(check_relation(&db(), &me, &r).is_none());
+    }
+
+    #[test]
+    fn should_be_ok_when_fk_exists_for_has_many() {
+        let me = TableIdent::parse("products");
+        let r = RelationInfo::new(RelationKind::HasMany, "orders", &["orders"], "product_id");
+        assert!(check_relation(&db(), &me, &r).is_none());
+    }
+
+    #[test]
+    fn should_report_missing_fk_constraint() {
+        let me = TableIdent::parse("orders");
+        let r = RelationInfo::new(RelationKind::BelongsTo, "other", &["products"], "other_id");
+        let (broken, issue) = check_relation(&db(), &me, &r).unwrap();
+        assert!(!broken);
+        assert_eq!(issue.foreign_key, "other_id");
+    }
+
+    #[test]
+    fn should_report_fk_column_missing_as_broken() {
+        let me = TableIdent::parse("products");
+        let r = RelationInfo::new(RelationKind::HasMany, "orders", &["orders"], "nope_id");
+        let (broken, issue) = check_relation(&db(), &me, &r).unwrap();
+        assert!(broken);
+        assert_eq!(issue.db_references, None);
+    }
+
+    #[test]
+    fn should_report_fk_pointing_at_wrong_table_as_broken() {
+        let me = TableIdent::parse("orders");
+        let r = RelationInfo::new(
+            RelationKind::BelongsTo,
+            "product",
+            &["products"],
+            "seller_id",
+        );
+        let (broken, issue) = check_relation(&db(), &me, &r).unwrap();
+        assert!(broken);
+        assert_eq!(
+            issue.db_references,
+            Some(TableIdent::parse("public.sellers"))
+        );
+    }
+}
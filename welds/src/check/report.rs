@@ -0,0 +1,268 @@
+use super::{Issue, Kind, Level, Severity, TableIssues};
+
+/// A flattened list of issues, in a shape that is stable to serialize.
+/// Useful for piping the results of a check into other tools (CI, dashboards, etc)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Report {
+    pub issues: Vec<ReportIssue>,
+}
+
+/// A single issue in a Report
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReportIssue {
+    /// The table the issue was found on
+    pub table: String,
+    pub level: Level,
+    pub severity: Severity,
+    /// The kind of issue in snake_case. I.E. `missing_table`, `changed`
+    pub kind: String,
+    pub column: Option<String>,
+    /// The type on the welds struct
+    pub expected_type: Option<String>,
+    /// The type in the database
+    pub actual_type: Option<String>,
+    /// If the welds struct allows null
+    pub expected_nullable: Option<bool>,
+    /// If the database allows null
+    pub actual_nullable: Option<bool>,
+    /// The rust type welds recommends for the database column
+    pub suggested_type: Option<String>,
+    /// A human readable description of the issue
+    pub message: String,
+}
+
+impl Report {
+    pub fn new(issues: &[Issue]) -> Report {
+        Report {
+            issues: issues.iter().map(ReportIssue::from).collect(),
+        }
+    }
+
+    /// Builds a Report from the results of `check::schema_all`
+    pub fn from_tables(tables: &[TableIssues]) -> Report {
+        Report {
+            issues: tables
+                .iter()
+                .flat_map(|t| t.issues.iter())
+                .map(ReportIssue::from)
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns a Report with only the issues that will cause queries to fail
+    pub fn errors_only(&self) -> Report {
+        self.with_severity(Severity::Error)
+    }
+
+    /// Returns a Report with only the issues of a given severity
+    pub fn with_severity(&self, severity: Severity) -> Report {
+        Report {
+            issues: self
+                .issues
+                .iter()
+                .filter(|x| x.severity == severity)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// returns true if any of the issues will cause queries to fail
+    pub fn has_errors(&self) -> bool {
+        self.issues.iter().any(|x| x.severity == Severity::Error)
+    }
+
+    /// Writes this report out as JSON
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> crate::errors::Result<String> {
+        let json = serde_json::to_string_pretty(self).map_err(anyhow::Error::from)?;
+        Ok(json)
+    }
+
+    /// Reads in a report that was written with `to_json`
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> crate::errors::Result<Report> {
+        let report = serde_json::from_str(json).map_err(anyhow::Error::from)?;
+        Ok(report)
+    }
+}
+
+impl From<&Issue> for ReportIssue {
+    fn from(issue: &Issue) -> Self {
+        let mut r = ReportIssue {
+            table: issue.ident.to_string(),
+            level: issue.level,
+            severity: issue.severity,
+            kind: kind_name(&issue.kind).to_string(),
+            column: None,
+            expected_type: None,
+            actual_type: None,
+            expected_nullable: None,
+            actual_nullable: None,
+            suggested_type: None,
+            message: issue.kind.to_string(),
+        };
+        match &issue.kind {
+            Kind::MissingTable => {}
+            Kind::InOtherSchema(_) => {}
+            Kind::InDbNotModel(missing) => {
+                r.column = Some(missing.column.clone());
+                r.actual_type = Some(missing.ty.clone());
+                r.actual_nullable = Some(missing.nullable);
+            }
+            Kind::OnModelNotDb(missing) => {
+                r.column = Some(missing.column.clone());
+                r.expected_type = Some(missing.ty.clone());
+                r.expected_nullable = Some(missing.nullable);
+            }
+            Kind::Changed(diff) => {
+                r.column = Some(diff.column.clone());
+                r.expected_type = Some(diff.welds_type.clone());
+                r.actual_type = Some(diff.db_type.clone());
+                r.expected_nullable = Some(diff.welds_nullable);
+                r.actual_nullable = Some(diff.db_nullable);
+                r.suggested_type = diff.suggested_rust_type.clone();
+            }
+            Kind::PrimaryKeyChanged(_) => {}
+            Kind::AutoIncrementChanged(diff) => {
+                r.column = Some(diff.column.clone());
+            }
+            Kind::MissingForeignKey(fk) | Kind::BrokenForeignKey(fk) => {
+                r.column = Some(fk.foreign_key.clone());
+            }
+            Kind::MissingIndex(_) | Kind::ExtraIndex(_) => {}
+            Kind::ViewOnly => {}
+            Kind::MissingUniqueConstraint(_) => {}
+        }
+        r
+    }
+}
+
+fn kind_name(kind: &Kind) -> &'static str {
+    match kind {
+        Kind::MissingTable => "missing_table",
+        Kind::InOtherSchema(_) => "in_other_schema",
+        Kind::InDbNotModel(_) => "in_db_not_model",
+        Kind::OnModelNotDb(_) => "on_model_not_db",
+        Kind::Changed(_) => "changed",
+        Kind::PrimaryKeyChanged(_) => "primary_key_changed",
+        Kind::AutoIncrementChanged(_) => "auto_increment_changed",
+        Kind::MissingForeignKey(_) => "missing_foreign_key",
+        Kind::BrokenForeignKey(_) => "broken_foreign_key",
+        Kind::MissingIndex(_) => "missing_index",
+        Kind::ExtraIndex(_) => "extra_index",
+        Kind::ViewOnly => "view_only",
+        Kind::MissingUniqueConstraint(_) => "missing_unique_constraint",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check::Diff;
+
+    fn changed() -> Issue {
+        Issue::changed(
+            Some("public"),
+            "cars",
+            Diff {
+                column: "name".to_string(),
+                db_type: "INT4".to_string(),
+                db_nullable: true,
+                welds_type: "String".to_string(),
+                welds_nullable: false,
+                type_changed: true,
+                db_default: None,
+                welds_default: None,
+                default_changed: false,
+                db_collation: None,
+                welds_collation: None,
+                collation_changed: false,
+                db_max_length: None,
+                welds_max_length: None,
+                db_precision: None,
+                welds_precision: None,
+                db_scale: None,
+                welds_scale: None,
+                size_changed: false,
+                suggested_rust_type: None,
+            },
+        )
+    }
+
+    #[test]
+    fn should_flatten_a_changed_column() {
+        let report = Report::new(&[changed()]);
+        let issue = &report.issues[0];
+        assert_eq!(issue.table, "public.cars");
+        assert_eq!(issue.kind, "changed");
+        assert_eq!(issue.column.as_deref(), Some("name"));
+        assert_eq!(issue.expected_type.as_deref(), Some("String"));
+        assert_eq!(issue.actual_type.as_deref(), Some("INT4"));
+    }
+
+    #[test]
+    fn should_keep_only_errors() {
+        let mut widened = changed();
+        if let Kind::Changed(diff) = &mut widened.kind {
+            diff.type_changed = false;
+            diff.db_nullable = false;
+            diff.welds_nullable = true;
+        }
+        widened.severity = Severity::classify(&widened.kind);
+        assert_eq!(widened.severity, Severity::Info);
+
+        let report = Report::new(&[changed(), widened]);
+        assert!(report.has_errors());
+        let errors = report.errors_only();
+        assert_eq!(errors.issues.len(), 1);
+        assert_eq!(errors.issues[0].severity, Severity::Error);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn should_write_stable_json() {
+        let report = Report::new(&[Issue::missing_table(None, "cars")]);
+        let json = report.to_json().unwrap();
+        let expected = r#"{
+  "issues": [
+    {
+      "table": "cars",
+      "level": "Critical",
+      "severity": "Error",
+      "kind": "missing_table",
+      "column": null,
+      "expected_type": null,
+      "actual_type": null,
+      "expected_nullable": null,
+      "actual_nullable": null,
+      "suggested_type": null,
+      "message": "The Table was not found in the database"
+    }
+  ]
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn should_round_trip_through_json() {
+        let report = Report::new(&[changed(), Issue::missing_table(None, "cars")]);
+        let json = report.to_json().unwrap();
+        assert_eq!(Report::from_json(&json).unwrap(), report);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn should_round_trip_issues_through_json() {
+        let issue = changed();
+        let json = serde_json::to_string(&issue).unwrap();
+        let back: Issue = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, issue);
+    }
+}
@@ -0,0 +1,370 @@
+use super::*;
+use crate::WeldsModel;
+
+fn db_col(name: &str, primary_key: bool) -> ColumnDef {
+    ColumnDef {
+        name: name.to_string(),
+        ty: "INT".to_string(),
+        null: false,
+        primary_key,
+        primary_key_position: primary_key.then_some(1),
+        updatable: true,
+        default: None,
+        collation: None,
+        charset: None,
+        max_length: None,
+        precision: None,
+        scale: None,
+        auto_increment: false,
+        generated: false,
+        comment: None,
+    }
+}
+
+fn db_index(name: &str, columns: &[&str], primary_key: bool) -> IndexDef {
+    IndexDef {
+        name: name.to_string(),
+        columns: columns.iter().map(|x| x.to_string()).collect(),
+        unique: primary_key,
+        primary_key,
+        method: "btree".to_string(),
+    }
+}
+
+#[test]
+fn should_not_require_generated_columns_on_the_model() {
+    let mut total = db_col("total", false);
+    total.generated = true;
+    let table = vec![db_col("id", true), total];
+    let model = vec![Column::new("id", "i32", false)];
+    assert!(struct_missing(&table, &model).is_empty());
+}
+
+#[test]
+fn should_find_index_missing_from_db() {
+    let table = vec![db_index("pk", &["id"], true)];
+    let model = vec![Index::new(&["name"])];
+    let missing = index_missing(&table, &model);
+    assert_eq!(missing, vec![&model[0]]);
+    assert!(index_extra(&table, &model).is_empty());
+}
+
+#[test]
+fn should_find_index_in_db_not_on_model() {
+    let table = vec![
+        db_index("pk", &["id"], true),
+        db_index("ix_name", &["name"], false),
+    ];
+    let model = vec![];
+    let extra = index_extra(&table, &model);
+    assert_eq!(extra, vec![&table[1]]);
+    assert!(index_missing(&table, &model).is_empty());
+}
+
+#[test]
+fn should_find_unique_index_missing_from_db() {
+    let table = vec![
+        db_index("pk", &["id"], true),
+        db_index("ix_email", &["email"], false),
+    ];
+    let model = vec![Index::new(&["email"]).with_unique()];
+    assert_eq!(unique_missing(&table, &model), vec![&model[0]]);
+    // reported as not unique, not as missing
+    assert!(index_missing(&table, &model).is_empty());
+    assert!(index_extra(&table, &model).is_empty());
+}
+
+#[test]
+fn should_accept_unique_indexes_and_primary_keys_as_unique() {
+    let mut ux_email = db_index("ux_email", &["email"], false);
+    ux_email.unique = true;
+    let table = vec![db_index("pk", &["id"], true), ux_email];
+    let model = vec![
+        Index::new(&["id"]).with_unique(),
+        Index::new(&["email"]).with_unique(),
+    ];
+    assert!(unique_missing(&table, &model).is_empty());
+}
+
+#[test]
+fn should_match_indexes_by_columns_not_name() {
+    let table = vec![db_index("ix_other_name", &["a", "b"], false)];
+    let model = vec![Index::new(&["a", "b"]).with_name("ix_name")];
+    assert!(index_missing(&table, &model).is_empty());
+    assert!(index_extra(&table, &model).is_empty());
+}
+
+#[test]
+fn should_care_about_index_column_order() {
+    let table = vec![db_index("ix", &["b", "a"], false)];
+    let model = vec![Index::new(&["a", "b"])];
+    assert_eq!(index_missing(&table, &model).len(), 1);
+    assert_eq!(index_extra(&table, &model).len(), 1);
+}
+
+#[test]
+fn should_not_diff_matching_primary_keys() {
+    let table = vec![db_col("name", false), db_col("id", true)];
+    let model = vec![Column::new("id", "i32", false)];
+    assert_eq!(build_pk_diff(&table, &model), None);
+}
+
+#[test]
+fn should_diff_when_db_has_no_primary_key() {
+    let table = vec![db_col("id", false)];
+    let model = vec![Column::new("id", "i32", false)];
+    let diff = build_pk_diff(&table, &model).unwrap();
+    assert!(diff.db_columns.is_empty());
+    assert_eq!(diff.welds_columns, vec!["id".to_string()]);
+}
+
+#[test]
+fn should_diff_when_primary_key_is_a_different_column() {
+    let table = vec![db_col("id", false), db_col("uuid", true)];
+    let model = vec![Column::new("id", "i32", false)];
+    let diff = build_pk_diff(&table, &model).unwrap();
+    assert_eq!(diff.db_columns, vec!["uuid".to_string()]);
+    assert_eq!(diff.welds_columns, vec!["id".to_string()]);
+}
+
+#[test]
+fn should_diff_when_db_does_not_generate_an_integer_key() {
+    let table = vec![db_col("id", true)];
+    let model = vec![Column::new("id", "i32", false)];
+    let diff = build_auto_increment_diff(&table, &model).unwrap();
+    assert!(diff.welds_auto_increment);
+    assert!(!diff.db_auto_increment);
+
+    let mut id = db_col("id", true);
+    id.auto_increment = true;
+    assert_eq!(build_auto_increment_diff(&[id], &model), None);
+}
+
+#[test]
+fn should_diff_when_db_generates_a_key_the_model_provides() {
+    let mut id = db_col("id", true);
+    id.auto_increment = true;
+    let model = vec![Column::new("id", "uuid::Uuid", false)];
+    let diff = build_auto_increment_diff(&[id], &model).unwrap();
+    assert!(!diff.welds_auto_increment);
+
+    let model = vec![Column::new("id", "String", false)];
+    assert_eq!(
+        build_auto_increment_diff(&[db_col("id", true)], &model),
+        None
+    );
+}
+
+#[test]
+fn should_diff_defaults_only_when_the_model_has_one() {
+    let pairs = get_pairs(crate::Syntax::Postgres);
+    let options = CheckOptions::default();
+    let mut col = db_col("created_at", false);
+    col.ty = "TIMESTAMPTZ".to_string();
+    col.default = Some("now()".to_string());
+    let field = Column::new("created_at", "DateTime<Utc>", false);
+    assert_eq!(build_diff(&pairs, &options, true, &col, &field), None);
+
+    let field = field.with_default("CURRENT_TIMESTAMP");
+    assert_eq!(build_diff(&pairs, &options, true, &col, &field), None);
+
+    let strict = CheckOptions {
+        defaults: DefaultMatching::Exact,
+        ..Default::default()
+    };
+    let diff = build_diff(&pairs, &strict, true, &col, &field).unwrap();
+    assert!(diff.default_changed());
+    assert!(!diff.type_changed());
+}
+
+#[test]
+fn should_only_diff_unsafe_nullability_when_read_safe() {
+    let pairs = get_pairs(crate::Syntax::Postgres);
+    let read_safe = CheckOptions {
+        nullability: Nullability::ReadSafe,
+        ..Default::default()
+    };
+    // not null column into an Option<T> is fine to read
+    let col = db_col("id", false);
+    let field = Column::new("id", "i32", true);
+    assert!(build_diff(&pairs, &CheckOptions::default(), true, &col, &field).is_some());
+    assert_eq!(build_diff(&pairs, &read_safe, true, &col, &field), None);
+
+    // null column into a T is not
+    let mut col = db_col("id", false);
+    col.null = true;
+    let field = Column::new("id", "i32", false);
+    let diff = build_diff(&pairs, &read_safe, true, &col, &field).unwrap();
+    assert!(diff.nullable_changed());
+}
+
+#[test]
+fn should_diff_sizes_only_when_the_model_has_one() {
+    let pairs = get_pairs(crate::Syntax::Postgres);
+    let options = CheckOptions::default();
+    let mut col = db_col("code", false);
+    col.ty = "VARCHAR".to_string();
+    col.max_length = Some(255);
+    let field = Column::new("code", "String", false);
+    assert_eq!(build_diff(&pairs, &options, true, &col, &field), None);
+
+    let field = field.with_max_length(255);
+    assert_eq!(build_diff(&pairs, &options, true, &col, &field), None);
+
+    let field = field.with_max_length(50);
+    let diff = build_diff(&pairs, &options, true, &col, &field).unwrap();
+    assert!(diff.size_changed());
+    assert!(!diff.type_changed());
+    assert!(diff.to_string().contains("db_size: (255) welds_size: (50)"));
+}
+
+#[test]
+fn should_diff_collations_only_when_the_model_has_one() {
+    let pairs = get_pairs(crate::Syntax::Mysql);
+    let options = CheckOptions::default();
+    let mut col = db_col("email", false);
+    col.ty = "varchar".to_string();
+    col.collation = Some("utf8mb4_bin".to_string());
+    let field = Column::new("email", "String", false);
+    assert_eq!(build_diff(&pairs, &options, true, &col, &field), None);
+
+    let field = field.with_collation("UTF8MB4_BIN");
+    assert_eq!(build_diff(&pairs, &options, true, &col, &field), None);
+
+    let field = field.with_collation("utf8mb4_general_ci");
+    let diff = build_diff(&pairs, &options, true, &col, &field).unwrap();
+    assert!(diff.collation_changed());
+    assert!(!diff.type_changed());
+}
+
+#[test]
+fn should_suggest_a_rust_type_for_changed_columns() {
+    let pairs = get_pairs(crate::Syntax::Postgres);
+    let options = CheckOptions::default();
+    let mut col = db_col("created_at", false);
+    col.ty = "timestamptz".to_string();
+    col.null = true;
+    let field = Column::new("created_at", "NaiveDateTime", false);
+    let diff = build_diff(&pairs, &options, true, &col, &field).unwrap();
+    assert_eq!(
+        diff.suggested_rust_type.as_deref(),
+        Some("Option<chrono::DateTime<chrono::Utc>>")
+    );
+    assert!(diff
+        .to_string()
+        .contains("suggested_type: Option<chrono::DateTime<chrono::Utc>>"));
+}
+
+#[derive(Debug, WeldsModel)]
+#[welds(table = "cars")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Car {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub name: String,
+}
+
+fn car_snapshot(mut columns: Vec<ColumnDef>) -> SchemaSnapshot {
+    // the Car id is an i32, it is expected to be generated
+    columns
+        .iter_mut()
+        .for_each(|c| c.auto_increment = c.primary_key);
+    snapshot_of(DataType::Table, columns)
+}
+
+fn snapshot_of(ty: DataType, columns: Vec<ColumnDef>) -> SchemaSnapshot {
+    use crate::detect::{TableDef, TableSnapshot};
+    let table = TableDef {
+        ident: TableIdent::new("cars", Some("public")),
+        ty,
+        columns,
+        has_many: Vec::default(),
+        belongs_to: Vec::default(),
+        foreign_keys: Vec::default(),
+        check_constraints: Vec::default(),
+        comment: None,
+        partitioning: None,
+        partition_of: None,
+        syntax: Syntax::Postgres,
+    };
+    let indexes = vec![db_index("cars_pkey", &["id"], true)];
+    let table = TableSnapshot {
+        table,
+        indexes,
+        triggers: Vec::default(),
+    };
+    SchemaSnapshot::new(Syntax::Postgres, vec![table])
+}
+
+#[test]
+fn should_check_a_model_against_a_snapshot() {
+    let mut name = db_col("name", false);
+    name.ty = "text".to_string();
+    let snapshot = car_snapshot(vec![db_col("id", true), name]);
+    let issues = schema_against_snapshot::<Car>(&snapshot);
+    assert!(issues.is_empty(), "{:?}", issues);
+}
+
+#[test]
+fn should_find_changes_in_a_snapshot() {
+    let snapshot = car_snapshot(vec![db_col("id", true), db_col("name", false)]);
+    let issues = schema_against_snapshot::<Car>(&snapshot);
+    assert_eq!(issues.len(), 1);
+    assert!(issues[0].kind.as_changed().unwrap().type_changed());
+}
+
+#[test]
+fn should_report_table_missing_from_snapshot() {
+    let snapshot = SchemaSnapshot::new(Syntax::Postgres, Vec::default());
+    let issues = schema_against_snapshot::<Car>(&snapshot);
+    assert_eq!(issues, vec![Issue::missing_table(Some("public"), "cars")]);
+}
+
+#[test]
+fn should_report_table_found_in_another_schema() {
+    let mut snapshot = car_snapshot(vec![db_col("id", true), db_col("name", false)]);
+    snapshot.tables[0].table.ident = TableIdent::new("cars", Some("archive"));
+    let issues = schema_against_snapshot::<Car>(&snapshot);
+    assert_eq!(issues.len(), 1);
+    let found = issues[0].kind.as_in_other_schema().unwrap();
+    assert_eq!(found, &[TableIdent::parse("archive.cars")]);
+}
+
+#[derive(Debug, WeldsModel)]
+#[welds(table = "cars")]
+#[welds(readonly)]
+#[welds_path(crate)] // needed only within the welds crate.
+struct CarView {
+    pub id: i32,
+    pub name: String,
+}
+
+fn car_view() -> SchemaSnapshot {
+    let mut id = db_col("id", false);
+    id.null = true;
+    let mut name = db_col("name", false);
+    name.ty = "text".to_string();
+    name.null = true;
+    snapshot_of(DataType::View, vec![id, name])
+}
+
+#[test]
+fn should_not_compare_nullable_on_views() {
+    let issues = schema_against_snapshot::<CarView>(&car_view());
+    assert!(issues.is_empty(), "{:?}", issues);
+}
+
+#[test]
+fn should_report_writable_model_on_a_view() {
+    let issues = schema_against_snapshot::<Car>(&car_view());
+    assert_eq!(issues, vec![Issue::view_only(Some("public"), "cars")]);
+}
+
+#[test]
+fn should_treat_materialized_views_as_views() {
+    let mut snapshot = car_view();
+    snapshot.tables[0].table.ty = DataType::MaterializedView;
+    assert!(schema_against_snapshot::<CarView>(&snapshot).is_empty());
+    let issues = schema_against_snapshot::<Car>(&snapshot);
+    assert_eq!(issues, vec![Issue::view_only(Some("public"), "cars")]);
+}
@@ -0,0 +1,109 @@
+use super::relations::same_table;
+use super::Registration;
+use crate::detect::{find_tables, TableDef};
+use crate::errors::Result;
+use crate::model_traits::TableIdent;
+use crate::Client;
+use crate::Syntax;
+
+/// Returns the tables and views in the database that none of the registered models are connected to
+///
+/// Useful for auditing a large database for dead tables or missing models
+/// ```rust,ignore
+/// let models = welds::models![Product, Order, Seller];
+/// for table in welds::check::unmapped_tables(client, &models).await? {
+///     println!("{}", table.ident());
+/// }
+/// ```
+pub async fn unmapped_tables(
+    client: &dyn Client,
+    models: &[Registration],
+) -> Result<Vec<TableDef>> {
+    let syntax = client.syntax();
+    let tables = find_tables(client).await?;
+    let mapped: Vec<TableIdent> = models
+        .iter()
+        .map(|m| with_namespace(syntax, m.ident()))
+        .collect();
+    Ok(unmapped(tables, &mapped))
+}
+
+/// models without a schema are in the default schema for the database
+fn with_namespace(syntax: Syntax, ident: TableIdent) -> TableIdent {
+    match ident.schema() {
+        Some(_) => ident,
+        None => TableIdent::new(ident.name(), TableIdent::default_namespace(syntax)),
+    }
+}
+
+fn unmapped(mut tables: Vec<TableDef>, mapped: &[TableIdent]) -> Vec<TableDef> {
+    // a partition belongs to the model of its partitioned table
+    tables.retain(|t| {
+        let ident = t.partition_of().unwrap_or(t.ident());
+        !mapped.iter().any(|m| same_table(ident, m))
+    });
+    tables
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::DataType;
+
+    fn table(name: &str, ty: DataType) -> TableDef {
+        TableDef {
+            ident: TableIdent::parse(name),
+            ty,
+            columns: Vec::default(),
+            has_many: Vec::default(),
+            belongs_to: Vec::default(),
+            foreign_keys: Vec::default(),
+            check_constraints: Vec::default(),
+            comment: None,
+            partitioning: None,
+            partition_of: None,
+            syntax: Syntax::Postgres,
+        }
+    }
+
+    #[test]
+    fn should_list_tables_and_views_without_a_model() {
+        let tables = vec![
+            table("public.cars", DataType::Table),
+            table("public.old_cars", DataType::Table),
+            table("public.car_report", DataType::View),
+        ];
+        let mapped = vec![with_namespace(Syntax::Postgres, TableIdent::parse("cars"))];
+        let found: Vec<String> = unmapped(tables, &mapped)
+            .iter()
+            .map(|t| t.ident().to_string())
+            .collect();
+        assert_eq!(found, vec!["public.old_cars", "public.car_report"]);
+    }
+
+    #[test]
+    fn should_not_map_a_model_to_a_table_in_another_schema() {
+        let tables = vec![table("archive.cars", DataType::Table)];
+        let mapped = vec![with_namespace(Syntax::Postgres, TableIdent::parse("cars"))];
+        assert_eq!(unmapped(tables, &mapped).len(), 1);
+    }
+
+    #[test]
+    fn should_treat_partitions_as_part_of_their_table() {
+        let mut partition = table("public.events_2024", DataType::Table);
+        partition.partition_of = Some(TableIdent::parse("public.events"));
+        let tables = vec![table("public.events", DataType::Table), partition];
+        let mapped = vec![with_namespace(
+            Syntax::Postgres,
+            TableIdent::parse("events"),
+        )];
+        assert!(unmapped(tables, &mapped).is_empty());
+    }
+
+    #[test]
+    fn should_match_any_schema_when_the_syntax_has_no_default() {
+        let tables = vec![table("shop.cars", DataType::Table)];
+        let mapped = vec![with_namespace(Syntax::Mysql, TableIdent::parse("cars"))];
+        assert!(unmapped(tables, &mapped).is_empty());
+    }
+}
@@ -0,0 +1,32 @@
+use crate::model_traits::TableIdent;
+use crate::Row;
+
+pub struct CheckScanRow {
+    pub(super) schema: Option<String>,
+    pub(super) table_name: String,
+    pub(super) constraint_name: String,
+    pub(super) check_clause: String,
+    pub(super) column_name: Option<String>,
+}
+
+impl TryFrom<Row> for CheckScanRow {
+    type Error = crate::WeldsError;
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        Ok(CheckScanRow {
+            schema: row.get_by_position(0)?,
+            table_name: row.get_by_position(1)?,
+            constraint_name: row.get_by_position(2)?,
+            check_clause: row.get_by_position(3)?,
+            column_name: row.get_by_position(4)?,
+        })
+    }
+}
+
+impl CheckScanRow {
+    pub fn ident(&self) -> TableIdent {
+        TableIdent {
+            schema: self.schema.clone(),
+            name: self.table_name.clone(),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::model_traits::TableIdent;
+use crate::Row;
+
+pub struct EnumScanRow {
+    pub(super) schema: Option<String>,
+    pub(super) name: String,
+    pub(super) label: String,
+}
+
+impl TryFrom<Row> for EnumScanRow {
+    type Error = crate::WeldsError;
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        Ok(EnumScanRow {
+            schema: row.get_by_position(0)?,
+            name: row.get_by_position(1)?,
+            label: row.get_by_position(2)?,
+        })
+    }
+}
+
+impl EnumScanRow {
+    pub fn ident(&self) -> TableIdent {
+        TableIdent {
+            schema: self.schema.clone(),
+            name: self.name.clone(),
+        }
+    }
+}
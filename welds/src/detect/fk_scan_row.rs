@@ -5,6 +5,10 @@ use crate::Row;
 pub struct FkScanRow {
     pub(super) me: FkScanTableCol,
     pub(super) other: FkScanTableCol,
+    pub(super) constraint_name: Option<String>,
+    pub(super) column_position: i64,
+    pub(super) on_delete: String,
+    pub(super) on_update: String,
 }
 
 #[derive(Debug)]
@@ -37,6 +41,10 @@ impl TryFrom<Row> for FkScanRow {
         Ok(FkScanRow {
             me: FkScanTableCol::new(c0, c1, c2),
             other: FkScanTableCol::new(c3, c4, c5),
+            constraint_name: row.get_by_position(6)?,
+            column_position: row.get_by_position(7)?,
+            on_delete: row.get_by_position(8)?,
+            on_update: row.get_by_position(9)?,
         })
     }
 }
@@ -0,0 +1,26 @@
+/// How the name of a table is compared to the names found in the database
+///
+/// Postgres folds unquoted names to lower case but keeps the case of quoted names,
+/// while Mssql and Mysql usually compare names with a case-insensitive collation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum IdentMatching {
+    /// The name must be exactly the same. I.E. a quoted Postgres name
+    Exact,
+    /// The name is lower cased and then must be exactly the same. I.E. an unquoted Postgres name
+    FoldToLower,
+    /// Any difference in case is ignored.
+    /// When more than one table matches, the one with the exact same name is preferred
+    #[default]
+    CaseInsensitive,
+}
+
+impl IdentMatching {
+    /// true if the name the user asked for matches a name found in the database
+    pub fn matches(&self, wanted: &str, found: &str) -> bool {
+        match self {
+            IdentMatching::Exact => wanted == found,
+            IdentMatching::FoldToLower => wanted.to_lowercase() == found,
+            IdentMatching::CaseInsensitive => wanted.to_lowercase() == found.to_lowercase(),
+        }
+    }
+}
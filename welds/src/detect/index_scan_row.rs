@@ -0,0 +1,22 @@
+use crate::Row;
+
+pub struct IndexScanRow {
+    pub(super) index_name: String,
+    pub(super) column_name: String,
+    pub(super) is_unique: i32,
+    pub(super) is_primary_key: i32,
+    pub(super) index_method: String,
+}
+
+impl TryFrom<Row> for IndexScanRow {
+    type Error = crate::WeldsError;
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        Ok(IndexScanRow {
+            index_name: row.get_by_position(0)?,
+            column_name: row.get_by_position(1)?,
+            is_unique: row.get_by_position(2)?,
+            is_primary_key: row.get_by_position(3)?,
+            index_method: row.get_by_position(4)?,
+        })
+    }
+}
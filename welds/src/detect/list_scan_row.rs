@@ -0,0 +1,38 @@
+use super::table_def::DataType;
+use crate::model_traits::TableIdent;
+use crate::Row;
+
+pub struct ListScanRow {
+    pub(super) schema: Option<String>,
+    pub(super) table_name: String,
+    pub(super) ty: String,
+}
+
+impl TryFrom<Row> for ListScanRow {
+    type Error = crate::WeldsError;
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        Ok(ListScanRow {
+            schema: row.get_by_position(0)?,
+            table_name: row.get_by_position(1)?,
+            ty: row.get_by_position(2)?,
+        })
+    }
+}
+
+impl ListScanRow {
+    pub fn ident(&self) -> TableIdent {
+        TableIdent {
+            schema: self.schema.clone(),
+            name: self.table_name.clone(),
+        }
+    }
+    pub fn kind(&self) -> DataType {
+        if self.ty == "table" {
+            return DataType::Table;
+        }
+        if self.ty == "materialized_view" {
+            return DataType::MaterializedView;
+        }
+        DataType::View
+    }
+}
@@ -11,6 +11,28 @@ mod table_scan_row;
 use table_scan_row::TableScanRow;
 mod fk_scan_row;
 use fk_scan_row::{FkScanRow, FkScanTableCol};
+mod index_scan_row;
+use index_scan_row::IndexScanRow;
+mod enum_scan_row;
+use enum_scan_row::EnumScanRow;
+mod check_scan_row;
+use check_scan_row::CheckScanRow;
+mod trigger_scan_row;
+use trigger_scan_row::TriggerScanRow;
+mod sequence_scan_row;
+use sequence_scan_row::SequenceScanRow;
+mod list_scan_row;
+use list_scan_row::ListScanRow;
+mod partition_scan_row;
+use partition_scan_row::PartitionScanRow;
+mod routine_scan_row;
+use routine_scan_row::RoutineScanRow;
+mod stats_scan_row;
+use stats_scan_row::StatsScanRow;
+mod table_filter;
+pub use table_filter::TableFilter;
+mod ident_matching;
+pub use ident_matching::IdentMatching;
 
 #[cfg(feature = "mock")]
 pub use table_def::mock::MockColumnDef;
@@ -18,7 +40,13 @@ pub use table_def::mock::MockColumnDef;
 pub use table_def::mock::MockTableDef;
 
 pub(crate) mod table_def;
-pub use table_def::{ColumnDef, DataType, RelationDef, TableDef, TableDefSingle};
+pub use table_def::{
+    CheckConstraintDef, ColumnDef, DataType, EnumDef, ForeignKeyDef, IndexDef, PartitionDef,
+    RelationDef, RoutineDef, SequenceDef, TableDef, TableDefSingle, TableStats, TableSummary,
+    TriggerDef,
+};
+mod snapshot;
+pub use snapshot::{snapshot, SchemaSnapshot, TableSnapshot};
 
 /// Returns a list of all user defined tables in the database
 /// requires feature `detect`
@@ -43,43 +71,519 @@ pub async fn find_tables(client: &dyn Client) -> Result<Vec<TableDef>> {
 
     link_fks_into_tables(&fks, &mut tables);
 
+    // Add the check constraints to the tables
+    if let Some(sql) = ts.check_scan_sql() {
+        let args: ParamArgs = Vec::default();
+        let checks_raw = match (syntax, client.fetch_rows(sql, &args).await) {
+            // CHECK_CONSTRAINTS was added in Mysql 8. Older versions don't enforce checks
+            (Syntax::Mysql, Err(_)) => Vec::default(),
+            (_, checks_raw) => checks_raw?,
+        };
+        let checks: Result<Vec<CheckScanRow>> =
+            checks_raw.into_iter().map(|r| r.try_into()).collect();
+        link_checks_into_tables(checks?, &mut tables);
+    }
+
+    // Add the partitioning info to the tables
+    if let Some(sql) = ts.partition_scan_sql() {
+        let args: ParamArgs = Vec::default();
+        let mut parts_raw = client.fetch_rows(sql, &args).await?;
+        let parts: Result<Vec<PartitionScanRow>> =
+            parts_raw.drain(..).map(|r| r.try_into()).collect();
+        link_partitions_into_tables(parts?, &mut tables);
+    }
+
     Ok(tables)
 }
 
+/// Returns the names of the user defined tables that match a filter.
+/// Much lighter than find_tables, no column info is pulled from the database
+pub async fn list_tables(client: &dyn Client, filter: TableFilter) -> Result<Vec<TableSummary>> {
+    let syntax = client.syntax();
+    let ts = TableScan::new(syntax);
+    let sql = ts.list_scan_sql();
+
+    let kind = filter.kind_str();
+    let mut args: ParamArgs = Vec::default();
+    for param in [&filter.namespace, &filter.name_like, &kind] {
+        args.push(param);
+        // Mysql can't reuse a param, it needs each one twice
+        if let Syntax::Mysql = syntax {
+            args.push(param);
+        }
+    }
+
+    let mut raw_rows = client.fetch_rows(sql, &args).await?;
+    let rows: Result<Vec<ListScanRow>> = raw_rows.drain(..).map(|r| r.try_into()).collect();
+    let rows = rows?;
+
+    let summaries = rows.iter().skip(filter.offset).map(|r| TableSummary {
+        ident: r.ident(),
+        ty: r.kind(),
+    });
+    Ok(match filter.limit {
+        Some(limit) => summaries.take(limit).collect(),
+        None => summaries.collect(),
+    })
+}
+
+/// Searches every schema the connection can see for a table (or view) with this name.
+/// Returns each match along with the schema it was found in
+pub async fn find_table_any_schema(
+    tablename: impl Into<String>,
+    client: &dyn Client,
+) -> Result<Vec<TableSummary>> {
+    let tablename: String = tablename.into();
+    let matching = IdentMatching::default();
+    let mut tables = list_tables(client, TableFilter::default()).await?;
+    tables.retain(|t| matching.matches(&tablename, t.ident.name()));
+    Ok(tables)
+}
+
+/// Returns a list of all user defined views in the database and their columns
+/// NOTE: does not include relationship info. views don't have foreign keys
+pub async fn find_views(client: &dyn Client) -> Result<Vec<TableDef>> {
+    let syntax = client.syntax();
+    let ts = TableScan::new(syntax);
+    let sql = ts.table_scan_sql();
+
+    let args: ParamArgs = Vec::default();
+    let mut raw_rows = client.fetch_rows(sql, &args).await?;
+
+    let rows: Result<Vec<TableScanRow>> = raw_rows.drain(..).map(|r| r.try_into()).collect();
+    let rows = rows?;
+    let views = build_table_defs(syntax, rows)
+        .drain(..)
+        .filter(|t| t.ty == DataType::View)
+        .collect();
+
+    Ok(views)
+}
+
+/// Re-runs the query of a materialized view and stores the results.
+/// `concurrently` lets readers keep using the view, but requires a unique index on it.
+/// Only Postgres has materialized views
+pub async fn refresh_materialized_view(
+    client: &dyn Client,
+    view: &TableIdent,
+    concurrently: bool,
+) -> Result<()> {
+    if client.syntax() != Syntax::Postgres {
+        return Err(anyhow::anyhow!("Materialized views are only supported by Postgres").into());
+    }
+    let sql = match concurrently {
+        true => format!("REFRESH MATERIALIZED VIEW CONCURRENTLY {}", view),
+        false => format!("REFRESH MATERIALIZED VIEW {}", view),
+    };
+    client.execute(&sql, &[]).await?;
+    Ok(())
+}
+
 /// Returns the schema info for a given table in the database
 /// NOTE: does not include relationship info. use find_tables for that
 pub async fn find_table(
     namespace: Option<impl Into<String>>,
     tablename: impl Into<String>,
     client: &dyn Client,
+) -> Result<Option<TableDefSingle>> {
+    find_table_with(namespace, tablename, IdentMatching::default(), client).await
+}
+
+/// Same as `find_table` but lets you pick how the names of the namespace and table are matched
+pub async fn find_table_with(
+    namespace: Option<impl Into<String>>,
+    tablename: impl Into<String>,
+    matching: IdentMatching,
+    client: &dyn Client,
 ) -> Result<Option<TableDefSingle>> {
     let syntax = client.syntax();
     let ts = TableScan::new(syntax);
     let sql = ts.single_table_scan_sql();
-    let mut args: ParamArgs = Vec::default();
 
     let namespace: Option<String> = namespace
         .map(|x| x.into())
         .or(TableIdent::default_namespace(syntax).map(|s| s.to_string()));
+    let tablename: String = tablename.into();
+    let args = single_table_args(syntax, &namespace, &tablename);
+
+    let mut raw_rows = client.fetch_rows(sql, &args).await?;
+
+    let rows: Result<Vec<TableScanRow>> = raw_rows.drain(..).map(|r| r.try_into()).collect();
+    let mut rows = rows?;
+    // the SQL finds the table ignoring case, the matching rules are applied here
+    rows.retain(|r| matched(matching, namespace.as_deref(), &tablename, &r.ident()));
+
+    let mut tables = build_table_defs(syntax, rows);
+    let exact = tables.iter().position(|t| t.ident.name == tablename);
+    let table = match exact {
+        Some(index) => Some(tables.swap_remove(index)),
+        None => tables.pop(),
+    };
+
+    Ok(table.map(|x| x.into()))
+}
+
+/// true if the namespace/table the user asked for is the table that was found
+fn matched(
+    matching: IdentMatching,
+    namespace: Option<&str>,
+    tablename: &str,
+    found: &TableIdent,
+) -> bool {
+    let schemas_match = match (namespace, found.schema()) {
+        (Some(wanted), Some(found)) => matching.matches(wanted, found),
+        _ => true,
+    };
+    schemas_match && matching.matches(tablename, found.name())
+}
+
+/// Returns the indexes that exist on a given table in the database
+/// Indexes on expressions are not included
+pub async fn find_indexes(
+    namespace: Option<impl Into<String>>,
+    tablename: impl Into<String>,
+    client: &dyn Client,
+) -> Result<Vec<IndexDef>> {
+    let syntax = client.syntax();
+    let ts = TableScan::new(syntax);
+    let sql = ts.index_scan_sql();
 
+    let namespace: Option<String> = namespace
+        .map(|x| x.into())
+        .or(TableIdent::default_namespace(syntax).map(|s| s.to_string()));
     let tablename: String = tablename.into();
-    args.push(&namespace);
+    let args = single_table_args(syntax, &namespace, &tablename);
+
+    let mut raw_rows = client.fetch_rows(sql, &args).await?;
+
+    let rows: Result<Vec<IndexScanRow>> = raw_rows.drain(..).map(|r| r.try_into()).collect();
+    let rows = rows?;
+
+    Ok(build_index_defs(rows))
+}
+
+/// Returns the foreign keys on a given table in the database
+pub async fn find_foreign_keys(
+    namespace: Option<impl Into<String>>,
+    tablename: impl Into<String>,
+    client: &dyn Client,
+) -> Result<Vec<ForeignKeyDef>> {
+    let syntax = client.syntax();
+    let ts = TableScan::new(syntax);
+    let sql = ts.fk_scan_sql();
 
+    let namespace: Option<String> = namespace
+        .map(|x| x.into())
+        .or(TableIdent::default_namespace(syntax).map(|s| s.to_string()));
+    let tablename: String = tablename.into();
+
+    let args: ParamArgs = Vec::default();
+    let mut raw_rows = client.fetch_rows(sql, &args).await?;
+    let rows: Result<Vec<FkScanRow>> = raw_rows.drain(..).map(|r| r.try_into()).collect();
+    let rows = rows?;
+
+    let rows: Vec<&FkScanRow> = rows
+        .iter()
+        .filter(|r| {
+            let ident = &r.me.ident;
+            let schemas_match = match (&namespace, ident.schema()) {
+                (Some(x), Some(y)) => x == y,
+                _ => true,
+            };
+            schemas_match && ident.name() == tablename
+        })
+        .collect();
+
+    Ok(build_foreign_key_defs(&rows))
+}
+
+/// Returns the triggers attached to a given table in the database
+pub async fn find_triggers(
+    namespace: Option<impl Into<String>>,
+    tablename: impl Into<String>,
+    client: &dyn Client,
+) -> Result<Vec<TriggerDef>> {
+    let syntax = client.syntax();
+    let ts = TableScan::new(syntax);
+    let sql = ts.trigger_scan_sql();
+
+    let namespace: Option<String> = namespace
+        .map(|x| x.into())
+        .or(TableIdent::default_namespace(syntax).map(|s| s.to_string()));
+    let tablename: String = tablename.into();
+    let args = single_table_args(syntax, &namespace, &tablename);
+
+    let mut raw_rows = client.fetch_rows(sql, &args).await?;
+    let rows: Result<Vec<TriggerScanRow>> = raw_rows.drain(..).map(|r| r.try_into()).collect();
+    let rows = rows?;
+
+    Ok(build_trigger_defs(rows))
+}
+
+/// Returns the approximate row count and on disk size of a table.
+/// Returns None if the table doesn't exist.
+///
+/// Useful to check how big a table is before running an unbatched bulk update on it
+/// NOTE: sqlite doesn't estimate rows, and only reports a size when built with the dbstat virtual table
+pub async fn table_stats(
+    namespace: Option<impl Into<String>>,
+    tablename: impl Into<String>,
+    client: &dyn Client,
+) -> Result<Option<TableStats>> {
+    let syntax = client.syntax();
+    let ts = TableScan::new(syntax);
+    let sql = ts.stats_scan_sql();
+
+    let namespace: Option<String> = namespace
+        .map(|x| x.into())
+        .or(TableIdent::default_namespace(syntax).map(|s| s.to_string()));
+    let tablename: String = tablename.into();
+    let args = single_table_args(syntax, &namespace, &tablename);
+
+    let raw_rows = match client.fetch_rows(sql, &args).await {
+        Ok(rows) => rows,
+        // sqlite without dbstat can't tell us anything
+        Err(_) if syntax == Syntax::Sqlite => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    let row: StatsScanRow = match raw_rows.into_iter().next() {
+        Some(row) => row.try_into()?,
+        None => return Ok(None),
+    };
+
+    Ok(Some(TableStats {
+        ident: TableIdent::new(&tablename, namespace.as_deref()),
+        estimated_rows: row.estimated_rows,
+        size_bytes: row.size_bytes,
+    }))
+}
+
+/// Returns the sequences in the database and the columns that use them
+/// Only Postgres and Mssql have sequences, other databases return an empty list
+pub async fn find_sequences(client: &dyn Client) -> Result<Vec<SequenceDef>> {
+    let ts = TableScan::new(client.syntax());
+    let sql = match ts.sequence_scan_sql() {
+        Some(sql) => sql,
+        None => return Ok(Vec::default()),
+    };
+
+    let args: ParamArgs = Vec::default();
+    let mut raw_rows = client.fetch_rows(sql, &args).await?;
+    let rows: Result<Vec<SequenceScanRow>> = raw_rows.drain(..).map(|r| r.try_into()).collect();
+    let rows = rows?;
+
+    Ok(build_sequence_defs(rows))
+}
+
+/// Returns the stored procedures and functions in the database.
+/// Overloaded routines are returned once for each set of arguments
+/// Sqlite doesn't have stored routines and returns an empty list
+pub async fn find_routines(client: &dyn Client) -> Result<Vec<RoutineDef>> {
+    let ts = TableScan::new(client.syntax());
+    let sql = match ts.routine_scan_sql() {
+        Some(sql) => sql,
+        None => return Ok(Vec::default()),
+    };
+
+    let args: ParamArgs = Vec::default();
+    let mut raw_rows = client.fetch_rows(sql, &args).await?;
+    let rows: Result<Vec<RoutineScanRow>> = raw_rows.drain(..).map(|r| r.try_into()).collect();
+    let rows = rows?;
+
+    Ok(build_routine_defs(rows))
+}
+
+/// Returns the user defined enum types in the database and their labels
+/// Only Postgres has enum types, other databases return an empty list
+pub async fn find_enums(client: &dyn Client) -> Result<Vec<EnumDef>> {
+    let ts = TableScan::new(client.syntax());
+    let sql = match ts.enum_scan_sql() {
+        Some(sql) => sql,
+        None => return Ok(Vec::default()),
+    };
+
+    let args: ParamArgs = Vec::default();
+    let mut raw_rows = client.fetch_rows(sql, &args).await?;
+    let rows: Result<Vec<EnumScanRow>> = raw_rows.drain(..).map(|r| r.try_into()).collect();
+    let rows = rows?;
+
+    Ok(build_enum_defs(rows))
+}
+
+/// The params used to look up a single table
+fn single_table_args<'a>(
+    syntax: Syntax,
+    namespace: &'a Option<String>,
+    tablename: &'a String,
+) -> ParamArgs<'a> {
+    let mut args: ParamArgs = Vec::default();
+    args.push(namespace);
     // Mysql query needs the namespace param twice
     if let Syntax::Mysql = syntax {
-        args.push(&namespace);
+        args.push(namespace);
     }
+    args.push(tablename);
+    args
+}
 
-    args.push(&tablename);
+/// Groups the FK Scan Rows for a single table into ForeignKeyDefs
+/// NOTE: rows are expected to be ordered by constraint and then column position
+fn build_foreign_key_defs(rows: &[&FkScanRow]) -> Vec<ForeignKeyDef> {
+    let mut fks: Vec<ForeignKeyDef> = Vec::default();
+    for row in rows {
+        // sqlite doesn't name its constraints, a new constraint starts at the first column
+        let current = fks
+            .last_mut()
+            .filter(|x| row.column_position > 1 && x.name == row.constraint_name);
+        match current {
+            Some(fk) => {
+                fk.columns.push(row.me.column.clone());
+                fk.other_columns.push(row.other.column.clone());
+            }
+            None => fks.push(ForeignKeyDef {
+                name: row.constraint_name.clone(),
+                columns: vec![row.me.column.clone()],
+                other_table: row.other.ident.clone(),
+                other_columns: vec![row.other.column.clone()],
+                on_delete: fk_action(&row.on_delete),
+                on_update: fk_action(&row.on_update),
+            }),
+        }
+    }
+    fks
+}
 
-    let mut raw_rows = client.fetch_rows(sql, &args).await?;
+/// normalizes the on_delete/on_update actions. mssql returns NO_ACTION
+fn fk_action(action: &str) -> String {
+    action.trim().replace('_', " ").to_uppercase()
+}
 
-    let rows: Result<Vec<TableScanRow>> = raw_rows.drain(..).map(|r| r.try_into()).collect();
-    let rows = rows?;
+/// Groups the Trigger Scan Rows into TriggerDefs
+/// NOTE: rows are expected to be ordered by trigger
+fn build_trigger_defs(rows: Vec<TriggerScanRow>) -> Vec<TriggerDef> {
+    let mut triggers: Vec<TriggerDef> = Vec::default();
+    for row in rows {
+        // sqlite only gives us the SQL of the trigger
+        let (timing, event) = match (row.action_timing, row.event_manipulation) {
+            (Some(timing), Some(event)) => (timing, event),
+            (_, _) => parse_trigger_sql(row.action_statement.as_deref().unwrap_or_default()),
+        };
+        let timing = timing.replace('_', " ").to_uppercase();
+        let event = event.to_uppercase();
+        match triggers.last_mut().filter(|x| x.name == row.trigger_name) {
+            Some(trigger) => trigger.events.push(event),
+            None => triggers.push(TriggerDef {
+                name: row.trigger_name,
+                timing,
+                events: vec![event],
+                body: row.action_statement,
+            }),
+        }
+    }
+    triggers
+}
+
+/// reads the timing and event out of a `CREATE TRIGGER` statement
+fn parse_trigger_sql(sql: &str) -> (String, String) {
+    let words = sql.split_whitespace().map(|w| w.to_uppercase());
+    let words = words.skip_while(|w| w != "TRIGGER");
+    // sqlite triggers are BEFORE unless told otherwise
+    let mut timing = "BEFORE";
+    for word in words.take_while(|w| w != "ON") {
+        match word.as_str() {
+            "BEFORE" => timing = "BEFORE",
+            "AFTER" => timing = "AFTER",
+            "INSTEAD" => timing = "INSTEAD OF",
+            "INSERT" | "UPDATE" | "DELETE" => return (timing.to_string(), word),
+            _ => {}
+        }
+    }
+    (timing.to_string(), String::default())
+}
+
+/// Builds the SequenceDefs. A sequence used by more than one column is only given its first owner
+/// NOTE: rows are expected to be ordered by sequence
+fn build_sequence_defs(rows: Vec<SequenceScanRow>) -> Vec<SequenceDef> {
+    let mut sequences: Vec<SequenceDef> = Vec::default();
+    for row in rows {
+        let ident = row.ident();
+        if sequences
+            .last()
+            .map(|x| x.ident == ident)
+            .unwrap_or_default()
+        {
+            continue;
+        }
+        sequences.push(SequenceDef {
+            ident,
+            current_value: row.current_value,
+            owner: row.owner(),
+            owner_column: row.owner_column,
+        });
+    }
+    sequences
+}
+
+/// Groups the Routine Scan Rows into RoutineDefs
+/// NOTE: rows are expected to be ordered by routine and then argument position
+fn build_routine_defs(rows: Vec<RoutineScanRow>) -> Vec<RoutineDef> {
+    let mut routines: Vec<(String, RoutineDef)> = Vec::default();
+    for row in rows {
+        let current = routines.last_mut().filter(|(id, _)| id == &row.routine_id);
+        let routine = match current {
+            Some((_, routine)) => routine,
+            None => {
+                let routine = RoutineDef {
+                    ident: row.ident(),
+                    kind: row.routine_type.to_uppercase(),
+                    arguments: Vec::default(),
+                    return_type: row.return_type.clone(),
+                };
+                routines.push((row.routine_id.clone(), routine));
+                &mut routines.last_mut().unwrap().1
+            }
+        };
+        if let Some(arg) = row.argument_type {
+            routine.arguments.push(arg);
+        }
+    }
+    routines.drain(..).map(|(_, r)| r).collect()
+}
 
-    let table = build_table_defs(syntax, rows).pop().map(|x| x.into());
+/// Groups the Enum Scan Rows into EnumDefs
+/// NOTE: rows are expected to be ordered by enum and then sort order
+fn build_enum_defs(rows: Vec<EnumScanRow>) -> Vec<EnumDef> {
+    let mut enums: Vec<EnumDef> = Vec::default();
+    for row in rows {
+        let ident = row.ident();
+        match enums.last_mut().filter(|x| x.ident == ident) {
+            Some(def) => def.labels.push(row.label),
+            None => enums.push(EnumDef {
+                ident,
+                labels: vec![row.label],
+            }),
+        }
+    }
+    enums
+}
 
-    Ok(table)
+/// Groups the Index Scan Rows into IndexDefs
+/// NOTE: rows are expected to be ordered by index and then column position
+fn build_index_defs(rows: Vec<IndexScanRow>) -> Vec<IndexDef> {
+    let mut indexes: Vec<IndexDef> = Vec::default();
+    for row in rows {
+        match indexes.iter_mut().find(|x| x.name == row.index_name) {
+            Some(index) => index.columns.push(row.column_name),
+            None => indexes.push(IndexDef {
+                name: row.index_name,
+                columns: vec![row.column_name],
+                unique: row.is_unique > 0,
+                primary_key: row.is_primary_key > 0,
+                method: row.index_method.to_lowercase(),
+            }),
+        }
+    }
+    indexes
 }
 
 fn link_fks_into_tables(fks: &[FkScanRow], tables: &mut [TableDef]) {
@@ -92,6 +596,7 @@ fn link_fks_into_tables(fks: &[FkScanRow], tables: &mut [TableDef]) {
         let ident = table.ident.clone();
         // build the belongs_to
         if let Some(bt) = belongs_to.remove(&ident) {
+            table.foreign_keys = build_foreign_key_defs(&bt);
             bt.iter().for_each(|&x| {
                 let other_table = x.other.ident.clone();
                 let fk = x.me.column.as_str();
@@ -113,6 +618,71 @@ fn link_fks_into_tables(fks: &[FkScanRow], tables: &mut [TableDef]) {
     }
 }
 
+/// Groups the Check Scan Rows into CheckConstraintDefs on their tables
+/// NOTE: rows are expected to be ordered by table and then constraint
+fn link_checks_into_tables(rows: Vec<CheckScanRow>, tables: &mut [TableDef]) {
+    for row in rows {
+        let ident = row.ident();
+        let table = match tables.iter_mut().find(|t| t.ident == ident) {
+            Some(table) => table,
+            None => continue,
+        };
+        let checks = &mut table.check_constraints;
+        let check = match checks.last_mut().filter(|c| c.name == row.constraint_name) {
+            Some(check) => check,
+            None => {
+                checks.push(CheckConstraintDef {
+                    name: row.constraint_name,
+                    expression: row.check_clause,
+                    columns: Vec::default(),
+                });
+                checks.last_mut().unwrap()
+            }
+        };
+        if let Some(column) = row.column_name {
+            check.columns.push(column);
+        }
+    }
+}
+
+/// Adds the PartitionDefs to partitioned tables, and links the partitions back to their table
+/// NOTE: rows are expected to be ordered by table and then partition
+fn link_partitions_into_tables(rows: Vec<PartitionScanRow>, tables: &mut [TableDef]) {
+    for row in rows {
+        let ident = row.ident();
+        if let Some(partition) = row.partition_ident() {
+            if let Some(table) = tables.iter_mut().find(|t| t.ident == partition) {
+                table.partition_of = Some(ident.clone());
+            }
+        }
+        let table = match tables.iter_mut().find(|t| t.ident == ident) {
+            Some(table) => table,
+            None => continue,
+        };
+        let def = table.partitioning.get_or_insert_with(|| PartitionDef {
+            method: row.partition_method.to_uppercase(),
+            key: partition_key(&row.partition_key),
+            partitions: Vec::default(),
+        });
+        if let Some(name) = row.partition_name {
+            def.partitions.push(name);
+        }
+    }
+}
+
+/// Postgres gives back the method with the key. I.E. `RANGE (created_at)`
+fn partition_key(raw: &str) -> String {
+    let raw = raw.trim();
+    match raw.split_once('(') {
+        Some((method, rest))
+            if ["RANGE", "LIST", "HASH"].contains(&method.trim()) && rest.ends_with(')') =>
+        {
+            rest[..rest.len() - 1].trim().to_string()
+        }
+        _ => raw.to_string(),
+    }
+}
+
 /// Groups the Table Scan Rows into TableDefs
 fn build_table_defs(syntax: Syntax, rows: Vec<TableScanRow>) -> Vec<TableDef> {
     //group the rows into vecs for each table
@@ -126,6 +696,7 @@ fn build_table_defs(syntax: Syntax, rows: Vec<TableScanRow>) -> Vec<TableDef> {
     let mut tables = Vec::default();
     for (ident, bucket) in buckets.drain() {
         let ty = bucket[0].kind();
+        let comment = bucket[0].table_comment.clone();
         let columns = build_cols(bucket);
         tables.push(TableDef {
             syntax,
@@ -134,6 +705,11 @@ fn build_table_defs(syntax: Syntax, rows: Vec<TableScanRow>) -> Vec<TableDef> {
             columns,
             has_many: Vec::default(),
             belongs_to: Vec::default(),
+            foreign_keys: Vec::default(),
+            check_constraints: Vec::default(),
+            comment,
+            partitioning: None,
+            partition_of: None,
         });
     }
     tables
@@ -166,8 +742,349 @@ fn build_cols(mut rows: Vec<TableScanRow>) -> Vec<ColumnDef> {
                 ty,
                 null: r.is_nullable > 0,
                 primary_key: r.is_primary_key > 0,
-                updatable: r.is_updatable > 0,
+                primary_key_position: (r.is_primary_key > 0).then_some(r.is_primary_key as usize),
+                updatable: r.is_updatable > 0 && r.is_generated == 0,
+                default: r.column_default,
+                collation: r.collation_name,
+                charset: r.character_set_name,
+                max_length: r.max_length,
+                precision: r.numeric_precision,
+                scale: r.numeric_scale,
+                auto_increment: r.is_auto_increment > 0,
+                generated: r.is_generated > 0,
+                comment: r.column_comment,
             }
         })
+        .map(with_sizes_from_type)
         .collect()
 }
+
+/// Some databases (sqlite) only report sizes as part of the type. I.E. `VARCHAR(50)`
+/// fills in the sizes from the type if the database didn't give them to us
+fn with_sizes_from_type(mut col: ColumnDef) -> ColumnDef {
+    if col.max_length.is_some() || col.precision.is_some() || col.scale.is_some() {
+        return col;
+    }
+    let (base, args) = match col.ty.split_once('(') {
+        Some((base, rest)) => (base.trim(), rest.trim_end_matches(')')),
+        None => return col,
+    };
+    let sizes: Option<Vec<i64>> = args.split(',').map(|x| x.trim().parse().ok()).collect();
+    let is_numeric = base == "NUMERIC" || base == "DECIMAL";
+    match sizes.as_deref() {
+        Some([size]) if is_numeric => col.precision = Some(*size),
+        Some([size]) => col.max_length = Some(*size),
+        Some([precision, scale]) => {
+            col.precision = Some(*precision);
+            col.scale = Some(*scale);
+        }
+        _ => {}
+    }
+    col
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn col(ty: &str) -> ColumnDef {
+        ColumnDef {
+            name: "a".to_string(),
+            ty: ty.to_string(),
+            null: false,
+            primary_key: false,
+            primary_key_position: None,
+            updatable: true,
+            default: None,
+            collation: None,
+            charset: None,
+            max_length: None,
+            precision: None,
+            scale: None,
+            auto_increment: false,
+            generated: false,
+            comment: None,
+        }
+    }
+
+    #[test]
+    fn should_read_sizes_out_of_the_type() {
+        let c = with_sizes_from_type(col("VARCHAR(50)"));
+        assert_eq!(c.max_length(), Some(50));
+        let c = with_sizes_from_type(col("NUMERIC(10, 2)"));
+        assert_eq!((c.precision(), c.scale()), (Some(10), Some(2)));
+        let c = with_sizes_from_type(col("DECIMAL(8)"));
+        assert_eq!((c.max_length(), c.precision()), (None, Some(8)));
+        let c = with_sizes_from_type(col("VARCHAR(MAX)"));
+        assert_eq!(c.max_length(), None);
+    }
+
+    #[test]
+    fn should_match_table_names_by_the_matching_rules() {
+        let found = TableIdent::new("Cars", Some("public"));
+        assert!(matched(
+            IdentMatching::Exact,
+            Some("public"),
+            "Cars",
+            &found
+        ));
+        assert!(!matched(
+            IdentMatching::Exact,
+            Some("public"),
+            "cars",
+            &found
+        ));
+        assert!(!matched(IdentMatching::FoldToLower, None, "Cars", &found));
+        let lower = TableIdent::new("cars", Some("public"));
+        assert!(matched(IdentMatching::FoldToLower, None, "Cars", &lower));
+        assert!(matched(
+            IdentMatching::CaseInsensitive,
+            Some("PUBLIC"),
+            "cARS",
+            &found
+        ));
+        // pg ilike treats _ as a wildcard, it shouldn't match here
+        let other = TableIdent::new("carxs", Some("public"));
+        assert!(!matched(
+            IdentMatching::CaseInsensitive,
+            None,
+            "car_s",
+            &other
+        ));
+    }
+
+    #[test]
+    fn should_know_when_a_column_ignores_case() {
+        let with_collation = |collation: &str| ColumnDef {
+            collation: Some(collation.to_string()),
+            ..col("VARCHAR")
+        };
+        assert!(with_collation("utf8mb4_general_ci").case_insensitive());
+        assert!(with_collation("SQL_Latin1_General_CP1_CI_AS").case_insensitive());
+        assert!(!with_collation("utf8mb4_bin").case_insensitive());
+        assert!(!with_collation("Latin1_General_CS_AS").case_insensitive());
+        assert!(col("CITEXT").case_insensitive());
+        assert!(!col("TEXT").case_insensitive());
+    }
+
+    #[test]
+    fn should_order_primary_keys_by_key_position() {
+        let pk = |name: &str, position: usize| ColumnDef {
+            name: name.to_string(),
+            primary_key: true,
+            primary_key_position: Some(position),
+            ..col("INT")
+        };
+        let columns = vec![pk("a", 2), col("TEXT"), pk("b", 1)];
+        let pks: Vec<&str> = table_def::primary_keys(&columns)
+            .iter()
+            .map(|c| c.name())
+            .collect();
+        assert_eq!(pks, vec!["b", "a"]);
+    }
+
+    fn index_row(index: &str, column: &str, method: &str) -> IndexScanRow {
+        IndexScanRow {
+            index_name: index.to_string(),
+            column_name: column.to_string(),
+            is_unique: 0,
+            is_primary_key: 0,
+            index_method: method.to_string(),
+        }
+    }
+
+    #[test]
+    fn should_group_index_rows_by_index() {
+        let rows = vec![
+            index_row("ix_ab", "a", "BTREE"),
+            index_row("ix_ab", "b", "BTREE"),
+            index_row("ix_tags", "tags", "gin"),
+        ];
+        let indexes = build_index_defs(rows);
+        assert_eq!(indexes.len(), 2);
+        assert_eq!(indexes[0].columns(), &["a".to_string(), "b".to_string()]);
+        assert_eq!(indexes[0].method(), "btree");
+        assert_eq!(indexes[1].method(), "gin");
+    }
+
+    #[test]
+    fn should_add_check_constraints_to_their_table() {
+        let row = |name: &str, column: Option<&str>| CheckScanRow {
+            schema: None,
+            table_name: "cars".to_string(),
+            constraint_name: name.to_string(),
+            check_clause: "(a < b)".to_string(),
+            column_name: column.map(|x| x.to_string()),
+        };
+        let rows = vec![
+            row("ck_ab", Some("a")),
+            row("ck_ab", Some("b")),
+            row("ck_other", None),
+        ];
+        let mut tables = vec![TableDef {
+            ident: TableIdent::new("cars", None::<String>),
+            ty: DataType::Table,
+            columns: Vec::default(),
+            has_many: Vec::default(),
+            belongs_to: Vec::default(),
+            foreign_keys: Vec::default(),
+            check_constraints: Vec::default(),
+            comment: None,
+            partitioning: None,
+            partition_of: None,
+            syntax: Syntax::Sqlite,
+        }];
+        link_checks_into_tables(rows, &mut tables);
+        let checks = tables[0].check_constraints();
+        assert_eq!(checks.len(), 2);
+        assert_eq!(checks[0].columns(), &["a".to_string(), "b".to_string()]);
+        assert_eq!(checks[0].expression(), "(a < b)");
+        assert!(checks[1].columns().is_empty());
+    }
+
+    #[test]
+    fn should_read_timing_and_event_from_trigger_sql() {
+        let parse = |sql: &str| {
+            let (timing, event) = parse_trigger_sql(sql);
+            format!("{} {}", timing, event)
+        };
+        let sql = "CREATE TRIGGER log_after AFTER UPDATE OF name ON cars BEGIN SELECT 1; END";
+        assert_eq!(parse(sql), "AFTER UPDATE");
+        let sql = "create trigger if not exists t1 insert on cars begin select 1; end";
+        assert_eq!(parse(sql), "BEFORE INSERT");
+        let sql = "CREATE TRIGGER t2 INSTEAD OF DELETE ON car_view BEGIN SELECT 1; END";
+        assert_eq!(parse(sql), "INSTEAD OF DELETE");
+    }
+
+    #[test]
+    fn should_group_trigger_rows_by_trigger() {
+        let row = |name: &str, event: &str| TriggerScanRow {
+            trigger_name: name.to_string(),
+            action_timing: Some("BEFORE".to_string()),
+            event_manipulation: Some(event.to_string()),
+            action_statement: Some("EXECUTE FUNCTION touch()".to_string()),
+        };
+        let rows = vec![
+            row("touch", "INSERT"),
+            row("touch", "UPDATE"),
+            row("audit", "delete"),
+        ];
+        let triggers = build_trigger_defs(rows);
+        assert_eq!(triggers.len(), 2);
+        assert_eq!(
+            triggers[0].events(),
+            &["INSERT".to_string(), "UPDATE".to_string()]
+        );
+        assert_eq!(triggers[1].events(), &["DELETE".to_string()]);
+        assert_eq!(triggers[1].timing(), "BEFORE");
+    }
+
+    #[test]
+    fn should_keep_the_first_owner_of_a_sequence() {
+        let row = |name: &str, table: Option<&str>| SequenceScanRow {
+            schema: Some("public".to_string()),
+            name: name.to_string(),
+            current_value: Some(7),
+            owner_schema: table.map(|_| "public".to_string()),
+            owner_table: table.map(|x| x.to_string()),
+            owner_column: table.map(|_| "id".to_string()),
+        };
+        let rows = vec![
+            row("cars_id_seq", Some("cars")),
+            row("cars_id_seq", Some("trucks")),
+            row("tickets", None),
+        ];
+        let sequences = build_sequence_defs(rows);
+        assert_eq!(sequences.len(), 2);
+        assert_eq!(sequences[0].owner().unwrap().to_string(), "public.cars");
+        assert_eq!(sequences[0].owner_column(), Some("id"));
+        assert_eq!(sequences[0].current_value(), Some(7));
+        assert_eq!(sequences[1].owner(), None);
+    }
+
+    #[test]
+    fn should_read_the_key_of_a_partition() {
+        assert_eq!(partition_key("RANGE (created_at)"), "created_at");
+        assert_eq!(partition_key("HASH (a, b)"), "a, b");
+        // mysql only gives us the expression
+        assert_eq!(partition_key("year(created_at)"), "year(created_at)");
+        assert_eq!(partition_key("`id`"), "`id`");
+    }
+
+    #[test]
+    fn should_keep_overloaded_routines_apart() {
+        let row = |id: &str, arg: Option<&str>| RoutineScanRow {
+            schema: Some("public".to_string()),
+            name: "add".to_string(),
+            routine_type: "function".to_string(),
+            return_type: Some("integer".to_string()),
+            routine_id: id.to_string(),
+            argument_type: arg.map(|x| x.to_string()),
+        };
+        let rows = vec![
+            row("1", Some("integer")),
+            row("1", Some("integer")),
+            row("2", Some("numeric(10,2)")),
+            row("3", None),
+        ];
+        let routines = build_routine_defs(rows);
+        assert_eq!(routines.len(), 3);
+        assert_eq!(routines[0].arguments(), &["integer", "integer"]);
+        assert_eq!(routines[0].kind(), "FUNCTION");
+        assert_eq!(routines[1].arguments(), &["numeric(10,2)"]);
+        assert!(routines[2].arguments().is_empty());
+    }
+
+    #[test]
+    fn should_group_enum_rows_by_type() {
+        let row = |name: &str, label: &str| EnumScanRow {
+            schema: Some("public".to_string()),
+            name: name.to_string(),
+            label: label.to_string(),
+        };
+        let rows = vec![row("mood", "sad"), row("mood", "ok"), row("size", "big")];
+        let enums = build_enum_defs(rows);
+        assert_eq!(enums.len(), 2);
+        assert_eq!(enums[0].ident().to_string(), "public.mood");
+        assert_eq!(enums[0].labels(), &["sad".to_string(), "ok".to_string()]);
+        assert_eq!(enums[1].labels(), &["big".to_string()]);
+    }
+
+    fn fk_row(name: Option<&str>, position: i64, column: &str, other: &str) -> FkScanRow {
+        FkScanRow {
+            me: FkScanTableCol::new(None, "cars".to_string(), column.to_string()),
+            other: FkScanTableCol::new(None, "owners".to_string(), other.to_string()),
+            constraint_name: name.map(|x| x.to_string()),
+            column_position: position,
+            on_delete: "SET_NULL".to_string(),
+            on_update: "no action".to_string(),
+        }
+    }
+
+    #[test]
+    fn should_group_fk_rows_by_constraint() {
+        let rows = [
+            fk_row(None, 1, "owner_a", "a"),
+            fk_row(None, 2, "owner_b", "b"),
+            fk_row(None, 1, "owner_id", "id"),
+        ];
+        let rows: Vec<&FkScanRow> = rows.iter().collect();
+        let fks = build_foreign_key_defs(&rows);
+        assert_eq!(fks.len(), 2);
+        assert_eq!(
+            fks[0].columns(),
+            &["owner_a".to_string(), "owner_b".to_string()]
+        );
+        assert_eq!(fks[0].other_columns(), &["a".to_string(), "b".to_string()]);
+        assert_eq!(fks[0].on_delete(), "SET NULL");
+        assert_eq!(fks[0].on_update(), "NO ACTION");
+        assert_eq!(fks[1].columns(), &["owner_id".to_string()]);
+    }
+
+    #[test]
+    fn should_keep_sizes_the_database_reported() {
+        let mut c = col("VARCHAR(50)");
+        c.max_length = Some(10);
+        assert_eq!(with_sizes_from_type(c).max_length(), Some(10));
+    }
+}
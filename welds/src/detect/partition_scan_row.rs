@@ -0,0 +1,41 @@
+use crate::model_traits::TableIdent;
+use crate::Row;
+
+pub struct PartitionScanRow {
+    pub(super) schema: Option<String>,
+    pub(super) table_name: String,
+    pub(super) partition_method: String,
+    pub(super) partition_key: String,
+    pub(super) partition_schema: Option<String>,
+    pub(super) partition_name: Option<String>,
+}
+
+impl TryFrom<Row> for PartitionScanRow {
+    type Error = crate::WeldsError;
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        Ok(PartitionScanRow {
+            schema: row.get_by_position(0)?,
+            table_name: row.get_by_position(1)?,
+            partition_method: row.get_by_position(2)?,
+            partition_key: row.get_by_position(3)?,
+            partition_schema: row.get_by_position(4)?,
+            partition_name: row.get_by_position(5)?,
+        })
+    }
+}
+
+impl PartitionScanRow {
+    pub fn ident(&self) -> TableIdent {
+        TableIdent {
+            schema: self.schema.clone(),
+            name: self.table_name.clone(),
+        }
+    }
+    /// The partition as its own table. Only Postgres partitions are tables
+    pub fn partition_ident(&self) -> Option<TableIdent> {
+        Some(TableIdent {
+            schema: Some(self.partition_schema.clone()?),
+            name: self.partition_name.clone()?,
+        })
+    }
+}
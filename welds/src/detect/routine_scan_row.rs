@@ -0,0 +1,34 @@
+use crate::model_traits::TableIdent;
+use crate::Row;
+
+pub struct RoutineScanRow {
+    pub(super) schema: Option<String>,
+    pub(super) name: String,
+    pub(super) routine_type: String,
+    pub(super) return_type: Option<String>,
+    pub(super) routine_id: String,
+    pub(super) argument_type: Option<String>,
+}
+
+impl TryFrom<Row> for RoutineScanRow {
+    type Error = crate::WeldsError;
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        Ok(RoutineScanRow {
+            schema: row.get_by_position(0)?,
+            name: row.get_by_position(1)?,
+            routine_type: row.get_by_position(2)?,
+            return_type: row.get_by_position(3)?,
+            routine_id: row.get_by_position(4)?,
+            argument_type: row.get_by_position(5)?,
+        })
+    }
+}
+
+impl RoutineScanRow {
+    pub fn ident(&self) -> TableIdent {
+        TableIdent {
+            schema: self.schema.clone(),
+            name: self.name.clone(),
+        }
+    }
+}
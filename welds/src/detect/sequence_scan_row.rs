@@ -0,0 +1,42 @@
+use crate::model_traits::TableIdent;
+use crate::Row;
+
+pub struct SequenceScanRow {
+    pub(super) schema: Option<String>,
+    pub(super) name: String,
+    pub(super) current_value: Option<i64>,
+    pub(super) owner_schema: Option<String>,
+    pub(super) owner_table: Option<String>,
+    pub(super) owner_column: Option<String>,
+}
+
+impl TryFrom<Row> for SequenceScanRow {
+    type Error = crate::WeldsError;
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        Ok(SequenceScanRow {
+            schema: row.get_by_position(0)?,
+            name: row.get_by_position(1)?,
+            current_value: row.get_by_position(2)?,
+            owner_schema: row.get_by_position(3)?,
+            owner_table: row.get_by_position(4)?,
+            owner_column: row.get_by_position(5)?,
+        })
+    }
+}
+
+impl SequenceScanRow {
+    pub fn ident(&self) -> TableIdent {
+        TableIdent {
+            schema: self.schema.clone(),
+            name: self.name.clone(),
+        }
+    }
+    /// The table that owns this sequence, if one does
+    pub fn owner(&self) -> Option<TableIdent> {
+        let name = self.owner_table.clone()?;
+        Some(TableIdent {
+            schema: self.owner_schema.clone(),
+            name,
+        })
+    }
+}
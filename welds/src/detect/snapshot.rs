@@ -0,0 +1,252 @@
+use super::{EnumDef, IdentMatching, IndexDef, RoutineDef, SequenceDef, TableDef, TriggerDef};
+use crate::errors::Result;
+use crate::model_traits::TableIdent;
+use crate::Client;
+use crate::Syntax;
+
+/// A point in time copy of what `detect` found in a database.
+/// Can be saved to a file and used by check later without a connection to the database
+///
+/// Tables are kept sorted by their identifier so snapshots of the same schema
+/// produce the same output and can be committed and diffed across environments
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchemaSnapshot {
+    pub(crate) syntax: Syntax,
+    pub(crate) tables: Vec<TableSnapshot>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) enums: Vec<EnumDef>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) sequences: Vec<SequenceDef>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) routines: Vec<RoutineDef>,
+}
+
+/// A single table in a `SchemaSnapshot`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableSnapshot {
+    pub(crate) table: TableDef,
+    pub(crate) indexes: Vec<IndexDef>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) triggers: Vec<TriggerDef>,
+}
+
+impl TableSnapshot {
+    pub fn table(&self) -> &TableDef {
+        &self.table
+    }
+    pub fn indexes(&self) -> &[IndexDef] {
+        &self.indexes
+    }
+    pub fn triggers(&self) -> &[TriggerDef] {
+        &self.triggers
+    }
+}
+
+impl SchemaSnapshot {
+    /// Builds a snapshot from the tables and indexes found in the database
+    pub fn new(syntax: Syntax, mut tables: Vec<TableSnapshot>) -> Self {
+        tables.sort_by_key(|t| t.table.ident().to_string());
+        Self {
+            syntax,
+            tables,
+            enums: Vec::default(),
+            sequences: Vec::default(),
+            routines: Vec::default(),
+        }
+    }
+
+    /// the syntax of the database the snapshot was taken from
+    pub fn syntax(&self) -> Syntax {
+        self.syntax
+    }
+
+    /// All the tables in the snapshot
+    pub fn tables(&self) -> &[TableSnapshot] {
+        &self.tables
+    }
+
+    /// All the user defined enum types in the snapshot
+    pub fn enums(&self) -> &[EnumDef] {
+        &self.enums
+    }
+
+    /// All the sequences in the snapshot
+    pub fn sequences(&self) -> &[SequenceDef] {
+        &self.sequences
+    }
+
+    /// All the stored procedures and functions in the snapshot
+    pub fn routines(&self) -> &[RoutineDef] {
+        &self.routines
+    }
+
+    /// Returns the table that matches an identifier.
+    /// a missing schema will match a table in any schema
+    pub fn find(&self, ident: &TableIdent) -> Option<&TableSnapshot> {
+        self.find_with(ident, IdentMatching::Exact)
+    }
+
+    /// Same as `find` but lets you pick how the names are matched.
+    /// When more than one table matches, the one with the exact same name is preferred
+    pub fn find_with(&self, ident: &TableIdent, matching: IdentMatching) -> Option<&TableSnapshot> {
+        let matched = |t: &&TableSnapshot| {
+            let found = t.table.ident();
+            let schemas_match = match (ident.schema(), found.schema()) {
+                (Some(x), Some(y)) => matching.matches(x, y),
+                _ => true,
+            };
+            schemas_match && matching.matches(ident.name(), found.name())
+        };
+        let mut found = self.tables.iter().filter(matched);
+        let first = found.next()?;
+        if first.table.ident().name() == ident.name() {
+            return Some(first);
+        }
+        found
+            .find(|t| t.table.ident().name() == ident.name())
+            .or(Some(first))
+    }
+
+    /// Writes this snapshot out as JSON
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String> {
+        let json = serde_json::to_string_pretty(self).map_err(anyhow::Error::from)?;
+        Ok(json)
+    }
+
+    /// Reads in a snapshot that was written with `to_json`
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<SchemaSnapshot> {
+        let snapshot = serde_json::from_str(json).map_err(anyhow::Error::from)?;
+        Ok(snapshot)
+    }
+
+    /// Writes this snapshot to a JSON file
+    #[cfg(feature = "serde")]
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> Result<()> {
+        std::fs::write(path, self.to_json()?).map_err(anyhow::Error::from)?;
+        Ok(())
+    }
+
+    /// Reads in a snapshot file that was written with `save`
+    #[cfg(feature = "serde")]
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<SchemaSnapshot> {
+        let json = std::fs::read_to_string(path).map_err(anyhow::Error::from)?;
+        Self::from_json(&json)
+    }
+}
+
+/// Takes a snapshot of all the user defined tables in the database
+/// along with their indexes and triggers, and the enums, sequences and routines
+pub async fn snapshot(client: &dyn Client) -> Result<SchemaSnapshot> {
+    let mut tables = Vec::default();
+    for table in super::find_tables(client).await? {
+        let ident = table.ident();
+        let indexes = super::find_indexes(ident.schema(), ident.name(), client).await?;
+        let triggers = super::find_triggers(ident.schema(), ident.name(), client).await?;
+        tables.push(TableSnapshot {
+            table,
+            indexes,
+            triggers,
+        });
+    }
+    let mut snapshot = SchemaSnapshot::new(client.syntax(), tables);
+    snapshot.enums = super::find_enums(client).await?;
+    snapshot.sequences = super::find_sequences(client).await?;
+    snapshot.routines = super::find_routines(client).await?;
+    Ok(snapshot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::{ColumnDef, DataType};
+
+    fn sample() -> SchemaSnapshot {
+        let table = TableDef {
+            ident: TableIdent::new("cars", Some("public")),
+            ty: DataType::Table,
+            columns: vec![ColumnDef {
+                name: "id".to_string(),
+                ty: "int4".to_string(),
+                null: false,
+                primary_key: true,
+                primary_key_position: Some(1),
+                updatable: true,
+                default: None,
+                collation: None,
+                charset: None,
+                max_length: None,
+                precision: None,
+                scale: None,
+                auto_increment: false,
+                generated: false,
+                comment: None,
+            }],
+            has_many: Vec::default(),
+            belongs_to: Vec::default(),
+            foreign_keys: Vec::default(),
+            check_constraints: Vec::default(),
+            comment: None,
+            partitioning: None,
+            partition_of: None,
+            syntax: Syntax::Postgres,
+        };
+        let indexes = vec![IndexDef {
+            name: "cars_pkey".to_string(),
+            columns: vec!["id".to_string()],
+            unique: true,
+            primary_key: true,
+            method: "btree".to_string(),
+        }];
+        let table = TableSnapshot {
+            table,
+            indexes,
+            triggers: Vec::default(),
+        };
+        SchemaSnapshot::new(Syntax::Postgres, vec![table])
+    }
+
+    #[test]
+    fn should_find_table_without_schema() {
+        let snapshot = sample();
+        assert!(snapshot.find(&TableIdent::parse("cars")).is_some());
+        assert!(snapshot.find(&TableIdent::parse("public.cars")).is_some());
+        assert!(snapshot.find(&TableIdent::parse("other.cars")).is_none());
+    }
+
+    #[test]
+    fn should_find_table_ignoring_case_when_asked() {
+        let snapshot = sample();
+        let ident = TableIdent::parse("public.CARS");
+        assert!(snapshot.find(&ident).is_none());
+        assert!(snapshot
+            .find_with(&ident, IdentMatching::CaseInsensitive)
+            .is_some());
+    }
+
+    #[test]
+    fn should_keep_tables_sorted_by_ident() {
+        let mut snapshot = sample();
+        let mut other = snapshot.tables[0].clone();
+        other.table.ident = TableIdent::new("bikes", Some("public"));
+        let tables = vec![snapshot.tables.remove(0), other];
+        let snapshot = SchemaSnapshot::new(Syntax::Postgres, tables);
+        let names: Vec<&str> = snapshot
+            .tables()
+            .iter()
+            .map(|t| t.table().ident().name())
+            .collect();
+        assert_eq!(names, vec!["bikes", "cars"]);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn should_round_trip_through_json() {
+        let snapshot = sample();
+        let json = snapshot.to_json().unwrap();
+        assert_eq!(SchemaSnapshot::from_json(&json).unwrap(), snapshot);
+    }
+}
@@ -0,0 +1,16 @@
+use crate::Row;
+
+pub struct StatsScanRow {
+    pub(super) estimated_rows: Option<i64>,
+    pub(super) size_bytes: Option<i64>,
+}
+
+impl TryFrom<Row> for StatsScanRow {
+    type Error = crate::WeldsError;
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        Ok(StatsScanRow {
+            estimated_rows: row.get_by_position(0)?,
+            size_bytes: row.get_by_position(1)?,
+        })
+    }
+}
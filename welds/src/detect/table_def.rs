@@ -2,7 +2,7 @@ use crate::model_traits::TableIdent;
 use crate::Syntax;
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Describes what is known about a table and its relationships
 pub struct TableDef {
     pub(crate) ident: TableIdent,
@@ -10,6 +10,11 @@ pub struct TableDef {
     pub(crate) columns: Vec<ColumnDef>, // What are the columns on this table
     pub(crate) has_many: Vec<RelationDef>,
     pub(crate) belongs_to: Vec<RelationDef>,
+    pub(crate) foreign_keys: Vec<ForeignKeyDef>,
+    pub(crate) check_constraints: Vec<CheckConstraintDef>,
+    pub(crate) comment: Option<String>,
+    pub(crate) partitioning: Option<PartitionDef>,
+    pub(crate) partition_of: Option<TableIdent>,
     pub(crate) syntax: Syntax,
 }
 
@@ -23,23 +28,47 @@ impl TableDef {
     pub fn columns(&self) -> &[ColumnDef] {
         &self.columns
     }
+    /// the columns of the primary key, in the order they are in the key
+    pub fn primary_keys(&self) -> Vec<&ColumnDef> {
+        primary_keys(&self.columns)
+    }
     pub fn has_many(&self) -> &[RelationDef] {
         &self.has_many
     }
     pub fn belongs_to(&self) -> &[RelationDef] {
         &self.belongs_to
     }
+    pub fn foreign_keys(&self) -> &[ForeignKeyDef] {
+        &self.foreign_keys
+    }
+    pub fn check_constraints(&self) -> &[CheckConstraintDef] {
+        &self.check_constraints
+    }
+    /// the comment on the table. I.E. `COMMENT ON TABLE`
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+    /// How this table is partitioned. None if the table isn't partitioned
+    pub fn partitioning(&self) -> Option<&PartitionDef> {
+        self.partitioning.as_ref()
+    }
+    /// The partitioned table this table is a partition of. Only Postgres partitions are tables
+    pub fn partition_of(&self) -> Option<&TableIdent> {
+        self.partition_of.as_ref()
+    }
     pub fn syntax(&self) -> Syntax {
         self.syntax
     }
 }
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Describes what is known about a table
 pub struct TableDefSingle {
     pub(crate) ident: TableIdent,
     pub(crate) ty: DataType,
     pub(crate) columns: Vec<ColumnDef>, // What are the columns on this table
+    pub(crate) comment: Option<String>,
     pub(crate) syntax: Syntax,
 }
 
@@ -49,6 +78,7 @@ impl From<TableDef> for TableDefSingle {
             ident: t.ident,
             ty: t.ty,
             columns: t.columns,
+            comment: t.comment,
             syntax: t.syntax,
         }
     }
@@ -64,18 +94,44 @@ impl TableDefSingle {
     pub fn columns(&self) -> &[ColumnDef] {
         &self.columns
     }
+    /// the columns of the primary key, in the order they are in the key
+    pub fn primary_keys(&self) -> Vec<&ColumnDef> {
+        primary_keys(&self.columns)
+    }
+    /// the comment on the table. I.E. `COMMENT ON TABLE`
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
     pub fn syntax(&self) -> Syntax {
         self.syntax
     }
 }
 
+/// the primary key columns, in the order they are in the key
+pub(crate) fn primary_keys(columns: &[ColumnDef]) -> Vec<&ColumnDef> {
+    let mut pks: Vec<&ColumnDef> = columns.iter().filter(|c| c.primary_key).collect();
+    pks.sort_by_key(|c| c.primary_key_position.unwrap_or(usize::MAX));
+    pks
+}
+
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColumnDef {
     pub(crate) name: String,
     pub(crate) ty: String,
     pub(crate) null: bool,
     pub(crate) primary_key: bool,
+    pub(crate) primary_key_position: Option<usize>,
     pub(crate) updatable: bool,
+    pub(crate) default: Option<String>,
+    pub(crate) collation: Option<String>,
+    pub(crate) charset: Option<String>,
+    pub(crate) max_length: Option<i64>,
+    pub(crate) precision: Option<i64>,
+    pub(crate) scale: Option<i64>,
+    pub(crate) auto_increment: bool,
+    pub(crate) generated: bool,
+    pub(crate) comment: Option<String>,
 }
 
 impl ColumnDef {
@@ -98,11 +154,78 @@ impl ColumnDef {
     pub fn primary_key(&self) -> bool {
         self.primary_key
     }
+
+    /// returns the position (starting at 1) of this column in the primary key.
+    /// None if the column isn't part of the primary key
+    pub fn primary_key_position(&self) -> Option<usize> {
+        self.primary_key_position
+    }
+
     /// returns true is this column can be written to. I.E. not readonly
     pub fn updatable(&self) -> bool {
         self.updatable
     }
 
+    /// returns the default value/expression of this column as the database reports it
+    pub fn default(&self) -> Option<&str> {
+        self.default.as_deref()
+    }
+
+    /// returns the collation of this column, if the database reports one
+    pub fn collation(&self) -> Option<&str> {
+        self.collation.as_deref()
+    }
+
+    /// returns true if comparisons on this column ignore case.
+    /// I.E. a `_ci` collation in Mysql/Mssql, or a Postgres `CITEXT` column
+    pub fn case_insensitive(&self) -> bool {
+        if self.ty.eq_ignore_ascii_case("citext") {
+            return true;
+        }
+        let collation = match &self.collation {
+            Some(x) => x.to_lowercase(),
+            None => return false,
+        };
+        collation.ends_with("_ci") || collation.contains("_ci_")
+    }
+
+    /// returns the character set of this column, if the database reports one
+    pub fn charset(&self) -> Option<&str> {
+        self.charset.as_deref()
+    }
+
+    /// returns the max length of this column. I.E. the 50 in `VARCHAR(50)`
+    pub fn max_length(&self) -> Option<i64> {
+        self.max_length
+    }
+
+    /// returns the precision of this column. I.E. the 10 in `NUMERIC(10,2)`
+    pub fn precision(&self) -> Option<i64> {
+        self.precision
+    }
+
+    /// returns the scale of this column. I.E. the 2 in `NUMERIC(10,2)`
+    pub fn scale(&self) -> Option<i64> {
+        self.scale
+    }
+
+    /// returns true if the database generates the value of this column on insert.
+    /// I.E. SERIAL, IDENTITY, AUTO_INCREMENT
+    pub fn auto_increment(&self) -> bool {
+        self.auto_increment
+    }
+
+    /// returns true if the database computes the value of this column from other columns.
+    /// I.E. `GENERATED ALWAYS AS (...)`. These columns can't be written to
+    pub fn generated(&self) -> bool {
+        self.generated
+    }
+
+    /// returns the comment on this column. I.E. `COMMENT ON COLUMN`
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
     /// returns a model_traits::Column. This can be used for queries.
     pub fn as_query_column(&self, syntax: Syntax) -> Option<crate::model_traits::Column> {
         let db_type = self.ty();
@@ -116,13 +239,68 @@ impl ColumnDef {
     }
 }
 
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Describes an index that exists on a table
+pub struct IndexDef {
+    pub(crate) name: String,
+    pub(crate) columns: Vec<String>,
+    pub(crate) unique: bool,
+    pub(crate) primary_key: bool,
+    pub(crate) method: String,
+}
+
+impl IndexDef {
+    /// returns the name of the index in the database
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// returns the columns (in order) covered by this index
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+    /// returns true if this index enforces uniqueness
+    pub fn unique(&self) -> bool {
+        self.unique
+    }
+    /// returns true if this is the index backing the primary key
+    pub fn primary_key(&self) -> bool {
+        self.primary_key
+    }
+    /// returns the access method of the index. I.E. `btree`, `gin`, `hash`
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+}
+
+/// The name and kind of a table without any of its columns. Returned from `list_tables`
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableSummary {
+    pub(crate) ident: TableIdent,
+    pub(crate) ty: DataType,
+}
+
+impl TableSummary {
+    pub fn ident(&self) -> &TableIdent {
+        &self.ident
+    }
+    pub fn ty(&self) -> DataType {
+        self.ty
+    }
+}
+
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DataType {
     Table,
     View,
+    /// A Postgres view whose rows are stored and updated with `REFRESH MATERIALIZED VIEW`
+    MaterializedView,
 }
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RelationDef {
     other_table: TableIdent,
     foreign_key: String,
@@ -152,6 +330,225 @@ impl RelationDef {
     }
 }
 
+/// A foreign key constraint on a table in the database
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ForeignKeyDef {
+    pub(crate) name: Option<String>,
+    pub(crate) columns: Vec<String>,
+    pub(crate) other_table: TableIdent,
+    pub(crate) other_columns: Vec<String>,
+    pub(crate) on_delete: String,
+    pub(crate) on_update: String,
+}
+
+impl ForeignKeyDef {
+    /// returns the name of the constraint. Sqlite doesn't name its foreign keys
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+    /// returns the columns (in order) on this table
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+    /// returns the table this foreign key points to
+    pub fn other_table(&self) -> &TableIdent {
+        &self.other_table
+    }
+    /// returns the columns (in order) on the other table
+    pub fn other_columns(&self) -> &[String] {
+        &self.other_columns
+    }
+    /// returns what happens when the other row is deleted. I.E. `CASCADE`, `SET NULL`, `NO ACTION`
+    pub fn on_delete(&self) -> &str {
+        &self.on_delete
+    }
+    /// returns what happens when the other row's key is updated. I.E. `CASCADE`, `NO ACTION`
+    pub fn on_update(&self) -> &str {
+        &self.on_update
+    }
+}
+
+/// How a partitioned table splits up its rows
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PartitionDef {
+    pub(crate) method: String,
+    pub(crate) key: String,
+    pub(crate) partitions: Vec<String>,
+}
+
+impl PartitionDef {
+    /// returns how rows are assigned to partitions. I.E. `RANGE`, `LIST`, `HASH`
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+    /// returns the columns/expression rows are partitioned by. I.E. `created_at`
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+    /// returns the names of the partitions
+    pub fn partitions(&self) -> &[String] {
+        &self.partitions
+    }
+}
+
+/// A check constraint on a table in the database
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CheckConstraintDef {
+    pub(crate) name: String,
+    pub(crate) expression: String,
+    pub(crate) columns: Vec<String>,
+}
+
+impl CheckConstraintDef {
+    /// returns the name of the constraint in the database
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// returns the expression of the constraint as the database reports it. I.E. `(price > 0)`
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+    /// returns the columns used by the constraint.
+    /// NOTE: Mysql doesn't report these, and Mssql only reports it for column level constraints
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+}
+
+/// A trigger attached to a table in the database
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TriggerDef {
+    pub(crate) name: String,
+    pub(crate) timing: String,
+    pub(crate) events: Vec<String>,
+    pub(crate) body: Option<String>,
+}
+
+impl TriggerDef {
+    /// returns the name of the trigger in the database
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// returns when the trigger fires. I.E. `BEFORE`, `AFTER`, `INSTEAD OF`
+    pub fn timing(&self) -> &str {
+        &self.timing
+    }
+    /// returns the events that fire the trigger. I.E. `INSERT`, `UPDATE`, `DELETE`
+    pub fn events(&self) -> &[String] {
+        &self.events
+    }
+    /// returns what the trigger runs as the database reports it.
+    /// Postgres reports the function call, other databases the trigger's SQL
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+}
+
+/// A sequence in the database. I.E. what backs a SERIAL column
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SequenceDef {
+    pub(crate) ident: TableIdent,
+    pub(crate) current_value: Option<i64>,
+    pub(crate) owner: Option<TableIdent>,
+    pub(crate) owner_column: Option<String>,
+}
+
+impl SequenceDef {
+    /// returns the schema and name of the sequence
+    pub fn ident(&self) -> &TableIdent {
+        &self.ident
+    }
+    /// returns the last value handed out by the sequence. None if it hasn't been used yet
+    pub fn current_value(&self) -> Option<i64> {
+        self.current_value
+    }
+    /// returns the table of the column that uses this sequence, if there is one
+    pub fn owner(&self) -> Option<&TableIdent> {
+        self.owner.as_ref()
+    }
+    /// returns the column that uses this sequence, if there is one
+    pub fn owner_column(&self) -> Option<&str> {
+        self.owner_column.as_deref()
+    }
+}
+
+/// Approximate size information about a table.
+/// These numbers come from the database's statistics and can be out of date
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableStats {
+    pub(crate) ident: TableIdent,
+    pub(crate) estimated_rows: Option<i64>,
+    pub(crate) size_bytes: Option<i64>,
+}
+
+impl TableStats {
+    pub fn ident(&self) -> &TableIdent {
+        &self.ident
+    }
+    /// the approximate number of rows in the table. None if the database doesn't have an estimate
+    pub fn estimated_rows(&self) -> Option<i64> {
+        self.estimated_rows
+    }
+    /// the space the table and its indexes take on disk, in bytes
+    pub fn size_bytes(&self) -> Option<i64> {
+        self.size_bytes
+    }
+}
+
+/// A stored procedure or function in the database
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoutineDef {
+    pub(crate) ident: TableIdent,
+    pub(crate) kind: String,
+    pub(crate) arguments: Vec<String>,
+    pub(crate) return_type: Option<String>,
+}
+
+impl RoutineDef {
+    /// returns the schema and name of the routine
+    pub fn ident(&self) -> &TableIdent {
+        &self.ident
+    }
+    /// returns `FUNCTION` or `PROCEDURE`
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+    /// returns the database types of the arguments (in order)
+    pub fn arguments(&self) -> &[String] {
+        &self.arguments
+    }
+    /// returns the database type the routine returns. None for procedures
+    pub fn return_type(&self) -> Option<&str> {
+        self.return_type.as_deref()
+    }
+}
+
+/// A user defined enum type in the database. I.E. `CREATE TYPE mood AS ENUM ('sad', 'ok')`
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnumDef {
+    pub(crate) ident: TableIdent,
+    pub(crate) labels: Vec<String>,
+}
+
+impl EnumDef {
+    /// returns the schema and name of the enum type
+    pub fn ident(&self) -> &TableIdent {
+        &self.ident
+    }
+    /// returns the allowed values (in sort order) of the enum type
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+}
+
 #[cfg(feature = "mock")]
 /// This module allows you to mock TableDef
 /// useful while testing
@@ -168,7 +565,17 @@ pub mod mock {
                 ty,
                 null: false,
                 primary_key: false,
+                primary_key_position: None,
                 updatable: true,
+                default: None,
+                collation: None,
+                charset: None,
+                max_length: None,
+                precision: None,
+                scale: None,
+                auto_increment: false,
+                generated: false,
+                comment: None,
             })
         }
 
@@ -179,6 +586,7 @@ pub mod mock {
 
         pub fn primary_key(mut self) -> Self {
             self.0.primary_key = true;
+            self.0.primary_key_position = Some(1);
             self
         }
         pub fn readonly(mut self) -> Self {
@@ -186,6 +594,48 @@ pub mod mock {
             self
         }
 
+        pub fn auto_increment(mut self) -> Self {
+            self.0.auto_increment = true;
+            self
+        }
+
+        pub fn generated(mut self) -> Self {
+            self.0.generated = true;
+            self.0.updatable = false;
+            self
+        }
+
+        pub fn comment(mut self, comment: impl Into<String>) -> Self {
+            self.0.comment = Some(comment.into());
+            self
+        }
+
+        pub fn default(mut self, default: impl Into<String>) -> Self {
+            self.0.default = Some(default.into());
+            self
+        }
+
+        pub fn collation(mut self, collation: impl Into<String>) -> Self {
+            self.0.collation = Some(collation.into());
+            self
+        }
+
+        pub fn charset(mut self, charset: impl Into<String>) -> Self {
+            self.0.charset = Some(charset.into());
+            self
+        }
+
+        pub fn max_length(mut self, max_length: i64) -> Self {
+            self.0.max_length = Some(max_length);
+            self
+        }
+
+        pub fn precision(mut self, precision: i64, scale: i64) -> Self {
+            self.0.precision = Some(precision);
+            self.0.scale = Some(scale);
+            self
+        }
+
         pub fn build(self) -> ColumnDef {
             self.0
         }
@@ -204,6 +654,11 @@ pub mod mock {
                 columns: Vec::default(),
                 has_many: Vec::default(),
                 belongs_to: Vec::default(),
+                foreign_keys: Vec::default(),
+                check_constraints: Vec::default(),
+                comment: None,
+                partitioning: None,
+                partition_of: None,
             })
         }
 
@@ -217,13 +672,25 @@ pub mod mock {
             self
         }
 
+        /// adds a column to the primary key. Call more than once for a composite key
         pub fn with_pk(mut self, name: impl Into<String>, ty: impl Into<String>) -> Self {
+            let position = self.0.columns.iter().filter(|c| c.primary_key).count() + 1;
             self.0.columns.push(ColumnDef {
                 name: name.into(),
                 ty: ty.into(),
                 null: false,
                 primary_key: true,
+                primary_key_position: Some(position),
                 updatable: true,
+                default: None,
+                collation: None,
+                charset: None,
+                max_length: None,
+                precision: None,
+                scale: None,
+                auto_increment: false,
+                generated: false,
+                comment: None,
             });
             self
         }
@@ -234,7 +701,17 @@ pub mod mock {
                 ty: ty.into(),
                 null: false,
                 primary_key: false,
+                primary_key_position: None,
                 updatable: true,
+                default: None,
+                collation: None,
+                charset: None,
+                max_length: None,
+                precision: None,
+                scale: None,
+                auto_increment: false,
+                generated: false,
+                comment: None,
             });
             self
         }
@@ -249,7 +726,17 @@ pub mod mock {
                 ty: ty.into(),
                 null: true,
                 primary_key: false,
+                primary_key_position: None,
                 updatable: true,
+                default: None,
+                collation: None,
+                charset: None,
+                max_length: None,
+                precision: None,
+                scale: None,
+                auto_increment: false,
+                generated: false,
+                comment: None,
             });
             self
         }
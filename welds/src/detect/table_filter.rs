@@ -0,0 +1,37 @@
+use super::DataType;
+
+/// Narrows down which tables are returned from `list_tables`
+///
+/// ```
+/// use welds::detect::{DataType, TableFilter};
+/// let filter = TableFilter {
+///     name_like: Some("car%".to_string()),
+///     kind: Some(DataType::Table),
+///     limit: Some(100),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TableFilter {
+    /// Only tables in this schema. None is every schema
+    pub namespace: Option<String>,
+    /// A SQL `LIKE` pattern the name of the table must match. I.E. `car%`
+    pub name_like: Option<String>,
+    /// Only tables or only views. None is both
+    pub kind: Option<DataType>,
+    /// The max number of tables to return
+    pub limit: Option<usize>,
+    /// The number of tables to skip over
+    pub offset: usize,
+}
+
+impl TableFilter {
+    /// the value used for kind in the list SQL
+    pub(crate) fn kind_str(&self) -> Option<String> {
+        self.kind.map(|k| match k {
+            DataType::Table => "table".to_string(),
+            DataType::View => "view".to_string(),
+            DataType::MaterializedView => "materialized_view".to_string(),
+        })
+    }
+}
@@ -4,6 +4,15 @@ pub(crate) struct TableScan {
     table_scan_sql: fn() -> &'static str,
     single_table_scan_sql: fn() -> &'static str,
     fk_scan_sql: fn() -> &'static str,
+    index_scan_sql: fn() -> &'static str,
+    enum_scan_sql: fn() -> Option<&'static str>,
+    check_scan_sql: fn() -> Option<&'static str>,
+    trigger_scan_sql: fn() -> &'static str,
+    sequence_scan_sql: fn() -> Option<&'static str>,
+    list_scan_sql: fn() -> &'static str,
+    partition_scan_sql: fn() -> Option<&'static str>,
+    routine_scan_sql: fn() -> Option<&'static str>,
+    stats_scan_sql: fn() -> &'static str,
 }
 
 impl TableScan {
@@ -13,21 +22,57 @@ impl TableScan {
                 table_scan_sql: MySql::table_scan_sql,
                 single_table_scan_sql: MySql::single_table_scan_sql,
                 fk_scan_sql: MySql::fk_scan_sql,
+                index_scan_sql: MySql::index_scan_sql,
+                enum_scan_sql: MySql::enum_scan_sql,
+                check_scan_sql: MySql::check_scan_sql,
+                trigger_scan_sql: MySql::trigger_scan_sql,
+                sequence_scan_sql: MySql::sequence_scan_sql,
+                list_scan_sql: MySql::list_scan_sql,
+                partition_scan_sql: MySql::partition_scan_sql,
+                routine_scan_sql: MySql::routine_scan_sql,
+                stats_scan_sql: MySql::stats_scan_sql,
             },
             Syntax::Postgres => TableScan {
                 table_scan_sql: Postgres::table_scan_sql,
                 single_table_scan_sql: Postgres::single_table_scan_sql,
                 fk_scan_sql: Postgres::fk_scan_sql,
+                index_scan_sql: Postgres::index_scan_sql,
+                enum_scan_sql: Postgres::enum_scan_sql,
+                check_scan_sql: Postgres::check_scan_sql,
+                trigger_scan_sql: Postgres::trigger_scan_sql,
+                sequence_scan_sql: Postgres::sequence_scan_sql,
+                list_scan_sql: Postgres::list_scan_sql,
+                partition_scan_sql: Postgres::partition_scan_sql,
+                routine_scan_sql: Postgres::routine_scan_sql,
+                stats_scan_sql: Postgres::stats_scan_sql,
             },
             Syntax::Sqlite => TableScan {
                 table_scan_sql: Sqlite::table_scan_sql,
                 single_table_scan_sql: Sqlite::single_table_scan_sql,
                 fk_scan_sql: Sqlite::fk_scan_sql,
+                index_scan_sql: Sqlite::index_scan_sql,
+                enum_scan_sql: Sqlite::enum_scan_sql,
+                check_scan_sql: Sqlite::check_scan_sql,
+                trigger_scan_sql: Sqlite::trigger_scan_sql,
+                sequence_scan_sql: Sqlite::sequence_scan_sql,
+                list_scan_sql: Sqlite::list_scan_sql,
+                partition_scan_sql: Sqlite::partition_scan_sql,
+                routine_scan_sql: Sqlite::routine_scan_sql,
+                stats_scan_sql: Sqlite::stats_scan_sql,
             },
             Syntax::Mssql => TableScan {
                 table_scan_sql: Mssql::table_scan_sql,
                 single_table_scan_sql: Mssql::single_table_scan_sql,
                 fk_scan_sql: Mssql::fk_scan_sql,
+                index_scan_sql: Mssql::index_scan_sql,
+                enum_scan_sql: Mssql::enum_scan_sql,
+                check_scan_sql: Mssql::check_scan_sql,
+                trigger_scan_sql: Mssql::trigger_scan_sql,
+                sequence_scan_sql: Mssql::sequence_scan_sql,
+                list_scan_sql: Mssql::list_scan_sql,
+                partition_scan_sql: Mssql::partition_scan_sql,
+                routine_scan_sql: Mssql::routine_scan_sql,
+                stats_scan_sql: Mssql::stats_scan_sql,
             },
         }
     }
@@ -43,6 +88,47 @@ impl TableScan {
     pub(crate) fn fk_scan_sql(&self) -> &'static str {
         (self.fk_scan_sql)()
     }
+
+    pub(crate) fn index_scan_sql(&self) -> &'static str {
+        (self.index_scan_sql)()
+    }
+
+    /// None if the database doesn't have enum types
+    pub(crate) fn enum_scan_sql(&self) -> Option<&'static str> {
+        (self.enum_scan_sql)()
+    }
+
+    /// None if the check constraints can't be read from the database
+    pub(crate) fn check_scan_sql(&self) -> Option<&'static str> {
+        (self.check_scan_sql)()
+    }
+
+    pub(crate) fn trigger_scan_sql(&self) -> &'static str {
+        (self.trigger_scan_sql)()
+    }
+
+    /// None if the database doesn't have sequences
+    pub(crate) fn sequence_scan_sql(&self) -> Option<&'static str> {
+        (self.sequence_scan_sql)()
+    }
+
+    pub(crate) fn list_scan_sql(&self) -> &'static str {
+        (self.list_scan_sql)()
+    }
+
+    /// None if the database doesn't support partitioned tables
+    pub(crate) fn partition_scan_sql(&self) -> Option<&'static str> {
+        (self.partition_scan_sql)()
+    }
+
+    /// None if the database doesn't have stored routines
+    pub(crate) fn routine_scan_sql(&self) -> Option<&'static str> {
+        (self.routine_scan_sql)()
+    }
+
+    pub(crate) fn stats_scan_sql(&self) -> &'static str {
+        (self.stats_scan_sql)()
+    }
 }
 
 struct Postgres;
@@ -56,6 +142,33 @@ impl Postgres {
     fn fk_scan_sql() -> &'static str {
         include_str!("./postgres_fk.sql")
     }
+    fn index_scan_sql() -> &'static str {
+        include_str!("./postgres_index.sql")
+    }
+    fn enum_scan_sql() -> Option<&'static str> {
+        Some(include_str!("./postgres_enum.sql"))
+    }
+    fn check_scan_sql() -> Option<&'static str> {
+        Some(include_str!("./postgres_check.sql"))
+    }
+    fn trigger_scan_sql() -> &'static str {
+        include_str!("./postgres_trigger.sql")
+    }
+    fn sequence_scan_sql() -> Option<&'static str> {
+        Some(include_str!("./postgres_sequence.sql"))
+    }
+    fn list_scan_sql() -> &'static str {
+        include_str!("./postgres_list.sql")
+    }
+    fn partition_scan_sql() -> Option<&'static str> {
+        Some(include_str!("./postgres_partition.sql"))
+    }
+    fn routine_scan_sql() -> Option<&'static str> {
+        Some(include_str!("./postgres_routine.sql"))
+    }
+    fn stats_scan_sql() -> &'static str {
+        include_str!("./postgres_stats.sql")
+    }
 }
 
 struct MySql;
@@ -69,6 +182,33 @@ impl MySql {
     fn fk_scan_sql() -> &'static str {
         include_str!("./mysql_fk.sql")
     }
+    fn index_scan_sql() -> &'static str {
+        include_str!("./mysql_index.sql")
+    }
+    fn enum_scan_sql() -> Option<&'static str> {
+        None
+    }
+    fn check_scan_sql() -> Option<&'static str> {
+        Some(include_str!("./mysql_check.sql"))
+    }
+    fn trigger_scan_sql() -> &'static str {
+        include_str!("./mysql_trigger.sql")
+    }
+    fn sequence_scan_sql() -> Option<&'static str> {
+        None
+    }
+    fn list_scan_sql() -> &'static str {
+        include_str!("./mysql_list.sql")
+    }
+    fn partition_scan_sql() -> Option<&'static str> {
+        Some(include_str!("./mysql_partition.sql"))
+    }
+    fn routine_scan_sql() -> Option<&'static str> {
+        Some(include_str!("./mysql_routine.sql"))
+    }
+    fn stats_scan_sql() -> &'static str {
+        include_str!("./mysql_stats.sql")
+    }
 }
 
 struct Mssql;
@@ -82,6 +222,33 @@ impl Mssql {
     fn fk_scan_sql() -> &'static str {
         include_str!("./mssql_fk.sql")
     }
+    fn index_scan_sql() -> &'static str {
+        include_str!("./mssql_index.sql")
+    }
+    fn enum_scan_sql() -> Option<&'static str> {
+        None
+    }
+    fn check_scan_sql() -> Option<&'static str> {
+        Some(include_str!("./mssql_check.sql"))
+    }
+    fn trigger_scan_sql() -> &'static str {
+        include_str!("./mssql_trigger.sql")
+    }
+    fn sequence_scan_sql() -> Option<&'static str> {
+        Some(include_str!("./mssql_sequence.sql"))
+    }
+    fn list_scan_sql() -> &'static str {
+        include_str!("./mssql_list.sql")
+    }
+    fn partition_scan_sql() -> Option<&'static str> {
+        None
+    }
+    fn routine_scan_sql() -> Option<&'static str> {
+        Some(include_str!("./mssql_routine.sql"))
+    }
+    fn stats_scan_sql() -> &'static str {
+        include_str!("./mssql_stats.sql")
+    }
 }
 
 struct Sqlite;
@@ -95,4 +262,31 @@ impl Sqlite {
     fn fk_scan_sql() -> &'static str {
         include_str!("./sqlite_fk.sql")
     }
+    fn index_scan_sql() -> &'static str {
+        include_str!("./sqlite_index.sql")
+    }
+    fn enum_scan_sql() -> Option<&'static str> {
+        None
+    }
+    fn check_scan_sql() -> Option<&'static str> {
+        None
+    }
+    fn trigger_scan_sql() -> &'static str {
+        include_str!("./sqlite_trigger.sql")
+    }
+    fn sequence_scan_sql() -> Option<&'static str> {
+        None
+    }
+    fn list_scan_sql() -> &'static str {
+        include_str!("./sqlite_list.sql")
+    }
+    fn partition_scan_sql() -> Option<&'static str> {
+        None
+    }
+    fn routine_scan_sql() -> Option<&'static str> {
+        None
+    }
+    fn stats_scan_sql() -> &'static str {
+        include_str!("./sqlite_stats.sql")
+    }
 }
@@ -5,16 +5,30 @@ SELECT
     c.name column_name,
     t.Name column_type,
     CAST(c.is_nullable as INT) as is_nullable,
-    CAST(ISNULL(i.is_primary_key, 0) as INT) as is_primary_key,
-    CAST(1 as INT) as is_updatable
+    CAST(ISNULL((
+        SELECT ic.key_ordinal FROM sys.index_columns ic
+        INNER JOIN sys.indexes i ON ic.object_id = i.object_id AND ic.index_id = i.index_id
+        WHERE i.is_primary_key = 1 AND ic.object_id = c.object_id AND ic.column_id = c.column_id
+    ), 0) as INT) as is_primary_key,
+    CAST(1 as INT) as is_updatable,
+    OBJECT_DEFINITION(c.default_object_id) as column_default,
+    CAST(c.collation_name as NVARCHAR(128)) as collation_name,
+    CAST(COLLATIONPROPERTY(c.collation_name, 'CodePage') as NVARCHAR(128)) as character_set_name,
+    CAST(CASE
+        WHEN c.max_length = -1 THEN NULL
+        WHEN t.name IN ('nvarchar', 'nchar') THEN c.max_length / 2
+        WHEN t.name IN ('varchar', 'char', 'varbinary', 'binary') THEN c.max_length
+    END as BIGINT) as max_length,
+    CAST(CASE WHEN t.name IN ('decimal', 'numeric') THEN c.precision END as BIGINT) as numeric_precision,
+    CAST(CASE WHEN t.name IN ('decimal', 'numeric') THEN c.scale END as BIGINT) as numeric_scale,
+    CAST(c.is_identity as INT) as is_auto_increment,
+    CAST((SELECT ep.value FROM sys.extended_properties ep WHERE ep.class = 1 AND ep.major_id = c.object_id AND ep.minor_id = c.column_id AND ep.name = 'MS_Description') as NVARCHAR(MAX)) as column_comment,
+    CAST((SELECT ep.value FROM sys.extended_properties ep WHERE ep.class = 1 AND ep.major_id = c.object_id AND ep.minor_id = 0 AND ep.name = 'MS_Description') as NVARCHAR(MAX)) as table_comment,
+    CAST(c.is_computed as INT) as is_generated
 FROM    
     sys.columns c
 INNER JOIN 
     sys.types t ON c.user_type_id = t.user_type_id
-LEFT OUTER JOIN 
-    sys.index_columns ic ON ic.object_id = c.object_id AND ic.column_id = c.column_id
-LEFT OUTER JOIN 
-    sys.indexes i ON ic.object_id = i.object_id AND ic.index_id = i.index_id
 JOIN (
 SELECT table_schema, table_name, 'table' as ty FROM INFORMATION_SCHEMA.TABLES WHERE TABLE_NAME NOT in (select name from sys.objects where type = 'U' and is_ms_shipped = 1) AND TABLE_TYPE='BASE TABLE'
 UNION
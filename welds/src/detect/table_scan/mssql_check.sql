@@ -0,0 +1,10 @@
+SELECT
+    schema_name(t.schema_id) as table_schema,
+    t.name as table_name,
+    cc.name as constraint_name,
+    cc.definition as check_clause,
+    col.name as column_name
+FROM sys.check_constraints cc
+JOIN sys.tables t ON t.object_id = cc.parent_object_id
+LEFT JOIN sys.columns col ON col.object_id = cc.parent_object_id AND col.column_id = cc.parent_column_id
+ORDER BY schema_name(t.schema_id), t.name, cc.name
@@ -0,0 +1,16 @@
+SELECT 
+    i.name as index_name,
+    c.name as column_name,
+    CAST(i.is_unique as INT) as is_unique,
+    CAST(i.is_primary_key as INT) as is_primary_key,
+    LOWER(i.type_desc) as index_method
+FROM
+    sys.indexes i
+INNER JOIN 
+    sys.index_columns ic ON ic.object_id = i.object_id AND ic.index_id = i.index_id
+INNER JOIN 
+    sys.columns c ON c.object_id = ic.object_id AND c.column_id = ic.column_id
+
+WHERE i.object_id = OBJECT_ID(CONCAT(@p1, '.', @p2)) AND i.name IS NOT NULL AND ic.is_included_column = 0
+
+ORDER BY i.name, ic.key_ordinal
@@ -0,0 +1,12 @@
+SELECT tv.table_schema, tv.table_name, tv.ty
+FROM (
+  SELECT table_schema, table_name,
+    CASE WHEN TABLE_TYPE = 'VIEW' THEN 'view' ELSE 'table' END as ty
+  FROM INFORMATION_SCHEMA.TABLES
+  WHERE TABLE_TYPE IN ('BASE TABLE', 'VIEW')
+  AND TABLE_NAME NOT IN (select name from sys.objects where is_ms_shipped = 1)
+) tv
+WHERE (@p1 IS NULL OR tv.table_schema = @p1)
+  AND (@p2 IS NULL OR tv.table_name LIKE @p2)
+  AND (@p3 IS NULL OR tv.ty = @p3)
+ORDER BY tv.table_schema, tv.table_name
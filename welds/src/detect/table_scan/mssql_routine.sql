@@ -0,0 +1,14 @@
+SELECT
+    schema_name(o.schema_id) as routine_schema,
+    o.name as routine_name,
+    CASE WHEN o.type = 'P' THEN 'PROCEDURE' ELSE 'FUNCTION' END as routine_type,
+    CASE
+      WHEN o.type = 'FN' THEN (SELECT TYPE_NAME(r.user_type_id) FROM sys.parameters r WHERE r.object_id = o.object_id AND r.parameter_id = 0)
+      WHEN o.type IN ('IF', 'TF') THEN 'TABLE'
+    END as return_type,
+    CAST(o.object_id as NVARCHAR(20)) as routine_id,
+    TYPE_NAME(pa.user_type_id) as argument_type
+FROM sys.objects o
+LEFT JOIN sys.parameters pa ON pa.object_id = o.object_id AND pa.parameter_id > 0
+WHERE o.type IN ('P', 'FN', 'IF', 'TF') AND o.is_ms_shipped = 0
+ORDER BY schema_name(o.schema_id), o.name, o.object_id, pa.parameter_id
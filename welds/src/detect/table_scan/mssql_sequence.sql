@@ -0,0 +1,13 @@
+SELECT
+    schema_name(s.schema_id) as sequence_schema,
+    s.name as sequence_name,
+    CAST(s.current_value as BIGINT) as current_value,
+    schema_name(t.schema_id) as owner_schema,
+    t.name as owner_table,
+    col.name as owner_column
+FROM sys.sequences s
+LEFT JOIN sys.sql_expression_dependencies dep ON dep.referenced_id = s.object_id
+LEFT JOIN sys.default_constraints dc ON dc.object_id = dep.referencing_id
+LEFT JOIN sys.tables t ON t.object_id = dc.parent_object_id
+LEFT JOIN sys.columns col ON col.object_id = dc.parent_object_id AND col.column_id = dc.parent_column_id
+ORDER BY schema_name(s.schema_id), s.name
@@ -0,0 +1,6 @@
+SELECT
+    CAST(SUM(CASE WHEN ps.index_id IN (0, 1) THEN ps.row_count ELSE 0 END) as BIGINT) as estimated_rows,
+    CAST(SUM(ps.reserved_page_count) * 8192 as BIGINT) as size_bytes
+FROM sys.dm_db_partition_stats ps
+WHERE ps.object_id = OBJECT_ID(CONCAT(@p1, '.', @p2))
+GROUP BY ps.object_id
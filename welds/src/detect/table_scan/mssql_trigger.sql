@@ -0,0 +1,9 @@
+SELECT
+    t.name as trigger_name,
+    CASE WHEN t.is_instead_of_trigger = 1 THEN 'INSTEAD OF' ELSE 'AFTER' END as action_timing,
+    te.type_desc as event_manipulation,
+    OBJECT_DEFINITION(t.object_id) as action_statement
+FROM sys.triggers t
+JOIN sys.trigger_events te ON te.object_id = t.object_id
+WHERE t.parent_id = OBJECT_ID(CONCAT(@p1, '.', @p2))
+ORDER BY t.name, te.type_desc
@@ -0,0 +1,11 @@
+SELECT
+    cast( tc.TABLE_SCHEMA as CHAR ) as TABLE_SCHEMA,
+    cast( tc.TABLE_NAME as CHAR ) as TABLE_NAME,
+    cast( cc.CONSTRAINT_NAME as CHAR ) as CONSTRAINT_NAME,
+    cast( cc.CHECK_CLAUSE as CHAR ) as CHECK_CLAUSE,
+    cast( NULL as CHAR ) as COLUMN_NAME
+FROM INFORMATION_SCHEMA.TABLE_CONSTRAINTS tc
+JOIN INFORMATION_SCHEMA.CHECK_CONSTRAINTS cc
+ON cc.CONSTRAINT_SCHEMA = tc.CONSTRAINT_SCHEMA AND cc.CONSTRAINT_NAME = tc.CONSTRAINT_NAME
+WHERE tc.CONSTRAINT_TYPE = 'CHECK'
+ORDER BY tc.TABLE_SCHEMA, tc.TABLE_NAME, cc.CONSTRAINT_NAME
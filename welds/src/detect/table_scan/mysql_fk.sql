@@ -4,8 +4,14 @@ SELECT
     cast( info.COLUMN_NAME as CHAR ) as COLUMN_NAME,
     cast( info.REFERENCED_TABLE_SCHEMA as CHAR ) as REFERENCED_TABLE_SCHEMA,
     cast( info.REFERENCED_TABLE_NAME as CHAR ) as REFERENCED_TABLE_NAME,
-    cast( info.REFERENCED_COLUMN_NAME as CHAR ) as REFERENCED_COLUMN_NAME
+    cast( info.REFERENCED_COLUMN_NAME as CHAR ) as REFERENCED_COLUMN_NAME,
+    cast( info.CONSTRAINT_NAME as CHAR ) as CONSTRAINT_NAME,
+    cast( info.ORDINAL_POSITION as SIGNED INTEGER ) as COLUMN_POSITION,
+    cast( rc.DELETE_RULE as CHAR ) as ON_DELETE,
+    cast( rc.UPDATE_RULE as CHAR ) as ON_UPDATE
 FROM INFORMATION_SCHEMA.KEY_COLUMN_USAGE info
 JOIN INFORMATION_SCHEMA.TABLE_CONSTRAINTS cons
 ON cons.CONSTRAINT_NAME = info.CONSTRAINT_NAME AND cons.CONSTRAINT_SCHEMA = info.CONSTRAINT_SCHEMA AND cons.CONSTRAINT_TYPE = 'FOREIGN KEY'
-
+JOIN INFORMATION_SCHEMA.REFERENTIAL_CONSTRAINTS rc
+ON rc.CONSTRAINT_NAME = info.CONSTRAINT_NAME AND rc.CONSTRAINT_SCHEMA = info.CONSTRAINT_SCHEMA AND rc.TABLE_NAME = info.TABLE_NAME
+ORDER BY info.TABLE_SCHEMA, info.TABLE_NAME, info.CONSTRAINT_NAME, info.ORDINAL_POSITION
@@ -0,0 +1,9 @@
+SELECT
+cast(s.index_name as CHAR) as index_name,
+cast(s.column_name as CHAR) as column_name,
+case when s.non_unique = 0 then 1 else 0 end as is_unique,
+case when s.index_name = 'PRIMARY' then 1 else 0 end as is_primary_key,
+cast(lower(s.index_type) as CHAR) as index_method
+FROM information_schema.statistics s
+WHERE (s.table_schema = ? OR (? is null AND s.table_schema = DATABASE()) ) and s.table_name = ?
+ORDER BY s.index_name, s.seq_in_index
@@ -0,0 +1,14 @@
+SELECT tv.table_schema, tv.table_name, tv.ty
+FROM (
+  SELECT
+    cast(table_schema as CHAR) as table_schema,
+    cast(table_name as CHAR) as table_name,
+    case when TABLE_TYPE = 'VIEW' then 'view' else 'table' end as ty
+  FROM information_schema.tables
+  WHERE TABLE_TYPE IN ('BASE TABLE', 'VIEW')
+  AND table_schema NOT IN ('mysql', 'sys', 'performance_schema', 'information_schema')
+) tv
+WHERE (? is null OR tv.table_schema = ?)
+  AND (? is null OR tv.table_name like ?)
+  AND (? is null OR tv.ty = ?)
+ORDER BY tv.table_schema, tv.table_name
@@ -0,0 +1,10 @@
+SELECT
+    cast( p.TABLE_SCHEMA as CHAR ) as TABLE_SCHEMA,
+    cast( p.TABLE_NAME as CHAR ) as TABLE_NAME,
+    cast( p.PARTITION_METHOD as CHAR ) as PARTITION_METHOD,
+    cast( p.PARTITION_EXPRESSION as CHAR ) as PARTITION_KEY,
+    cast( NULL as CHAR ) as PARTITION_SCHEMA,
+    cast( p.PARTITION_NAME as CHAR ) as PARTITION_NAME
+FROM INFORMATION_SCHEMA.PARTITIONS p
+WHERE p.PARTITION_NAME IS NOT NULL
+ORDER BY p.TABLE_SCHEMA, p.TABLE_NAME, p.PARTITION_ORDINAL_POSITION
@@ -0,0 +1,12 @@
+SELECT
+    cast( r.ROUTINE_SCHEMA as CHAR ) as ROUTINE_SCHEMA,
+    cast( r.ROUTINE_NAME as CHAR ) as ROUTINE_NAME,
+    cast( r.ROUTINE_TYPE as CHAR ) as ROUTINE_TYPE,
+    cast( case when r.ROUTINE_TYPE = 'FUNCTION' then r.DTD_IDENTIFIER end as CHAR ) as RETURN_TYPE,
+    cast( r.SPECIFIC_NAME as CHAR ) as ROUTINE_ID,
+    cast( pa.DTD_IDENTIFIER as CHAR ) as ARGUMENT_TYPE
+FROM INFORMATION_SCHEMA.ROUTINES r
+LEFT JOIN INFORMATION_SCHEMA.PARAMETERS pa
+ON pa.SPECIFIC_SCHEMA = r.ROUTINE_SCHEMA AND pa.SPECIFIC_NAME = r.SPECIFIC_NAME AND pa.ORDINAL_POSITION > 0
+WHERE r.ROUTINE_SCHEMA NOT IN ('mysql', 'sys', 'performance_schema', 'information_schema')
+ORDER BY r.ROUTINE_SCHEMA, r.ROUTINE_NAME, r.SPECIFIC_NAME, pa.ORDINAL_POSITION
@@ -5,11 +5,23 @@ cast(tv.ty as CHAR ) as ty,
 cast(col.column_name as CHAR ) as column_name,
 cast(col.data_type as CHAR ) as data_type,
 cast(col.is_nullable='YES' AS SIGNED INTEGER) as is_nullable,
-case when column_key= 'PRI' then 1 else 0 end as is_primary_key,
-1 as is_updatable
+cast(coalesce((select k.ordinal_position from information_schema.key_column_usage k
+  where k.constraint_name = 'PRIMARY' and k.table_schema = col.table_schema
+  and k.table_name = col.table_name and k.column_name = col.column_name), 0) as SIGNED INTEGER) as is_primary_key,
+1 as is_updatable,
+cast(col.column_default as CHAR ) as column_default,
+cast(col.collation_name as CHAR ) as collation_name,
+cast(col.character_set_name as CHAR ) as character_set_name,
+cast(col.character_maximum_length AS SIGNED INTEGER) as max_length,
+case when col.data_type in ('decimal', 'numeric') then cast(col.numeric_precision AS SIGNED INTEGER) end as numeric_precision,
+case when col.data_type in ('decimal', 'numeric') then cast(col.numeric_scale AS SIGNED INTEGER) end as numeric_scale,
+case when col.extra like '%auto_increment%' then 1 else 0 end as is_auto_increment,
+cast(nullif(col.column_comment, '') as CHAR ) as column_comment,
+cast(nullif(tv.table_comment, '') as CHAR ) as table_comment,
+case when coalesce(col.generation_expression, '') != '' then 1 else 0 end as is_generated
 FROM (
 select 
-table_name, table_schema, 'table' as ty 
+table_name, table_schema, 'table' as ty, table_comment
 from information_schema.tables 
 where TABLE_TYPE like 'BASE TABLE'
 AND table_schema != 'sys' AND table_schema != 'performance_schema' AND table_schema != 'information_schema' 
@@ -52,13 +64,13 @@ AND ( concat(table_schema, '::', table_name) != 'mysql::time_zone_transition' )
 AND ( concat(table_schema, '::', table_name) != 'mysql::time_zone_transition_type' )
 AND ( concat(table_schema, '::', table_name) != 'mysql::user' ) 
 UNION
-select table_name, table_schema, 'view' as ty  from information_schema.tables 
+select table_name, table_schema, 'view' as ty, null as table_comment from information_schema.tables 
 where TABLE_TYPE like 'VIEW'
 AND table_schema != 'sys' AND table_schema != 'performance_schema' AND table_schema != 'information_schema' 
 ) tv
-JOIN INFORMATION_SCHEMA.COLUMNS col on col.table_name = tv.table_name AND col.table_schema = col.table_schema
+JOIN INFORMATION_SCHEMA.COLUMNS col on col.table_name = tv.table_name AND col.table_schema = tv.table_schema
 
-WHERE (tv.table_schema = ? OR (? is null AND tv.table_schema = DATABASE()) ) and tv.table_name = ?
+WHERE (tv.table_schema = ? OR (? is null AND tv.table_schema = DATABASE()) ) and lower(tv.table_name) = lower(?)
 
 ORDER BY tv.table_schema, tv.table_name, is_primary_key desc, col.column_name
 
@@ -0,0 +1,5 @@
+SELECT
+    cast( t.TABLE_ROWS as SIGNED INTEGER ) as ESTIMATED_ROWS,
+    cast( t.DATA_LENGTH + t.INDEX_LENGTH as SIGNED INTEGER ) as SIZE_BYTES
+FROM INFORMATION_SCHEMA.TABLES t
+WHERE (t.TABLE_SCHEMA = ? OR (? is null AND t.TABLE_SCHEMA = DATABASE()) ) and t.TABLE_NAME = ? and t.TABLE_TYPE = 'BASE TABLE'
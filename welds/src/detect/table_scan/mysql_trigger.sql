@@ -0,0 +1,8 @@
+SELECT
+    cast( t.TRIGGER_NAME as CHAR ) as TRIGGER_NAME,
+    cast( t.ACTION_TIMING as CHAR ) as ACTION_TIMING,
+    cast( t.EVENT_MANIPULATION as CHAR ) as EVENT_MANIPULATION,
+    cast( t.ACTION_STATEMENT as CHAR ) as ACTION_STATEMENT
+FROM INFORMATION_SCHEMA.TRIGGERS t
+WHERE (t.EVENT_OBJECT_SCHEMA = ? OR (? is null AND t.EVENT_OBJECT_SCHEMA = DATABASE()) ) and t.EVENT_OBJECT_TABLE = ?
+ORDER BY t.TRIGGER_NAME, t.EVENT_MANIPULATION
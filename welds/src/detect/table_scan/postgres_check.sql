@@ -0,0 +1,14 @@
+SELECT
+    n.nspname::text as table_schema,
+    t.relname::text as table_name,
+    c.conname::text as constraint_name,
+    pg_get_expr(c.conbin, c.conrelid)::text as check_clause,
+    a.attname::text as column_name
+FROM
+    pg_catalog.pg_constraint c
+    JOIN pg_catalog.pg_class t ON t.oid = c.conrelid
+    JOIN pg_catalog.pg_namespace n ON n.oid = t.relnamespace
+    LEFT JOIN LATERAL unnest(c.conkey) WITH ORDINALITY AS k(attnum, ord) ON true
+    LEFT JOIN pg_catalog.pg_attribute a ON a.attrelid = t.oid AND a.attnum = k.attnum
+WHERE c.contype = 'c' AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+ORDER BY n.nspname, t.relname, c.conname, k.ord
@@ -0,0 +1,10 @@
+SELECT
+    n.nspname::text as enum_schema,
+    t.typname::text as enum_name,
+    e.enumlabel::text as enum_label
+FROM
+    pg_catalog.pg_type t
+    JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
+    JOIN pg_catalog.pg_enum e ON e.enumtypid = t.oid
+WHERE n.nspname NOT IN ('pg_catalog', 'information_schema')
+ORDER BY n.nspname, t.typname, e.enumsortorder
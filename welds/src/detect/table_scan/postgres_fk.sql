@@ -1,16 +1,22 @@
 SELECT
-    tc.table_schema, 
-    tc.table_name, 
-    kcu.column_name, 
-    ccu.table_schema AS foreign_table_schema,
-    ccu.table_name AS foreign_table_name,
-    ccu.column_name AS foreign_column_name 
-FROM 
-    information_schema.table_constraints AS tc 
-    JOIN information_schema.key_column_usage AS kcu
-      ON tc.constraint_name = kcu.constraint_name
-      AND tc.table_schema = kcu.table_schema
-    JOIN information_schema.constraint_column_usage AS ccu
-      ON ccu.constraint_name = tc.constraint_name
-      AND ccu.table_schema = tc.table_schema
-WHERE tc.constraint_type = 'FOREIGN KEY'
+    sn.nspname::text as table_schema,
+    st.relname::text as table_name,
+    sa.attname::text as column_name,
+    tn.nspname::text as foreign_table_schema,
+    tt.relname::text as foreign_table_name,
+    ta.attname::text as foreign_column_name,
+    c.conname::text as constraint_name,
+    k.ord::bigint as column_position,
+    case c.confdeltype when 'r' then 'RESTRICT' when 'c' then 'CASCADE' when 'n' then 'SET NULL' when 'd' then 'SET DEFAULT' else 'NO ACTION' end as on_delete,
+    case c.confupdtype when 'r' then 'RESTRICT' when 'c' then 'CASCADE' when 'n' then 'SET NULL' when 'd' then 'SET DEFAULT' else 'NO ACTION' end as on_update
+FROM
+    pg_catalog.pg_constraint c
+    JOIN pg_catalog.pg_class st ON st.oid = c.conrelid
+    JOIN pg_catalog.pg_namespace sn ON sn.oid = st.relnamespace
+    JOIN pg_catalog.pg_class tt ON tt.oid = c.confrelid
+    JOIN pg_catalog.pg_namespace tn ON tn.oid = tt.relnamespace
+    JOIN LATERAL unnest(c.conkey, c.confkey) WITH ORDINALITY AS k(src, dest, ord) ON true
+    JOIN pg_catalog.pg_attribute sa ON sa.attrelid = st.oid AND sa.attnum = k.src
+    JOIN pg_catalog.pg_attribute ta ON ta.attrelid = tt.oid AND ta.attnum = k.dest
+WHERE c.contype = 'f'
+ORDER BY sn.nspname, st.relname, c.conname, k.ord
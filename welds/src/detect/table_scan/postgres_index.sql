@@ -0,0 +1,18 @@
+SELECT
+    i.relname::text as index_name,
+    a.attname::text as column_name,
+    ix.indisunique::int as is_unique,
+    ix.indisprimary::int as is_primary_key,
+    am.amname::text as index_method
+FROM 
+    pg_catalog.pg_class t
+    JOIN pg_catalog.pg_namespace n ON n.oid = t.relnamespace
+    JOIN pg_catalog.pg_index ix ON ix.indrelid = t.oid
+    JOIN pg_catalog.pg_class i ON i.oid = ix.indexrelid
+    JOIN pg_catalog.pg_am am ON am.oid = i.relam
+    JOIN LATERAL unnest(ix.indkey::int2[]) WITH ORDINALITY AS k(attnum, ord) ON true
+    JOIN pg_catalog.pg_attribute a ON a.attrelid = t.oid AND a.attnum = k.attnum
+WHERE n.nspname ilike $1 and t.relname ilike $2
+    -- an expression in the index has no column (attnum 0), those indexes are left out
+    and NOT (0 = ANY(ix.indkey::int2[]))
+ORDER BY i.relname, k.ord
@@ -0,0 +1,22 @@
+SELECT things.schemaname, things.tablename, things.ty
+FROM (
+    SELECT table_schema::text as schemaname, table_name::text as tablename,
+      case when table_type = 'VIEW' then 'view' else 'table' end as ty
+    FROM information_schema.tables
+      WHERE table_type IN ('BASE TABLE', 'VIEW')
+      AND table_schema != 'pg_catalog'
+      AND table_schema != 'information_schema'
+      AND table_schema != '_timescaledb_catalog'
+      AND table_schema != '_timescaledb_cache'
+      AND table_schema != '_timescaledb_config'
+      AND table_schema != '_timescaledb_internal'
+      AND table_schema != 'timescaledb_information'
+      AND table_schema != 'timescaledb_experimental'
+    UNION ALL
+    SELECT schemaname::text as schemaname, matviewname::text as tablename, 'materialized_view' as ty
+    FROM pg_catalog.pg_matviews
+) things
+WHERE ($1::text is null OR things.schemaname ilike $1)
+  AND ($2::text is null OR things.tablename ilike $2)
+  AND ($3::text is null OR things.ty = $3)
+ORDER BY things.schemaname, things.tablename
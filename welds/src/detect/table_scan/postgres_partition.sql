@@ -0,0 +1,15 @@
+SELECT
+    pn.nspname::text as table_schema,
+    p.relname::text as table_name,
+    case pt.partstrat when 'r' then 'RANGE' when 'l' then 'LIST' when 'h' then 'HASH' end as partition_method,
+    pg_get_partkeydef(p.oid)::text as partition_key,
+    cn.nspname::text as partition_schema,
+    c.relname::text as partition_name
+FROM
+    pg_catalog.pg_partitioned_table pt
+    JOIN pg_catalog.pg_class p ON p.oid = pt.partrelid
+    JOIN pg_catalog.pg_namespace pn ON pn.oid = p.relnamespace
+    LEFT JOIN pg_catalog.pg_inherits i ON i.inhparent = p.oid
+    LEFT JOIN pg_catalog.pg_class c ON c.oid = i.inhrelid
+    LEFT JOIN pg_catalog.pg_namespace cn ON cn.oid = c.relnamespace
+ORDER BY pn.nspname, p.relname, c.relname
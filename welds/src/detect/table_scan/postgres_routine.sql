@@ -0,0 +1,13 @@
+SELECT
+    n.nspname::text as routine_schema,
+    p.proname::text as routine_name,
+    case p.prokind when 'p' then 'PROCEDURE' else 'FUNCTION' end as routine_type,
+    case when p.prokind = 'p' then null else pg_get_function_result(p.oid)::text end as return_type,
+    p.oid::text as routine_id,
+    format_type(arg.oid, NULL)::text as argument_type
+FROM
+    pg_catalog.pg_proc p
+    JOIN pg_catalog.pg_namespace n ON n.oid = p.pronamespace
+    LEFT JOIN LATERAL unnest(p.proargtypes::oid[]) WITH ORDINALITY AS arg(oid, ord) ON true
+WHERE p.prokind IN ('f', 'p') AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+ORDER BY n.nspname, p.proname, p.oid, arg.ord
@@ -0,0 +1,20 @@
+SELECT
+    n.nspname::text as sequence_schema,
+    c.relname::text as sequence_name,
+    s.last_value::bigint as current_value,
+    tn.nspname::text as owner_schema,
+    t.relname::text as owner_table,
+    a.attname::text as owner_column
+FROM
+    pg_catalog.pg_class c
+    JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+    LEFT JOIN pg_catalog.pg_sequences s ON s.schemaname = n.nspname AND s.sequencename = c.relname
+    LEFT JOIN pg_catalog.pg_depend d ON d.objid = c.oid
+      AND d.classid = 'pg_catalog.pg_class'::regclass
+      AND d.refclassid = 'pg_catalog.pg_class'::regclass
+      AND d.deptype IN ('a', 'i')
+    LEFT JOIN pg_catalog.pg_class t ON t.oid = d.refobjid
+    LEFT JOIN pg_catalog.pg_namespace tn ON tn.oid = t.relnamespace
+    LEFT JOIN pg_catalog.pg_attribute a ON a.attrelid = d.refobjid AND a.attnum = d.refobjsubid
+WHERE c.relkind = 'S' AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+ORDER BY n.nspname, c.relname
@@ -1,35 +1,73 @@
 
 SELECT 
-    things.schemaname as schema,
-    things.tablename as table_name,
+    things.schemaname::text as schema,
+    things.tablename::text as table_name,
     things.ty,
-    col.column_name,
-    col.udt_name as column_type,
+    col.column_name::text as column_name,
+    col.udt_name::text as column_type,
     case when col.is_nullable = 'YES' then 1 else 0 end as is_nullable,
-    case when (
-SELECT 1  
+    -- the position of the column in the primary key, 0 when not part of it
+    coalesce((
+SELECT kcu.ordinal_position::int
 FROM information_schema.table_constraints AS tc
 INNER JOIN
-    information_schema.constraint_column_usage AS ccu
+    information_schema.key_column_usage AS kcu
     ON
-        tc.constraint_schema = ccu.constraint_schema
-        AND tc.constraint_name = ccu.constraint_name
-WHERE 
+        tc.constraint_schema = kcu.constraint_schema
+        AND tc.constraint_name = kcu.constraint_name
+WHERE
     tc.constraint_type = 'PRIMARY KEY'
     AND tc.constraint_schema = things.schemaname
     AND tc.table_name = things.tablename
-    AND ccu.column_name = col.column_name
-
-) is not null then 1 else 0 end as is_primary_key,
-    case when col.is_updatable = 'YES' then 1 else 0 end as is_updatable
+    AND kcu.table_name = things.tablename
+    AND kcu.column_name = col.column_name
+), 0) as is_primary_key,
+    case when col.is_updatable = 'YES' then 1 else 0 end as is_updatable,
+    col.column_default::text as column_default,
+    col.collation_name::text as collation_name,
+    col.character_set_name::text as character_set_name,
+    col.character_maximum_length::int8 as max_length,
+    case when col.data_type = 'numeric' then col.numeric_precision::int8 end as numeric_precision,
+    case when col.data_type = 'numeric' then col.numeric_scale::int8 end as numeric_scale,
+    case when col.is_identity = 'YES' or col.column_default like 'nextval(%' then 1 else 0 end as is_auto_increment,
+    col_description(format('%I.%I', things.schemaname, things.tablename)::regclass, col.ordinal_position::int) as column_comment,
+    obj_description(format('%I.%I', things.schemaname, things.tablename)::regclass, 'pg_class') as table_comment,
+    case when col.is_generated = 'ALWAYS' then 1 else 0 end as is_generated
 FROM (
     SELECT schemaname, tablename, 'table' as ty FROM pg_catalog.pg_tables WHERE schemaname != 'pg_catalog' AND schemaname != 'information_schema'
     UNION
-    SELECT table_schema as schemaname, table_name as tablename, 'view' as ty from INFORMATION_SCHEMA.views WHERE table_schema = ANY (current_schemas(false))
+    SELECT table_schema as schemaname, table_name as tablename, 'view' as ty from INFORMATION_SCHEMA.views WHERE table_schema != 'pg_catalog' AND table_schema != 'information_schema'
 ) things
 join information_schema.columns col on col.table_schema = things.schemaname AND col.table_name = things.tablename
 WHERE things.schemaname ilike $1 and things.tablename ilike $2
-ORDER BY things.schemaname, things.tablename, is_primary_key desc, col.column_name
+UNION ALL
+SELECT
+    n.nspname::text as schema,
+    c.relname::text as table_name,
+    'materialized_view' as ty,
+    a.attname::text as column_name,
+    t.typname::text as column_type,
+    case when a.attnotnull then 0 else 1 end as is_nullable,
+    0 as is_primary_key,
+    0 as is_updatable,
+    null::text as column_default,
+    null::text as collation_name,
+    null::text as character_set_name,
+    null::int8 as max_length,
+    null::int8 as numeric_precision,
+    null::int8 as numeric_scale,
+    0 as is_auto_increment,
+    col_description(c.oid, a.attnum) as column_comment,
+    obj_description(c.oid, 'pg_class') as table_comment,
+    0 as is_generated
+FROM
+    pg_catalog.pg_class c
+    JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+    JOIN pg_catalog.pg_attribute a ON a.attrelid = c.oid AND a.attnum > 0 AND NOT a.attisdropped
+    JOIN pg_catalog.pg_type t ON t.oid = a.atttypid
+WHERE c.relkind = 'm'
+  AND n.nspname ilike $1 and c.relname ilike $2
+ORDER BY 1, 2, 7 desc, 4
 
 
 
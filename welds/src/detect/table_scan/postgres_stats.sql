@@ -0,0 +1,6 @@
+SELECT
+    case when c.reltuples < 0 then null else c.reltuples::int8 end as estimated_rows,
+    pg_total_relation_size(c.oid)::int8 as size_bytes
+FROM pg_catalog.pg_class c
+JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+WHERE n.nspname ilike $1 and c.relname ilike $2 and c.relkind IN ('r', 'p', 'm')
@@ -0,0 +1,8 @@
+SELECT
+    t.trigger_name::text as trigger_name,
+    t.action_timing::text as action_timing,
+    t.event_manipulation::text as event_manipulation,
+    t.action_statement::text as action_statement
+FROM information_schema.triggers t
+WHERE t.event_object_schema ilike $1 and t.event_object_table ilike $2
+ORDER BY t.trigger_name, t.event_manipulation
@@ -6,10 +6,25 @@ select
   table_info.type as column_type,
   NOT table_info."notnull" as is_nullable,
   table_info.pk as is_primary_key,
-  1 as is_updatable
+  1 as is_updatable,
+  table_info.dflt_value as column_default,
+  null as collation_name,
+  null as character_set_name,
+  null as max_length,
+  null as numeric_precision,
+  null as numeric_scale,
+  -- an INTEGER PRIMARY KEY is an alias for the rowid
+  case when table_info.pk = 1 and upper(table_info.type) = 'INTEGER'
+    and (select count(*) from pragma_table_info(sqlite_master.name) where pk > 0) = 1
+    then 1 else 0 end as is_auto_increment,
+  null as column_comment,
+  null as table_comment,
+  -- hidden is 2 for VIRTUAL and 3 for STORED generated columns
+  table_info.hidden in (2, 3) as is_generated
 from
   sqlite_master
-  join pragma_table_info(sqlite_master.name) as table_info
+  join pragma_table_xinfo(sqlite_master.name) as table_info
   JOIN sqlite_schema ss on ss.name = sqlite_master.name
+where table_info.hidden != 1
 order by
   sqlite_master.name, is_primary_key, column_name
@@ -4,9 +4,13 @@ SELECT
     p."from" as from_column,
     null as fk_schemaname,
     p."table" as to_table,
-    p."to" as to_column
+    p."to" as to_column,
+    null as constraint_name,
+    p.seq + 1 as column_position,
+    p.on_delete,
+    p.on_update
 FROM
     sqlite_master m
     JOIN pragma_foreign_key_list(m.name) p ON m.name != p."table"
 WHERE m.type = 'table'
-ORDER BY m.name
+ORDER BY m.name, p.id, p.seq
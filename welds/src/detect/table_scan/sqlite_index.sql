@@ -0,0 +1,12 @@
+SELECT
+  il.name as index_name,
+  ii.name as column_name,
+  il."unique" as is_unique,
+  case when il.origin = 'pk' then 1 else 0 end as is_primary_key,
+  'btree' as index_method
+FROM
+  sqlite_master m
+  JOIN pragma_index_list(m.name) as il
+  JOIN pragma_index_info(il.name) as ii
+WHERE ? is null AND m.type = 'table' AND m.name = ?
+ORDER BY il.name, ii.seqno
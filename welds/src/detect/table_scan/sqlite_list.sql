@@ -0,0 +1,11 @@
+SELECT
+  null as schemaname,
+  m.name as tablename,
+  m.type as ty
+FROM sqlite_master m
+WHERE m.type IN ('table', 'view')
+  AND m.name NOT LIKE 'sqlite_%'
+  AND ?1 is null
+  AND (?2 is null OR m.name like ?2)
+  AND (?3 is null OR m.type = ?3)
+ORDER BY m.name
@@ -0,0 +1,8 @@
+-- sqlite doesn't keep a row estimate without ANALYZE, only the size is reported
+SELECT
+  null as estimated_rows,
+  sum(d.pgsize) as size_bytes
+FROM sqlite_master m
+JOIN dbstat d ON d.name = m.name
+WHERE ? is null AND m.tbl_name = ?
+GROUP BY m.tbl_name
@@ -0,0 +1,8 @@
+SELECT
+    m.name as trigger_name,
+    null as action_timing,
+    null as event_manipulation,
+    m.sql as action_statement
+FROM sqlite_master m
+WHERE ? is null AND m.type = 'trigger' AND m.tbl_name = ?
+ORDER BY m.name
@@ -11,6 +11,16 @@ pub struct TableScanRow {
     pub(super) is_nullable: i32,
     pub(super) is_primary_key: i32,
     pub(super) is_updatable: i32,
+    pub(super) column_default: Option<String>,
+    pub(super) collation_name: Option<String>,
+    pub(super) character_set_name: Option<String>,
+    pub(super) max_length: Option<i64>,
+    pub(super) numeric_precision: Option<i64>,
+    pub(super) numeric_scale: Option<i64>,
+    pub(super) is_auto_increment: i32,
+    pub(super) column_comment: Option<String>,
+    pub(super) table_comment: Option<String>,
+    pub(super) is_generated: i32,
 }
 
 impl TryFrom<Row> for TableScanRow {
@@ -25,6 +35,16 @@ impl TryFrom<Row> for TableScanRow {
             is_nullable: row.get_by_position(5)?,
             is_primary_key: row.get_by_position(6)?,
             is_updatable: row.get_by_position(7)?,
+            column_default: row.get_by_position(8)?,
+            collation_name: row.get_by_position(9)?,
+            character_set_name: row.get_by_position(10)?,
+            max_length: row.get_by_position(11)?,
+            numeric_precision: row.get_by_position(12)?,
+            numeric_scale: row.get_by_position(13)?,
+            is_auto_increment: row.get_by_position(14)?,
+            column_comment: row.get_by_position(15)?,
+            table_comment: row.get_by_position(16)?,
+            is_generated: row.get_by_position(17)?,
         })
     }
 }
@@ -40,6 +60,9 @@ impl TableScanRow {
         if self.ty == "table" {
             return DataType::Table;
         }
+        if self.ty == "materialized_view" {
+            return DataType::MaterializedView;
+        }
         DataType::View
     }
 }
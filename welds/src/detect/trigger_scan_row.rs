@@ -0,0 +1,20 @@
+use crate::Row;
+
+pub struct TriggerScanRow {
+    pub(super) trigger_name: String,
+    pub(super) action_timing: Option<String>,
+    pub(super) event_manipulation: Option<String>,
+    pub(super) action_statement: Option<String>,
+}
+
+impl TryFrom<Row> for TriggerScanRow {
+    type Error = crate::WeldsError;
+    fn try_from(row: Row) -> Result<Self, Self::Error> {
+        Ok(TriggerScanRow {
+            trigger_name: row.get_by_position(0)?,
+            action_timing: row.get_by_position(1)?,
+            event_manipulation: row.get_by_position(2)?,
+            action_statement: row.get_by_position(3)?,
+        })
+    }
+}
@@ -26,6 +26,18 @@ pub enum WeldsError {
     #[error("An underlying Hook canceled the action")]
     ActionCanceled,
 
+    #[error("The row was changed by someone else since it was read")]
+    StaleObject,
+
+    #[error("The database stopped the query, it ran out of time")]
+    Timeout,
+
+    #[error("The query has {0} params, over the {1} the database allows. Only a query that isn't ordered or limited is ran in chunks")]
+    TooManyParams(usize, usize),
+
+    #[error("The database schema does not match the models:\n{0}")]
+    SchemaMismatch(String),
+
     #[error("Anyhow Error")]
     Other(#[from] anyhow::Error),
 }
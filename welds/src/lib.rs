@@ -25,6 +25,21 @@
 //! ```rust,ignore
 //! #[welds(readonly)]
 //! ```
+//! if the table has columns you intentionally don't map, `check` can be told to not report them
+//! ```rust,ignore
+//! #[welds(check_extra_columns = false)]
+//! ```
+//! welds can keep the `created_at` and `updated_at` fields of the model up to date when it is saved.
+//! Upserts and the `_with_hooks` bulk inserts and upserts set them too, an upsert of a row that already exists keeps its `created_at`
+//! ```rust,ignore
+//! #[welds(timestamps)]
+//! ```
+//! rows can be soft deleted, `delete()` sets the time they were deleted instead of deleting them.
+//! This is true for a single model and for deleting from a query in bulk, `force_delete()` really deletes them.
+//! Queries hide the deleted rows, use `with_deleted()` or `only_deleted()` to see them
+//! ```rust,ignore
+//! #[welds(soft_delete = "deleted_at")]
+//! ```
 //!
 //! ## Build Relations for Joining
 //! You can write queries that join across tables if you Wireup welds with relationships
@@ -75,6 +90,62 @@
 //! - `#[welds(primary_key)]` Important! Add this to the primary key of your table.
 //! - `#[welds(rename = "xyz")]` let welds know the underlying column has a different name than the field
 //! - `#[welds(ignore)]` Tell welds this fields it not in the database.
+//! - `#[welds(index)]` or `#[welds(index = "xyz")]` let welds know this column should be indexed. (used by `check`)
+//! - `#[welds(unique)]` let welds know this column should have a unique index. (used by `check`)
+//! - `#[welds(default = "now()")]` let welds know the default the underlying column should have. (used by `check`)
+//! - `#[welds(created_at)]`, `#[welds(updated_at)]` welds sets the field to now when it is created, and `updated_at` again on each save. (the `chrono` and `time` features add their types)
+//! - `#[welds(json)]` store a field of any `Serialize + DeserializeOwned` type as JSON. (json/jsonb on Postgres, JSON on MySQL, TEXT on Sqlite, NVARCHAR(MAX) on MSSQL. requires the `serde` feature)
+//! - `#[welds(primary_key, uuid_v4)]`, `#[welds(primary_key, uuid_v7)]` welds makes the key in rust when a model is created with the default key. For `Uuid` and `String` keys, or any type that is `UuidKey`. (requires the `uuid` feature)
+//! - `#[welds(version)]` an integer version of the row. Updates only save if the row is still on the version that was read, otherwise they fail with `WeldsError::StaleObject`
+//! - `#[welds(db_default)]` the database fills in this column. It is left out of inserts and updates, and its value is read back into the model
+//! - `#[welds(collation = "utf8mb4_bin")]` let welds know the collation the underlying column should have. (used by `check`)
+//! - `#[welds(max_length = 50)]`, `#[welds(precision = 10, scale = 2)]` let welds know the size the underlying column should have. (used by `check`)
+//! - `#[welds(check = "ignore")]` tells `check` to never report on this column
+//!
+//! ## Enum Fields
+//! A plain rust enum can be a field of a model when it derives `welds::WeldsEnum`.
+//! Each variant is stored as TEXT, its name or `#[welds(rename = "...")]`.
+//! Add `#[welds(store = "int")]` to store the variants as INT, the number of the variant
+//! ```rust,ignore
+//! #[derive(Debug, Clone, Default, PartialEq, welds::WeldsEnum)]
+//! pub enum Status {
+//!     #[default]
+//!     Open,
+//!     #[welds(rename = "done")]
+//!     Closed,
+//! }
+//! // Task::where_col(|t| t.status.equal(Status::Open))
+//! ```
+//! NOTE: MSSQL can't take an `Option<...>` of an enum as a param, use a model without the Option
+//!
+//! ## Custom Field Types
+//! A type of your own, like `EmailAddress(String)` or `Cents(i64)`, can be a field of a model
+//! when it derives `welds::WeldsType`. A struct with a single field is stored as that field.
+//! ```rust,ignore
+//! #[derive(Debug, Clone, Default, PartialEq, welds::WeldsType)]
+//! pub struct Cents(i64);
+//! ```
+//! To encode and decode the value your own way add `#[welds(custom)]` and implement `WeldsType`,
+//! the value is stored as its `Stored` type
+//! ```rust,ignore
+//! #[derive(Debug, Clone, Default, PartialEq, welds::WeldsType)]
+//! #[welds(custom)]
+//! pub struct EmailAddress(String);
+//!
+//! impl welds::WeldsType for EmailAddress {
+//!     type Stored = String;
+//!     fn to_stored(&self) -> String {
+//!         self.0.to_lowercase()
+//!     }
+//!     fn from_stored(stored: String) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+//!         match stored.contains('@') {
+//!             true => Ok(EmailAddress(stored)),
+//!             false => Err(format!("not an email address: {}", stored).into()),
+//!         }
+//!     }
+//! }
+//! ```
+//! NOTE: MSSQL can't take an `Option<...>` of a custom type as a param either
 //!
 //!
 //! ## Putting it all together
@@ -98,7 +169,7 @@
 //! When WeldsModel is added to your struct the following is added directly to your model
 //!
 //! - [`Model::new()`]( ./state/struct.DbState.html#method.new_uncreated ) make a new model ready to be saved to the database
-//! - `Model::find_by_id()`
+//! - `Model::find_by_id()` finds a Model by its primary key, a tuple of values for a composite key
 //! - [`Model::all()`](./query/builder/struct.QueryBuilder.html) start a query for a Model
 //! - [`Model::where_col()`](./query/builder/struct.QueryBuilder.html) start a query for a Model
 //! - `Model::from_raw_sql()` finds Model using raw custom SQL str
@@ -139,6 +210,7 @@
 //! - check - enables checking your models against table in the database
 //! - migrations - adds all the migration structs and traits
 //! - full - all the features excluding (mock)
+//! - serde - adds Serialize/Deserialize to the check and detect types. Allows saving a `detect::SchemaSnapshot`
 //! - mock - Use for testing ONLY. Enables mocking out database schemas
 //!
 //!
@@ -169,7 +241,7 @@ pub mod migrations;
 pub use welds_connections as connections;
 
 /// Re-export welds_connections
-pub use welds_connections::{Client, Row, Syntax, TransactStart};
+pub use welds_connections::{Client, Row, Syntax, TransactStart, WeldsEnum, WeldsType};
 
 /// Re-export the Macro used to make models
-pub use welds_macros::WeldsModel;
+pub use welds_macros::{WeldsDto, WeldsEnum, WeldsModel, WeldsRow, WeldsType};
@@ -72,6 +72,7 @@ pub struct ColumnBuilder {
 }
 
 impl ColumnBuilder {
+    #[allow(clippy::wrong_self_convention)]
     pub fn is_null(mut self) -> Self {
         self.nullable = true;
         self
@@ -33,6 +33,7 @@ impl Change {
         self
     }
 
+    #[allow(clippy::wrong_self_convention)]
     pub fn to_type(mut self, ty: Type) -> Change {
         self.new_ty = Some(ty);
         self
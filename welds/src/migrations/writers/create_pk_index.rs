@@ -1,10 +1,6 @@
 use crate::migrations::create_table::ColumnBuilder;
-use crate::migrations::create_table::IdBuilder;
-use crate::migrations::create_table::TableBuilder;
 use crate::migrations::types::Index;
-use crate::migrations::types::Type;
 use crate::model_traits::TableIdent;
-use crate::Syntax;
 
 pub fn write(table: &TableIdent, col: &ColumnBuilder) -> String {
     let tablename = table.to_string();
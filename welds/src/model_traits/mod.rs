@@ -16,6 +16,13 @@ pub struct Column {
     name: String,
     rust_type: String,
     nullable: bool,
+    default: Option<String>,
+    collation: Option<String>,
+    max_length: Option<i64>,
+    precision: Option<i64>,
+    scale: Option<i64>,
+    db_default: bool,
+    created_at: bool,
 }
 
 impl Column {
@@ -26,8 +33,50 @@ impl Column {
             name: name.into(),
             rust_type,
             nullable,
+            default: None,
+            collation: None,
+            max_length: None,
+            precision: None,
+            scale: None,
+            db_default: false,
+            created_at: false,
         }
     }
+    /// Sets the default value/expression the database column is expected to have
+    pub fn with_default(mut self, default: impl Into<String>) -> Self {
+        self.default = Some(default.into());
+        self
+    }
+    /// Sets the collation the database column is expected to have
+    pub fn with_collation(mut self, collation: impl Into<String>) -> Self {
+        self.collation = Some(collation.into());
+        self
+    }
+    /// Sets the max length the database column is expected to have. I.E. `VARCHAR(50)`
+    pub fn with_max_length(mut self, max_length: i64) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+    /// Sets the precision the database column is expected to have. I.E. the 10 in `NUMERIC(10,2)`
+    pub fn with_precision(mut self, precision: i64) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+    /// Sets the scale the database column is expected to have. I.E. the 2 in `NUMERIC(10,2)`
+    pub fn with_scale(mut self, scale: i64) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+    /// Marks the column as filled in by the database, it is left out of inserts and read back
+    pub fn with_db_default(mut self) -> Self {
+        self.db_default = true;
+        self
+    }
+    /// Marks the column as the time the row was created, it is never updated
+    pub fn with_created_at(mut self) -> Self {
+        self.created_at = true;
+        self
+    }
     /// The name of the column in the database
     pub fn name(&self) -> &str {
         self.name.as_str()
@@ -41,6 +90,34 @@ impl Column {
     pub fn rust_type(&self) -> &str {
         self.rust_type.as_str()
     }
+    /// The default value/expression of the column in the database, if one was given
+    pub fn default(&self) -> Option<&str> {
+        self.default.as_deref()
+    }
+    /// The collation of the column in the database, if one was given
+    pub fn collation(&self) -> Option<&str> {
+        self.collation.as_deref()
+    }
+    /// The max length of the column in the database, if one was given
+    pub fn max_length(&self) -> Option<i64> {
+        self.max_length
+    }
+    /// The precision of the column in the database, if one was given
+    pub fn precision(&self) -> Option<i64> {
+        self.precision
+    }
+    /// The scale of the column in the database, if one was given
+    pub fn scale(&self) -> Option<i64> {
+        self.scale
+    }
+    /// if the database makes the value of this column when a row is inserted
+    pub fn db_default(&self) -> bool {
+        self.db_default
+    }
+    /// if the column holds the time the row was created. `#[welds(created_at)]`
+    pub fn created_at(&self) -> bool {
+        self.created_at
+    }
 }
 
 /// How welds knows what columns exist on your model
@@ -51,6 +128,149 @@ pub trait TableColumns {
     fn columns() -> Vec<Column>;
 }
 
+/// An index the model expects to exist on its table
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Index {
+    name: Option<String>,
+    columns: Vec<String>,
+    unique: bool,
+}
+
+impl Index {
+    pub fn new(columns: &[&str]) -> Self {
+        Self {
+            name: None,
+            columns: columns.iter().map(|x| x.to_string()).collect(),
+            unique: false,
+        }
+    }
+    /// Sets the name the index is expected to have in the database
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+    /// Marks this index as enforcing uniqueness. I.E. `#[welds(unique)]`
+    pub fn with_unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
+    /// The name of the index, if one was given
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+    /// The db columns (in order) covered by this index
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+    /// true if the index is expected to enforce uniqueness
+    pub fn unique(&self) -> bool {
+        self.unique
+    }
+}
+
+/// How welds knows what indexes your model expects
+/// This trait is impl by the model's schema not the model
+pub trait TableIndexes {
+    fn indexes() -> Vec<Index>;
+}
+
+/// Tells `check` which parts of the table it should skip over
+/// This trait is impl by the model's schema not the model
+pub trait TableCheck {
+    /// columns that should never be reported on
+    fn ignored_columns() -> &'static [&'static str];
+    /// if false, columns in the database that are not on the model are not reported
+    fn check_extra_columns() -> bool;
+    /// true if the model is never written to. I.E. `#[welds(readonly)]`
+    fn readonly() -> bool;
+}
+
+/// Which side of a relationship a model is on
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum RelationKind {
+    /// The foreign_key column is on this model's table
+    BelongsTo,
+    /// The foreign_key column is on the other table
+    HasMany,
+}
+
+/// A relationship the model has to another table
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RelationInfo {
+    kind: RelationKind,
+    field: String,
+    other: TableIdent,
+    foreign_key: String,
+}
+
+impl RelationInfo {
+    pub fn new(
+        kind: RelationKind,
+        field: impl Into<String>,
+        other_identifier: &[&str],
+        foreign_key: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind,
+            field: field.into(),
+            other: TableIdent::parse(&other_identifier.join(".")),
+            foreign_key: foreign_key.into(),
+        }
+    }
+    /// Which side of the relationship this model is on
+    pub fn kind(&self) -> RelationKind {
+        self.kind
+    }
+    /// The name of the relation field
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+    /// The table on the other side of the relationship
+    pub fn other(&self) -> &TableIdent {
+        &self.other
+    }
+    /// The foreign_key column regardless of which side this defines
+    pub fn foreign_key(&self) -> &str {
+        &self.foreign_key
+    }
+}
+
+/// How welds knows what relationships your model has to other tables
+/// This trait is impl by the model's schema not the model
+pub trait TableRelations {
+    fn relations() -> Vec<RelationInfo>;
+}
+
+/// Lets a model with `#[welds(soft_delete = "deleted_at")]` be marked as deleted
+/// instead of deleting its row.
+///
+/// This is AUTOMATICALLY implemented by the Welds Macros for ALL models
+pub trait SoftDelete {
+    /// The column holding the time the row was deleted. None if its rows are really deleted
+    fn soft_delete_column() -> Option<&'static str> {
+        None
+    }
+    /// sets the time the model was deleted to now, or clears it when restoring
+    fn set_deleted(&mut self, _deleted: bool) {}
+    /// the time to set on the rows deleted in bulk, now
+    fn deleted_now() -> Option<Box<dyn Param + Send + Sync>> {
+        None
+    }
+}
+
+/// Lets a model with a `#[welds(version)]` column be locked optimistically.
+/// Updates only change the row if its version hasn't moved since it was read.
+///
+/// This is AUTOMATICALLY implemented by the Welds Macros for ALL models
+pub trait Versioned {
+    /// The column holding the version of the row. None if the model isn't versioned
+    fn version_column() -> Option<&'static str> {
+        None
+    }
+    /// moves the model to the version it has in the database after an update
+    fn next_version(&mut self) {}
+}
+
 /// If the model can be uniquely identifed by a single column,
 /// This is used to create get_by_id methods
 pub trait UniqueIdentifier {
@@ -58,8 +278,15 @@ pub trait UniqueIdentifier {
     fn id_column() -> Column;
 }
 
+/// The types of the primary keys of a model, as a tuple.
+/// This is used to walk a table in primary key order
+pub trait PrimaryKeyValues {
+    type Values: crate::query::keyset::PageValues;
+}
+
 use crate::errors::Result;
 use crate::query::clause::ParamArgs;
+use welds_connections::Param;
 
 pub trait WriteToArgs {
     fn bind<'s, 'c, 'a, 'p>(&'s self, column: &'c str, args: &'a mut ParamArgs<'p>) -> Result<()>
@@ -68,17 +295,48 @@ pub trait WriteToArgs {
 }
 
 pub trait ColumnDefaultCheck {
-    fn col_is_default<'s, 'c>(&'s self, column: &'c str) -> Result<bool>;
+    fn col_is_default(&self, column: &str) -> Result<bool>;
+}
+
+/// Reads the model out of a row where the names of its columns start with a prefix.
+/// Used to read several models out of the same row
+pub trait FromPrefixedRow: Sized {
+    fn from_prefixed_row(row: &crate::Row, prefix: &str) -> crate::errors::Result<Self>;
 }
 
 pub trait UpdateFromRow {
     fn update_from_row(&mut self, row: &mut crate::Row) -> crate::errors::Result<()>;
 }
 
+/// Sets a single field of the model from a column in a row.
+/// Used to read back the columns asked for with `returning`
+pub trait UpdateColumnFromRow {
+    fn update_column_from_row(
+        &mut self,
+        column: &str,
+        row: &mut crate::Row,
+    ) -> crate::errors::Result<()>;
+}
+
 /// Used to link a models schema to the model
 pub trait HasSchema: Sync + Send {
     type Schema: Default + TableInfo;
 }
 
+/// A read-only struct filled from a subset of the columns of a model.
+/// Use `#[derive(WeldsDto)]` to implement
+pub trait Dto: TryFrom<crate::Row> {
+    /// The model the columns are selected from
+    type Model: Send + HasSchema;
+    /// adds the columns of the Dto to the SELECT
+    fn select(
+        sb: crate::query::select_cols::SelectBuilder<Self::Model>,
+    ) -> crate::query::select_cols::SelectBuilder<Self::Model>;
+}
+
 mod tableident;
 pub use tableident::TableIdent;
+mod timestamp;
+pub use timestamp::Timestamp;
+mod uuid_key;
+pub use uuid_key::UuidKey;
@@ -5,6 +5,7 @@ use crate::Syntax;
 
 /// a unique identifier for a table.
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableIdent {
     pub(crate) schema: Option<String>,
     pub(crate) name: String,
@@ -0,0 +1,53 @@
+use std::time::SystemTime;
+
+/// A time a `created_at` or `updated_at` field of a model can be set to.
+///
+/// Welds sets these fields to `now()` when the model is saved.
+/// Implement it for your own time type to use it as a timestamp
+pub trait Timestamp {
+    /// the current time
+    fn now() -> Self;
+}
+
+impl Timestamp for SystemTime {
+    fn now() -> Self {
+        SystemTime::now()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Timestamp for chrono::DateTime<chrono::Utc> {
+    fn now() -> Self {
+        chrono::Utc::now()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Timestamp for chrono::NaiveDateTime {
+    fn now() -> Self {
+        chrono::Utc::now().naive_utc()
+    }
+}
+
+#[cfg(feature = "time")]
+impl Timestamp for time::OffsetDateTime {
+    fn now() -> Self {
+        time::OffsetDateTime::now_utc()
+    }
+}
+
+#[cfg(feature = "time")]
+impl Timestamp for time::PrimitiveDateTime {
+    fn now() -> Self {
+        let now = time::OffsetDateTime::now_utc();
+        time::PrimitiveDateTime::new(now.date(), now.time())
+    }
+}
+
+// the tests check the time written to a model, it is always the same string
+#[cfg(test)]
+impl Timestamp for String {
+    fn now() -> Self {
+        "now".to_owned()
+    }
+}
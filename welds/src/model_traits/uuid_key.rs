@@ -0,0 +1,32 @@
+/// A primary key welds can make a new uuid for.
+///
+/// With `#[welds(primary_key, uuid_v4)]` or `#[welds(primary_key, uuid_v7)]` the key is made
+/// in rust when a model with the default (empty) key is created.
+/// Implement it for your own key type to have welds make its keys
+pub trait UuidKey {
+    /// a new random uuid
+    fn new_v4() -> Self;
+    /// a new uuid that sorts by the time it was made
+    fn new_v7() -> Self;
+}
+
+#[cfg(feature = "uuid")]
+impl UuidKey for uuid::Uuid {
+    fn new_v4() -> Self {
+        uuid::Uuid::new_v4()
+    }
+    fn new_v7() -> Self {
+        uuid::Uuid::now_v7()
+    }
+}
+
+/// For keys stored as text. I.E. a CHAR(36) on MySQL or TEXT on Sqlite
+#[cfg(feature = "uuid")]
+impl UuidKey for String {
+    fn new_v4() -> Self {
+        uuid::Uuid::new_v4().to_string()
+    }
+    fn new_v7() -> Self {
+        uuid::Uuid::now_v7().to_string()
+    }
+}
@@ -1,3 +1,13 @@
+pub use crate::query::builder::Hint;
+pub use crate::query::builder::LockMode;
+pub use crate::query::builder::Scope;
+pub use crate::query::clause::Case;
+pub use crate::query::clause::DatePart;
+pub use crate::query::clause::Expr;
+pub use crate::query::clause::InList;
+pub use crate::query::clause::OrderExpr;
+pub use crate::query::clause::Tuple;
+pub use crate::query::raw::QueryAs;
 pub use crate::state::DbState;
 pub use crate::Client;
 pub use crate::TransactStart;
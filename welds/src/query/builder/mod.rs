@@ -1,16 +1,31 @@
 use super::clause;
 pub use super::clause::manualwhereparam::ManualWhereParam;
+use super::clause::raw::ClauseRaw;
+pub use super::clause::RawParam;
 use super::select_cols::SelectBuilder;
 use super::update::bulk::UpdateBuilder;
-use crate::model_traits::{HasSchema, TableColumns, TableInfo, UniqueIdentifier};
+use crate::errors::{Result, WeldsError};
+use crate::model_traits::{Dto, HasSchema, SoftDelete, TableColumns, TableInfo, UniqueIdentifier};
 use crate::query::clause::exists::ExistIn;
-use crate::query::clause::{AsFieldName, ClauseAdder, OrderBy};
+use crate::query::clause::{
+    AsFieldName, ClauseAdder, ClauseSoftDeleted, Expr, OrderBy, OrderExpr, RelatedCount, WhereGroup,
+};
 use crate::relations::{HasRelations, Relationship};
 use crate::writers::alias::TableAlias;
+use crate::writers::distinct::Distinct;
+use crate::writers::lock::{Lock, LockWait};
+pub use crate::writers::Hint;
+pub use crate::writers::LockMode;
+use crate::writers::NextParam;
+use crate::Syntax;
 use std::marker::PhantomData;
 use std::sync::Arc;
+use std::time::Duration;
 use welds_connections::Param;
 
+mod scope;
+pub use scope::Scope;
+
 /// An un-executed Query.
 ///
 /// Build out a query that can be executed on the database.
@@ -18,15 +33,44 @@ use welds_connections::Param;
 /// Can be chained with other queries to make more complex queries.
 ///
 /// Can be mapped into other queries to  make more complex queries.
+///
+/// Cloning is cheap, the filters are shared between the clones.
 pub struct QueryBuilder<T> {
     _t: PhantomData<T>,
-    pub(crate) wheres: Vec<Box<dyn ClauseAdder>>,
+    pub(crate) wheres: Vec<Arc<dyn ClauseAdder>>,
     pub(crate) exist_ins: Vec<ExistIn>,
     pub(crate) limit: Option<i64>,
     pub(crate) offset: Option<i64>,
     pub(crate) orderby: Vec<OrderBy>,
     pub(crate) alias: String,
     pub(crate) alias_asigner: Arc<TableAlias>,
+    pub(crate) distinct: Option<Distinct>,
+    pub(crate) lock: Option<Lock>,
+    pub(crate) hints: Vec<Hint>,
+    pub(crate) timeout: Option<Duration>,
+    // the soft delete column and the filter hiding its deleted rows
+    pub(crate) soft_delete: Option<(String, Arc<dyn ClauseAdder>)>,
+}
+
+// derive would require T: Clone
+impl<T> Clone for QueryBuilder<T> {
+    fn clone(&self) -> Self {
+        Self {
+            _t: PhantomData,
+            wheres: self.wheres.clone(),
+            exist_ins: self.exist_ins.clone(),
+            limit: self.limit,
+            offset: self.offset,
+            orderby: self.orderby.clone(),
+            alias: self.alias.clone(),
+            alias_asigner: self.alias_asigner.clone(),
+            distinct: self.distinct.clone(),
+            lock: self.lock,
+            hints: self.hints.clone(),
+            timeout: self.timeout,
+            soft_delete: self.soft_delete.clone(),
+        }
+    }
 }
 
 impl<T> Default for QueryBuilder<T>
@@ -54,6 +98,22 @@ where
             exist_ins: Default::default(),
             alias,
             alias_asigner: Arc::new(ta),
+            distinct: None,
+            lock: None,
+            hints: Vec::default(),
+            timeout: None,
+            soft_delete: None,
+        }
+    }
+
+    /// A new query hiding the soft deleted rows of the model, like the model's `all()`
+    pub(crate) fn scoped() -> Self
+    where
+        T: SoftDelete,
+    {
+        match T::soft_delete_column() {
+            Some(column) => Self::new().soft_delete(column),
+            None => Self::new(),
         }
     }
 
@@ -67,7 +127,42 @@ where
         <T as HasSchema>::Schema: Default,
     {
         let qba = lam(Default::default());
-        self.wheres.push(qba);
+        self.wheres.push(qba.into());
+        self
+    }
+
+    /// Apply a reusable set of filters to this query. See `Scope`
+    ///
+    /// ```rust,ignore
+    /// User::all().scope(active).scope(CreatedAfter(since))
+    /// ```
+    pub fn scope(self, scope: impl Scope<T>) -> Self {
+        scope.apply(self)
+    }
+
+    /// Filter the results with a group of clauses where any of them can be true (OR)
+    ///
+    /// Example
+    /// ```rust,ignore
+    /// where_any(|g| g.col(|o| o.status.equal("new")).col(|o| o.price.gt(10.0)))
+    /// // WHERE ( ( t1.status = $1 OR t1.price > $2 ) )
+    /// ```
+    pub fn where_any(mut self, lam: impl FnOnce(WhereGroup<T>) -> WhereGroup<T>) -> Self
+    where
+        T: 'static,
+    {
+        self.wheres.push(Arc::new(lam(WhereGroup::new_any())));
+        self
+    }
+
+    /// Filter the results with a group of clauses where every one of them must be true (AND)
+    ///
+    /// Useful for nesting groups: `where_all(|g| g.any(..).any(..))`
+    pub fn where_all(mut self, lam: impl FnOnce(WhereGroup<T>) -> WhereGroup<T>) -> Self
+    where
+        T: 'static,
+    {
+        self.wheres.push(Arc::new(lam(WhereGroup::new_all())));
         self
     }
 
@@ -97,7 +192,7 @@ where
             sql: sql.to_string(),
             params: params.into_inner(),
         };
-        self.wheres.push(Box::new(c));
+        self.wheres.push(Arc::new(c));
         self
     }
 
@@ -117,14 +212,66 @@ where
             sql: sql.to_string(),
             params: params.into_inner(),
         };
-        self.wheres.push(Box::new(c));
+        self.wheres.push(Arc::new(c));
         self
     }
 
+    /// write a raw SQL clause in the where block, binding the params safely
+    /// NOTE: use '{}' for params. They will be swapped out for the correct Syntax
+    /// NOTE: use '$' for table prefix/alias. It will be swapped out for the prefix used at runtime
+    ///
+    /// Example
+    /// ```rust,ignore
+    /// where_raw("lower($.name) = {}", &[&name])
+    /// // WHERE lower(t1.name) = $1
+    /// ```
+    ///
+    pub fn where_raw(mut self, sql: impl Into<String>, params: &[&dyn RawParam]) -> Self {
+        self.wheres.push(Arc::new(ClauseRaw::new(sql, params)));
+        self
+    }
+
+    /// Hide the rows that have been soft deleted, the rows with `column` set.
+    ///
+    /// The queries of a model with `#[welds(soft_delete = "deleted_at")]` start with this
+    pub fn soft_delete(mut self, column: impl Into<String>) -> Self {
+        let column = column.into();
+        let filter: Arc<dyn ClauseAdder> = Arc::new(ClauseSoftDeleted {
+            col: column.clone(),
+            deleted: false,
+        });
+        self.wheres.push(filter.clone());
+        self.soft_delete = Some((column, filter));
+        self
+    }
+
+    /// Include the soft deleted rows in the results
+    pub fn with_deleted(mut self) -> Self {
+        if let Some((_, filter)) = &self.soft_delete {
+            self.wheres.retain(|w| !Arc::ptr_eq(w, filter));
+        }
+        self
+    }
+
+    /// Only return the rows that have been soft deleted
+    pub fn only_deleted(self) -> Self {
+        let column = match &self.soft_delete {
+            Some((column, _)) => column.clone(),
+            None => return self,
+        };
+        let mut qb = self.with_deleted();
+        let filter = ClauseSoftDeleted {
+            col: column,
+            deleted: true,
+        };
+        qb.wheres.push(Arc::new(filter));
+        qb
+    }
+
     /// Add a query to this query (JOIN on a relationship)
     /// results on a query that is filtered using the results of both queries
     pub fn where_relation<R, Ship>(
-        mut self,
+        self,
         relationship: impl Fn(<T as HasRelations>::Relation) -> Ship,
         filter: QueryBuilder<R>,
     ) -> Self
@@ -136,13 +283,186 @@ where
         <R as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
         <T as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
         <T as HasRelations>::Relation: Default,
+    {
+        let exist_in = Self::related(relationship, filter);
+        self.push_exists(exist_in)
+    }
+
+    /// Filter to the rows with no rows in the relationship matching the query (an anti-join).
+    /// I.E. the customers that never ordered anything
+    /// ```rust,ignore
+    /// let never_ordered = Customer::all().where_not_in_relation(|c| c.orders, Order::all());
+    /// ```
+    /// Written as a `NOT EXISTS`, which every database plans as an anti-join.
+    /// Unlike `NOT IN` it is still right when the related keys have NULLs
+    pub fn where_not_in_relation<R, Ship>(
+        self,
+        relationship: impl Fn(<T as HasRelations>::Relation) -> Ship,
+        filter: QueryBuilder<R>,
+    ) -> Self
+    where
+        T: HasRelations,
+        Ship: Relationship<R>,
+        R: Send + Sync + HasSchema,
+        <R as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasRelations>::Relation: Default,
+    {
+        let exist_in = Self::related(relationship, filter).negate();
+        self.push_exists(exist_in)
+    }
+
+    /// Filter to the rows that have at least one related row matching the filter (EXISTS)
+    /// ```rust,ignore
+    /// let authors = User::all().where_has(|u| u.posts, |q| q.where_col(|p| p.published.equal(true)));
+    /// ```
+    pub fn where_has<R, Ship>(
+        self,
+        relationship: impl Fn(<T as HasRelations>::Relation) -> Ship,
+        filter: impl FnOnce(QueryBuilder<R>) -> QueryBuilder<R>,
+    ) -> Self
+    where
+        T: HasRelations,
+        Ship: Relationship<R>,
+        R: Send + Sync + HasSchema + SoftDelete,
+        <R as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasRelations>::Relation: Default,
+    {
+        self.where_relation(relationship, filter(QueryBuilder::scoped()))
+    }
+
+    /// Filter to the rows that don't have any related rows matching the filter (NOT EXISTS)
+    /// ```rust,ignore
+    /// let lurkers = User::all().where_doesnt_have(|u| u.posts, |q| q.where_col(|p| p.published.equal(true)));
+    /// ```
+    pub fn where_doesnt_have<R, Ship>(
+        self,
+        relationship: impl Fn(<T as HasRelations>::Relation) -> Ship,
+        filter: impl FnOnce(QueryBuilder<R>) -> QueryBuilder<R>,
+    ) -> Self
+    where
+        T: HasRelations,
+        Ship: Relationship<R>,
+        R: Send + Sync + HasSchema + SoftDelete,
+        <R as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasRelations>::Relation: Default,
+    {
+        self.where_not_in_relation(relationship, filter(QueryBuilder::scoped()))
+    }
+
+    fn related<R, Ship>(
+        relationship: impl Fn(<T as HasRelations>::Relation) -> Ship,
+        filter: QueryBuilder<R>,
+    ) -> ExistIn
+    where
+        T: HasRelations,
+        Ship: Relationship<R>,
+        R: Send + Sync + HasSchema,
+        <R as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasRelations>::Relation: Default,
     {
         let ship = relationship(Default::default());
         let out_col = ship.my_key::<R::Schema, T::Schema>();
-        let inner_tn = <R as HasSchema>::Schema::identifier();
-        let inner_tn = inner_tn.join(".");
+        let inner_tn = <R as HasSchema>::Schema::identifier().join(".");
         let inner_col = ship.their_key::<R::Schema, T::Schema>();
-        let mut exist_in = ExistIn::new(filter, out_col, inner_tn, inner_col);
+        ExistIn::new(filter, out_col, inner_tn, inner_col)
+    }
+
+    /// Filter on the number of rows in a relationship, counted with a correlated sub-query
+    /// ```rust,ignore
+    /// let regulars = Customer::all().where_relation_count(|c| c.orders, |count| count.gt(5));
+    /// ```
+    pub fn where_relation_count<R, Ship>(
+        mut self,
+        relationship: impl Fn(<T as HasRelations>::Relation) -> Ship,
+        lam: impl Fn(RelatedCount) -> Box<dyn ClauseAdder>,
+    ) -> Self
+    where
+        T: HasRelations,
+        Ship: Relationship<R>,
+        R: Send + Sync + HasSchema,
+        <R as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasRelations>::Relation: Default,
+    {
+        let ship = relationship(Default::default());
+        let out_col = ship.my_key::<R::Schema, T::Schema>();
+        let inner_tn = <R as HasSchema>::Schema::identifier().join(".");
+        let inner_col = ship.their_key::<R::Schema, T::Schema>();
+        let count = RelatedCount::new(out_col, inner_tn, inner_col);
+        self.wheres.push(Arc::from(lam(count)));
+        self
+    }
+
+    /// Filter to the rows where the sub-query finds at least one row (EXISTS)
+    ///
+    /// `on` correlates the sub-query to this query, (column of this table, column of the sub-query)
+    /// ```rust,ignore
+    /// let with_open_orders = Customer::all().where_exists(
+    ///     Order::where_col(|o| o.status.equal("open")),
+    ///     |c, o| (c.id, o.customer_id),
+    /// );
+    /// ```
+    pub fn where_exists<R, V1, V2, OUTER, INNER>(
+        self,
+        subquery: QueryBuilder<R>,
+        on: impl Fn(<T as HasSchema>::Schema, <R as HasSchema>::Schema) -> (OUTER, INNER),
+    ) -> Self
+    where
+        R: Send + HasSchema,
+        <R as HasSchema>::Schema: TableInfo,
+        OUTER: AsFieldName<V1>,
+        INNER: AsFieldName<V2>,
+    {
+        let exist_in = Self::correlated(subquery, on);
+        self.push_exists(exist_in)
+    }
+
+    /// Filter to the rows where the sub-query doesn't find any rows (NOT EXISTS)
+    ///
+    /// `on` correlates the sub-query to this query, (column of this table, column of the sub-query)
+    /// ```rust,ignore
+    /// let never_ordered = Customer::all().where_not_exists(Order::all(), |c, o| (c.id, o.customer_id));
+    /// ```
+    pub fn where_not_exists<R, V1, V2, OUTER, INNER>(
+        self,
+        subquery: QueryBuilder<R>,
+        on: impl Fn(<T as HasSchema>::Schema, <R as HasSchema>::Schema) -> (OUTER, INNER),
+    ) -> Self
+    where
+        R: Send + HasSchema,
+        <R as HasSchema>::Schema: TableInfo,
+        OUTER: AsFieldName<V1>,
+        INNER: AsFieldName<V2>,
+    {
+        let exist_in = Self::correlated(subquery, on).negate();
+        self.push_exists(exist_in)
+    }
+
+    fn correlated<R, V1, V2, OUTER, INNER>(
+        subquery: QueryBuilder<R>,
+        on: impl Fn(<T as HasSchema>::Schema, <R as HasSchema>::Schema) -> (OUTER, INNER),
+    ) -> ExistIn
+    where
+        R: Send + HasSchema,
+        <R as HasSchema>::Schema: TableInfo,
+        OUTER: AsFieldName<V1>,
+        INNER: AsFieldName<V2>,
+    {
+        let (outer, inner) = on(Default::default(), Default::default());
+        let inner_tn = <R as HasSchema>::Schema::identifier().join(".");
+        ExistIn::new(
+            subquery,
+            outer.colname().to_string(),
+            inner_tn,
+            inner.colname().to_string(),
+        )
+    }
+
+    fn push_exists(mut self, mut exist_in: ExistIn) -> Self {
         exist_in.set_aliases(&self.alias_asigner);
         self.exist_ins.push(exist_in);
         self
@@ -157,13 +477,13 @@ where
         T: HasRelations,
         Ship: Relationship<R>,
         T: HasSchema,
-        R: Send + Sync + HasSchema,
+        R: Send + Sync + HasSchema + SoftDelete,
         <R as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
         <T as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
         <T as HasRelations>::Relation: Default,
     {
         let ship = relationship(Default::default());
-        let mut qb: QueryBuilder<R> = QueryBuilder::new();
+        let mut qb: QueryBuilder<R> = QueryBuilder::scoped();
         qb.set_aliases(&self.alias_asigner);
 
         let out_col = ship.their_key::<R::Schema, T::Schema>();
@@ -183,6 +503,49 @@ where
         }
     }
 
+    /// Splits this query into queries that each fit in the params the database allows,
+    /// by splitting up its longest list of values. I.E. an `in_list` of 50k ids
+    ///
+    /// Returns None if the query already fits.
+    /// The rows of the queries can't be ordered or limited together, so those are never split.
+    /// A query that doesn't fit and can't be split is an error, before it is sent to the database
+    pub(crate) fn split_for_params(&self, syntax: Syntax) -> Result<Option<Vec<QueryBuilder<T>>>> {
+        let count = |clause: &dyn ClauseAdder| {
+            let mut args = Vec::default();
+            clause.bind(&mut args);
+            args.len()
+        };
+        let counts: Vec<usize> = self.wheres.iter().map(|w| count(w.as_ref())).collect();
+        let exists: usize = self.exist_ins.iter().map(|e| count(e)).sum();
+        let total = counts.iter().sum::<usize>() + exists;
+        let max = NextParam::new(syntax).max_params() as usize;
+        if total <= max {
+            return Ok(None);
+        }
+        let too_many = WeldsError::TooManyParams(total, max);
+        if !self.orderby.is_empty() || self.limit.is_some() || self.offset.is_some() {
+            return Err(too_many);
+        }
+
+        // the longest list that can be split, the other params are in every chunk
+        let mut longest: Vec<usize> = (0..counts.len()).collect();
+        longest.sort_by_key(|i| std::cmp::Reverse(counts[*i]));
+        let queries = longest.into_iter().find_map(|i| {
+            let size = max.checked_sub(total - counts[i])?;
+            let chunks = self.wheres[i].split(size)?;
+            let queries = chunks
+                .into_iter()
+                .map(|chunk| {
+                    let mut q = self.clone();
+                    q.wheres[i] = chunk;
+                    q
+                })
+                .collect();
+            Some(queries)
+        });
+        queries.map(Some).ok_or(too_many)
+    }
+
     /// Limit the number of rows returned by this query
     pub fn limit(mut self, x: i64) -> Self {
         self.limit = Some(x);
@@ -195,6 +558,38 @@ where
         self
     }
 
+    /// Only return unique rows (SELECT DISTINCT)
+    pub fn distinct(mut self) -> Self {
+        self.distinct = Some(Distinct::Rows);
+        self
+    }
+
+    /// Only return the first row for each unique value of a column (SELECT DISTINCT ON)
+    ///
+    /// multiple calls will make rows unique on multiple columns
+    ///
+    /// NOTE: only supported by Postgres. The ORDER BY of the query must start
+    /// with the same columns, the rest of the ORDER BY picks the row that is kept
+    /// ```rust,ignore
+    /// // the latest order for each customer
+    /// Order::all()
+    ///     .distinct_on(|o| o.customer_id)
+    ///     .order_by_asc(|o| o.customer_id)
+    ///     .order_by_desc(|o| o.placed_at)
+    /// ```
+    pub fn distinct_on<V, FN: AsFieldName<V>>(
+        mut self,
+        lam: impl Fn(<T as HasSchema>::Schema) -> FN,
+    ) -> Self {
+        let field = lam(Default::default());
+        let colname = field.colname().to_string();
+        match &mut self.distinct {
+            Some(Distinct::On(cols)) => cols.push(colname),
+            _ => self.distinct = Some(Distinct::On(vec![colname])),
+        }
+        self
+    }
+
     /// Order the results of the query by a given column
     ///
     /// multiple calls will result in multiple OrderBys
@@ -217,10 +612,8 @@ where
         lam: impl Fn(<T as HasSchema>::Schema) -> FN,
     ) -> Self {
         let field = lam(Default::default());
-        let colname = field.colname();
-        let colnull = format!("{colname} is null");
-        self.orderby.push(OrderBy::new(colnull, "ASC"));
-        self.orderby.push(OrderBy::new(colname, "DESC"));
+        self.orderby
+            .push(OrderExpr::col(field).desc().nulls_last().into());
         self
     }
 
@@ -246,10 +639,111 @@ where
         lam: impl Fn(<T as HasSchema>::Schema) -> FN,
     ) -> Self {
         let field = lam(Default::default());
-        let colname = field.colname();
-        let colnull = format!("{colname} is null");
-        self.orderby.push(OrderBy::new(colnull, "DESC"));
-        self.orderby.push(OrderBy::new(colname, "ASC"));
+        self.orderby
+            .push(OrderExpr::col(field).asc().nulls_first().into());
+        self
+    }
+
+    /// Order the results of the query by an expression.
+    /// NULLS FIRST/LAST is emulated on databases without it
+    /// ```rust,ignore
+    /// let people = Person::all().order_by(|p| OrderExpr::lower(p.name).desc().nulls_last());
+    /// ```
+    ///
+    /// multiple calls will result in multiple OrderBys
+    pub fn order_by(mut self, lam: impl Fn(<T as HasSchema>::Schema) -> OrderExpr) -> Self {
+        self.orderby.push(lam(Default::default()).into());
+        self
+    }
+
+    /// Shuffle the results of the query into a random order
+    pub fn order_random(mut self) -> Self {
+        self.orderby.push(OrderBy::random());
+        self
+    }
+
+    /// Pick `n` random rows from the results of the query
+    ///
+    /// NOTE: every row is shuffled, see `sample_percent` for large tables
+    pub fn sample(self, n: i64) -> Self {
+        self.order_random().limit(n)
+    }
+
+    /// Keep roughly `percent` of the rows, each row is picked at random
+    ///
+    /// The rows are filtered in the WHERE so it also works on sub-queries, updates and deletes.
+    /// TABLESAMPLE isn't used, it samples whole pages of the table on Postgres and MSSQL
+    pub fn sample_percent(mut self, percent: f64) -> Self {
+        let fraction = percent.clamp(0.0, 100.0) / 100.0;
+        self.wheres
+            .push(Arc::new(clause::ClauseSample { fraction }));
+        self
+    }
+
+    /// Lock the rows read by this query until the end of the transaction.
+    /// ```rust,ignore
+    /// // take the next job no other worker has
+    /// let jobs = Job::where_col(|j| j.done.equal(false))
+    ///     .limit(1)
+    ///     .lock(LockMode::ForUpdate)
+    ///     .skip_locked()
+    ///     .run(&trans)
+    ///     .await?;
+    /// ```
+    ///
+    /// MSSQL uses table hints `WITH (UPDLOCK, ROWLOCK)`.
+    /// NOTE: Sqlite has no row locks and ignores this
+    pub fn lock(mut self, mode: LockMode) -> Self {
+        self.lock = Some(Lock::new(mode));
+        self
+    }
+
+    /// Error instead of waiting when a row is already locked (NOWAIT).
+    /// Locks FOR UPDATE if no lock was given
+    pub fn nowait(self) -> Self {
+        self.lock_wait(LockWait::NoWait)
+    }
+
+    /// Leave rows locked by other transactions out of the results (SKIP LOCKED / READPAST).
+    /// Locks FOR UPDATE if no lock was given
+    pub fn skip_locked(self) -> Self {
+        self.lock_wait(LockWait::SkipLocked)
+    }
+
+    fn lock_wait(mut self, wait: LockWait) -> Self {
+        let mut lock = self.lock.unwrap_or(Lock::new(LockMode::ForUpdate));
+        lock.wait = wait;
+        self.lock = Some(lock);
+        self
+    }
+
+    /// Give the query planner a hint, for the rare query the database plans badly.
+    /// ```rust,ignore
+    /// let orders = Order::where_col(|o| o.customer_id.equal(id))
+    ///     .hint(Hint::use_index("idx_orders_customer_id"))
+    ///     .run(client)
+    ///     .await?;
+    /// ```
+    ///
+    /// Hints are only written for the databases that have them. See `Hint`
+    pub fn hint(mut self, hint: Hint) -> Self {
+        self.hints.push(hint);
+        self
+    }
+
+    /// Limit how long the database can spend running this query.
+    /// A query that runs out of time fails with `WeldsError::Timeout`
+    /// ```rust,ignore
+    /// let orders = Order::all().timeout(Duration::from_secs(2)).run(client).await?;
+    /// ```
+    ///
+    /// The limit is the `statement_timeout` on Postgres, and `max_execution_time` on MySQL
+    /// which only stops SELECTs. MSSQL (`LOCK_TIMEOUT`) and Sqlite (`busy_timeout`)
+    /// only limit the time spent waiting on locks.
+    /// Also used by the joins, keyset pages, and the inserts, updates and deletes made from this query.
+    /// Rows streamed from the server, unions, and models saved or upserted one at a time are not limited
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
         self
     }
 
@@ -268,6 +762,41 @@ where
         sb.select(lam)
     }
 
+    /// Select only the columns of a Dto. Read the results with `run_as::<D>()`
+    /// ```rust,ignore
+    /// #[derive(WeldsDto)]
+    /// #[welds(model(Product))]
+    /// struct ProductName {
+    ///     pub id: i32,
+    ///     pub name: String,
+    /// }
+    /// let names = Product::all().select_as::<ProductName>().run_as::<ProductName>(client).await?;
+    /// ```
+    pub fn select_as<D>(self) -> SelectBuilder<T>
+    where
+        D: Dto<Model = T>,
+    {
+        D::select(SelectBuilder::new(self))
+    }
+
+    /// Select all the columns of the model.
+    /// Useful when combining the query with other queries
+    pub fn select_all(self) -> SelectBuilder<T>
+    where
+        <T as HasSchema>::Schema: TableColumns,
+    {
+        SelectBuilder::new(self).select_all()
+    }
+
+    /// Group the rows by a column, selecting only the specific columns/aggregates
+    pub fn group_by<V, FN: AsFieldName<V>>(
+        self,
+        lam: impl Fn(<T as HasSchema>::Schema) -> FN,
+    ) -> SelectBuilder<T> {
+        let sb = SelectBuilder::new(self);
+        sb.group_by(lam)
+    }
+
     /// Filter the results returned by this query.
     /// Used when you want to filter on the columns of this table.
     pub fn set<V, FIELD>(
@@ -283,4 +812,20 @@ where
         let ub = UpdateBuilder::new(self);
         ub.set(lam, value)
     }
+
+    /// Start a bulk update setting a column to an expression of the columns already in the row.
+    /// See `UpdateBuilder::set_expr`
+    pub fn set_expr<V, FIELD, E>(
+        self,
+        lam: impl Fn(<T as HasSchema>::Schema) -> FIELD,
+        expr: impl Fn(<T as HasSchema>::Schema) -> E,
+    ) -> UpdateBuilder<T>
+    where
+        <T as HasSchema>::Schema: Default,
+        FIELD: AsFieldName<V>,
+        E: Into<Expr>,
+    {
+        let ub = UpdateBuilder::new(self);
+        ub.set_expr(lam, expr)
+    }
 }
@@ -0,0 +1,35 @@
+use super::QueryBuilder;
+
+/// A reusable set of filters that can be applied to a query.
+///
+/// Any function taking and returning a `QueryBuilder` is a scope.
+/// ```rust,ignore
+/// fn active(q: QueryBuilder<User>) -> QueryBuilder<User> {
+///     q.where_col(|u| u.active.equal(true))
+/// }
+/// let users = User::all().scope(active).run(client).await?;
+/// ```
+///
+/// Implement it on a struct to give a scope its own arguments
+/// ```rust,ignore
+/// struct CreatedAfter(i64);
+///
+/// impl Scope<User> for CreatedAfter {
+///     fn apply(self, q: QueryBuilder<User>) -> QueryBuilder<User> {
+///         q.where_col(|u| u.created_at.gt(self.0))
+///     }
+/// }
+/// let users = User::all().scope(CreatedAfter(since)).run(client).await?;
+/// ```
+pub trait Scope<T> {
+    fn apply(self, q: QueryBuilder<T>) -> QueryBuilder<T>;
+}
+
+impl<T, F> Scope<T> for F
+where
+    F: FnOnce(QueryBuilder<T>) -> QueryBuilder<T>,
+{
+    fn apply(self, q: QueryBuilder<T>) -> QueryBuilder<T> {
+        self(q)
+    }
+}
@@ -0,0 +1,386 @@
+use super::{AsFieldName, ClauseAdder, ClauseColVal, ParamArgs};
+use crate::query::optional::HasSomeNone;
+use crate::query::optional::Optional;
+use crate::writers::NextParam;
+use crate::Syntax;
+use std::marker::PhantomData;
+use welds_connections::Param;
+
+/// The type of the items in an array column
+pub trait ArrayElement {
+    type Element;
+}
+
+impl<X> ArrayElement for Vec<X> {
+    type Element = X;
+}
+
+/// Clauses for array columns (Vec<T>)
+///
+/// NOTE: the array operators are only supported by Postgres
+pub struct Array<T> {
+    col: String,
+    field: String,
+    _t: PhantomData<T>,
+}
+
+impl<T> Clone for Array<T> {
+    fn clone(&self) -> Self {
+        Self {
+            col: self.col.clone(),
+            field: self.field.clone(),
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<T> AsFieldName<T> for Array<T> {
+    fn colname(&self) -> &str {
+        self.col.as_str()
+    }
+    fn fieldname(&self) -> &str {
+        self.field.as_str()
+    }
+}
+
+impl<T> Array<T> {
+    pub fn new(col: impl Into<String>, field: impl Into<String>) -> Self {
+        Self {
+            col: col.into(),
+            field: field.into(),
+            _t: Default::default(),
+        }
+    }
+}
+
+impl<T> Array<T>
+where
+    T: 'static + Clone + Send + Sync + ArrayElement,
+    <T as ArrayElement>::Element: 'static + Clone + Send + Sync + Param,
+    Vec<<T as ArrayElement>::Element>: Param,
+{
+    /// Will write SQL checking the value is equal to this (==)
+    pub fn equal(self, v: impl Into<T>) -> Box<dyn ClauseAdder>
+    where
+        T: Param,
+    {
+        let cv = ClauseColVal::<T> {
+            null_clause: false,
+            not_clause: false,
+            col: self.col,
+            operator: "=",
+            val: Some(v.into()),
+        };
+        Box::new(cv)
+    }
+
+    /// Will write SQL checking the value is equal to this (!=)
+    pub fn not_equal(self, v: impl Into<T>) -> Box<dyn ClauseAdder>
+    where
+        T: Param,
+    {
+        let cv = ClauseColVal::<T> {
+            null_clause: false,
+            not_clause: true,
+            col: self.col,
+            operator: "!=",
+            val: Some(v.into()),
+        };
+        Box::new(cv)
+    }
+
+    /// Will write SQL checking the array has this item (= ANY(col))
+    pub fn has(self, v: impl Into<<T as ArrayElement>::Element>) -> Box<dyn ClauseAdder> {
+        has(self.col, v.into())
+    }
+
+    /// Will write SQL checking the array has all of these items (@>)
+    pub fn contains<P>(self, items: &[P]) -> Box<dyn ClauseAdder>
+    where
+        P: Into<<T as ArrayElement>::Element> + Clone,
+    {
+        compare(self.col, "@>", items)
+    }
+
+    /// Will write SQL checking all the items in the array are in these items (<@)
+    pub fn contained_by<P>(self, items: &[P]) -> Box<dyn ClauseAdder>
+    where
+        P: Into<<T as ArrayElement>::Element> + Clone,
+    {
+        compare(self.col, "<@", items)
+    }
+
+    /// Will write SQL checking the array has any of these items (&&)
+    pub fn overlaps<P>(self, items: &[P]) -> Box<dyn ClauseAdder>
+    where
+        P: Into<<T as ArrayElement>::Element> + Clone,
+    {
+        compare(self.col, "&&", items)
+    }
+
+    /// Filter on the number of items in the array. An empty array has a length of 0
+    pub fn len(self) -> ArrayLength {
+        ArrayLength { col: self.col }
+    }
+}
+
+/// Clauses for nullable array columns (Option<Vec<T>>)
+///
+/// NOTE: the array operators are only supported by Postgres
+pub struct ArrayOpt<T> {
+    col: String,
+    field: String,
+    _t: PhantomData<T>,
+}
+
+impl<T> Clone for ArrayOpt<T> {
+    fn clone(&self) -> Self {
+        Self {
+            col: self.col.clone(),
+            field: self.field.clone(),
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<T> AsFieldName<T> for ArrayOpt<T> {
+    fn colname(&self) -> &str {
+        self.col.as_str()
+    }
+    fn fieldname(&self) -> &str {
+        self.field.as_str()
+    }
+}
+
+impl<T> ArrayOpt<T> {
+    pub fn new(col: impl Into<String>, field: impl Into<String>) -> Self {
+        Self {
+            col: col.into(),
+            field: field.into(),
+            _t: Default::default(),
+        }
+    }
+}
+
+impl<T> ArrayOpt<T>
+where
+    T: 'static + Clone + Send + Sync + ArrayElement,
+    <T as ArrayElement>::Element: 'static + Clone + Send + Sync + Param,
+    Vec<<T as ArrayElement>::Element>: Param,
+{
+    /// Will write SQL checking the value is equal to this (==)
+    pub fn equal(self, v: impl Into<Optional<T>>) -> Box<dyn ClauseAdder>
+    where
+        T: Param,
+    {
+        let opt = v.into();
+        let is_none = opt.is_none();
+        let val: Option<T> = opt.into();
+        let cv = ClauseColVal::<T> {
+            null_clause: is_none,
+            not_clause: false,
+            col: self.col,
+            operator: "=",
+            val,
+        };
+        Box::new(cv)
+    }
+
+    /// Will write SQL checking the value is equal to this (!=)
+    pub fn not_equal(self, v: impl Into<Optional<T>>) -> Box<dyn ClauseAdder>
+    where
+        T: Param,
+    {
+        let opt = v.into();
+        let is_none = opt.is_none();
+        let val: Option<T> = opt.into();
+        let cv = ClauseColVal::<T> {
+            null_clause: is_none,
+            not_clause: true,
+            col: self.col,
+            operator: "!=",
+            val,
+        };
+        Box::new(cv)
+    }
+
+    /// Will write SQL checking the array has this item (= ANY(col))
+    pub fn has(self, v: impl Into<<T as ArrayElement>::Element>) -> Box<dyn ClauseAdder> {
+        has(self.col, v.into())
+    }
+
+    /// Will write SQL checking the array has all of these items (@>)
+    pub fn contains<P>(self, items: &[P]) -> Box<dyn ClauseAdder>
+    where
+        P: Into<<T as ArrayElement>::Element> + Clone,
+    {
+        compare(self.col, "@>", items)
+    }
+
+    /// Will write SQL checking all the items in the array are in these items (<@)
+    pub fn contained_by<P>(self, items: &[P]) -> Box<dyn ClauseAdder>
+    where
+        P: Into<<T as ArrayElement>::Element> + Clone,
+    {
+        compare(self.col, "<@", items)
+    }
+
+    /// Will write SQL checking the array has any of these items (&&)
+    pub fn overlaps<P>(self, items: &[P]) -> Box<dyn ClauseAdder>
+    where
+        P: Into<<T as ArrayElement>::Element> + Clone,
+    {
+        compare(self.col, "&&", items)
+    }
+
+    /// Filter on the number of items in the array. An empty or null array has a length of 0
+    pub fn len(self) -> ArrayLength {
+        ArrayLength { col: self.col }
+    }
+}
+
+fn has<V>(col: String, val: V) -> Box<dyn ClauseAdder>
+where
+    V: 'static + Send + Sync + Param,
+{
+    Box::new(ClauseArray {
+        col,
+        val,
+        write: |col, param| format!("{} = ANY({})", param, col),
+    })
+}
+
+fn compare<P, V>(col: String, operator: &'static str, items: &[P]) -> Box<dyn ClauseAdder>
+where
+    P: Into<V> + Clone,
+    V: 'static + Send + Sync,
+    Vec<V>: Param,
+{
+    let val: Vec<V> = items.iter().map(|x| x.clone().into()).collect();
+    let write = match operator {
+        "@>" => |col: &str, param: &str| format!("{} @> {}", col, param),
+        "<@" => |col: &str, param: &str| format!("{} <@ {}", col, param),
+        _ => |col: &str, param: &str| format!("{} && {}", col, param),
+    };
+    Box::new(ClauseArray { col, val, write })
+}
+
+/// The number of items in an array column
+pub struct ArrayLength {
+    col: String,
+}
+
+impl ArrayLength {
+    fn clause(self, operator: &'static str, len: i32) -> Box<dyn ClauseAdder> {
+        let write = match operator {
+            "=" => |col: &str, p: &str| format!("{} = {}", array_length(col), p),
+            "!=" => |col: &str, p: &str| format!("{} != {}", array_length(col), p),
+            ">" => |col: &str, p: &str| format!("{} > {}", array_length(col), p),
+            ">=" => |col: &str, p: &str| format!("{} >= {}", array_length(col), p),
+            "<" => |col: &str, p: &str| format!("{} < {}", array_length(col), p),
+            _ => |col: &str, p: &str| format!("{} <= {}", array_length(col), p),
+        };
+        Box::new(ClauseArray {
+            col: self.col,
+            val: len,
+            write,
+        })
+    }
+
+    /// Will write SQL checking the length is equal to this (==)
+    pub fn equal(self, len: i32) -> Box<dyn ClauseAdder> {
+        self.clause("=", len)
+    }
+
+    /// Will write SQL checking the length is not equal to this (!=)
+    pub fn not_equal(self, len: i32) -> Box<dyn ClauseAdder> {
+        self.clause("!=", len)
+    }
+
+    /// Will write SQL checking the length is greater than (>)
+    pub fn gt(self, len: i32) -> Box<dyn ClauseAdder> {
+        self.clause(">", len)
+    }
+
+    /// Will write SQL checking the length is greater than or equal to (>=)
+    pub fn gte(self, len: i32) -> Box<dyn ClauseAdder> {
+        self.clause(">=", len)
+    }
+
+    /// Will write SQL checking the length is less than (<)
+    pub fn lt(self, len: i32) -> Box<dyn ClauseAdder> {
+        self.clause("<", len)
+    }
+
+    /// Will write SQL checking the length is less than or equal to (<=)
+    pub fn lte(self, len: i32) -> Box<dyn ClauseAdder> {
+        self.clause("<=", len)
+    }
+}
+
+/// array_length is null for an empty array
+fn array_length(col: &str) -> String {
+    format!("coalesce(array_length({}, 1), 0)", col)
+}
+
+/// A clause on an array column with a single param
+struct ClauseArray<V> {
+    col: String,
+    val: V,
+    write: fn(&str, &str) -> String,
+}
+
+impl<V> ClauseAdder for ClauseArray<V>
+where
+    V: Send + Sync + Param,
+{
+    fn bind<'lam, 'args, 'p>(&'lam self, args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+        args.push(&self.val);
+    }
+
+    fn clause(&self, _syntax: Syntax, alias: &str, next_params: &NextParam) -> Option<String> {
+        let col = format!("{}.{}", alias, self.col);
+        Some((self.write)(&col, &next_params.next()))
+    }
+}
+
+// array columns can only be bound when Postgres is the only database
+#[cfg(all(
+    test,
+    feature = "postgres",
+    not(feature = "sqlite"),
+    not(feature = "mysql"),
+    not(feature = "mssql")
+))]
+mod tests {
+    use crate::query::builder::QueryBuilder;
+    use crate::Syntax;
+    use crate::WeldsModel;
+
+    #[derive(Debug, WeldsModel)]
+    #[welds(table = "posts")]
+    #[welds_path(crate)] // needed only within the welds crate.
+    struct Post {
+        pub id: i32,
+        pub tags: Vec<String>,
+        pub scores: Option<Vec<i32>>,
+        pub blob: Vec<u8>,
+    }
+
+    #[test]
+    fn should_write_array_operators() {
+        let q = QueryBuilder::<Post>::new()
+            .where_col(|p| p.tags.has("rust"))
+            .where_col(|p| p.tags.contains(&["a", "b"]))
+            .where_col(|p| p.scores.overlaps(&[1, 2]))
+            .where_col(|p| p.tags.len().gt(2))
+            .where_col(|p| p.blob.equal(vec![1u8]));
+        assert_eq!(
+            q.to_sql(Syntax::Postgres),
+            "SELECT t1.\"id\", t1.\"tags\", t1.\"scores\", t1.\"blob\" FROM posts t1 WHERE ( $1 = ANY(t1.tags) AND t1.tags @> $2 AND t1.scores && $3 AND coalesce(array_length(t1.tags, 1), 0) > $4 AND t1.blob = $5 )"
+        );
+    }
+}
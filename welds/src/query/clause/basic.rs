@@ -1,4 +1,7 @@
-use super::{AsFieldName, ClauseAdder, ClauseColVal};
+use super::subquery::{in_cte, subquery};
+use super::{col_col, in_list, AsFieldName, ClauseAdder, ClauseColVal, InList};
+use crate::model_traits::{HasSchema, TableColumns, TableInfo};
+use crate::query::select_cols::SelectBuilder;
 use std::marker::PhantomData;
 use welds_connections::Param;
 
@@ -8,6 +11,16 @@ pub struct Basic<T> {
     _t: PhantomData<T>,
 }
 
+impl<T> Clone for Basic<T> {
+    fn clone(&self) -> Self {
+        Self {
+            col: self.col.clone(),
+            field: self.field.clone(),
+            _t: PhantomData,
+        }
+    }
+}
+
 impl<T> AsFieldName<T> for Basic<T> {
     fn colname(&self) -> &str {
         self.col.as_str()
@@ -56,4 +69,79 @@ where
         };
         Box::new(cv)
     }
+
+    /// Will write SQL checking the value is one of the values in the list (IN)
+    ///
+    /// A list too long for the params of the database is run in chunks.
+    /// Chunks can't be ordered or limited together, those queries fail with `WeldsError::TooManyParams`
+    pub fn in_list<P>(self, slice: &[P]) -> Box<dyn ClauseAdder>
+    where
+        P: Into<T> + Clone,
+        T: Param + PartialEq,
+    {
+        in_list(self.col, false, slice, InList::Params)
+    }
+
+    /// Will write SQL checking the value is one of the values in the list, written as told
+    /// ```rust,ignore
+    /// Order::where_col(|o| o.id.in_list_with(&ids, InList::ValuesOver(1000)))
+    /// ```
+    pub fn in_list_with<P>(self, slice: &[P], strategy: InList) -> Box<dyn ClauseAdder>
+    where
+        P: Into<T> + Clone,
+        T: Param + PartialEq,
+    {
+        in_list(self.col, false, slice, strategy)
+    }
+
+    /// Will write SQL checking the value is not any of the values in the list (NOT IN)
+    pub fn not_in_list<P>(self, slice: &[P]) -> Box<dyn ClauseAdder>
+    where
+        P: Into<T> + Clone,
+        T: Param + PartialEq,
+    {
+        in_list(self.col, true, slice, InList::Params)
+    }
+
+    /// Will write SQL checking the value is equal to another column of the row (=)
+    pub fn equal_col(self, other: impl AsFieldName<T>) -> Box<dyn ClauseAdder> {
+        col_col(self.col, "=", other)
+    }
+
+    /// Will write SQL checking the value is not equal to another column of the row (!=)
+    pub fn not_equal_col(self, other: impl AsFieldName<T>) -> Box<dyn ClauseAdder> {
+        col_col(self.col, "!=", other)
+    }
+
+    /// Will write SQL checking the value is in the results of a sub-query (IN)
+    pub fn in_query<R>(self, query: SelectBuilder<R>) -> Box<dyn ClauseAdder>
+    where
+        R: 'static + Send + Sync + HasSchema,
+        <R as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        subquery(self.col, "IN", query)
+    }
+
+    /// Will write SQL checking the value is not in the results of a sub-query (NOT IN)
+    pub fn not_in_query<R>(self, query: SelectBuilder<R>) -> Box<dyn ClauseAdder>
+    where
+        R: 'static + Send + Sync + HasSchema,
+        <R as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        subquery(self.col, "NOT IN", query)
+    }
+
+    /// Will write SQL checking the value is equal to the result of a scalar sub-query (=)
+    pub fn equal_query<R>(self, query: SelectBuilder<R>) -> Box<dyn ClauseAdder>
+    where
+        R: 'static + Send + Sync + HasSchema,
+        <R as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        subquery(self.col, "=", query)
+    }
+
+    /// Will write SQL checking the value is in a column of a query from the WITH block
+    pub fn in_cte(self, cte: &str, column: &str) -> Box<dyn ClauseAdder> {
+        in_cte(self.col, cte, column)
+    }
 }
@@ -0,0 +1,89 @@
+use super::{ClauseAdder, Expr, ParamArgs};
+use crate::writers::NextParam;
+use crate::Syntax;
+
+/// A `CASE` expression, the value of the first branch whose condition matches.
+///
+/// Can be selected, ordered by, or used to SET a column
+/// ```rust,ignore
+/// // open orders first, then new, then everything else
+/// let weight = |o: OrderSchema| {
+///     Case::new()
+///         .when(o.status.clone().equal("open"), 1)
+///         .when(o.status.equal("new"), 2)
+///         .otherwise(3)
+/// };
+/// Order::all().order_by(|o| OrderExpr::case(weight(o)));
+/// Order::all().select(|o| o.id).select_case("weight", weight);
+/// Order::all().set_expr(|o| o.weight, weight);
+/// ```
+/// Without an `otherwise` the rows no branch matches are NULL
+#[derive(Default)]
+pub struct Case {
+    whens: Vec<(Box<dyn ClauseAdder>, Expr)>,
+    otherwise: Option<Expr>,
+}
+
+impl Case {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a branch, used when the condition is true and no branch before it was
+    pub fn when(mut self, condition: Box<dyn ClauseAdder>, then: impl Into<Expr>) -> Self {
+        self.whens.push((condition, then.into()));
+        self
+    }
+
+    /// The value used when none of the branches match (ELSE)
+    pub fn otherwise(mut self, value: impl Into<Expr>) -> Self {
+        self.otherwise = Some(value.into());
+        self
+    }
+
+    /// Adds the values to the list of Arguments, in the order they are written
+    pub(crate) fn bind<'lam, 'args, 'p>(&'lam self, args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+        for (condition, then) in &self.whens {
+            condition.bind(args);
+            then.bind(args);
+        }
+        if let Some(otherwise) = &self.otherwise {
+            otherwise.bind(args);
+        }
+    }
+
+    pub(crate) fn write_as(
+        &self,
+        syntax: Syntax,
+        alias: &str,
+        next_params: &NextParam,
+        qualify: bool,
+    ) -> String {
+        let mut parts = vec!["CASE".to_owned()];
+        for (condition, then) in &self.whens {
+            // a condition that writes nothing is always true
+            let condition = condition
+                .clause(syntax, alias, next_params)
+                .unwrap_or_else(|| "1=1".to_owned());
+            let then = then.write_as(syntax, alias, next_params, qualify);
+            parts.push(format!("WHEN {} THEN {}", condition, then));
+        }
+        if let Some(otherwise) = &self.otherwise {
+            parts.push(format!(
+                "ELSE {}",
+                otherwise.write_as(syntax, alias, next_params, qualify)
+            ));
+        }
+        parts.push("END".to_owned());
+        parts.join(" ")
+    }
+}
+
+impl From<Case> for Expr {
+    fn from(case: Case) -> Self {
+        Expr::Case(Box::new(case))
+    }
+}
@@ -0,0 +1,209 @@
+use super::{ClauseAdder, ParamArgs};
+use crate::writers::NextParam;
+use crate::Syntax;
+use std::marker::PhantomData;
+use welds_connections::Param;
+
+// ******************************************************************************************
+// Date/time functions. Each database has its own functions so the SQL is written per syntax.
+// ******************************************************************************************
+
+/// A part of a date/time to truncate to or read out of a date
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatePart {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DateFunc {
+    Trunc(DatePart),
+    Extract(DatePart),
+    AgeDays,
+}
+
+/// A date/time function of a column. Compare it to a value to filter on it
+/// ```rust,ignore
+/// Order::where_col(|o| o.created_at.trunc(DatePart::Day).equal(today))
+/// Order::where_col(|o| o.created_at.age_days().gt(30))
+/// ```
+pub struct DateExpr<T> {
+    col: String,
+    func: DateFunc,
+    _t: PhantomData<T>,
+}
+
+impl<T> DateExpr<T>
+where
+    T: 'static + Clone + Send + Sync + Param,
+{
+    /// the column truncated down to the start of the part. I.E. midnight for `Day`
+    pub(crate) fn trunc(col: String, part: DatePart) -> Self {
+        Self::new(col, DateFunc::Trunc(part))
+    }
+
+    fn new(col: String, func: DateFunc) -> Self {
+        Self {
+            col,
+            func,
+            _t: PhantomData,
+        }
+    }
+
+    fn compare(self, operator: &'static str, val: T) -> Box<dyn ClauseAdder> {
+        Box::new(ClauseDate {
+            col: self.col,
+            func: self.func,
+            operator,
+            val,
+        })
+    }
+
+    /// Will write SQL checking the value is equal to this (==)
+    pub fn equal(self, v: impl Into<T>) -> Box<dyn ClauseAdder> {
+        self.compare("=", v.into())
+    }
+
+    /// Will write SQL checking the value is not equal to this (!=)
+    pub fn not_equal(self, v: impl Into<T>) -> Box<dyn ClauseAdder> {
+        self.compare("!=", v.into())
+    }
+
+    /// Will write SQL checking the value is greater than (>)
+    pub fn gt(self, v: impl Into<T>) -> Box<dyn ClauseAdder> {
+        self.compare(">", v.into())
+    }
+
+    /// Will write SQL checking the value is less than (<)
+    pub fn lt(self, v: impl Into<T>) -> Box<dyn ClauseAdder> {
+        self.compare("<", v.into())
+    }
+
+    /// Will write SQL checking the value is greater than or equal to (>=)
+    pub fn gte(self, v: impl Into<T>) -> Box<dyn ClauseAdder> {
+        self.compare(">=", v.into())
+    }
+
+    /// Will write SQL checking the value is less than or equal to (<=)
+    pub fn lte(self, v: impl Into<T>) -> Box<dyn ClauseAdder> {
+        self.compare("<=", v.into())
+    }
+}
+
+impl DateExpr<i32> {
+    /// the number of a part of the date. I.E. the month of the year
+    pub(crate) fn extract(col: String, part: DatePart) -> Self {
+        Self::new(col, DateFunc::Extract(part))
+    }
+
+    /// the number of days from the date to today
+    pub(crate) fn age_days(col: String) -> Self {
+        Self::new(col, DateFunc::AgeDays)
+    }
+}
+
+struct ClauseDate<T> {
+    col: String,
+    func: DateFunc,
+    operator: &'static str,
+    val: T,
+}
+
+impl<T> ClauseAdder for ClauseDate<T>
+where
+    T: Clone + Send + Sync + Param,
+{
+    fn bind<'lam, 'args, 'p>(&'lam self, args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+        args.push(&self.val);
+    }
+
+    fn clause(&self, syntax: Syntax, alias: &str, next_params: &NextParam) -> Option<String> {
+        let col = format!("{}.{}", alias, self.col);
+        let call = match self.func {
+            DateFunc::Trunc(part) => trunc(syntax, &col, part),
+            DateFunc::Extract(part) => extract(syntax, &col, part),
+            DateFunc::AgeDays => age_days(syntax, &col),
+        };
+        Some(format!("{} {} {}", call, self.operator, next_params.next()))
+    }
+}
+
+fn trunc(syntax: Syntax, col: &str, part: DatePart) -> String {
+    match syntax {
+        Syntax::Postgres => format!("date_trunc('{}', {})", part_name(part), col),
+        Syntax::Mysql => {
+            let format = match part {
+                DatePart::Year => "%Y-01-01 00:00:00",
+                DatePart::Month => "%Y-%m-01 00:00:00",
+                DatePart::Day => "%Y-%m-%d 00:00:00",
+                DatePart::Hour => "%Y-%m-%d %H:00:00",
+                DatePart::Minute => "%Y-%m-%d %H:%i:00",
+            };
+            format!("CAST(DATE_FORMAT({}, '{}') AS DATETIME)", col, format)
+        }
+        // count the whole parts since day 0 and add them back on
+        Syntax::Mssql => {
+            let part = part_name(part);
+            format!("DATEADD({}, DATEDIFF({}, 0, {}), 0)", part, part, col)
+        }
+        // dates are text in sqlite
+        Syntax::Sqlite => {
+            let format = match part {
+                DatePart::Year => "%Y-01-01 00:00:00",
+                DatePart::Month => "%Y-%m-01 00:00:00",
+                DatePart::Day => "%Y-%m-%d 00:00:00",
+                DatePart::Hour => "%Y-%m-%d %H:00:00",
+                DatePart::Minute => "%Y-%m-%d %H:%M:00",
+            };
+            format!("strftime('{}', {})", format, col)
+        }
+    }
+}
+
+fn extract(syntax: Syntax, col: &str, part: DatePart) -> String {
+    match syntax {
+        Syntax::Postgres | Syntax::Mysql => {
+            format!("EXTRACT({} FROM {})", part_name(part).to_uppercase(), col)
+        }
+        Syntax::Mssql => format!("DATEPART({}, {})", part_name(part), col),
+        Syntax::Sqlite => {
+            let format = match part {
+                DatePart::Year => "%Y",
+                DatePart::Month => "%m",
+                DatePart::Day => "%d",
+                DatePart::Hour => "%H",
+                DatePart::Minute => "%M",
+            };
+            format!("CAST(strftime('{}', {}) AS INTEGER)", format, col)
+        }
+    }
+}
+
+// whole calendar days, I.E. yesterday at 23:59 is one day ago
+fn age_days(syntax: Syntax, col: &str) -> String {
+    match syntax {
+        Syntax::Postgres => format!("(CURRENT_DATE - CAST({} AS DATE))", col),
+        Syntax::Mysql => format!("DATEDIFF(CURRENT_DATE, {})", col),
+        Syntax::Mssql => format!("DATEDIFF(day, {}, GETDATE())", col),
+        Syntax::Sqlite => format!(
+            "CAST(julianday(date('now')) - julianday(date({})) AS INTEGER)",
+            col
+        ),
+    }
+}
+
+fn part_name(part: DatePart) -> &'static str {
+    match part {
+        DatePart::Year => "year",
+        DatePart::Month => "month",
+        DatePart::Day => "day",
+        DatePart::Hour => "hour",
+        DatePart::Minute => "minute",
+    }
+}
@@ -1,3 +1,4 @@
+use super::orderby;
 use super::ClauseAdder;
 use crate::query::builder::QueryBuilder;
 use crate::query::clause::OrderBy;
@@ -8,17 +9,18 @@ use crate::Syntax;
 use std::sync::Arc;
 
 /// Used to generated a SQL EXISTS OR IN clause for writing sub-queries
-
+#[derive(Clone)]
 pub struct ExistIn {
     outer_column: String,
     inner_column: String,
     inner_tablename: String,
     pub(crate) inner_tablealias: String,
-    wheres: Vec<Box<dyn ClauseAdder>>,
+    wheres: Vec<Arc<dyn ClauseAdder>>,
     inner_exists_ins: Vec<Self>,
     limit: Option<i64>,
     offset: Option<i64>,
     orderby: Vec<OrderBy>,
+    negated: bool,
 }
 
 impl ExistIn {
@@ -38,9 +40,16 @@ impl ExistIn {
             limit: sb.limit,
             offset: sb.offset,
             orderby: sb.orderby,
+            negated: false,
         }
     }
 
+    /// flip to NOT EXISTS / NOT IN
+    pub(crate) fn negate(mut self) -> Self {
+        self.negated = true;
+        self
+    }
+
     // re-assign all the alias and alias for sub-tables
     pub(crate) fn set_aliases(&mut self, alias_asigner: &Arc<TableAlias>) {
         self.inner_tablealias = alias_asigner.next();
@@ -56,30 +65,59 @@ impl ExistIn {
         )
     }
 
-    fn tails(&self, syntax: Syntax) -> String {
+    fn tails(&self, syntax: Syntax, next_params: &NextParam) -> String {
         use crate::query::tail;
-        tail::write(syntax, &self.limit, &self.offset, &self.orderby).unwrap_or_default()
+        let alias = &self.inner_tablealias;
+        // the values of the orders are bound with the wheres
+        tail::write(
+            syntax,
+            &self.limit,
+            &self.offset,
+            &self.orderby,
+            alias,
+            next_params,
+            &mut None,
+        )
+        .unwrap_or_default()
     }
 
-    fn exists_clause(&self, syntax: Syntax, _tablealias: &str, inner_clauses: &str) -> String {
-        let tails = self.tails(syntax);
+    fn exists_clause(
+        &self,
+        syntax: Syntax,
+        next_params: &NextParam,
+        inner_clauses: &str,
+    ) -> String {
+        let tails = self.tails(syntax, next_params);
+        let not = if self.negated { "NOT " } else { "" };
         format!(
-            "EXISTS ( SELECT {} FROM {} {} WHERE {} {})",
-            self.inner_column, self.inner_tablename, self.inner_tablealias, inner_clauses, tails
+            "{}EXISTS ( SELECT {} FROM {} {} WHERE {} {})",
+            not,
+            self.inner_column,
+            self.inner_tablename,
+            self.inner_tablealias,
+            inner_clauses,
+            tails
         )
     }
 
-    fn in_clause(&self, syntax: Syntax, tablealias: &str, inner_clauses: &str) -> String {
+    fn in_clause(
+        &self,
+        syntax: Syntax,
+        tablealias: &str,
+        next_params: &NextParam,
+        inner_clauses: &str,
+    ) -> String {
         let outcol = format!("{}.{}", tablealias, self.outer_column);
         let innercol = format!("{}.{}", self.inner_tablealias, self.inner_column);
-        let tails = self.tails(syntax);
+        let tails = self.tails(syntax, next_params);
         let mut wheres = "".to_string();
         if !inner_clauses.is_empty() {
             wheres = format!("WHERE {}", inner_clauses);
         }
+        let not = if self.negated { "NOT " } else { "" };
         format!(
-            " {} IN (SELECT {} FROM {} {} {} {}) ",
-            outcol, innercol, self.inner_tablename, self.inner_tablealias, wheres, tails
+            " {} {}IN (SELECT {} FROM {} {} {} {}) ",
+            outcol, not, innercol, self.inner_tablename, self.inner_tablealias, wheres, tails
         )
     }
 }
@@ -95,6 +133,7 @@ impl ClauseAdder for ExistIn {
         for w in &self.inner_exists_ins {
             w.bind(args);
         }
+        orderby::bind(&self.orderby, args);
     }
 
     fn clause(&self, syntax: Syntax, alias: &str, next_params: &NextParam) -> Option<String> {
@@ -119,9 +158,9 @@ impl ClauseAdder for ExistIn {
 
         let inner_clauses = inner_wheres.join(" AND ");
         if using_in {
-            Some(self.in_clause(syntax, self_tablealias, &inner_clauses))
+            Some(self.in_clause(syntax, self_tablealias, next_params, &inner_clauses))
         } else {
-            Some(self.exists_clause(syntax, self_tablealias, &inner_clauses))
+            Some(self.exists_clause(syntax, next_params, &inner_clauses))
         }
     }
 }
@@ -0,0 +1,313 @@
+use super::{AsFieldName, Case, ClauseAdder, ParamArgs};
+use crate::writers::{ColumnWriter, NextParam};
+use crate::Syntax;
+use std::ops::{Add, Div, Mul, Sub};
+use welds_connections::Param;
+
+/// An expression of columns and values. Used to SET a column from the columns already in the row
+/// ```rust,ignore
+/// Post::all()
+///     .set_expr(|p| p.views, |p| Expr::col(p.views) + 1)
+///     .set_expr(|p| p.title, |p| Expr::col(p.title).coalesce(String::new()).concat("!".to_owned()))
+///     .run(client)
+///     .await?;
+/// ```
+/// Can also be filtered on, selected and ordered by
+/// ```rust,ignore
+/// Person::where_col(|p| Expr::col(p.nickname).coalesce(p.name).equal("bob"))
+///     .order_by(|p| OrderExpr::expr(Expr::col(p.nickname).coalesce(p.name)))
+///     .select(|p| p.id)
+///     .select_expr("display_name", |p| Expr::col(p.nickname).ifnull(p.name));
+/// ```
+pub enum Expr {
+    Col(String),
+    Value(Box<dyn Param + Send + Sync>),
+    /// Raw SQL, written as is
+    Sql(String),
+    Op(Box<Expr>, &'static str, Box<Expr>),
+    /// The first of the values that isn't NULL
+    Coalesce(Vec<Expr>),
+    /// NULL when both values are equal, otherwise the first value
+    NullIf(Box<Expr>, Box<Expr>),
+    /// The second value when the first is NULL
+    IfNull(Box<Expr>, Box<Expr>),
+    Concat(Box<Expr>, Box<Expr>),
+    Case(Box<Case>),
+}
+
+impl<V> From<V> for Expr
+where
+    V: 'static + Param + Send + Sync,
+{
+    fn from(value: V) -> Self {
+        Expr::Value(Box::new(value))
+    }
+}
+
+impl Expr {
+    /// The value of a column of the row
+    pub fn col<V>(field: impl AsFieldName<V>) -> Expr {
+        Expr::Col(field.colname().to_string())
+    }
+
+    /// A raw SQL snippet. NOTE: it is not escaped
+    pub fn sql(sql: impl Into<String>) -> Expr {
+        Expr::Sql(sql.into())
+    }
+
+    /// Use the other value when this is NULL.
+    /// Chaining adds to the same `COALESCE`, I.E. `COALESCE(a, b, c)`
+    pub fn coalesce(self, other: impl Into<Expr>) -> Expr {
+        match self {
+            Expr::Coalesce(mut values) => {
+                values.push(other.into());
+                Expr::Coalesce(values)
+            }
+            first => Expr::Coalesce(vec![first, other.into()]),
+        }
+    }
+
+    /// NULL when this is equal to the other value. I.E. to treat empty text as NULL
+    pub fn nullif(self, other: impl Into<Expr>) -> Expr {
+        Expr::NullIf(Box::new(self), Box::new(other.into()))
+    }
+
+    /// Use the other value when this is NULL.
+    /// Written as `IFNULL` on MySQL and Sqlite, `ISNULL` on MSSQL and `COALESCE` on Postgres
+    pub fn ifnull(self, other: impl Into<Expr>) -> Expr {
+        Expr::IfNull(Box::new(self), Box::new(other.into()))
+    }
+
+    /// Join the text of this and the other
+    pub fn concat(self, other: impl Into<Expr>) -> Expr {
+        Expr::Concat(Box::new(self), Box::new(other.into()))
+    }
+
+    fn op(self, op: &'static str, other: Expr) -> Expr {
+        Expr::Op(Box::new(self), op, Box::new(other))
+    }
+
+    pub fn equal(self, other: impl Into<Expr>) -> Box<dyn ClauseAdder> {
+        self.compare("=", Some(other.into()))
+    }
+
+    pub fn not_equal(self, other: impl Into<Expr>) -> Box<dyn ClauseAdder> {
+        self.compare("!=", Some(other.into()))
+    }
+
+    pub fn gt(self, other: impl Into<Expr>) -> Box<dyn ClauseAdder> {
+        self.compare(">", Some(other.into()))
+    }
+
+    pub fn lt(self, other: impl Into<Expr>) -> Box<dyn ClauseAdder> {
+        self.compare("<", Some(other.into()))
+    }
+
+    pub fn gte(self, other: impl Into<Expr>) -> Box<dyn ClauseAdder> {
+        self.compare(">=", Some(other.into()))
+    }
+
+    pub fn lte(self, other: impl Into<Expr>) -> Box<dyn ClauseAdder> {
+        self.compare("<=", Some(other.into()))
+    }
+
+    pub fn is_null(self) -> Box<dyn ClauseAdder> {
+        self.compare("IS NULL", None)
+    }
+
+    pub fn not_null(self) -> Box<dyn ClauseAdder> {
+        self.compare("IS NOT NULL", None)
+    }
+
+    fn compare(self, operator: &'static str, other: Option<Expr>) -> Box<dyn ClauseAdder> {
+        Box::new(ClauseExpr {
+            expr: self,
+            operator,
+            other,
+        })
+    }
+
+    /// Adds the values to the list of Arguments, in the order they are written
+    pub(crate) fn bind<'lam, 'args, 'p>(&'lam self, args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+        match self {
+            Expr::Col(_) | Expr::Sql(_) => {}
+            Expr::Value(v) => args.push(v.as_ref()),
+            Expr::Op(a, _, b) | Expr::NullIf(a, b) | Expr::IfNull(a, b) | Expr::Concat(a, b) => {
+                a.bind(args);
+                b.bind(args);
+            }
+            Expr::Coalesce(values) => {
+                for v in values {
+                    v.bind(args);
+                }
+            }
+            Expr::Case(case) => case.bind(args),
+        }
+    }
+
+    /// Writes the columns as the columns of the row being SET. I.E. `"views" + 1`
+    pub(crate) fn write(&self, syntax: Syntax, alias: &str, next_params: &NextParam) -> String {
+        self.write_as(syntax, alias, next_params, false)
+    }
+
+    /// Writes the columns with the alias of their table. I.E. `t1.views + 1`
+    pub(crate) fn write_qualified(
+        &self,
+        syntax: Syntax,
+        alias: &str,
+        next_params: &NextParam,
+    ) -> String {
+        self.write_as(syntax, alias, next_params, true)
+    }
+
+    pub(crate) fn write_as(
+        &self,
+        syntax: Syntax,
+        alias: &str,
+        next_params: &NextParam,
+        qualify: bool,
+    ) -> String {
+        let writer = ColumnWriter::new(syntax);
+        let write = |e: &Expr| e.write_as(syntax, alias, next_params, qualify);
+        let nested = |e: &Expr| {
+            let sql = write(e);
+            // operators inside of operators are wrapped so they keep their order
+            match e {
+                Expr::Op(..) | Expr::Concat(..) => format!("({})", sql),
+                _ => sql,
+            }
+        };
+        match self {
+            Expr::Col(col) if qualify && !alias.is_empty() => format!("{}.{}", alias, col),
+            Expr::Col(col) => writer.excape(col),
+            Expr::Value(_) => next_params.next(),
+            Expr::Sql(sql) => sql.clone(),
+            Expr::Op(a, op, b) => format!("{} {} {}", nested(a), op, nested(b)),
+            Expr::Coalesce(values) => {
+                let values: Vec<String> = values.iter().map(write).collect();
+                format!("COALESCE({})", values.join(", "))
+            }
+            Expr::NullIf(a, b) => format!("NULLIF({}, {})", write(a), write(b)),
+            Expr::IfNull(a, b) => {
+                let func = match syntax {
+                    Syntax::Mysql | Syntax::Sqlite => "IFNULL",
+                    Syntax::Mssql => "ISNULL",
+                    Syntax::Postgres => "COALESCE",
+                };
+                format!("{}({}, {})", func, write(a), write(b))
+            }
+            Expr::Concat(a, b) => {
+                let (a, b) = (nested(a), nested(b));
+                match syntax {
+                    Syntax::Postgres | Syntax::Sqlite => format!("{} || {}", a, b),
+                    Syntax::Mysql | Syntax::Mssql => format!("CONCAT({}, {})", a, b),
+                }
+            }
+            Expr::Case(case) => case.write_as(syntax, alias, next_params, qualify),
+        }
+    }
+}
+
+macro_rules! impl_from_column {
+    ($($column:ident),+) => {
+        $(
+            impl<T> From<super::$column<T>> for Expr {
+                fn from(field: super::$column<T>) -> Self {
+                    Expr::Col(AsFieldName::<T>::colname(&field).to_string())
+                }
+            }
+        )+
+    };
+}
+
+// columns can be used as is where an Expr is expected. I.E. `Expr::col(p.nickname).coalesce(p.name)`
+impl_from_column!(
+    Basic, BasicOpt, Numeric, NumericOpt, Text, TextOpt, Json, JsonOpt, Array, ArrayOpt
+);
+
+impl<R: Into<Expr>> Add<R> for Expr {
+    type Output = Expr;
+    fn add(self, other: R) -> Expr {
+        self.op("+", other.into())
+    }
+}
+
+impl<R: Into<Expr>> Sub<R> for Expr {
+    type Output = Expr;
+    fn sub(self, other: R) -> Expr {
+        self.op("-", other.into())
+    }
+}
+
+impl<R: Into<Expr>> Mul<R> for Expr {
+    type Output = Expr;
+    fn mul(self, other: R) -> Expr {
+        self.op("*", other.into())
+    }
+}
+
+impl<R: Into<Expr>> Div<R> for Expr {
+    type Output = Expr;
+    fn div(self, other: R) -> Expr {
+        self.op("/", other.into())
+    }
+}
+
+/// SETs a column to an expression
+pub(crate) struct SetColExpr {
+    pub(crate) col_raw: String,
+    pub(crate) expr: Expr,
+}
+
+impl ClauseAdder for SetColExpr {
+    fn bind<'lam, 'args, 'p>(&'lam self, args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+        self.expr.bind(args);
+    }
+
+    fn clause(&self, syntax: Syntax, alias: &str, next_params: &NextParam) -> Option<String> {
+        let colname = ColumnWriter::new(syntax).excape(&self.col_raw);
+        let sql = format!(
+            "{}={}",
+            colname,
+            self.expr.write(syntax, alias, next_params)
+        );
+        Some(sql)
+    }
+}
+
+/// Filters on an expression. I.E. `COALESCE(t1.nickname, t1.name) = $1`
+pub(crate) struct ClauseExpr {
+    expr: Expr,
+    operator: &'static str,
+    other: Option<Expr>,
+}
+
+impl ClauseAdder for ClauseExpr {
+    fn bind<'lam, 'args, 'p>(&'lam self, args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+        self.expr.bind(args);
+        if let Some(other) = &self.other {
+            other.bind(args);
+        }
+    }
+
+    fn clause(&self, syntax: Syntax, alias: &str, next_params: &NextParam) -> Option<String> {
+        let expr = self.expr.write_qualified(syntax, alias, next_params);
+        let sql = match &self.other {
+            Some(other) => {
+                let other = other.write_qualified(syntax, alias, next_params);
+                format!("{} {} {}", expr, self.operator, other)
+            }
+            None => format!("{} {}", expr, self.operator),
+        };
+        Some(sql)
+    }
+}
@@ -0,0 +1,93 @@
+use super::{ClauseAdder, ParamArgs};
+use crate::model_traits::HasSchema;
+use crate::writers::NextParam;
+use crate::Syntax;
+use std::marker::PhantomData;
+
+/// A group of clauses in a where block joined with AND / OR.
+///
+/// Groups can be nested to build clauses such as `(a AND b) OR (c AND d)`
+/// ```rust,ignore
+/// Order::all().where_any(|g| {
+///     g.all(|g| g.col(|o| o.status.equal("open")).col(|o| o.price.gt(10.0)))
+///         .all(|g| g.col(|o| o.status.equal("new")).col(|o| o.price.lt(5.0)))
+/// })
+/// ```
+pub struct WhereGroup<T> {
+    _t: PhantomData<T>,
+    joiner: &'static str,
+    clauses: Vec<Box<dyn ClauseAdder>>,
+}
+
+impl<T> WhereGroup<T>
+where
+    T: HasSchema,
+{
+    /// A group where every clause must be true (AND)
+    pub(crate) fn new_all() -> Self {
+        Self {
+            _t: Default::default(),
+            joiner: " AND ",
+            clauses: Vec::default(),
+        }
+    }
+
+    /// A group where any of the clauses can be true (OR)
+    pub(crate) fn new_any() -> Self {
+        Self {
+            _t: Default::default(),
+            joiner: " OR ",
+            clauses: Vec::default(),
+        }
+    }
+
+    /// Add a clause on a column of the table to the group
+    pub fn col(mut self, lam: impl Fn(<T as HasSchema>::Schema) -> Box<dyn ClauseAdder>) -> Self {
+        self.clauses.push(lam(Default::default()));
+        self
+    }
+
+    /// Add a nested group where every clause must be true (AND)
+    pub fn all(mut self, lam: impl FnOnce(WhereGroup<T>) -> WhereGroup<T>) -> Self
+    where
+        T: 'static,
+    {
+        self.clauses.push(Box::new(lam(WhereGroup::new_all())));
+        self
+    }
+
+    /// Add a nested group where any of the clauses can be true (OR)
+    pub fn any(mut self, lam: impl FnOnce(WhereGroup<T>) -> WhereGroup<T>) -> Self
+    where
+        T: 'static,
+    {
+        self.clauses.push(Box::new(lam(WhereGroup::new_any())));
+        self
+    }
+}
+
+impl<T> ClauseAdder for WhereGroup<T>
+where
+    T: HasSchema,
+{
+    fn bind<'lam, 'args, 'p>(&'lam self, args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+        for clause in &self.clauses {
+            clause.bind(args);
+        }
+    }
+
+    fn clause(&self, syntax: Syntax, alias: &str, next_params: &NextParam) -> Option<String> {
+        let parts: Vec<String> = self
+            .clauses
+            .iter()
+            .filter_map(|c| c.clause(syntax, alias, next_params))
+            .collect();
+        if parts.is_empty() {
+            return None;
+        }
+        Some(format!("( {} )", parts.join(self.joiner)))
+    }
+}
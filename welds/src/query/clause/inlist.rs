@@ -0,0 +1,127 @@
+use super::{ClauseAdder, ParamArgs};
+use crate::writers::NextParam;
+use crate::Syntax;
+use std::sync::Arc;
+use welds_connections::Param;
+
+/// How a list of values is written in an `in_list_with`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InList {
+    /// Always `t1.id IN ($1, $2, ...)`
+    Params,
+    /// A `VALUES` list joined to, when there are more than this many values.
+    /// `t1.id IN (SELECT v FROM (VALUES ($1), ($2), ...) AS x(v))`
+    /// The database can hash these instead of checking each value, which is faster for long lists
+    ValuesOver(usize),
+}
+
+/// Checks a column is (or isn't) one of a list of values. I.E. `t1.id IN ($1, $2, $3)`
+///
+/// A query with more params than the database allows is run in chunks of values, see `split`
+pub(crate) struct ClauseColInList<T> {
+    col: String,
+    negated: bool,
+    list: Vec<T>,
+    strategy: InList,
+}
+
+pub(crate) fn in_list<T, P>(
+    col: String,
+    negated: bool,
+    slice: &[P],
+    strategy: InList,
+) -> Box<dyn ClauseAdder>
+where
+    T: 'static + Clone + Send + Sync + Param + PartialEq,
+    P: Into<T> + Clone,
+{
+    let list: Vec<T> = slice.iter().map(|p| p.clone().into()).collect();
+    Box::new(ClauseColInList {
+        col,
+        negated,
+        list,
+        strategy,
+    })
+}
+
+impl<T> ClauseColInList<T> {
+    fn use_values(&self) -> bool {
+        match self.strategy {
+            InList::Params => false,
+            InList::ValuesOver(over) => self.list.len() > over,
+        }
+    }
+}
+
+impl<T> ClauseAdder for ClauseColInList<T>
+where
+    T: 'static + Clone + Send + Sync + Param + PartialEq,
+{
+    fn bind<'lam, 'args, 'p>(&'lam self, args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+        for v in &self.list {
+            args.push(v);
+        }
+    }
+
+    fn clause(&self, syntax: Syntax, alias: &str, next_params: &NextParam) -> Option<String> {
+        let col = format!("{}.{}", alias, self.col);
+        let not = if self.negated { "NOT " } else { "" };
+
+        // an empty IN () isn't valid SQL, nothing is in an empty list
+        if self.list.is_empty() {
+            return Some(if self.negated { "1=1" } else { "1=0" }.to_owned());
+        }
+
+        let params = self.list.iter().map(|_| next_params.next());
+        if !self.use_values() {
+            let params: Vec<String> = params.collect();
+            return Some(format!("{} {}IN ({})", col, not, params.join(", ")));
+        }
+
+        let values = match syntax {
+            Syntax::Mysql => {
+                let rows: Vec<String> = params.map(|p| format!("ROW({})", p)).collect();
+                format!("SELECT column_0 FROM (VALUES {}) AS x", rows.join(", "))
+            }
+            Syntax::Sqlite => {
+                let rows: Vec<String> = params.map(|p| format!("({})", p)).collect();
+                format!("SELECT column1 FROM (VALUES {})", rows.join(", "))
+            }
+            Syntax::Postgres | Syntax::Mssql => {
+                let rows: Vec<String> = params.map(|p| format!("({})", p)).collect();
+                format!("SELECT v FROM (VALUES {}) AS x(v)", rows.join(", "))
+            }
+        };
+        Some(format!("{} {}IN ({})", col, not, values))
+    }
+
+    fn split(&self, size: usize) -> Option<Vec<Arc<dyn ClauseAdder>>> {
+        // a row must be in every chunk of a NOT IN, the chunks can't be run on their own
+        if self.negated || size == 0 {
+            return None;
+        }
+        // a value repeated in two chunks would find its rows twice
+        let mut list: Vec<T> = Vec::with_capacity(self.list.len());
+        for value in &self.list {
+            if !list.contains(value) {
+                list.push(value.clone());
+            }
+        }
+        let chunks = list
+            .chunks(size)
+            .map(|chunk| {
+                let clause: Arc<dyn ClauseAdder> = Arc::new(ClauseColInList {
+                    col: self.col.clone(),
+                    negated: false,
+                    list: chunk.to_vec(),
+                    strategy: self.strategy,
+                });
+                clause
+            })
+            .collect();
+        Some(chunks)
+    }
+}
@@ -0,0 +1,196 @@
+use crate::Syntax;
+use serde_json::Value;
+
+// ******************************************************************************************
+// SQLite and MSSQL don't have a json containment operator.
+// The document is read in rust and each of its keys and array items are checked in the SQL
+// ******************************************************************************************
+
+/// The kinds of json values a part of the document is checked against
+#[derive(Clone, Copy)]
+enum JsonKind {
+    Object,
+    Array,
+    Text,
+    Number,
+    True,
+    False,
+    Null,
+}
+
+/// Where the json being checked is. The column, or an item of json_each / OPENJSON
+enum JsonAt<'a> {
+    Column(&'a str),
+    Item(String),
+}
+
+/// writes the SQL checking the column contains the document (SQLite and MSSQL)
+pub(super) fn write_contains(syntax: Syntax, needle: &Option<Value>, col: &str) -> String {
+    match needle {
+        Some(needle @ (Value::Object(_) | Value::Array(_))) => {
+            let mut items = 0;
+            write_contains_at(syntax, &JsonAt::Column(col), needle, &mut items)
+        }
+        // not a document that can be looked for
+        _ => "1=0".to_owned(),
+    }
+}
+
+fn write_contains_at(syntax: Syntax, at: &JsonAt, needle: &Value, items: &mut usize) -> String {
+    let mut parts = Vec::default();
+    match needle {
+        Value::Object(map) => {
+            parts.push(write_is_kind(syntax, at, JsonKind::Object));
+            for (key, value) in map {
+                let (item, from) = next_item(syntax, at, items);
+                let key_col = match syntax {
+                    Syntax::Mssql => format!("{}.[key]", item),
+                    _ => format!("{}.key", item),
+                };
+                let inner = write_contains_at(syntax, &JsonAt::Item(item), value, items);
+                parts.push(format!(
+                    "EXISTS (SELECT 1 FROM {} WHERE {} = {} AND {})",
+                    from,
+                    key_col,
+                    text_literal(syntax, key),
+                    inner
+                ));
+            }
+        }
+        Value::Array(list) => {
+            parts.push(write_is_kind(syntax, at, JsonKind::Array));
+            for value in list {
+                let (item, from) = next_item(syntax, at, items);
+                let inner = write_contains_at(syntax, &JsonAt::Item(item), value, items);
+                parts.push(format!("EXISTS (SELECT 1 FROM {} WHERE {})", from, inner));
+            }
+        }
+        Value::String(text) => {
+            parts.push(write_is_kind(syntax, at, JsonKind::Text));
+            parts.push(format!(
+                "{} = {}",
+                item_value(syntax, at),
+                text_literal(syntax, text)
+            ));
+        }
+        Value::Number(number) => {
+            parts.push(write_is_kind(syntax, at, JsonKind::Number));
+            let value = match syntax {
+                Syntax::Mssql => format!("CAST({} AS FLOAT)", item_value(syntax, at)),
+                _ => item_value(syntax, at),
+            };
+            parts.push(format!("{} = {}", value, number));
+        }
+        Value::Bool(true) => parts.push(write_is_kind(syntax, at, JsonKind::True)),
+        Value::Bool(false) => parts.push(write_is_kind(syntax, at, JsonKind::False)),
+        Value::Null => parts.push(write_is_kind(syntax, at, JsonKind::Null)),
+    }
+    parts.join(" AND ")
+}
+
+/// the name and FROM of the next json_each / OPENJSON over the object or array
+fn next_item(syntax: Syntax, at: &JsonAt, items: &mut usize) -> (String, String) {
+    *items += 1;
+    let item = format!("j{}", items);
+    let from = match syntax {
+        Syntax::Mssql => format!("OPENJSON({}) AS {}", item_value(syntax, at), item),
+        _ => format!("json_each({}) AS {}", item_value(syntax, at), item),
+    };
+    (item, from)
+}
+
+/// the value of an item. an object or array is its json text
+fn item_value(syntax: Syntax, at: &JsonAt) -> String {
+    match (syntax, at) {
+        (_, JsonAt::Column(col)) => col.to_string(),
+        (Syntax::Mssql, JsonAt::Item(item)) => format!("{}.[value]", item),
+        (_, JsonAt::Item(item)) => format!("{}.value", item),
+    }
+}
+
+/// writes the SQL checking the json is of a kind
+fn write_is_kind(syntax: Syntax, at: &JsonAt, kind: JsonKind) -> String {
+    match (syntax, at) {
+        (Syntax::Mssql, JsonAt::Column(col)) => match kind {
+            JsonKind::Array => format!("LEFT(LTRIM({}), 1) = '['", col),
+            _ => format!("LEFT(LTRIM({}), 1) = '{{'", col),
+        },
+        (Syntax::Mssql, JsonAt::Item(item)) => match kind {
+            JsonKind::Null => format!("{}.[type] = 0", item),
+            JsonKind::Text => format!("{}.[type] = 1", item),
+            JsonKind::Number => format!("{}.[type] = 2", item),
+            JsonKind::True => format!("{0}.[type] = 3 AND {0}.[value] = 'true'", item),
+            JsonKind::False => format!("{0}.[type] = 3 AND {0}.[value] = 'false'", item),
+            JsonKind::Array => format!("{}.[type] = 4", item),
+            JsonKind::Object => format!("{}.[type] = 5", item),
+        },
+        (_, at) => {
+            let ty = match at {
+                JsonAt::Column(col) => format!("json_type({})", col),
+                JsonAt::Item(item) => format!("{}.type", item),
+            };
+            match kind {
+                JsonKind::Object => format!("{} = 'object'", ty),
+                JsonKind::Array => format!("{} = 'array'", ty),
+                JsonKind::Text => format!("{} = 'text'", ty),
+                JsonKind::Number => format!("{} IN ('integer', 'real')", ty),
+                JsonKind::True => format!("{} = 'true'", ty),
+                JsonKind::False => format!("{} = 'false'", ty),
+                JsonKind::Null => format!("{} = 'null'", ty),
+            }
+        }
+    }
+}
+
+/// text written into the SQL, I.E. 'it''s' or N'it''s'
+fn text_literal(syntax: Syntax, text: &str) -> String {
+    let text = text.replace('\'', "''");
+    match syntax {
+        Syntax::Mssql => format!("N'{}'", text),
+        _ => format!("'{}'", text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::json_contains;
+    use crate::writers::NextParam;
+    use crate::Syntax;
+
+    fn contains_sql(syntax: Syntax, json: &str) -> String {
+        let clause = json_contains("data".to_owned(), json.to_owned());
+        clause
+            .clause(syntax, "t1", &NextParam::new(syntax))
+            .unwrap()
+    }
+
+    #[test]
+    fn should_check_each_key_and_item_for_contains_on_sqlite() {
+        let sql = contains_sql(Syntax::Sqlite, r#"{"tags":["a"],"n":1}"#);
+        let expected = "(json(?) IS NOT NULL AND json_type(t1.data) = 'object' \
+            AND EXISTS (SELECT 1 FROM json_each(t1.data) AS j1 WHERE j1.key = 'n' \
+            AND j1.type IN ('integer', 'real') AND j1.value = 1) \
+            AND EXISTS (SELECT 1 FROM json_each(t1.data) AS j2 WHERE j2.key = 'tags' \
+            AND j2.type = 'array' \
+            AND EXISTS (SELECT 1 FROM json_each(j2.value) AS j3 WHERE j3.type = 'text' \
+            AND j3.value = 'a')))";
+        assert_eq!(sql, expected);
+    }
+
+    #[test]
+    fn should_check_each_key_and_item_for_contains_on_mssql() {
+        let sql = contains_sql(Syntax::Mssql, r#"{"it's":true}"#);
+        let expected = "(ISJSON(@p1) = 1 AND LEFT(LTRIM(t1.data), 1) = '{' \
+            AND EXISTS (SELECT 1 FROM OPENJSON(t1.data) AS j1 WHERE j1.[key] = N'it''s' \
+            AND j1.[type] = 3 AND j1.[value] = 'true'))";
+        assert_eq!(sql, expected);
+    }
+
+    #[test]
+    fn should_match_nothing_for_a_document_that_cant_be_looked_for() {
+        for json in ["not json", "3"] {
+            let sql = contains_sql(Syntax::Sqlite, json);
+            assert_eq!(sql, "(json(?) IS NOT NULL AND 1=0)");
+        }
+    }
+}
@@ -0,0 +1,400 @@
+use super::{AsFieldName, ClauseAdder, ClauseColVal, ParamArgs};
+use crate::query::optional::HasSomeNone;
+use crate::query::optional::Optional;
+use crate::writers::NextParam;
+use crate::Syntax;
+use std::marker::PhantomData;
+use welds_connections::Param;
+
+#[cfg(feature = "serde")]
+mod contains;
+
+/// Clauses for json/jsonb columns
+pub struct Json<T> {
+    col: String,
+    field: String,
+    _t: PhantomData<T>,
+}
+
+impl<T> Clone for Json<T> {
+    fn clone(&self) -> Self {
+        Self {
+            col: self.col.clone(),
+            field: self.field.clone(),
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<T> AsFieldName<T> for Json<T> {
+    fn colname(&self) -> &str {
+        self.col.as_str()
+    }
+    fn fieldname(&self) -> &str {
+        self.field.as_str()
+    }
+}
+
+// without bounds on T, so a `#[welds(json)]` field of any type can be filtered by its paths
+impl<T> Json<T> {
+    pub fn new(col: impl Into<String>, field: impl Into<String>) -> Self {
+        Self {
+            col: col.into(),
+            field: field.into(),
+            _t: Default::default(),
+        }
+    }
+
+    /// Will write SQL checking the value is equal to this (==)
+    pub fn equal(self, v: impl Into<T>) -> Box<dyn ClauseAdder>
+    where
+        T: 'static + Clone + Send + Sync + Param,
+    {
+        let cv = ClauseColVal::<T> {
+            null_clause: false,
+            not_clause: false,
+            col: self.col,
+            operator: "=",
+            val: Some(v.into()),
+        };
+        Box::new(cv)
+    }
+
+    /// Will write SQL checking the value is equal to this (!=)
+    pub fn not_equal(self, v: impl Into<T>) -> Box<dyn ClauseAdder>
+    where
+        T: 'static + Clone + Send + Sync + Param,
+    {
+        let cv = ClauseColVal::<T> {
+            null_clause: false,
+            not_clause: true,
+            col: self.col,
+            operator: "!=",
+            val: Some(v.into()),
+        };
+        Box::new(cv)
+    }
+
+    /// Read the value at a path inside the json (as text) to filter on it.
+    /// The path is made of keys and array indexes separated by '.'
+    /// ```rust,ignore
+    /// where_col(|u| u.settings.json_get("theme.colors.0").equal("dark"))
+    /// ```
+    pub fn json_get(self, path: &str) -> JsonPath {
+        JsonPath::new(self.col, path)
+    }
+
+    /// Will write SQL checking the json contains this json document
+    ///
+    /// Postgres uses @> and MySQL JSON_CONTAINS. SQLite and MSSQL check each of the keys and
+    /// array items of the document with json_each / OPENJSON.
+    /// NOTE: on SQLite and MSSQL the document must be a json object or array, read with `serde`
+    pub fn json_contains(self, json: impl Into<String>) -> Box<dyn ClauseAdder> {
+        json_contains(self.col, json.into())
+    }
+}
+
+/// Clauses for nullable json/jsonb columns
+pub struct JsonOpt<T> {
+    col: String,
+    field: String,
+    _t: PhantomData<T>,
+}
+
+impl<T> Clone for JsonOpt<T> {
+    fn clone(&self) -> Self {
+        Self {
+            col: self.col.clone(),
+            field: self.field.clone(),
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<T> AsFieldName<T> for JsonOpt<T> {
+    fn colname(&self) -> &str {
+        self.col.as_str()
+    }
+    fn fieldname(&self) -> &str {
+        self.field.as_str()
+    }
+}
+
+impl<T> JsonOpt<T> {
+    pub fn new(col: impl Into<String>, field: impl Into<String>) -> Self {
+        Self {
+            col: col.into(),
+            field: field.into(),
+            _t: Default::default(),
+        }
+    }
+
+    /// Will write SQL checking the value is equal to this (==)
+    pub fn equal(self, v: impl Into<Optional<T>>) -> Box<dyn ClauseAdder>
+    where
+        T: 'static + Clone + Send + Sync + Param,
+    {
+        let opt = v.into();
+        let is_none = opt.is_none();
+        let val: Option<T> = opt.into();
+        let cv = ClauseColVal::<T> {
+            null_clause: is_none,
+            not_clause: false,
+            col: self.col,
+            operator: "=",
+            val,
+        };
+        Box::new(cv)
+    }
+
+    /// Will write SQL checking the value is equal to this (!=)
+    pub fn not_equal(self, v: impl Into<Optional<T>>) -> Box<dyn ClauseAdder>
+    where
+        T: 'static + Clone + Send + Sync + Param,
+    {
+        let opt = v.into();
+        let is_none = opt.is_none();
+        let val: Option<T> = opt.into();
+        let cv = ClauseColVal::<T> {
+            null_clause: is_none,
+            not_clause: true,
+            col: self.col,
+            operator: "!=",
+            val,
+        };
+        Box::new(cv)
+    }
+
+    /// Read the value at a path inside the json (as text) to filter on it.
+    /// The path is made of keys and array indexes separated by '.'
+    pub fn json_get(self, path: &str) -> JsonPath {
+        JsonPath::new(self.col, path)
+    }
+
+    /// Will write SQL checking the json contains this json document
+    ///
+    /// Postgres uses @> and MySQL JSON_CONTAINS. SQLite and MSSQL check each of the keys and
+    /// array items of the document with json_each / OPENJSON.
+    /// NOTE: on SQLite and MSSQL the document must be a json object or array, read with `serde`
+    pub fn json_contains(self, json: impl Into<String>) -> Box<dyn ClauseAdder> {
+        json_contains(self.col, json.into())
+    }
+}
+
+/// A value at a path inside of a json column
+pub struct JsonPath {
+    col: String,
+    path: Vec<String>,
+}
+
+impl JsonPath {
+    pub(crate) fn new(col: String, path: &str) -> Self {
+        let path = path.split('.').map(|p| p.to_string()).collect();
+        Self { col, path }
+    }
+
+    fn clause(self, operator: &'static str, val: Option<String>) -> Box<dyn ClauseAdder> {
+        Box::new(ClauseJsonPath {
+            col: self.col,
+            path: self.path,
+            operator,
+            val,
+        })
+    }
+
+    /// Will write SQL checking the value is equal to this (==)
+    pub fn equal(self, v: impl Into<String>) -> Box<dyn ClauseAdder> {
+        self.clause("=", Some(v.into()))
+    }
+
+    /// Will write SQL checking the value is equal to this (!=)
+    pub fn not_equal(self, v: impl Into<String>) -> Box<dyn ClauseAdder> {
+        self.clause("!=", Some(v.into()))
+    }
+
+    /// Will write SQL checking the value is like this (like)
+    pub fn like(self, v: impl Into<String>) -> Box<dyn ClauseAdder> {
+        self.clause("like", Some(v.into()))
+    }
+
+    /// Will write SQL checking there is no value at this path (IS NULL)
+    pub fn is_null(self) -> Box<dyn ClauseAdder> {
+        self.clause("IS NULL", None)
+    }
+
+    /// Will write SQL checking there is a value at this path (IS NOT NULL)
+    pub fn is_not_null(self) -> Box<dyn ClauseAdder> {
+        self.clause("IS NOT NULL", None)
+    }
+}
+
+struct ClauseJsonPath {
+    col: String,
+    path: Vec<String>,
+    operator: &'static str,
+    val: Option<String>,
+}
+
+impl ClauseAdder for ClauseJsonPath {
+    fn bind<'lam, 'args, 'p>(&'lam self, args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+        if let Some(val) = &self.val {
+            args.push(val);
+        }
+    }
+
+    fn clause(&self, syntax: Syntax, alias: &str, next_params: &NextParam) -> Option<String> {
+        let col = format!("{}.{}", alias, self.col);
+        let value = write_json_get(syntax, &col, &self.path);
+        Some(match &self.val {
+            Some(_) => format!("{} {} {}", value, self.operator, next_params.next()),
+            None => format!("{} {}", value, self.operator),
+        })
+    }
+}
+
+/// Builds a clause checking a json column contains a json document
+pub(crate) fn json_contains(col: String, json: String) -> Box<dyn ClauseAdder> {
+    Box::new(ClauseJsonContains {
+        #[cfg(feature = "serde")]
+        needle: serde_json::from_str(&json).ok(),
+        col,
+        json,
+    })
+}
+
+struct ClauseJsonContains {
+    col: String,
+    json: String,
+    // the document read in rust, for the databases without a containment operator
+    #[cfg(feature = "serde")]
+    needle: Option<serde_json::Value>,
+}
+
+impl ClauseAdder for ClauseJsonContains {
+    fn bind<'lam, 'args, 'p>(&'lam self, args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+        args.push(&self.json);
+    }
+
+    fn clause(&self, syntax: Syntax, alias: &str, next_params: &NextParam) -> Option<String> {
+        let col = format!("{}.{}", alias, self.col);
+        let np = next_params.next();
+        Some(match syntax {
+            Syntax::Postgres => format!("CAST({} AS jsonb) @> CAST({} AS jsonb)", col, np),
+            Syntax::Mysql => format!("JSON_CONTAINS({}, {})", col, np),
+            // the param is only checked, the values of the document are written into the SQL
+            #[cfg(feature = "serde")]
+            Syntax::Sqlite => {
+                let contains = contains::write_contains(syntax, &self.needle, &col);
+                format!("(json({}) IS NOT NULL AND {})", np, contains)
+            }
+            #[cfg(feature = "serde")]
+            Syntax::Mssql => {
+                let contains = contains::write_contains(syntax, &self.needle, &col);
+                format!("(ISJSON({}) = 1 AND {})", np, contains)
+            }
+            // the document can only be read with serde, there is no containment operator
+            #[cfg(not(feature = "serde"))]
+            Syntax::Sqlite | Syntax::Mssql => format!("JSON_CONTAINS({}, {})", col, np),
+        })
+    }
+}
+
+/// writes the SQL to read the text at a path inside of a json column
+fn write_json_get(syntax: Syntax, col: &str, path: &[String]) -> String {
+    match syntax {
+        Syntax::Postgres => {
+            let parts: Vec<String> = path.iter().map(|p| pg_path_part(p)).collect();
+            format!("{} #>> '{{{}}}'", col, parts.join(","))
+        }
+        // a backslash is an escape in a MySQL string
+        Syntax::Mysql => format!(
+            "JSON_UNQUOTE(JSON_EXTRACT({}, '{}'))",
+            col,
+            dollar_path(path).replace('\\', "\\\\")
+        ),
+        Syntax::Mssql => format!("JSON_VALUE({}, '{}')", col, dollar_path(path)),
+        Syntax::Sqlite => format!("json_extract({}, '{}')", col, dollar_path(path)),
+    }
+}
+
+/// a part of a postgres text[] path. I.E. {settings,"the theme"}
+fn pg_path_part(part: &str) -> String {
+    let part = part.replace('\'', "''");
+    if part.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return part;
+    }
+    format!("\"{}\"", part.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// the standard json path. I.E. $.settings."the theme"[0]
+fn dollar_path(path: &[String]) -> String {
+    let mut out = "$".to_string();
+    for part in path {
+        let part = part.replace('\'', "''");
+        if !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()) {
+            out.push_str(&format!("[{}]", part));
+        } else if part.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            out.push_str(&format!(".{}", part));
+        } else {
+            let part = part.replace('\\', "\\\\").replace('"', "\\\"");
+            out.push_str(&format!(".\"{}\"", part));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(p: &str) -> Vec<String> {
+        p.split('.').map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn should_write_a_json_get_for_each_syntax() {
+        let p = path("settings.theme");
+        assert_eq!(
+            write_json_get(Syntax::Postgres, "t1.data", &p),
+            "t1.data #>> '{settings,theme}'"
+        );
+        assert_eq!(
+            write_json_get(Syntax::Mysql, "t1.data", &p),
+            "JSON_UNQUOTE(JSON_EXTRACT(t1.data, '$.settings.theme'))"
+        );
+        assert_eq!(
+            write_json_get(Syntax::Mssql, "t1.data", &p),
+            "JSON_VALUE(t1.data, '$.settings.theme')"
+        );
+        assert_eq!(
+            write_json_get(Syntax::Sqlite, "t1.data", &p),
+            "json_extract(t1.data, '$.settings.theme')"
+        );
+    }
+
+    #[test]
+    fn should_write_array_indexes_and_quoted_keys() {
+        let p = path("tags.0.it's here");
+        assert_eq!(dollar_path(&p), "$.tags[0].\"it''s here\"");
+        assert_eq!(
+            write_json_get(Syntax::Postgres, "t1.data", &p),
+            "t1.data #>> '{tags,0,\"it''s here\"}'"
+        );
+    }
+
+    #[test]
+    fn should_escape_backslashes_in_mysql_paths() {
+        let p = path(r"a\b");
+        assert_eq!(dollar_path(&p), r#"$."a\\b""#);
+        assert_eq!(
+            write_json_get(Syntax::Mysql, "t1.data", &p),
+            r#"JSON_UNQUOTE(JSON_EXTRACT(t1.data, '$."a\\\\b"'))"#
+        );
+    }
+}
@@ -1,5 +1,6 @@
-use crate::writers::NextParam;
+use crate::writers::{ColumnWriter, NextParam};
 use crate::Syntax;
+use std::sync::Arc;
 use welds_connections::Param;
 pub type ParamArgs<'a> = Vec<&'a (dyn Param + Sync)>;
 
@@ -16,15 +17,42 @@ mod text;
 pub use text::Text;
 mod textopt;
 pub use textopt::TextOpt;
+mod array;
+pub use array::{Array, ArrayElement, ArrayLength, ArrayOpt};
+mod json;
+pub use json::{Json, JsonOpt, JsonPath};
 
+mod datetime;
+pub use datetime::{DateExpr, DatePart};
+mod case;
+pub use case::Case;
+mod inlist;
+pub(crate) use inlist::in_list;
+pub use inlist::InList;
+mod tuple;
+pub(crate) use tuple::write_compare;
+pub use tuple::{Tuple, TupleValues};
+mod expr;
+pub use expr::Expr;
+pub(crate) use expr::SetColExpr;
+
+pub(crate) mod group;
 pub(crate) mod manualwhereparam;
+pub(crate) mod raw;
+pub(crate) mod search;
+pub use group::WhereGroup;
+pub use raw::RawParam;
+pub(crate) mod subquery;
 
 //  Relationships / SubQueries
 pub(crate) mod exists;
+pub(crate) mod related_count;
+pub use related_count::RelatedCount;
 pub(crate) mod wherein;
 
 pub(crate) mod orderby;
 pub(crate) use orderby::OrderBy;
+pub use orderby::OrderExpr;
 
 pub struct ClauseColVal<T> {
     pub null_clause: bool,
@@ -46,11 +74,69 @@ pub struct ClauseColManual {
     pub(crate) params: Vec<Box<dyn Param + Send + Sync>>,
 }
 
+/// Compares two columns of the same row. I.E. `t1.updated_at > t1.created_at`
+pub struct ClauseColCol {
+    pub col: String,
+    pub operator: &'static str,
+    pub other: String,
+}
+
+/// Keeps the rows that are (or aren't) soft deleted. I.E. `t1.deleted_at IS NULL`
+pub(crate) struct ClauseSoftDeleted {
+    pub(crate) col: String,
+    pub(crate) deleted: bool,
+}
+
+/// Keeps a random fraction of the rows
+pub(crate) struct ClauseSample {
+    pub(crate) fraction: f64,
+}
+
+pub(crate) fn col_col<T>(
+    col: String,
+    operator: &'static str,
+    other: impl AsFieldName<T>,
+) -> Box<dyn ClauseAdder> {
+    Box::new(ClauseColCol {
+        col,
+        operator,
+        other: other.colname().to_string(),
+    })
+}
+
 pub trait AsFieldName<T> {
     fn colname(&self) -> &str;
     fn fieldname(&self) -> &str;
 }
 
+/// A list of columns picked from a schema. Implemented for tuples of columns
+/// ```rust,ignore
+/// |c| (c.id, c.created_at)
+/// ```
+pub trait ColumnList<V> {
+    fn colnames(&self) -> Vec<String>;
+}
+
+macro_rules! impl_column_list {
+    ($($field:ident $value:ident $idx:tt),+) => {
+        impl<$($field, $value),+> ColumnList<($($value,)+)> for ($($field,)+)
+        where
+            $($field: AsFieldName<$value>),+
+        {
+            fn colnames(&self) -> Vec<String> {
+                vec![$(self.$idx.colname().to_string()),+]
+            }
+        }
+    };
+}
+
+impl_column_list!(A VA 0);
+impl_column_list!(A VA 0, B VB 1);
+impl_column_list!(A VA 0, B VB 1, C VC 2);
+impl_column_list!(A VA 0, B VB 1, C VC 2, D VD 3);
+impl_column_list!(A VA 0, B VB 1, C VC 2, D VD 3, E VE 4);
+impl_column_list!(A VA 0, B VB 1, C VC 2, D VD 3, E VE 4, F VF 5);
+
 pub trait ClauseAdder: Send + Sync {
     /// Add the argument to the list of Arguments to send to the database
     fn bind<'lam, 'args, 'p>(&'lam self, args: &'args mut ParamArgs<'p>)
@@ -59,6 +145,66 @@ pub trait ClauseAdder: Send + Sync {
 
     /// Returns the SQL snipit for this clause
     fn clause(&self, syntax: Syntax, alias: &str, next_params: &NextParam) -> Option<String>;
+
+    /// Splits this clause into clauses of at most `size` params each, that together match
+    /// the same rows. Used to run a query with more params than the database allows in chunks.
+    /// Returns None if the clause can't be split
+    fn split(&self, _size: usize) -> Option<Vec<Arc<dyn ClauseAdder>>> {
+        None
+    }
+}
+
+impl ClauseAdder for ClauseColCol {
+    fn bind<'lam, 'args, 'p>(&'lam self, _args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+    }
+
+    fn clause(&self, _syntax: Syntax, alias: &str, _next_params: &NextParam) -> Option<String> {
+        Some(format!(
+            "{}.{} {} {}.{}",
+            alias, self.col, self.operator, alias, self.other
+        ))
+    }
+}
+
+impl ClauseAdder for ClauseSoftDeleted {
+    fn bind<'lam, 'args, 'p>(&'lam self, _args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+    }
+
+    fn clause(&self, syntax: Syntax, alias: &str, _next_params: &NextParam) -> Option<String> {
+        let col = ColumnWriter::new(syntax).excape(&self.col);
+        let is = if self.deleted {
+            "IS NOT NULL"
+        } else {
+            "IS NULL"
+        };
+        Some(format!("{}.{} {}", alias, col, is))
+    }
+}
+
+impl ClauseAdder for ClauseSample {
+    fn bind<'lam, 'args, 'p>(&'lam self, _args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+    }
+
+    fn clause(&self, syntax: Syntax, _alias: &str, _next_params: &NextParam) -> Option<String> {
+        // the integers are brought into 0..1000000, modulo before ABS so it can't overflow
+        let below = (self.fraction * 1_000_000.0).round() as i64;
+        let sql = match syntax {
+            Syntax::Postgres => format!("RANDOM() < {}", self.fraction),
+            Syntax::Mysql => format!("RAND() < {}", self.fraction),
+            Syntax::Sqlite => format!("ABS(RANDOM() % 1000000) < {}", below),
+            Syntax::Mssql => format!("ABS(CHECKSUM(NEWID()) % 1000000) < {}", below),
+        };
+        Some(sql)
+    }
 }
 
 impl<T> ClauseAdder for ClauseColVal<T>
@@ -76,7 +222,7 @@ where
         }
     }
 
-    fn clause(&self, _syntax: Syntax, alias: &str, next_params: &NextParam) -> Option<String> {
+    fn clause(&self, syntax: Syntax, alias: &str, next_params: &NextParam) -> Option<String> {
         // build the column name
         let col = format!("{}.{}", alias, self.col);
         let mut parts = vec![col.as_str()];
@@ -92,6 +238,13 @@ where
             return Some(clause);
         }
 
+        // not all databases have an ilike
+        if self.operator == "ilike" || self.operator == "not ilike" {
+            let np = next_params.next();
+            let not = self.operator == "not ilike";
+            return Some(write_ilike(syntax, &col, not, &np));
+        }
+
         // normal path
         parts.push(self.operator);
         let np = next_params.next();
@@ -101,6 +254,17 @@ where
     }
 }
 
+/// writes a case-insensitive like for the given syntax
+fn write_ilike(syntax: Syntax, col: &str, not: bool, param: &str) -> String {
+    let not = if not { "not " } else { "" };
+    match syntax {
+        Syntax::Postgres => format!("{} {}ilike {}", col, not, param),
+        Syntax::Sqlite => format!("{} {}like {} COLLATE NOCASE", col, not, param),
+        // the collation of the column might be case-sensitive
+        Syntax::Mysql | Syntax::Mssql => format!("LOWER({}) {}like LOWER({})", col, not, param),
+    }
+}
+
 impl<T> ClauseAdder for ClauseColValList<T>
 where
     Vec<T>: Clone + Send + Sync + Param,
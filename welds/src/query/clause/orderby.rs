@@ -1,6 +1,24 @@
+use super::{AsFieldName, Case, Expr, ParamArgs};
+use crate::writers::NextParam;
+use crate::Syntax;
+use std::sync::Arc;
+
+#[derive(Clone)]
 pub struct OrderBy {
     pub(crate) field: String,
     pub(crate) direction: String,
+    pub(crate) nulls: Option<Nulls>,
+    /// order by a random number, the field and direction are not used
+    pub(crate) random: bool,
+    /// order by an expression instead of the field
+    pub(crate) expr: Option<Arc<Expr>>,
+}
+
+/// Where NULLs are placed in the ordered rows
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum Nulls {
+    First,
+    Last,
 }
 
 impl OrderBy {
@@ -8,29 +26,205 @@ impl OrderBy {
         Self {
             field: field.into(),
             direction: dir.into(),
+            nulls: None,
+            random: false,
+            expr: None,
+        }
+    }
+
+    /// shuffle the rows
+    pub(crate) fn random() -> Self {
+        Self {
+            random: true,
+            ..Self::new("", "")
+        }
+    }
+
+    fn write(&self, syntax: Syntax, alias: &str, next_params: &NextParam) -> String {
+        if self.random {
+            return random(syntax).to_owned();
+        }
+        let expr = self
+            .expr
+            .as_ref()
+            .map(|expr| expr.write_qualified(syntax, alias, next_params));
+        let field = expr.as_deref().unwrap_or(&self.field);
+        let by = format!("{} {}", field, self.direction);
+        let nulls = match self.nulls {
+            Some(nulls) => nulls,
+            None => return by,
+        };
+        match syntax {
+            Syntax::Postgres | Syntax::Sqlite => match nulls {
+                Nulls::First => format!("{} NULLS FIRST", by),
+                Nulls::Last => format!("{} NULLS LAST", by),
+            },
+            // writing an expression twice would need its params twice
+            Syntax::Mysql | Syntax::Mssql if expr.is_some() => by,
+            // No NULLS FIRST/LAST, sort on if the value is null first
+            Syntax::Mysql | Syntax::Mssql => {
+                let (is_null, not_null) = match nulls {
+                    Nulls::First => (0, 1),
+                    Nulls::Last => (1, 0),
+                };
+                format!(
+                    "CASE WHEN {} IS NULL THEN {} ELSE {} END ASC, {}",
+                    self.field, is_null, not_null, by
+                )
+            }
         }
     }
 }
 
-pub(crate) fn to_sql(parts: &[OrderBy]) -> String {
+/// An expression to order the results of a query by.
+/// ```rust,ignore
+/// let people = Person::all().order_by(|p| OrderExpr::lower(p.name).desc().nulls_last());
+/// ```
+pub struct OrderExpr {
+    expr: String,
+    direction: &'static str,
+    nulls: Option<Nulls>,
+    value: Option<Expr>,
+}
+
+impl OrderExpr {
+    fn new(expr: String) -> Self {
+        Self {
+            expr,
+            direction: "ASC",
+            nulls: None,
+            value: None,
+        }
+    }
+
+    /// Order by the value of a column
+    pub fn col<V>(field: impl AsFieldName<V>) -> Self {
+        Self::new(field.colname().to_string())
+    }
+
+    /// Order by the lowercase value of a column. I.E. `LOWER(name)`
+    pub fn lower<V>(field: impl AsFieldName<V>) -> Self {
+        Self::new(format!("LOWER({})", field.colname()))
+    }
+
+    /// Order by a manually written SQL expression. I.E. `price * quantity`
+    pub fn sql(expr: impl Into<String>) -> Self {
+        Self::new(expr.into())
+    }
+
+    /// Order by a `CASE` expression. I.E. to give each status a weight
+    /// ```rust,ignore
+    /// let case = |o: OrderSchema| Case::new().when(o.status.equal("open"), 1).otherwise(2);
+    /// Order::all().order_by(|o| OrderExpr::case(case(o)));
+    /// ```
+    /// NOTE: MySQL and MSSQL don't place NULLs first/last for a CASE, use `otherwise` instead
+    pub fn case(case: Case) -> Self {
+        Self::expr(case)
+    }
+
+    /// Order by an `Expr`. I.E. `COALESCE(t1.nickname, t1.name)`
+    /// ```rust,ignore
+    /// Person::all().order_by(|p| OrderExpr::expr(Expr::col(p.nickname).coalesce(p.name)));
+    /// ```
+    /// NOTE: MySQL and MSSQL don't place NULLs first/last for an expression, use `coalesce` instead
+    pub fn expr(expr: impl Into<Expr>) -> Self {
+        Self {
+            value: Some(expr.into()),
+            ..Self::new(String::default())
+        }
+    }
+
+    /// Smallest values first (the default)
+    pub fn asc(mut self) -> Self {
+        self.direction = "ASC";
+        self
+    }
+
+    /// Largest values first
+    pub fn desc(mut self) -> Self {
+        self.direction = "DESC";
+        self
+    }
+
+    /// Put NULLs at the front of the resulting rows
+    pub fn nulls_first(mut self) -> Self {
+        self.nulls = Some(Nulls::First);
+        self
+    }
+
+    /// Put NULLs at the end of the resulting rows
+    pub fn nulls_last(mut self) -> Self {
+        self.nulls = Some(Nulls::Last);
+        self
+    }
+}
+
+impl From<OrderExpr> for OrderBy {
+    fn from(expr: OrderExpr) -> Self {
+        Self {
+            field: expr.expr,
+            direction: expr.direction.to_string(),
+            nulls: expr.nulls,
+            random: false,
+            expr: expr.value.map(Arc::new),
+        }
+    }
+}
+
+/// a random number for each row
+pub(crate) fn random(syntax: Syntax) -> &'static str {
+    match syntax {
+        Syntax::Postgres | Syntax::Sqlite => "RANDOM()",
+        Syntax::Mysql => "RAND()",
+        Syntax::Mssql => "NEWID()",
+    }
+}
+
+/// Adds the values of the expressions to the list of Arguments, in the order they are written
+pub(crate) fn bind<'lam, 'args, 'p>(parts: &'lam [OrderBy], args: &'args mut ParamArgs<'p>)
+where
+    'lam: 'p,
+{
+    for expr in parts.iter().filter_map(|p| p.expr.as_ref()) {
+        expr.bind(args);
+    }
+}
+
+pub(crate) fn to_sql(
+    syntax: Syntax,
+    alias: &str,
+    next_params: &NextParam,
+    parts: &[OrderBy],
+) -> String {
     if parts.is_empty() {
         return "".to_owned();
     }
     let bys: Vec<String> = parts
         .iter()
-        .map(|p| format!("{} {}", p.field, p.direction))
+        .map(|p| p.write(syntax, alias, next_params))
         .collect();
     let bys = bys.join(", ");
     format!("ORDER BY {}", bys)
 }
 
+#[cfg(test)]
+const ALIAS: &str = "t1";
+
 #[test]
 fn single_order_by_field() {
     let parts = vec![OrderBy {
         field: "f1".to_owned(),
         direction: "desc".to_owned(),
+        nulls: None,
+        random: false,
+        expr: None,
     }];
-    let clause = to_sql(&parts);
+    let clause = to_sql(
+        Syntax::Postgres,
+        ALIAS,
+        &NextParam::new(Syntax::Postgres),
+        &parts,
+    );
     assert_eq!(clause.as_str(), "ORDER BY f1 desc")
 }
 
@@ -40,12 +234,79 @@ fn order_by_field_two_fields() {
         OrderBy {
             field: "f1".to_owned(),
             direction: "desc".to_owned(),
+            nulls: None,
+            random: false,
+            expr: None,
         },
         OrderBy {
             field: "f2".to_owned(),
             direction: "asc".to_owned(),
+            nulls: None,
+            random: false,
+            expr: None,
         },
     ];
-    let clause = to_sql(&parts);
+    let clause = to_sql(
+        Syntax::Postgres,
+        ALIAS,
+        &NextParam::new(Syntax::Postgres),
+        &parts,
+    );
     assert_eq!(clause.as_str(), "ORDER BY f1 desc, f2 asc")
 }
+
+#[test]
+fn order_by_nulls_last_on_postgres() {
+    let parts = vec![OrderExpr::lower(super::Text::<String>::new("name", "name"))
+        .desc()
+        .nulls_last()
+        .into()];
+    let clause = to_sql(
+        Syntax::Postgres,
+        ALIAS,
+        &NextParam::new(Syntax::Postgres),
+        &parts,
+    );
+    assert_eq!(clause.as_str(), "ORDER BY LOWER(name) DESC NULLS LAST")
+}
+
+#[test]
+fn order_by_nulls_first_is_emulated_on_mysql() {
+    let parts = vec![OrderExpr::sql("price * qty").nulls_first().into()];
+    let clause = to_sql(Syntax::Mysql, ALIAS, &NextParam::new(Syntax::Mysql), &parts);
+    assert_eq!(
+        clause.as_str(),
+        "ORDER BY CASE WHEN price * qty IS NULL THEN 0 ELSE 1 END ASC, price * qty ASC"
+    )
+}
+
+#[test]
+fn order_by_random_on_each_syntax() {
+    let parts = vec![OrderBy::random()];
+    assert_eq!(
+        to_sql(
+            Syntax::Postgres,
+            ALIAS,
+            &NextParam::new(Syntax::Postgres),
+            &parts
+        ),
+        "ORDER BY RANDOM()"
+    );
+    assert_eq!(
+        to_sql(
+            Syntax::Sqlite,
+            ALIAS,
+            &NextParam::new(Syntax::Sqlite),
+            &parts
+        ),
+        "ORDER BY RANDOM()"
+    );
+    assert_eq!(
+        to_sql(Syntax::Mysql, ALIAS, &NextParam::new(Syntax::Mysql), &parts),
+        "ORDER BY RAND()"
+    );
+    assert_eq!(
+        to_sql(Syntax::Mssql, ALIAS, &NextParam::new(Syntax::Mssql), &parts),
+        "ORDER BY NEWID()"
+    );
+}
@@ -0,0 +1,70 @@
+use super::{ClauseAdder, ParamArgs};
+use crate::writers::NextParam;
+use crate::Syntax;
+use welds_connections::Param;
+
+/// A value that can be bound as a param of `where_raw`
+///
+/// The value is copied into the query so the query doesn't borrow it.
+pub trait RawParam {
+    fn to_param(&self) -> Box<dyn Param + Send + Sync>;
+}
+
+impl<T> RawParam for T
+where
+    T: 'static + Param + Clone + Send + Sync,
+{
+    fn to_param(&self) -> Box<dyn Param + Send + Sync> {
+        Box::new(self.clone())
+    }
+}
+
+/// A raw SQL clause in a where block.
+/// `{}` is swapped for the next param, `$` for the table alias
+pub(crate) struct ClauseRaw {
+    sql: String,
+    params: Vec<Box<dyn Param + Send + Sync>>,
+}
+
+impl ClauseRaw {
+    pub(crate) fn new(sql: impl Into<String>, params: &[&dyn RawParam]) -> Self {
+        let sql = sql.into();
+        debug_assert_eq!(
+            sql.matches("{}").count(),
+            params.len(),
+            "where_raw needs a param for each {{}} in the SQL"
+        );
+        Self {
+            sql,
+            params: params.iter().map(|p| p.to_param()).collect(),
+        }
+    }
+}
+
+impl ClauseAdder for ClauseRaw {
+    fn bind<'lam, 'args, 'p>(&'lam self, args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+        for p in &self.params {
+            args.push(p.as_ref());
+        }
+    }
+
+    fn clause(&self, _syntax: Syntax, alias: &str, next_params: &NextParam) -> Option<String> {
+        let mut sql = String::default();
+        let mut chars = self.sql.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    sql.push_str(&next_params.next());
+                }
+                '$' => sql.push_str(alias),
+                _ => sql.push(c),
+            }
+        }
+        // wrapped so an OR in the raw SQL can't escape into the rest of the where block
+        Some(format!("( {} )", sql))
+    }
+}
@@ -0,0 +1,95 @@
+use super::{ClauseAdder, ParamArgs};
+use crate::writers::NextParam;
+use crate::Syntax;
+
+/// The number of rows related to a row, compared in a `where_relation_count`
+/// ```rust,ignore
+/// Customer::all().where_relation_count(|c| c.orders, |count| count.gt(5))
+/// ```
+pub struct RelatedCount {
+    outer_column: String,
+    inner_tablename: String,
+    inner_column: String,
+}
+
+impl RelatedCount {
+    pub(crate) fn new(outer_column: String, inner_tablename: String, inner_column: String) -> Self {
+        Self {
+            outer_column,
+            inner_tablename,
+            inner_column,
+        }
+    }
+
+    fn compare(self, operator: &'static str, val: i64) -> Box<dyn ClauseAdder> {
+        Box::new(ClauseRelatedCount {
+            count: self,
+            operator,
+            val,
+        })
+    }
+
+    /// Will write SQL checking the count is equal to this (=)
+    pub fn equal(self, v: i64) -> Box<dyn ClauseAdder> {
+        self.compare("=", v)
+    }
+
+    /// Will write SQL checking the count is not equal to this (!=)
+    pub fn not_equal(self, v: i64) -> Box<dyn ClauseAdder> {
+        self.compare("!=", v)
+    }
+
+    /// Will write SQL checking the count is greater than this (>)
+    pub fn gt(self, v: i64) -> Box<dyn ClauseAdder> {
+        self.compare(">", v)
+    }
+
+    /// Will write SQL checking the count is greater than or equal to this (>=)
+    pub fn gte(self, v: i64) -> Box<dyn ClauseAdder> {
+        self.compare(">=", v)
+    }
+
+    /// Will write SQL checking the count is less than this (<)
+    pub fn lt(self, v: i64) -> Box<dyn ClauseAdder> {
+        self.compare("<", v)
+    }
+
+    /// Will write SQL checking the count is less than or equal to this (<=)
+    pub fn lte(self, v: i64) -> Box<dyn ClauseAdder> {
+        self.compare("<=", v)
+    }
+}
+
+/// A correlated sub-query counting the related rows.
+/// I.E. `( SELECT COUNT(*) FROM orders t1_count WHERE t1_count.customer_id = t1.id ) > $1`
+struct ClauseRelatedCount {
+    count: RelatedCount,
+    operator: &'static str,
+    val: i64,
+}
+
+impl ClauseAdder for ClauseRelatedCount {
+    fn bind<'lam, 'args, 'p>(&'lam self, args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+        args.push(&self.val);
+    }
+
+    fn clause(&self, _syntax: Syntax, alias: &str, next_params: &NextParam) -> Option<String> {
+        // named from the outer alias, so it stays unique when this query is re-aliased
+        let inner_alias = format!("{}_count", alias);
+        let count = &self.count;
+        Some(format!(
+            "( SELECT COUNT(*) FROM {} {} WHERE {}.{} = {}.{} ) {} {}",
+            count.inner_tablename,
+            inner_alias,
+            inner_alias,
+            count.inner_column,
+            alias,
+            count.outer_column,
+            self.operator,
+            next_params.next()
+        ))
+    }
+}
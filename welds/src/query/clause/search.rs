@@ -0,0 +1,94 @@
+use super::{ClauseAdder, ParamArgs};
+use crate::writers::{ColumnWriter, NextParam};
+use crate::Syntax;
+
+// ******************************************************************************************
+// Full-text search. Each database has its own flavor so the SQL is written per syntax.
+// The column (or table on sqlite) is expected to already have a full-text index
+// ******************************************************************************************
+
+pub(crate) fn search(col: String, query: String) -> Box<dyn ClauseAdder> {
+    Box::new(ClauseSearch { col, query })
+}
+
+struct ClauseSearch {
+    col: String,
+    query: String,
+}
+
+impl ClauseAdder for ClauseSearch {
+    fn bind<'lam, 'args, 'p>(&'lam self, args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+        args.push(&self.query);
+    }
+
+    fn clause(&self, syntax: Syntax, alias: &str, next_params: &NextParam) -> Option<String> {
+        let col = format!("{}.{}", alias, self.col);
+        let np = next_params.next();
+        Some(match syntax {
+            Syntax::Postgres => format!("to_tsvector({}) @@ plainto_tsquery({})", col, np),
+            Syntax::Mysql => format!("MATCH ({}) AGAINST ({} IN NATURAL LANGUAGE MODE)", col, np),
+            Syntax::Mssql => format!("CONTAINS({}, {})", col, np),
+            // FTS5 virtual table
+            Syntax::Sqlite => format!("{} MATCH {}", col, np),
+        })
+    }
+}
+
+/// The relevance of a row to a full-text search, selected as a column.
+/// Higher values are more relevant on all databases
+pub(crate) struct SearchRank {
+    pub(crate) col: String,
+    pub(crate) field_name: String,
+    pub(crate) query: String,
+    /// the name of the table (without schema). Used by sqlite's bm25()
+    pub(crate) table: String,
+    /// the full identifier of the table. Used by mssql's CONTAINSTABLE
+    pub(crate) identifier: String,
+    /// the full-text key of the table. Used by mssql's CONTAINSTABLE
+    pub(crate) key: String,
+}
+
+impl SearchRank {
+    /// true if the search query is sent as a param for this rank.
+    /// sqlite reads the search from the MATCH in the where clause
+    pub(crate) fn has_param(syntax: Syntax) -> bool {
+        syntax != Syntax::Sqlite
+    }
+
+    /// writes out the rank as it goes in the SELECT list of a query
+    pub(crate) fn write(
+        &self,
+        syntax: Syntax,
+        writer: &ColumnWriter,
+        alias: &str,
+        next_params: &NextParam,
+    ) -> String {
+        let col = format!("{}.{}", alias, self.col);
+        let call = match syntax {
+            Syntax::Postgres => format!(
+                "ts_rank(to_tsvector({}), plainto_tsquery({}))",
+                col,
+                next_params.next()
+            ),
+            Syntax::Mysql => format!(
+                "MATCH ({}) AGAINST ({} IN NATURAL LANGUAGE MODE)",
+                col,
+                next_params.next()
+            ),
+            Syntax::Mssql => format!(
+                "(SELECT welds_ft.[RANK] FROM CONTAINSTABLE({}, {}, {}) welds_ft WHERE welds_ft.[KEY] = {}.{})",
+                self.identifier,
+                self.col,
+                next_params.next(),
+                alias,
+                self.key
+            ),
+            // bm25 is best when lowest, flip it so higher is more relevant like the others
+            Syntax::Sqlite => format!("-bm25({})", self.table),
+        };
+        format!("{} as {}", call, writer.excape(&self.field_name))
+    }
+}
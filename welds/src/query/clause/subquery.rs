@@ -0,0 +1,92 @@
+use super::{ClauseAdder, ParamArgs};
+use crate::model_traits::{HasSchema, TableColumns, TableInfo};
+use crate::query::select_cols::SelectBuilder;
+use crate::writers::{ColumnWriter, NextParam};
+use crate::Syntax;
+
+/// Compares a column to the results of a sub-query.
+/// I.E. `t1.id IN ( SELECT ... )` or `t1.price > ( SELECT ... )`
+pub(crate) struct ClauseColQuery<R> {
+    col: String,
+    operator: &'static str,
+    query: SelectBuilder<R>,
+}
+
+/// Builds a clause comparing a column to the results of a sub-query
+pub(crate) fn subquery<R>(
+    col: String,
+    operator: &'static str,
+    query: SelectBuilder<R>,
+) -> Box<dyn ClauseAdder>
+where
+    R: 'static + Send + Sync + HasSchema,
+    <R as HasSchema>::Schema: TableInfo + TableColumns,
+{
+    Box::new(ClauseColQuery {
+        col,
+        operator,
+        query,
+    })
+}
+
+impl<R> ClauseAdder for ClauseColQuery<R>
+where
+    R: Send + Sync + HasSchema,
+    <R as HasSchema>::Schema: TableInfo + TableColumns,
+{
+    fn bind<'lam, 'args, 'p>(&'lam self, args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+        // the args are bound in the order the SQL is written, the SQL itself isn't needed
+        let syntax = Syntax::Postgres;
+        let next_params = NextParam::new(syntax);
+        let mut inner = Some(std::mem::take(args));
+        self.query.sql_with_params(syntax, &next_params, &mut inner);
+        *args = inner.unwrap_or_default();
+    }
+
+    fn clause(&self, syntax: Syntax, alias: &str, next_params: &NextParam) -> Option<String> {
+        let inner_sql = self.query.sql_with_params(syntax, next_params, &mut None);
+        Some(format!(
+            "{}.{} {} ( {} )",
+            alias, self.col, self.operator, inner_sql
+        ))
+    }
+}
+
+/// Checks a column is in a column of a named query from the WITH block
+/// I.E. `t1.id IN ( SELECT "customer_id" FROM "recent_orders" )`
+pub(crate) struct ClauseColCte {
+    col: String,
+    cte: String,
+    cte_col: String,
+}
+
+/// Builds a clause checking a column is in a column of a WITH block query
+pub(crate) fn in_cte(col: String, cte: &str, cte_col: &str) -> Box<dyn ClauseAdder> {
+    Box::new(ClauseColCte {
+        col,
+        cte: cte.to_string(),
+        cte_col: cte_col.to_string(),
+    })
+}
+
+impl ClauseAdder for ClauseColCte {
+    fn bind<'lam, 'args, 'p>(&'lam self, _args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+    }
+
+    fn clause(&self, syntax: Syntax, alias: &str, _next_params: &NextParam) -> Option<String> {
+        let writer = ColumnWriter::new(syntax);
+        Some(format!(
+            "{}.{} IN ( SELECT {} FROM {} )",
+            alias,
+            self.col,
+            writer.excape(&self.cte_col),
+            writer.excape(&self.cte)
+        ))
+    }
+}
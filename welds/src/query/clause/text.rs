@@ -1,4 +1,9 @@
-use super::{AsFieldName, ClauseAdder, ClauseColVal};
+use super::json::{json_contains, JsonPath};
+use super::search::search;
+use super::subquery::{in_cte, subquery};
+use super::{col_col, in_list, AsFieldName, ClauseAdder, ClauseColVal, InList};
+use crate::model_traits::{HasSchema, TableColumns, TableInfo};
+use crate::query::select_cols::SelectBuilder;
 use std::marker::PhantomData;
 use welds_connections::Param;
 
@@ -8,6 +13,16 @@ pub struct Text<T> {
     _t: PhantomData<T>,
 }
 
+impl<T> Clone for Text<T> {
+    fn clone(&self) -> Self {
+        Self {
+            col: self.col.clone(),
+            field: self.field.clone(),
+            _t: PhantomData,
+        }
+    }
+}
+
 impl<T> AsFieldName<T> for Text<T> {
     fn colname(&self) -> &str {
         self.col.as_str()
@@ -85,6 +100,8 @@ where
         Box::new(cv)
     }
 
+    /// Will write SQL checking the value matches the pattern, ignoring case.
+    /// Works on all databases (ilike on Postgres)
     pub fn ilike(self, v: impl Into<T>) -> Box<dyn ClauseAdder>
     where
         T: Param,
@@ -99,6 +116,8 @@ where
         Box::new(cv)
     }
 
+    /// Will write SQL checking the value doesn't match the pattern, ignoring case.
+    /// Works on all databases (not ilike on Postgres)
     pub fn not_ilike(self, v: impl Into<T>) -> Box<dyn ClauseAdder>
     where
         T: Param,
@@ -112,4 +131,118 @@ where
         };
         Box::new(cv)
     }
+
+    /// Will write SQL checking the value contains the text, ignoring case.
+    /// Works on all databases (ilike on Postgres)
+    ///
+    /// NOTE: '%' and '_' in the text are not escaped
+    pub fn contains_ci(self, text: impl AsRef<str>) -> Box<dyn ClauseAdder> {
+        let cv = ClauseColVal::<String> {
+            null_clause: false,
+            not_clause: false,
+            col: self.col,
+            operator: "ilike",
+            val: Some(format!("%{}%", text.as_ref())),
+        };
+        Box::new(cv)
+    }
+
+    /// Will write SQL doing a full-text search of this column.
+    /// The column needs a full-text index (an FTS5 table on sqlite)
+    ///
+    /// Postgres: `to_tsvector(col) @@ plainto_tsquery(query)`
+    /// MySQL: `MATCH (col) AGAINST (query IN NATURAL LANGUAGE MODE)`
+    /// MSSQL: `CONTAINS(col, query)`
+    /// Sqlite: `col MATCH query`
+    pub fn search(self, query: impl Into<String>) -> Box<dyn ClauseAdder> {
+        search(self.col, query.into())
+    }
+
+    /// Read the value at a path inside the json stored in this column to filter on it.
+    /// The path is made of keys and array indexes separated by '.'
+    pub fn json_get(self, path: &str) -> JsonPath {
+        JsonPath::new(self.col, path)
+    }
+
+    /// Will write SQL checking the json stored in this column contains this json document
+    ///
+    /// NOTE: on SQLite and MSSQL the document must be a json object or array, read with `serde`
+    pub fn json_contains(self, json: impl Into<String>) -> Box<dyn ClauseAdder> {
+        json_contains(self.col, json.into())
+    }
+
+    /// Will write SQL checking the value is one of the values in the list (IN)
+    ///
+    /// A list too long for the params of the database is run in chunks.
+    /// Chunks can't be ordered or limited together, those queries fail with `WeldsError::TooManyParams`
+    pub fn in_list<P>(self, slice: &[P]) -> Box<dyn ClauseAdder>
+    where
+        P: Into<T> + Clone,
+        T: Param + PartialEq,
+    {
+        in_list(self.col, false, slice, InList::Params)
+    }
+
+    /// Will write SQL checking the value is one of the values in the list, written as told
+    /// ```rust,ignore
+    /// Order::where_col(|o| o.id.in_list_with(&ids, InList::ValuesOver(1000)))
+    /// ```
+    pub fn in_list_with<P>(self, slice: &[P], strategy: InList) -> Box<dyn ClauseAdder>
+    where
+        P: Into<T> + Clone,
+        T: Param + PartialEq,
+    {
+        in_list(self.col, false, slice, strategy)
+    }
+
+    /// Will write SQL checking the value is not any of the values in the list (NOT IN)
+    pub fn not_in_list<P>(self, slice: &[P]) -> Box<dyn ClauseAdder>
+    where
+        P: Into<T> + Clone,
+        T: Param + PartialEq,
+    {
+        in_list(self.col, true, slice, InList::Params)
+    }
+
+    /// Will write SQL checking the value is equal to another column of the row (=)
+    pub fn equal_col(self, other: impl AsFieldName<T>) -> Box<dyn ClauseAdder> {
+        col_col(self.col, "=", other)
+    }
+
+    /// Will write SQL checking the value is not equal to another column of the row (!=)
+    pub fn not_equal_col(self, other: impl AsFieldName<T>) -> Box<dyn ClauseAdder> {
+        col_col(self.col, "!=", other)
+    }
+
+    /// Will write SQL checking the value is in the results of a sub-query (IN)
+    pub fn in_query<R>(self, query: SelectBuilder<R>) -> Box<dyn ClauseAdder>
+    where
+        R: 'static + Send + Sync + HasSchema,
+        <R as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        subquery(self.col, "IN", query)
+    }
+
+    /// Will write SQL checking the value is not in the results of a sub-query (NOT IN)
+    pub fn not_in_query<R>(self, query: SelectBuilder<R>) -> Box<dyn ClauseAdder>
+    where
+        R: 'static + Send + Sync + HasSchema,
+        <R as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        subquery(self.col, "NOT IN", query)
+    }
+
+    /// Will write SQL checking the value is equal to the result of a scalar sub-query (=)
+    pub fn equal_query<R>(self, query: SelectBuilder<R>) -> Box<dyn ClauseAdder>
+    where
+        R: 'static + Send + Sync + HasSchema,
+        <R as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        subquery(self.col, "=", query)
+    }
+
+    /// Will write SQL checking the value is in a column of a query from the WITH block
+    pub fn in_cte(self, cte: &str, column: &str) -> Box<dyn ClauseAdder> {
+        in_cte(self.col, cte, column)
+    }
 }
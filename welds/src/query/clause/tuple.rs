@@ -0,0 +1,209 @@
+use super::{ClauseAdder, ColumnList, ParamArgs};
+use crate::writers::NextParam;
+use crate::Syntax;
+use std::marker::PhantomData;
+use welds_connections::Param;
+
+/// Compares a few columns to a few values in order, as a row value.
+/// ```rust,ignore
+/// // the posts after (created_at, id)
+/// Post::where_col(|p| Tuple::new((p.created_at, p.id)).gt((created_at, id)));
+/// // WHERE (t1.created_at, t1.id) > ($1, $2)
+/// ```
+/// MSSQL can't compare row values, the compare is expanded out into ANDs and ORs
+pub struct Tuple<V> {
+    cols: Vec<String>,
+    _v: PhantomData<V>,
+}
+
+impl<V> Tuple<V>
+where
+    V: TupleValues + 'static,
+{
+    pub fn new(cols: impl ColumnList<V>) -> Self {
+        Self {
+            cols: cols.colnames(),
+            _v: PhantomData,
+        }
+    }
+
+    fn compare(self, operator: &'static str, values: V) -> Box<dyn ClauseAdder> {
+        Box::new(ClauseTuple {
+            cols: self.cols,
+            operator,
+            values,
+        })
+    }
+
+    /// Will write SQL checking every column is equal to its value (=)
+    pub fn equal(self, values: V) -> Box<dyn ClauseAdder> {
+        self.compare("=", values)
+    }
+
+    /// Will write SQL checking the columns sort after the values (>)
+    pub fn gt(self, values: V) -> Box<dyn ClauseAdder> {
+        self.compare(">", values)
+    }
+
+    /// Will write SQL checking the columns sort after or are equal to the values (>=)
+    pub fn gte(self, values: V) -> Box<dyn ClauseAdder> {
+        self.compare(">=", values)
+    }
+
+    /// Will write SQL checking the columns sort before the values (<)
+    pub fn lt(self, values: V) -> Box<dyn ClauseAdder> {
+        self.compare("<", values)
+    }
+
+    /// Will write SQL checking the columns sort before or are equal to the values (<=)
+    pub fn lte(self, values: V) -> Box<dyn ClauseAdder> {
+        self.compare("<=", values)
+    }
+}
+
+/// The values a `Tuple` is compared to. Implemented for tuples of values
+pub trait TupleValues: Send + Sync {
+    fn params(&self) -> Vec<&(dyn Param + Sync)>;
+}
+
+macro_rules! impl_tuple_values {
+    ($($value:ident $idx:tt),+) => {
+        impl<$($value),+> TupleValues for ($($value,)+)
+        where
+            $($value: Param + Send + Sync),+
+        {
+            fn params(&self) -> Vec<&(dyn Param + Sync)> {
+                vec![$(&self.$idx),+]
+            }
+        }
+    };
+}
+
+impl_tuple_values!(VA 0);
+impl_tuple_values!(VA 0, VB 1);
+impl_tuple_values!(VA 0, VB 1, VC 2);
+impl_tuple_values!(VA 0, VB 1, VC 2, VD 3);
+impl_tuple_values!(VA 0, VB 1, VC 2, VD 3, VE 4);
+impl_tuple_values!(VA 0, VB 1, VC 2, VD 3, VE 4, VF 5);
+
+struct ClauseTuple<V> {
+    cols: Vec<String>,
+    operator: &'static str,
+    values: V,
+}
+
+impl<V> ClauseAdder for ClauseTuple<V>
+where
+    V: TupleValues,
+{
+    fn bind<'lam, 'args, 'p>(&'lam self, args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+        for param in self.values.params() {
+            args.push(param);
+        }
+    }
+
+    fn clause(&self, syntax: Syntax, alias: &str, next_params: &NextParam) -> Option<String> {
+        let cols: Vec<String> = self
+            .cols
+            .iter()
+            .map(|c| format!("{}.{}", alias, c))
+            .collect();
+        // each value is bound once, MSSQL numbers its params so they can be used again
+        let params: Vec<String> = self.cols.iter().map(|_| next_params.next()).collect();
+        Some(write_compare(syntax, &cols, self.operator, |i| {
+            params[i].clone()
+        }))
+    }
+}
+
+/// writes a row value compare. I.E. `(t1.a, t1.b) > ($1, $2)`
+///
+/// On MSSQL it is expanded out to `( a > x ) OR ( a = x AND b > y ) ...`,
+/// `param` is called for each value in the order they are written
+pub(crate) fn write_compare(
+    syntax: Syntax,
+    cols: &[String],
+    operator: &str,
+    mut param: impl FnMut(usize) -> String,
+) -> String {
+    if syntax != Syntax::Mssql {
+        let params: Vec<String> = (0..cols.len()).map(param).collect();
+        return format!("({}) {} ({})", cols.join(", "), operator, params.join(", "));
+    }
+
+    if operator == "=" {
+        let ands: Vec<String> = cols
+            .iter()
+            .enumerate()
+            .map(|(i, col)| format!("{} = {}", col, param(i)))
+            .collect();
+        return format!("( {} )", ands.join(" AND "));
+    }
+
+    // only the last column can be equal for >= and <=
+    let strict = operator.trim_end_matches('=');
+    let mut ors = Vec::default();
+    for (i, col) in cols.iter().enumerate() {
+        let mut ands = Vec::default();
+        for (j, prev) in cols.iter().enumerate().take(i) {
+            ands.push(format!("{} = {}", prev, param(j)));
+        }
+        let op = if i + 1 == cols.len() {
+            operator
+        } else {
+            strict
+        };
+        ands.push(format!("{} {} {}", col, op, param(i)));
+        ors.push(format!("( {} )", ands.join(" AND ")));
+    }
+    format!("( {} )", ors.join(" OR "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WeldsModel;
+
+    #[derive(Debug, WeldsModel)]
+    #[welds(table = "posts")]
+    #[welds_path(crate)] // needed only within the welds crate.
+    struct Post {
+        #[welds(primary_key)]
+        pub id: i32,
+        pub score: i64,
+    }
+
+    fn cols() -> Vec<String> {
+        vec!["t1.a".to_owned(), "t1.b".to_owned()]
+    }
+
+    #[test]
+    fn should_write_a_row_value_compare() {
+        let next_params = NextParam::new(Syntax::Postgres);
+        let sql = write_compare(Syntax::Postgres, &cols(), ">", |_| next_params.next());
+        assert_eq!("(t1.a, t1.b) > ($1, $2)", sql);
+    }
+
+    #[test]
+    fn should_expand_the_compare_on_mssql() {
+        let params = ["@p1", "@p2"];
+        let sql = write_compare(Syntax::Mssql, &cols(), ">=", |i| params[i].to_owned());
+        let expected = "( ( t1.a > @p1 ) OR ( t1.a = @p1 AND t1.b >= @p2 ) )";
+        assert_eq!(expected, sql);
+        let sql = write_compare(Syntax::Mssql, &cols(), "=", |i| params[i].to_owned());
+        assert_eq!("( t1.a = @p1 AND t1.b = @p2 )", sql);
+    }
+
+    #[test]
+    fn should_filter_on_a_tuple_of_columns() {
+        let q = Post::where_col(|p| Tuple::new((p.score, p.id)).lt((10, 3)));
+        let expected =
+            r#"SELECT t1."id", t1."score" FROM posts t1 WHERE ( (t1.score, t1.id) < ($1, $2) )"#;
+        assert_eq!(expected, q.to_sql(Syntax::Postgres));
+        let (_, args) = q.to_sql_with_params(Syntax::Mssql);
+        assert_eq!(2, args.len());
+    }
+}
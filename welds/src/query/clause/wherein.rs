@@ -1,3 +1,4 @@
+use super::orderby;
 use super::ClauseAdder;
 use crate::model_traits::HasSchema;
 use crate::model_traits::TableColumns;
@@ -5,14 +6,13 @@ use crate::model_traits::TableInfo;
 use crate::model_traits::UniqueIdentifier;
 use crate::query::builder::QueryBuilder;
 use crate::query::clause::ParamArgs;
-use crate::query::helpers::{build_tail, build_where, join_sql_parts};
+use crate::query::helpers::{build_tail, build_top, build_where, join_sql_parts, primary_keys};
 use crate::writers::ColumnWriter;
 use crate::writers::NextParam;
 use crate::Syntax;
 
 /// Used to generated a SQL IN clause.
 /// This is used when deleting and updating to be able to apply limit
-
 pub struct WhereIn<'qb, T> {
     qb: &'qb QueryBuilder<T>,
 }
@@ -47,6 +47,7 @@ where
         for w in &self.qb.exist_ins {
             w.bind(args);
         }
+        orderby::bind(&self.qb.orderby, args);
     }
 
     fn clause(&self, syntax: Syntax, alias: &str, next_params: &NextParam) -> Option<String> {
@@ -56,7 +57,11 @@ where
         let inner_alias = &self.qb.alias;
         let mut args = None;
         let inner_sql = join_sql_parts(&[
-            build_head_select::<<T as HasSchema>::Schema>(syntax, inner_alias),
+            build_head_select::<<T as HasSchema>::Schema>(
+                syntax,
+                inner_alias,
+                build_top(syntax, self.qb),
+            ),
             build_where(
                 syntax,
                 next_params,
@@ -65,14 +70,20 @@ where
                 &mut args,
                 &self.qb.exist_ins,
             ),
-            build_tail(syntax, self.qb),
+            build_tail(
+                syntax,
+                self.qb,
+                &primary_keys(self.qb),
+                next_params,
+                &mut None,
+            ),
         ]);
 
         Some(format!(" {} IN ({}) ", outcol, inner_sql))
     }
 }
 
-fn build_head_select<S>(syntax: Syntax, tablealias: &str) -> Option<String>
+fn build_head_select<S>(syntax: Syntax, tablealias: &str, top: Option<String>) -> Option<String>
 where
     S: TableInfo + UniqueIdentifier,
 {
@@ -83,5 +94,8 @@ where
     let writer = ColumnWriter::new(syntax);
     let col_raw = S::id_column();
     let col = writer.write(tablealias, &col_raw);
-    Some(format!("SELECT {} FROM {}", col, tablename))
+    match top {
+        Some(top) => Some(format!("SELECT {} {} FROM {}", top, col, tablename)),
+        None => Some(format!("SELECT {} FROM {}", col, tablename)),
+    }
 }
@@ -1,18 +1,16 @@
 use super::super::{
     builder::QueryBuilder,
     clause::{wherein::WhereIn, ClauseAdder},
-    helpers::{build_where, join_sql_parts},
+    helpers::{self, build_where, join_sql_parts},
 };
 use crate::errors::Result;
 use crate::model_traits::UniqueIdentifier;
-use crate::model_traits::{HasSchema, TableColumns, TableInfo};
+use crate::model_traits::{HasSchema, SoftDelete, TableColumns, TableInfo, Versioned};
 use crate::query::clause::ParamArgs;
-use crate::writers::ColumnWriter;
-use crate::writers::CountWriter;
-use crate::writers::LimitSkipWriter;
-use crate::writers::NextParam;
+use crate::query::update::bulk::{build_where_update, version_bump};
+use crate::writers::{ColumnWriter, NextParam};
 use crate::Syntax;
-use welds_connections::Client;
+use welds_connections::{Client, Param};
 
 // ******************************************************************************************
 // This file contains code on a Query builder to allow it to bulk delete
@@ -24,16 +22,23 @@ where
 {
     /// The SQL to delete a `DELETE FROM ... `
     ///
-    /// return SQL to delete all the resulting rows from the database
+    /// return SQL to delete all the resulting rows from the database.
+    /// On a model with `#[welds(soft_delete = "..")]` this is the `UPDATE` marking them deleted
     pub fn delete_sql(&self, syntax: Syntax) -> String
     where
+        T: Versioned,
         <T as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
     {
         // we are wrapping this query in a where in clause.
         // This is needed if the user has a limit
         let mut w_in = WhereIn::new(self);
 
-        self.delete_sql_internal(syntax, &mut w_in, &mut None)
+        match &self.soft_delete {
+            Some((column, _)) => {
+                self.soft_delete_sql_internal(syntax, column, None, &mut w_in, &mut None)
+            }
+            None => self.delete_sql_internal(syntax, &mut w_in, &mut None),
+        }
     }
 
     fn delete_sql_internal<'s, 'w, 'args, 'p>(
@@ -58,28 +63,100 @@ where
         ])
     }
 
+    /// An `UPDATE ... SET deleted_at = now` marking the rows as deleted
+    fn soft_delete_sql_internal<'s, 'w, 'args, 'p>(
+        &'s self,
+        syntax: Syntax,
+        column: &str,
+        now: Option<&'p (dyn Param + Send + Sync)>,
+        w_in: &'w mut WhereIn<T>,
+        args: &'args mut Option<ParamArgs<'p>>,
+    ) -> String
+    where
+        'w: 'p,
+        's: 'p,
+        T: Versioned,
+        <T as HasSchema>::Schema: UniqueIdentifier + TableInfo + TableColumns,
+    {
+        let next_params = NextParam::new(syntax);
+        let identifier = <T as HasSchema>::Schema::identifier().join(".");
+
+        let mut set = format!(
+            "{}={}",
+            ColumnWriter::new(syntax).excape(column),
+            next_params.next()
+        );
+        if let Some(version) = T::version_column() {
+            set = format!("{}, {}", set, version_bump(syntax, version));
+        }
+        if let (Some(args), Some(now)) = (args.as_mut(), now) {
+            args.push(now);
+        }
+
+        join_sql_parts(&[
+            Some(format!("UPDATE {} SET {}", identifier, set)),
+            build_where_update(syntax, w_in, &next_params, &identifier, args, self),
+        ])
+    }
+
     /// Executes a `DELETE FROM ... `
     ///
-    /// deletes all the resulting rows from the database
-    pub async fn delete<'s, 'c>(&'s self, client: &'c dyn Client) -> Result<()>
+    /// deletes all the resulting rows from the database.
+    /// The rows of a model with `#[welds(soft_delete = "..")]` are marked as deleted instead,
+    /// use `force_delete` to remove them
+    pub async fn delete(&self, client: &dyn Client) -> Result<()>
     where
+        T: SoftDelete + Versioned,
         <T as HasSchema>::Schema: UniqueIdentifier + TableInfo + TableColumns,
     {
-        // we are wrapping this query in a where in clause.
-        // This is needed if the user has a limit
-        let w_in_q = self;
-        let mut w_in = WhereIn::new(w_in_q);
+        let column = match &self.soft_delete {
+            Some((column, _)) => column,
+            None => return self.force_delete(client).await,
+        };
+        let now = T::deleted_now().ok_or_else(|| {
+            anyhow::anyhow!("The model doesn't soft delete, add #[welds(soft_delete = \"..\")]")
+        })?;
 
         let syntax = client.syntax();
-        let mut args: Option<ParamArgs> = Some(Vec::default());
-        let sql = self.delete_sql_internal(syntax, &mut w_in, &mut args);
-        let args: ParamArgs = args.unwrap();
-        client.execute(&sql, &args).await?;
+        let queries = self.split_for_params(syntax)?;
+        let queries = queries.as_deref().unwrap_or(std::slice::from_ref(self));
+
+        for q in queries {
+            let mut w_in = WhereIn::new(q);
+            let mut args: Option<ParamArgs> = Some(Vec::default());
+            let sql = q.soft_delete_sql_internal(syntax, column, Some(&*now), &mut w_in, &mut args);
+            let args: ParamArgs = args.unwrap();
+            helpers::execute(client, &sql, &args, q.timeout).await?;
+        }
+        Ok(())
+    }
+
+    /// Executes a `DELETE FROM ... `
+    ///
+    /// deletes all the resulting rows from the database, even the rows of a soft deleted model
+    pub async fn force_delete(&self, client: &dyn Client) -> Result<()>
+    where
+        <T as HasSchema>::Schema: UniqueIdentifier + TableInfo + TableColumns,
+    {
+        let syntax = client.syntax();
+        // a list of values too long for the database is deleted in chunks
+        let queries = self.split_for_params(syntax)?;
+        let queries = queries.as_deref().unwrap_or(std::slice::from_ref(self));
+
+        for q in queries {
+            // we are wrapping this query in a where in clause.
+            // This is needed if the user has a limit
+            let mut w_in = WhereIn::new(q);
+            let mut args: Option<ParamArgs> = Some(Vec::default());
+            let sql = q.delete_sql_internal(syntax, &mut w_in, &mut args);
+            let args: ParamArgs = args.unwrap();
+            helpers::execute(client, &sql, &args, q.timeout).await?;
+        }
         Ok(())
     }
 }
 
-fn build_head_delete<S>(syntax: Syntax) -> Option<String>
+fn build_head_delete<S>(_syntax: Syntax) -> Option<String>
 where
     S: TableInfo + TableColumns,
 {
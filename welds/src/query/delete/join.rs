@@ -0,0 +1,261 @@
+use crate::errors::Result;
+use crate::model_traits::UniqueIdentifier;
+use crate::model_traits::{HasSchema, SoftDelete, TableColumns, TableInfo, Versioned};
+use crate::query::builder::QueryBuilder;
+use crate::query::clause::exists::ExistIn;
+use crate::query::clause::{ClauseAdder, ParamArgs};
+use crate::query::helpers::{self, build_where_clauses};
+use crate::query::update::bulk::version_bump;
+use crate::relations::{HasRelations, Relationship};
+use crate::writers::{ColumnWriter, NextParam};
+use crate::Syntax;
+use std::sync::Arc;
+use welds_connections::{Client, Param};
+
+// ******************************************************************************************
+// This file contains code to delete rows filtered by the rows of related tables
+// joined into the same DELETE statement
+// ******************************************************************************************
+
+/// An un-executed Sql Delete that joins in related tables to filter the rows to delete.
+/// ```rust,ignore
+/// // delete all the orders of banned customers
+/// Order::all()
+///     .delete_join(|o| o.customer, Customer::where_col(|c| c.banned.equal(true)))
+///     .run(client)
+///     .await?;
+/// ```
+///
+/// NOTE: Sqlite can't join in a DELETE, the joins are written as EXISTS sub-queries.
+/// Limits and orders of the query are not used.
+/// The rows of a model with `#[welds(soft_delete = "..")]` are marked as deleted with an
+/// `UPDATE`, its joins are written as EXISTS sub-queries. Use `force_run` to remove them
+pub struct DeleteJoinBuilder<T> {
+    qb: QueryBuilder<T>,
+    joins: Vec<DeleteJoin>,
+}
+
+struct DeleteJoin {
+    tablename: String,
+    alias: String,
+    outer_column: String,
+    inner_column: String,
+    wheres: Vec<Arc<dyn ClauseAdder>>,
+    exist_ins: Vec<ExistIn>,
+}
+
+impl DeleteJoin {
+    fn on(&self, outer_alias: &str) -> String {
+        format!(
+            "{}.{} = {}.{}",
+            outer_alias, self.outer_column, self.alias, self.inner_column
+        )
+    }
+}
+
+impl<T> QueryBuilder<T>
+where
+    T: Send + HasSchema,
+{
+    /// Delete the rows of this query, joining in a relationship to filter on the related rows.
+    /// See `DeleteJoinBuilder`
+    pub fn delete_join<R, Ship>(
+        self,
+        relationship: impl Fn(<T as HasRelations>::Relation) -> Ship,
+        filter: QueryBuilder<R>,
+    ) -> DeleteJoinBuilder<T>
+    where
+        T: HasRelations,
+        Ship: Relationship<R>,
+        R: Send + Sync + HasSchema,
+        <R as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasRelations>::Relation: Default,
+    {
+        DeleteJoinBuilder {
+            qb: self,
+            joins: Vec::default(),
+        }
+        .join(relationship, filter)
+    }
+}
+
+impl<T> DeleteJoinBuilder<T>
+where
+    T: Send + HasSchema,
+{
+    /// Join in another relationship to filter on
+    pub fn join<R, Ship>(
+        mut self,
+        relationship: impl Fn(<T as HasRelations>::Relation) -> Ship,
+        mut filter: QueryBuilder<R>,
+    ) -> Self
+    where
+        T: HasRelations,
+        Ship: Relationship<R>,
+        R: Send + Sync + HasSchema,
+        <R as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasRelations>::Relation: Default,
+    {
+        let ship = relationship(Default::default());
+        filter.set_aliases(&self.qb.alias_asigner);
+        self.joins.push(DeleteJoin {
+            tablename: <R as HasSchema>::Schema::identifier().join("."),
+            alias: filter.alias,
+            outer_column: ship.my_key::<R::Schema, T::Schema>(),
+            inner_column: ship.their_key::<R::Schema, T::Schema>(),
+            wheres: filter.wheres,
+            exist_ins: filter.exist_ins,
+        });
+        self
+    }
+
+    /// writes the DELETE, or with `soft` the UPDATE setting the soft delete column to `now`
+    fn sql_internal<'s, 'args, 'p>(
+        &'s self,
+        syntax: Syntax,
+        soft: Option<(&str, Option<&'p (dyn Param + Send + Sync)>)>,
+        args: &'args mut Option<ParamArgs<'p>>,
+    ) -> String
+    where
+        's: 'p,
+        T: Versioned,
+        <T as HasSchema>::Schema: TableInfo,
+    {
+        let next_params = NextParam::new(syntax);
+        let tn = <T as HasSchema>::Schema::identifier().join(".");
+        let alias = &self.qb.alias;
+
+        // the value set is the first param
+        let set = soft.map(|(column, now)| {
+            if let (Some(args), Some(now)) = (args.as_mut(), now) {
+                args.push(now);
+            }
+            let column = ColumnWriter::new(syntax).excape(column);
+            let set = format!("{}={}", column, next_params.next());
+            match T::version_column() {
+                Some(version) => format!("{}, {}", set, version_bump(syntax, version)),
+                None => set,
+            }
+        });
+
+        // the filters of the joined tables come first, then the filters of this table
+        let mut wheres: Vec<String> = Vec::default();
+        for join in &self.joins {
+            let inner = build_where_clauses(
+                syntax,
+                &next_params,
+                &join.alias,
+                &join.wheres,
+                args,
+                &join.exist_ins,
+            );
+            if syntax == Syntax::Sqlite || set.is_some() {
+                let mut inner_wheres = vec![join.on(alias)];
+                inner_wheres.extend(inner);
+                wheres.push(format!(
+                    "EXISTS ( SELECT 1 FROM {} {} WHERE {} )",
+                    join.tablename,
+                    join.alias,
+                    inner_wheres.join(" AND ")
+                ));
+                continue;
+            }
+            if syntax == Syntax::Postgres {
+                wheres.push(join.on(alias));
+            }
+            wheres.extend(inner);
+        }
+        let qb = &self.qb;
+        wheres.extend(build_where_clauses(
+            syntax,
+            &next_params,
+            alias,
+            &qb.wheres,
+            args,
+            &qb.exist_ins,
+        ));
+
+        let joined: Vec<String> = self
+            .joins
+            .iter()
+            .map(|j| format!("{} {}", j.tablename, j.alias))
+            .collect();
+        let head = match (syntax, set) {
+            (Syntax::Mssql, Some(set)) => {
+                format!("UPDATE {} SET {} FROM {} {}", alias, set, tn, alias)
+            }
+            (_, Some(set)) => format!("UPDATE {} AS {} SET {}", tn, alias, set),
+            (Syntax::Postgres, None) => {
+                format!("DELETE FROM {} {} USING {}", tn, alias, joined.join(", "))
+            }
+            (Syntax::Mysql | Syntax::Mssql, None) => {
+                let joins: Vec<String> = self
+                    .joins
+                    .iter()
+                    .map(|j| format!("JOIN {} {} ON {}", j.tablename, j.alias, j.on(alias)))
+                    .collect();
+                format!("DELETE {} FROM {} {} {}", alias, tn, alias, joins.join(" "))
+            }
+            (Syntax::Sqlite, None) => format!("DELETE FROM {} AS {}", tn, alias),
+        };
+
+        if wheres.is_empty() {
+            return head;
+        }
+        format!("{} WHERE ( {} )", head, wheres.join(" AND "))
+    }
+
+    /// Get a copy of the SQL that will be executed when this query runs
+    pub fn to_sql(&self, syntax: Syntax) -> String
+    where
+        T: Versioned,
+        <T as HasSchema>::Schema: TableInfo,
+    {
+        let soft = self
+            .qb
+            .soft_delete
+            .as_ref()
+            .map(|(c, _)| (c.as_str(), None));
+        self.sql_internal(syntax, soft, &mut None)
+    }
+
+    /// Executes the `DELETE`
+    ///
+    /// deletes all the rows matching the filters on this and the joined tables.
+    /// The rows of a model with `#[welds(soft_delete = "..")]` are marked as deleted instead
+    pub async fn run(&self, client: &dyn Client) -> Result<()>
+    where
+        T: SoftDelete + Versioned,
+        <T as HasSchema>::Schema: TableInfo,
+    {
+        let column = match &self.qb.soft_delete {
+            Some((column, _)) => column,
+            None => return self.force_run(client).await,
+        };
+        let now = T::deleted_now().ok_or_else(|| {
+            anyhow::anyhow!("The model doesn't soft delete, add #[welds(soft_delete = \"..\")]")
+        })?;
+        let syntax = client.syntax();
+        let mut args: Option<ParamArgs> = Some(Vec::default());
+        let sql = self.sql_internal(syntax, Some((column, Some(&*now))), &mut args);
+        let args = args.unwrap();
+        helpers::execute(client, &sql, &args, self.qb.timeout).await?;
+        Ok(())
+    }
+
+    /// Executes the `DELETE`, even on the rows of a soft deleted model
+    pub async fn force_run(&self, client: &dyn Client) -> Result<()>
+    where
+        T: Versioned,
+        <T as HasSchema>::Schema: TableInfo,
+    {
+        let syntax = client.syntax();
+        let mut args: Option<ParamArgs> = Some(Vec::default());
+        let sql = self.sql_internal(syntax, None, &mut args);
+        let args = args.unwrap();
+        helpers::execute(client, &sql, &args, self.qb.timeout).await?;
+        Ok(())
+    }
+}
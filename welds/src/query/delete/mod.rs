@@ -1,13 +1,15 @@
 use crate::errors::Result;
 use crate::errors::WeldsError;
 use crate::model_traits::hooks::{AfterDelete, BeforeDelete};
-use crate::model_traits::{HasSchema, TableColumns, TableInfo, WriteToArgs};
+use crate::model_traits::{HasSchema, SoftDelete, TableColumns, TableInfo, Versioned, WriteToArgs};
 use crate::query::clause::ParamArgs;
+use crate::query::update::bulk::version_bump;
 use crate::writers::ColumnWriter;
 use crate::writers::NextParam;
 use welds_connections::Client;
 
 pub mod bulk;
+pub mod join;
 
 pub async fn delete_one<T>(obj: &T, client: &dyn Client) -> Result<()>
 where
@@ -45,5 +47,51 @@ where
     Ok(())
 }
 
+/// Marks the model as deleted, or restores it, setting its soft delete column.
+/// The row stays in the database
+pub async fn soft_delete_one<T>(obj: &mut T, deleted: bool, client: &dyn Client) -> Result<()>
+where
+    T: HasSchema + WriteToArgs + SoftDelete + Versioned,
+    <T as HasSchema>::Schema: TableInfo + TableColumns,
+{
+    let column = T::soft_delete_column().ok_or_else(|| {
+        anyhow::anyhow!("The model doesn't soft delete, add #[welds(soft_delete = \"..\")]")
+    })?;
+    obj.set_deleted(deleted);
+
+    let syntax = client.syntax();
+    let col_writer = ColumnWriter::new(syntax);
+    let next_params = NextParam::new(syntax);
+    let identifier = <<T as HasSchema>::Schema>::identifier().join(".");
+
+    let pks = <<T as HasSchema>::Schema as TableColumns>::primary_keys();
+    if pks.is_empty() {
+        return Err(WeldsError::NoPrimaryKey);
+    }
+
+    let mut args: ParamArgs = Vec::default();
+    obj.bind(column, &mut args)?;
+    let mut set = format!("{}={}", col_writer.excape(column), next_params.next());
+    if let Some(version) = T::version_column() {
+        set = format!("{}, {}", set, version_bump(syntax, version));
+    }
+
+    let mut wheres = Vec::default();
+    for col in pks {
+        obj.bind(col.name(), &mut args)?;
+        let p = next_params.next();
+        let colname = col_writer.excape(col.name());
+        wheres.push(format!("{}={}", colname, p));
+    }
+    let wheres = wheres.join(" AND ");
+
+    let sql = format!("UPDATE {} SET {} where {}", identifier, set, wheres);
+    client.execute(&sql, &args).await?;
+    if T::version_column().is_some() {
+        obj.next_version();
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests;
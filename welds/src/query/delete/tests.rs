@@ -1,6 +1,5 @@
 use crate::state::DbState;
 use crate::Syntax;
-use welds_connections::Row;
 
 // Testing that the tail end of the SQL is correct
 // Limits / skips / orders
@@ -31,6 +30,72 @@ fn should_be_able_to_delete_simple_object() {
     });
 }
 
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "user_roles")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct UserRole {
+    #[welds(primary_key)]
+    pub user_id: i32,
+    #[welds(primary_key)]
+    pub role_id: i32,
+}
+
+#[test]
+fn should_delete_by_every_column_of_a_composite_key() {
+    futures::executor::block_on(async move {
+        let mut obj = DbState::db_loaded(UserRole::default());
+        let client = welds_connections::noop::build(Syntax::Mysql);
+        obj.delete(&client).await.unwrap();
+        let ran_sql = client.last_sql().unwrap();
+        let expected = "DELETE FROM user_roles where user_id=? AND role_id=?";
+        assert_eq!(expected, &ran_sql);
+        assert_eq!(client.args_count().unwrap(), 2);
+    });
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "posts", soft_delete = "deleted_at")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Post {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub deleted_at: Option<String>,
+}
+
+#[test]
+fn should_mark_a_soft_deleted_model_as_deleted() {
+    futures::executor::block_on(async move {
+        let mut obj = DbState::db_loaded(Post::default());
+        let client = welds_connections::noop::build(Syntax::Mysql);
+        obj.delete(&client).await.unwrap();
+        let ran_sql = client.last_sql().unwrap();
+        assert_eq!("UPDATE posts SET deleted_at=? where id=?", &ran_sql);
+        assert_eq!(obj.deleted_at.as_deref(), Some("now"));
+        // the row is still in the database
+        assert_eq!(obj.db_status(), crate::state::DbStatus::NotModified);
+
+        obj.restore(&client).await.unwrap();
+        assert_eq!(obj.deleted_at, None);
+    });
+}
+
+#[test]
+fn should_hide_the_soft_deleted_rows() {
+    let sql = Post::where_col(|p| p.id.gt(1)).to_sql(Syntax::Mysql);
+    let expected =
+        "SELECT t1.id, t1.deleted_at FROM posts t1 WHERE ( t1.deleted_at IS NULL AND t1.id > ? )";
+    assert_eq!(expected, sql);
+
+    let sql = Post::with_deleted().to_sql(Syntax::Mysql);
+    assert_eq!("SELECT t1.id, t1.deleted_at FROM posts t1", sql);
+
+    let sql = Post::where_col(|p| p.id.gt(1))
+        .only_deleted()
+        .to_sql(Syntax::Mysql);
+    let expected = "SELECT t1.id, t1.deleted_at FROM posts t1 WHERE ( t1.id > ? AND t1.deleted_at IS NOT NULL )";
+    assert_eq!(expected, sql);
+}
+
 #[test]
 fn should_be_able_to_write_delete_query() {
     futures::executor::block_on(async move {
@@ -61,3 +126,216 @@ fn should_be_able_to_write_delete_query_with_limit() {
         assert_eq!(args_count, 1);
     });
 }
+
+#[test]
+fn should_order_by_the_primary_key_when_offsetting_on_mssql() {
+    use crate::query::builder::QueryBuilder;
+    let q = QueryBuilder::<Product>::new().limit(10).offset(20);
+    let expected = "SELECT t1.\"id\", t1.\"a\", t1.\"b\" FROM nums t1 ORDER BY t1.id OFFSET 20 ROWS FETCH FIRST 10 ROWS ONLY";
+    assert_eq!(expected, q.to_sql(Syntax::Mssql));
+    // the other databases page without an order
+    let expected =
+        "SELECT t1.\"id\", t1.\"a\", t1.\"b\" FROM nums t1 ORDER BY 1 OFFSET 20 LIMIT 10";
+    assert_eq!(expected, q.to_sql(Syntax::Postgres));
+}
+
+#[test]
+fn should_delete_with_a_top_on_mssql() {
+    futures::executor::block_on(async move {
+        use crate::query::builder::QueryBuilder;
+        let q = QueryBuilder::<Product>::new()
+            .where_col(|c| c.a.gt(1))
+            .limit(10);
+        let client = welds_connections::noop::build(Syntax::Mssql);
+        let _ = q.delete(&client).await;
+        let ran_sql = client.last_sql().unwrap();
+        let expected = "DELETE FROM nums WHERE (  nums.id IN (SELECT TOP (10) t1.\"id\" FROM nums t1 WHERE ( t1.a > @p1 ))  )";
+        assert_eq!(expected, &ran_sql);
+    });
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "customers")]
+#[welds_path(crate)] // needed only within the welds crate.
+#[welds(HasMany(orders, Order, "customer_id"))]
+struct Customer {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub banned: bool,
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "orders")]
+#[welds_path(crate)] // needed only within the welds crate.
+#[welds(BelongsTo(customer, Customer, "customer_id"))]
+struct Order {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub customer_id: i32,
+    pub total: i32,
+}
+
+fn delete_orders_of_banned(syntax: Syntax) -> String {
+    use crate::query::builder::QueryBuilder;
+    let banned = QueryBuilder::<Customer>::new().where_col(|c| c.banned.equal(true));
+    QueryBuilder::<Order>::new()
+        .where_col(|o| o.total.lt(10))
+        .delete_join(|o| o.customer, banned)
+        .to_sql(syntax)
+}
+
+#[test]
+fn should_delete_using_a_relationship_on_postgres() {
+    let expected = "DELETE FROM orders t1 USING customers t2 WHERE ( t1.customer_id = t2.id AND t2.banned = $1 AND t1.total < $2 )";
+    assert_eq!(expected, delete_orders_of_banned(Syntax::Postgres));
+}
+
+#[test]
+fn should_delete_from_a_join_on_mysql() {
+    let expected = "DELETE t1 FROM orders t1 JOIN customers t2 ON t1.customer_id = t2.id WHERE ( t2.banned = ? AND t1.total < ? )";
+    assert_eq!(expected, delete_orders_of_banned(Syntax::Mysql));
+}
+
+#[test]
+fn should_delete_from_a_join_on_mssql() {
+    let expected = "DELETE t1 FROM orders t1 JOIN customers t2 ON t1.customer_id = t2.id WHERE ( t2.banned = @p1 AND t1.total < @p2 )";
+    assert_eq!(expected, delete_orders_of_banned(Syntax::Mssql));
+}
+
+#[test]
+fn should_delete_with_exists_on_sqlite() {
+    let expected = "DELETE FROM orders AS t1 WHERE ( EXISTS ( SELECT 1 FROM customers t2 WHERE t1.customer_id = t2.id AND t2.banned = ? ) AND t1.total < ? )";
+    assert_eq!(expected, delete_orders_of_banned(Syntax::Sqlite));
+}
+
+#[test]
+fn should_mark_the_rows_of_a_soft_deleted_model_as_deleted_in_bulk() {
+    futures::executor::block_on(async move {
+        let client = welds_connections::noop::build(Syntax::Mysql);
+        Post::where_col(|p| p.id.gt(1))
+            .delete(&client)
+            .await
+            .unwrap();
+        let ran_sql = client.last_sql().unwrap();
+        let expected =
+            "UPDATE posts SET deleted_at=? WHERE ( posts.deleted_at IS NULL AND posts.id > ? )";
+        assert_eq!(expected, &ran_sql);
+        assert_eq!(client.args_count().unwrap(), 2);
+
+        let sql = Post::where_col(|p| p.id.gt(1)).delete_sql(Syntax::Mysql);
+        assert_eq!(expected, &sql);
+    });
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "docs", soft_delete = "deleted_at")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Doc {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub deleted_at: Option<String>,
+    #[welds(version)]
+    pub lock_version: i32,
+}
+
+#[test]
+fn should_move_the_version_forward_when_soft_deleting() {
+    futures::executor::block_on(async move {
+        let client = welds_connections::noop::build(Syntax::Mysql);
+        Doc::where_col(|d| d.id.gt(1))
+            .delete(&client)
+            .await
+            .unwrap();
+        let ran_sql = client.last_sql().unwrap();
+        let expected = "UPDATE docs SET deleted_at=?, lock_version=lock_version + 1 WHERE ( docs.deleted_at IS NULL AND docs.id > ? )";
+        assert_eq!(expected, &ran_sql);
+
+        let mut obj = DbState::db_loaded(Doc::default());
+        obj.delete(&client).await.unwrap();
+        let ran_sql = client.last_sql().unwrap();
+        let expected = "UPDATE docs SET deleted_at=?, lock_version=lock_version + 1 where id=?";
+        assert_eq!(expected, &ran_sql);
+        assert_eq!(obj.lock_version, 1);
+    });
+}
+
+#[test]
+fn should_really_delete_the_rows_of_a_soft_deleted_model_when_forced() {
+    futures::executor::block_on(async move {
+        let client = welds_connections::noop::build(Syntax::Mysql);
+        Post::where_col(|p| p.id.gt(1))
+            .force_delete(&client)
+            .await
+            .unwrap();
+        let ran_sql = client.last_sql().unwrap();
+        let expected = "DELETE FROM posts WHERE ( posts.deleted_at IS NULL AND posts.id > ? )";
+        assert_eq!(expected, &ran_sql);
+
+        let mut obj = DbState::db_loaded(Post::default());
+        obj.force_delete(&client).await.unwrap();
+        let ran_sql = client.last_sql().unwrap();
+        assert_eq!("DELETE FROM posts where id=?", &ran_sql);
+    });
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "reviews", soft_delete = "deleted_at")]
+#[welds_path(crate)] // needed only within the welds crate.
+#[welds(BelongsTo(customer, Customer, "customer_id"))]
+struct Review {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub customer_id: i32,
+    pub deleted_at: Option<String>,
+}
+
+#[test]
+fn should_mark_the_rows_of_a_soft_deleted_model_as_deleted_when_joining() {
+    futures::executor::block_on(async move {
+        use crate::query::builder::QueryBuilder;
+        let banned = QueryBuilder::<Customer>::new().where_col(|c| c.banned.equal(true));
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        Review::all()
+            .delete_join(|r| r.customer, banned)
+            .run(&client)
+            .await
+            .unwrap();
+        let ran_sql = client.last_sql().unwrap();
+        let expected = "UPDATE reviews AS t1 SET \"deleted_at\"=$1 WHERE ( EXISTS ( SELECT 1 FROM customers t2 WHERE t1.customer_id = t2.id AND t2.banned = $2 ) AND t1.\"deleted_at\" IS NULL )";
+        assert_eq!(expected, &ran_sql);
+        assert_eq!(client.args_count().unwrap(), 2);
+    });
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "authors")]
+#[welds_path(crate)] // needed only within the welds crate.
+#[welds(HasMany(notes, Note, "author_id"))]
+struct Author {
+    #[welds(primary_key)]
+    pub id: i32,
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "notes", soft_delete = "deleted_at")]
+#[welds_path(crate)] // needed only within the welds crate.
+#[welds(BelongsTo(author, Author, "author_id"))]
+struct Note {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub author_id: i32,
+    pub deleted_at: Option<String>,
+}
+
+#[test]
+fn should_hide_the_soft_deleted_rows_of_a_relationship() {
+    let sql = Author::all().map_query(|a| a.notes).to_sql(Syntax::Mysql);
+    let expected = "SELECT t2.id, t2.author_id, t2.deleted_at FROM notes t2 WHERE ( t2.deleted_at IS NULL AND EXISTS ( SELECT id FROM authors t1 WHERE t1.id = t2.author_id ) )";
+    assert_eq!(expected, sql);
+
+    let sql = Author::all()
+        .where_has(|a| a.notes, |q| q)
+        .to_sql(Syntax::Mysql);
+    let expected = "SELECT t1.id FROM authors t1 WHERE ( EXISTS ( SELECT author_id FROM notes t2 WHERE t2.deleted_at IS NULL AND t2.author_id = t1.id ) )";
+    assert_eq!(expected, sql);
+}
@@ -1,10 +1,53 @@
 use super::builder::QueryBuilder;
 use super::clause::ParamArgs;
-use crate::model_traits::{HasSchema, TableColumns, TableInfo};
+use crate::errors::{Result, WeldsError};
+use crate::model_traits::{HasSchema, TableColumns};
 use crate::query::clause::exists::ExistIn;
 use crate::query::clause::ClauseAdder;
 use crate::writers::NextParam;
 use crate::Syntax;
+use std::sync::Arc;
+use std::time::Duration;
+use welds_connections::{Client, Fetch, Row};
+
+/// Runs the SQL, limited to the timeout of the query when it has one
+pub(crate) async fn fetch_rows(
+    client: &dyn Client,
+    sql: &str,
+    args: &ParamArgs<'_>,
+    timeout: Option<Duration>,
+) -> Result<Vec<Row>> {
+    let timeout = match timeout {
+        Some(timeout) => timeout,
+        None => return Ok(client.fetch_rows(sql, args).await?),
+    };
+    let fetch = Fetch { sql, params: args };
+    let datasets = client.fetch_many_with_timeout(&[fetch], timeout).await;
+    // only a query with a limit runs out of time. Without one a busy SQLite is just busy
+    let mut datasets = datasets.map_err(|err| match err.is_timeout() {
+        true => WeldsError::Timeout,
+        false => WeldsError::Database(err),
+    })?;
+    Ok(datasets.pop().unwrap_or_default())
+}
+
+/// Runs a SQL command, limited to the timeout of the query when it has one
+pub(crate) async fn execute(
+    client: &dyn Client,
+    sql: &str,
+    args: &ParamArgs<'_>,
+    timeout: Option<Duration>,
+) -> Result<()> {
+    match timeout {
+        Some(_) => {
+            fetch_rows(client, sql, args, timeout).await?;
+        }
+        None => {
+            client.execute(sql, args).await?;
+        }
+    }
+    Ok(())
+}
 
 pub(crate) fn join_sql_parts(parts: &[Option<String>]) -> String {
     // Join al the parts into
@@ -20,7 +63,7 @@ pub(crate) fn build_where<'lam, 'exist, 'args, 'p>(
     syntax: Syntax,
     next_params: &NextParam,
     alias: &str,
-    wheres: &'lam [Box<dyn ClauseAdder>],
+    wheres: &'lam [Arc<dyn ClauseAdder>],
     args: &'args mut Option<ParamArgs<'p>>,
     exist_ins: &'exist [ExistIn],
 ) -> Option<String>
@@ -39,7 +82,7 @@ pub(crate) fn build_where_clauses<'lam, 'exist, 'args, 'p>(
     syntax: Syntax,
     next_params: &NextParam,
     alias: &str,
-    wheres: &'lam [Box<dyn ClauseAdder>],
+    wheres: &'lam [Arc<dyn ClauseAdder>],
     args: &'args mut Option<ParamArgs<'p>>,
     exist_ins: &'exist [ExistIn],
 ) -> Vec<String>
@@ -67,9 +110,46 @@ where
     where_sql
 }
 
-pub(crate) fn build_tail<T>(syntax: Syntax, select: &QueryBuilder<T>) -> Option<String>
+pub(crate) fn build_tail<'qb, 'args, 'p, T>(
+    syntax: Syntax,
+    select: &'qb QueryBuilder<T>,
+    primary_keys: &[String],
+    next_params: &NextParam,
+    args: &'args mut Option<ParamArgs<'p>>,
+) -> Option<String>
+where
+    'qb: 'p,
+    T: HasSchema,
+{
+    super::tail::write_for_table(
+        syntax,
+        &select.limit,
+        &select.offset,
+        &select.orderby,
+        primary_keys,
+        &select.alias,
+        next_params,
+        args,
+    )
+}
+
+/// The `TOP (n)` written after the SELECT when MSSQL limits a query without an order
+pub(crate) fn build_top<T>(syntax: Syntax, select: &QueryBuilder<T>) -> Option<String> {
+    super::tail::top(syntax, &select.limit, &select.offset, &select.orderby)
+}
+
+/// The columns a limited query without an order is ordered by.
+/// The rows of a DISTINCT can only be ordered by what is selected, so it has none
+pub(crate) fn primary_keys<T>(select: &QueryBuilder<T>) -> Vec<String>
 where
     T: HasSchema,
+    <T as HasSchema>::Schema: TableColumns,
 {
-    super::tail::write(syntax, &select.limit, &select.offset, &select.orderby)
+    if select.distinct.is_some() {
+        return Vec::default();
+    }
+    <T as HasSchema>::Schema::primary_keys()
+        .iter()
+        .map(|col| col.name().to_owned())
+        .collect()
 }
@@ -1,4 +1,6 @@
 use crate::errors::Result;
+use crate::model_traits::hooks::{AfterCreate, BeforeCreate};
+use crate::model_traits::{Column, ColumnDefaultCheck};
 use crate::model_traits::{HasSchema, TableColumns, TableInfo, WriteToArgs};
 use crate::query::clause::ParamArgs;
 use crate::writers::ColumnWriter;
@@ -7,26 +9,94 @@ use crate::Client;
 //use crate::Syntax;
 
 /// Executes the query in the database Bulk Inserting values
+///
+/// A key left as its default value is made by the database, and isn't read back.
+/// A composite key is always written.
+/// The hooks of the rows are not ran, use `run_with_hooks` to make their uuid keys and timestamps
 pub async fn run<T>(conn: &dyn Client, data: &[T]) -> Result<()>
 where
-    T: WriteToArgs + HasSchema,
+    T: WriteToArgs + HasSchema + ColumnDefaultCheck,
     <T as HasSchema>::Schema: TableInfo + TableColumns,
 {
     if data.is_empty() {
         return Ok(());
     }
-    let syntax = conn.syntax();
 
     // // If postgres do the fast bulk insert
     // if let Syntax::Postgres = syntax {
     //     return run_fast(conn, data).await;
     // }
 
-    let col_writer = ColumnWriter::new(syntax);
     let all_columns = <<T as HasSchema>::Schema as TableColumns>::columns();
     let pks = <<T as HasSchema>::Schema as TableColumns>::primary_keys();
-    let columns: Vec<_> = all_columns.iter().filter(|c| !pks.contains(c)).collect();
 
+    // the rows are split by who makes their key, each are inserted with their own columns
+    let mut given: Vec<&T> = Vec::default();
+    let mut made: Vec<&T> = Vec::default();
+    for d in data.iter() {
+        if pks.len() > 1 || !key_is_default(d, &pks)? {
+            given.push(d);
+        } else {
+            made.push(d);
+        }
+    }
+
+    // the db_default columns are filled in by the database
+    let columns: Vec<_> = all_columns
+        .iter()
+        .filter(|c| pks.contains(c) || !c.db_default())
+        .collect();
+    insert_rows(conn, &given, &columns).await?;
+
+    let columns: Vec<_> = all_columns
+        .iter()
+        .filter(|c| !pks.contains(c) && !c.db_default())
+        .collect();
+    insert_rows(conn, &made, &columns).await?;
+    Ok(())
+}
+
+/// Executes the query in the database Bulk Inserting values, running the hooks of each row.
+///
+/// The `BeforeCreate` hooks are ran on each row first, making its uuid keys and timestamps,
+/// and the `AfterCreate` hooks once all the rows are inserted
+pub async fn run_with_hooks<T>(conn: &dyn Client, data: &mut [T]) -> Result<()>
+where
+    T: WriteToArgs + HasSchema + ColumnDefaultCheck,
+    <T as HasSchema>::Schema: TableInfo + TableColumns,
+    T: BeforeCreate + AfterCreate,
+{
+    for d in data.iter_mut() {
+        BeforeCreate::before(d)?;
+    }
+    run(conn, data).await?;
+    for d in data.iter() {
+        AfterCreate::after(d);
+    }
+    Ok(())
+}
+
+/// true when all the parts of the key are still their default value
+fn key_is_default<T: ColumnDefaultCheck>(obj: &T, pks: &[Column]) -> Result<bool> {
+    for pk in pks {
+        if !obj.col_is_default(pk.name())? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Inserts the rows, as many at a time as the database allows params
+async fn insert_rows<T>(conn: &dyn Client, data: &[&T], columns: &[&Column]) -> Result<()>
+where
+    T: WriteToArgs + HasSchema,
+    <T as HasSchema>::Schema: TableInfo,
+{
+    if data.is_empty() {
+        return Ok(());
+    }
+    let syntax = conn.syntax();
+    let col_writer = ColumnWriter::new(syntax);
     let identifier = <<T as HasSchema>::Schema>::identifier().join(".");
 
     let colnames: Vec<String> = columns
@@ -37,7 +107,7 @@ where
 
     // number of to create per insert
     let max_params = NextParam::new(syntax).max_params();
-    let chunk_size = max_params as usize / colnames.len();
+    let chunk_size = max_params as usize / columns.len().max(1);
 
     for chunk in data.chunks(chunk_size) {
         let next_params = NextParam::new(syntax);
@@ -46,7 +116,7 @@ where
         let mut rows: Vec<String> = Vec::default();
         for d in chunk {
             let mut row: Vec<String> = Vec::default();
-            for col in &columns {
+            for col in columns {
                 d.bind(col.name(), &mut args)?;
                 row.push(next_params.next());
             }
@@ -124,3 +194,6 @@ where
 //
 //      Ok(())
 //  }
+
+#[cfg(test)]
+mod tests;
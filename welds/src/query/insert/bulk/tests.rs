@@ -0,0 +1,133 @@
+use crate::Syntax;
+use crate::WeldsModel;
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "nums")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Product {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub a: i32,
+    pub b: i32,
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "tasks")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Task {
+    #[welds(primary_key, uuid_v4)]
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "user_roles")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct UserRole {
+    #[welds(primary_key)]
+    pub user_id: i32,
+    #[welds(primary_key)]
+    pub role_id: i32,
+}
+
+#[test]
+fn should_leave_the_key_made_by_the_database_out_of_a_bulk_insert() {
+    futures::executor::block_on(async move {
+        let products = vec![Product::default(), Product::default()];
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        super::run(&client, &products).await.unwrap();
+        let ran_sql = client.last_sql().unwrap();
+        assert_eq!(
+            r#"INSERT INTO nums ("a", "b") VALUES ($1,$2),($3,$4)"#,
+            &ran_sql
+        );
+        assert_eq!(client.args_count(), Some(4));
+    });
+}
+
+#[test]
+fn should_make_the_uuid_keys_of_each_row_in_a_bulk_insert() {
+    futures::executor::block_on(async move {
+        let mut tasks = vec![Task::default(), Task::default()];
+        let client = welds_connections::noop::build(Syntax::Sqlite);
+        super::run_with_hooks(&client, &mut tasks).await.unwrap();
+        assert_eq!(tasks[0].id.len(), 36);
+        assert_eq!(tasks[1].id.len(), 36);
+        assert_ne!(tasks[0].id, tasks[1].id);
+        let ran_sql = client.last_sql().unwrap();
+        assert_eq!(
+            r#"INSERT INTO tasks ("id", "name") VALUES (?,?),(?,?)"#,
+            &ran_sql
+        );
+        assert_eq!(client.args_count(), Some(4));
+    });
+}
+
+#[test]
+fn should_write_the_keys_that_were_given_in_a_bulk_insert() {
+    futures::executor::block_on(async move {
+        let products = vec![Product {
+            id: 5,
+            ..Default::default()
+        }];
+        let client = welds_connections::noop::build(Syntax::Mysql);
+        super::run(&client, &products).await.unwrap();
+        let ran_sql = client.last_sql().unwrap();
+        assert_eq!("INSERT INTO nums (id, a, b) VALUES (?,?,?)", &ran_sql);
+    });
+}
+
+#[test]
+fn should_write_all_the_parts_of_a_composite_key_in_a_bulk_insert() {
+    futures::executor::block_on(async move {
+        let roles = vec![
+            UserRole::default(),
+            UserRole {
+                user_id: 1,
+                role_id: 0,
+            },
+        ];
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        super::run(&client, &roles).await.unwrap();
+        let ran_sql = client.last_sql().unwrap();
+        assert_eq!(
+            r#"INSERT INTO user_roles ("user_id", "role_id") VALUES ($1,$2),($3,$4)"#,
+            &ran_sql
+        );
+        assert_eq!(client.args_count(), Some(4));
+    });
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "comments", timestamps)]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Comment {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+}
+
+#[test]
+fn should_set_the_timestamps_of_each_row_in_a_bulk_insert() {
+    futures::executor::block_on(async move {
+        let mut comments = vec![Comment::default(), Comment::default()];
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        super::run_with_hooks(&client, &mut comments).await.unwrap();
+        assert!(comments.iter().all(|c| c.created_at == "now"));
+        assert!(comments
+            .iter()
+            .all(|c| c.updated_at.as_deref() == Some("now")));
+    });
+}
+
+#[test]
+fn should_not_run_the_hooks_without_asking() {
+    futures::executor::block_on(async move {
+        let comments = vec![Comment::default()];
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        super::run(&client, &comments).await.unwrap();
+        assert_eq!(comments[0].created_at, "");
+        assert_eq!(comments[0].updated_at, None);
+    });
+}
@@ -1,7 +1,11 @@
 mod bulk;
+mod select;
 mod single;
 
-pub use single::insert_one;
+pub use select::InsertSelectBuilder;
+pub(crate) use single::insert_prepared;
+pub use single::{insert_one, insert_one_returning};
 
 pub use bulk::run as bulk_insert;
+pub use bulk::run_with_hooks as bulk_insert_with_hooks;
 //pub use bulk::run_fast as bulk_insert_fast;
@@ -0,0 +1,108 @@
+use crate::errors::Result;
+use crate::model_traits::{HasSchema, TableColumns, TableInfo};
+use crate::query::clause::{ColumnList, ParamArgs};
+use crate::query::helpers;
+use crate::query::select_cols::SelectBuilder;
+use crate::writers::{ColumnWriter, NextParam};
+use crate::Client;
+use crate::Syntax;
+use std::marker::PhantomData;
+
+#[cfg(test)]
+mod tests;
+
+/// An un-executed `INSERT INTO ... SELECT`.
+///
+/// Copies the rows of a select into the table of `T` without reading them into the app.
+/// The selected columns are inserted into the columns of the same name,
+/// use `columns` to pick the columns when the names are different.
+/// ```rust,ignore
+/// // archive the old orders
+/// ArchivedOrder::insert_from(
+///     Order::where_col(|o| o.created_at.lt(cutoff)).select_as::<ArchivedOrderDto>(),
+/// )
+/// .run(client)
+/// .await?;
+/// ```
+pub struct InsertSelectBuilder<T, S> {
+    _t: PhantomData<T>,
+    select: SelectBuilder<S>,
+    columns: Option<Vec<String>>,
+}
+
+impl<T, S> InsertSelectBuilder<T, S>
+where
+    T: HasSchema,
+    S: Send + HasSchema,
+{
+    pub fn new(select: SelectBuilder<S>) -> Self {
+        Self {
+            _t: PhantomData,
+            select,
+            columns: None,
+        }
+    }
+
+    /// The columns to insert into, in the same order as the selected columns
+    pub fn columns<V, C>(mut self, lam: impl Fn(<T as HasSchema>::Schema) -> C) -> Self
+    where
+        C: ColumnList<V>,
+    {
+        self.columns = Some(lam(Default::default()).colnames());
+        self
+    }
+
+    fn sql_internal<'s, 'args, 'p>(
+        &'s self,
+        syntax: Syntax,
+        args: &'args mut Option<ParamArgs<'p>>,
+    ) -> String
+    where
+        's: 'p,
+        <T as HasSchema>::Schema: TableInfo,
+        <S as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        let writer = ColumnWriter::new(syntax);
+        let next_params = NextParam::new(syntax);
+        let identifier = <T as HasSchema>::Schema::identifier().join(".");
+        let columns: Vec<String> = match &self.columns {
+            Some(columns) => columns.iter().map(|c| writer.excape(c)).collect(),
+            None => self
+                .select
+                .selected_names()
+                .iter()
+                .map(|c| writer.excape(c))
+                .collect(),
+        };
+        let select = self.select.sql_with_params(syntax, &next_params, args);
+        format!(
+            "INSERT INTO {} ({}) {}",
+            identifier,
+            columns.join(", "),
+            select
+        )
+    }
+
+    /// Get a copy of the SQL that will be executed when this query runs
+    pub fn to_sql(&self, syntax: Syntax) -> String
+    where
+        <T as HasSchema>::Schema: TableInfo,
+        <S as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        self.sql_internal(syntax, &mut None)
+    }
+
+    /// Executes the `INSERT INTO ... SELECT` in the database
+    pub async fn run(&self, client: &dyn Client) -> Result<()>
+    where
+        <T as HasSchema>::Schema: TableInfo,
+        <S as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        let syntax = client.syntax();
+        let mut args: Option<ParamArgs> = Some(Vec::default());
+        let sql = self.sql_internal(syntax, &mut args);
+        let args = args.unwrap();
+        helpers::execute(client, &sql, &args, self.select.qb.timeout).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use crate::Syntax;
+use crate::WeldsModel;
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "orders")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Order {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub total: i32,
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "archived_orders")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct ArchivedOrder {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub order_id: i32,
+    pub total: i32,
+}
+
+#[test]
+fn should_insert_the_selected_columns_of_the_same_name() {
+    let old = Order::where_col(|o| o.id.lt(100)).select(|o| o.total);
+    let q = ArchivedOrder::insert_from(old);
+    let expected =
+        "INSERT INTO archived_orders (\"total\") SELECT t1.\"total\" FROM orders t1  WHERE ( t1.id < $1 )";
+    assert_eq!(expected, q.to_sql(Syntax::Postgres));
+}
+
+#[test]
+fn should_be_able_to_pick_the_columns_inserted_into() {
+    let old = Order::where_col(|o| o.id.lt(100))
+        .select(|o| o.id)
+        .select(|o| o.total);
+    let q = ArchivedOrder::insert_from(old).columns(|a| (a.order_id, a.total));
+    let expected = "INSERT INTO archived_orders (order_id, total) SELECT t1.id, t1.total FROM orders t1  WHERE ( t1.id < ? )";
+    assert_eq!(expected, q.to_sql(Syntax::Mysql));
+}
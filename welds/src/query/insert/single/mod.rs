@@ -1,7 +1,7 @@
 use crate::errors::Result;
 use crate::errors::WeldsError::InsertFailed;
 use crate::model_traits::hooks::{AfterCreate, BeforeCreate};
-use crate::model_traits::{ColumnDefaultCheck, UpdateFromRow};
+use crate::model_traits::{ColumnDefaultCheck, UpdateColumnFromRow, UpdateFromRow};
 use crate::model_traits::{HasSchema, TableColumns, TableInfo, WriteToArgs};
 use crate::query::clause::ParamArgs;
 use crate::writers::column::ColumnWriter;
@@ -19,10 +19,20 @@ where
     T: BeforeCreate + AfterCreate,
 {
     BeforeCreate::before(obj)?;
+    insert_prepared(obj, client).await
+}
 
+/// Inserts a model the `BeforeCreate` hooks have already been ran on
+pub(crate) async fn insert_prepared<T>(obj: &mut T, client: &dyn Client) -> Result<()>
+where
+    T: WriteToArgs + HasSchema + ColumnDefaultCheck,
+    <T as HasSchema>::Schema: TableInfo + TableColumns,
+    T: UpdateFromRow,
+    T: AfterCreate,
+{
     let syntax = client.syntax();
     let mut args: ParamArgs = Vec::default();
-    let args2: ParamArgs = Vec::default();
+    let mut args2: ParamArgs = Vec::default();
 
     let col_writer = ColumnWriter::new(syntax);
     let next_params = NextParam::new(syntax);
@@ -34,11 +44,15 @@ where
 
     let mut colargs = Vec::default();
     let mut id_return_required = true;
+    // the parts of a composite key are always given, even when one of them is 0
+    let composite = pks.len() > 1;
 
     for col in &columns {
         let pk = pks.iter().find(|p| p == &col);
 
         match pk {
+            // the database fills in the column, it is read back after
+            None if col.db_default() => continue,
             None => {
                 // column isn't PK just insert it
                 obj.bind(col.name(), &mut args)?;
@@ -51,7 +65,7 @@ where
                 //
                 // If it is NOT the default value we need to include
                 // it in the query for insertion
-                if !obj.col_is_default(col.name())? {
+                if composite || !obj.col_is_default(col.name())? {
                     id_return_required = false;
                     obj.bind(col.name(), &mut args)?;
                     let col = col_writer.excape(col.name());
@@ -68,16 +82,28 @@ where
         params: &args,
     }];
 
+    // The columns made by the database need to be read back even when the ID was given
+    let refresh = !id_return_required && columns.iter().any(|c| c.db_default());
+
     // If this insert needs a second select command to get the id, add it to the vec of sql to run
-    let sql2: String;
-    if id_return_required {
-        if let Some(select) = select {
-            sql2 = select.to_owned();
-            statements.push(Fetch {
-                sql: &sql2,
-                params: &args2,
-            })
+    let sql2: Option<String> = if id_return_required {
+        select
+    } else if refresh {
+        let next_params = NextParam::new(syntax);
+        let mut params = Vec::default();
+        for pk in &pks {
+            obj.bind(pk.name(), &mut args2)?;
+            params.push(next_params.next());
         }
+        writer.write_refresh(&identifier, &pks, &params)
+    } else {
+        None
+    };
+    if let Some(sql2) = &sql2 {
+        statements.push(Fetch {
+            sql: sql2,
+            params: &args2,
+        })
     }
 
     // WARNING: these statements MUST be ran on the same DB connection in the pool
@@ -87,7 +113,7 @@ where
     let mut rows: Vec<Row> = datasets.drain(..).flatten().collect();
 
     // If we are providing the DB with the ID, (string/uuid) it doesn't need to return the id, and will not
-    if !id_return_required {
+    if !id_return_required && !refresh {
         AfterCreate::after(obj);
         return Ok(());
     }
@@ -101,5 +127,118 @@ where
     Ok(())
 }
 
+/// Inserts the model reading back only the `returning` columns into it.
+/// The primary key is always read back when the database makes it.
+///
+/// MySQL and Sqlite read the columns with a follow-up SELECT
+pub async fn insert_one_returning<T>(
+    obj: &mut T,
+    returning: &[String],
+    client: &dyn Client,
+) -> Result<()>
+where
+    T: WriteToArgs + HasSchema + ColumnDefaultCheck,
+    <T as HasSchema>::Schema: TableInfo + TableColumns,
+    T: UpdateColumnFromRow,
+    T: BeforeCreate + AfterCreate,
+{
+    BeforeCreate::before(obj)?;
+
+    let syntax = client.syntax();
+    let mut args: ParamArgs = Vec::default();
+    let mut args2: ParamArgs = Vec::default();
+
+    let col_writer = ColumnWriter::new(syntax);
+    let next_params = NextParam::new(syntax);
+    let writer = InsertWriter::new(syntax);
+
+    let identifier = <<T as HasSchema>::Schema>::identifier().join(".");
+    let columns = <<T as HasSchema>::Schema as TableColumns>::columns();
+    let pks = <<T as HasSchema>::Schema as TableColumns>::primary_keys();
+
+    let mut colargs = Vec::default();
+    let mut id_return_required = false;
+    // the parts of a composite key are always given, even when one of them is 0
+    let composite = pks.len() > 1;
+
+    for col in &columns {
+        // A PK left as the default value is made by the database
+        if !composite && pks.contains(col) && obj.col_is_default(col.name())? {
+            id_return_required = true;
+            continue;
+        }
+        // The database fills in the column, it is read back with the rest
+        if col.db_default() {
+            continue;
+        }
+        obj.bind(col.name(), &mut args)?;
+        let col = col_writer.excape(col.name());
+        colargs.push(ColArg(col, next_params.next()));
+    }
+
+    // the made PK and columns need to be read back along with the asked for columns
+    let mut returning: Vec<String> = returning.to_vec();
+    for col in columns.iter().filter(|c| c.db_default()) {
+        if !returning.iter().any(|c| c == col.name()) {
+            returning.push(col.name().to_string());
+        }
+    }
+    if id_return_required {
+        for pk in pks.iter().rev() {
+            if !returning.iter().any(|c| c == pk.name()) {
+                returning.insert(0, pk.name().to_string());
+            }
+        }
+    }
+
+    // When the PK was given the follow-up select finds the row with it
+    let pk_params = if id_return_required {
+        None
+    } else {
+        let next_params = NextParam::new(syntax);
+        let mut params = Vec::default();
+        for pk in &pks {
+            obj.bind(pk.name(), &mut args2)?;
+            params.push(next_params.next());
+        }
+        Some(params)
+    };
+
+    let (insert, select) = writer.write_returning(
+        &identifier,
+        &colargs,
+        &returning,
+        &pks,
+        pk_params.as_deref(),
+    );
+
+    let mut statements = vec![Fetch {
+        sql: &insert,
+        params: &args,
+    }];
+    if let Some(select) = &select {
+        statements.push(Fetch {
+            sql: select,
+            params: &args2,
+        })
+    }
+
+    // WARNING: these statements MUST be ran on the same DB connection in the pool
+    let mut datasets = client.fetch_many(&statements).await?;
+    let mut rows: Vec<Row> = datasets.drain(..).flatten().collect();
+
+    if !returning.is_empty() {
+        let row = rows.pop();
+        let mut row =
+            row.ok_or_else(|| InsertFailed("Insert didn't return the inserted Row".to_owned()))?;
+        for col in &returning {
+            obj.update_column_from_row(col, &mut row)?;
+        }
+    }
+    AfterCreate::after(obj);
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests;
@@ -1,6 +1,5 @@
 use crate::state::DbState;
 use crate::Syntax;
-use welds_connections::Row;
 
 // Testing that the tail end of the SQL is correct
 // Limits / skips / orders
@@ -31,3 +30,241 @@ fn should_be_able_to_insert_simple_object() {
         assert_eq!(expected, &ran_sql);
     });
 }
+
+#[test]
+fn should_be_able_to_pick_the_columns_returned() {
+    futures::executor::block_on(async move {
+        let obj = Product::default();
+        let mut obj = DbState::new_uncreated(obj);
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        let _ = obj.returning(|p| (p.b,)).save(&client).await;
+        let ran_sql = client.last_sql().unwrap();
+
+        let expected = "INSERT INTO nums (\"a\", \"b\") VALUES ($1, $2) RETURNING \"id\", \"b\"";
+        assert_eq!(expected, &ran_sql);
+    });
+}
+
+#[test]
+fn should_output_the_columns_returned_on_mssql() {
+    futures::executor::block_on(async move {
+        let obj = Product::default();
+        let mut obj = DbState::new_uncreated(obj);
+        let client = welds_connections::noop::build(Syntax::Mssql);
+        let _ = obj.returning(|p| (p.b,)).save(&client).await;
+        let ran_sql = client.last_sql().unwrap();
+
+        let expected =
+            "INSERT INTO nums (\"a\", \"b\") OUTPUT Inserted.\"id\", Inserted.\"b\" VALUES (@p1, @p2)";
+        assert_eq!(expected, &ran_sql);
+    });
+}
+
+#[test]
+fn should_select_the_columns_returned_on_sqlite() {
+    futures::executor::block_on(async move {
+        let obj = Product::default();
+        let mut obj = DbState::new_uncreated(obj);
+        let client = welds_connections::noop::build(Syntax::Sqlite);
+        let _ = obj.returning(|p| (p.b,)).save(&client).await;
+        let ran_sql = client.last_sql().unwrap();
+
+        let expected = "INSERT INTO nums (\"a\", \"b\") VALUES (?, ?);SELECT \"id\", \"b\" FROM nums where id=last_insert_rowid()";
+        assert_eq!(expected, &ran_sql);
+    });
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "posts")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Post {
+    #[welds(primary_key)]
+    pub slug: String,
+    pub title: String,
+    #[welds(db_default)]
+    pub created_at: String,
+}
+
+#[test]
+fn should_leave_db_default_columns_out_of_the_insert() {
+    futures::executor::block_on(async move {
+        let mut obj = DbState::new_uncreated(Post::default());
+        obj.slug = "hello".to_owned();
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        let _ = obj.save(&client).await;
+        let ran_sql = client.last_sql().unwrap();
+
+        let expected = "INSERT INTO posts (\"slug\", \"title\") VALUES ($1, $2) RETURNING *";
+        assert_eq!(expected, &ran_sql);
+    });
+}
+
+#[test]
+fn should_read_back_db_default_columns_by_the_given_pk_on_sqlite() {
+    futures::executor::block_on(async move {
+        let mut obj = DbState::new_uncreated(Post::default());
+        obj.slug = "hello".to_owned();
+        let client = welds_connections::noop::build(Syntax::Sqlite);
+        let _ = obj.save(&client).await;
+        let ran_sql = client.last_sql().unwrap();
+
+        let expected = "INSERT INTO posts (\"slug\", \"title\") VALUES (?, ?);SELECT * FROM posts where \"slug\"=?";
+        assert_eq!(expected, &ran_sql);
+        assert_eq!(client.args_count().unwrap(), 3);
+    });
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "comments", timestamps)]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Comment {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+}
+
+#[test]
+fn should_set_the_timestamps_when_saving() {
+    futures::executor::block_on(async move {
+        let mut obj = DbState::new_uncreated(Comment::default());
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        let _ = obj.save(&client).await;
+        assert_eq!(obj.created_at, "now");
+        assert_eq!(obj.updated_at.as_deref(), Some("now"));
+
+        // only updated_at is set again when updating
+        let mut obj = DbState::db_loaded(Comment::default());
+        obj.created_at = "then".to_owned();
+        obj.save(&client).await.unwrap();
+        assert_eq!(obj.created_at, "then");
+        assert_eq!(obj.updated_at.as_deref(), Some("now"));
+    });
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "tasks")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Task {
+    #[welds(primary_key, uuid_v4)]
+    pub id: String,
+    pub name: String,
+}
+
+#[test]
+fn should_make_a_uuid_key_when_creating() {
+    futures::executor::block_on(async move {
+        let mut obj = DbState::new_uncreated(Task::default());
+        let client = welds_connections::noop::build(Syntax::Sqlite);
+        let _ = obj.save(&client).await;
+        assert_eq!(obj.id.len(), 36);
+        let sql = client.last_sql().unwrap();
+        assert!(
+            sql.starts_with(r#"INSERT INTO tasks ("id", "name") VALUES (?, ?)"#),
+            "{}",
+            sql
+        );
+    });
+}
+
+#[test]
+fn should_keep_a_uuid_key_that_was_given() {
+    futures::executor::block_on(async move {
+        let task = Task {
+            id: "mine".to_owned(),
+            ..Default::default()
+        };
+        let mut obj = DbState::new_uncreated(task);
+        let client = welds_connections::noop::build(Syntax::Sqlite);
+        let _ = obj.save(&client).await;
+        assert_eq!(obj.id, "mine");
+    });
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "user_roles")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct UserRole {
+    #[welds(primary_key)]
+    pub user_id: i32,
+    #[welds(primary_key)]
+    pub role_id: i32,
+}
+
+#[test]
+fn should_write_all_the_parts_of_a_composite_key() {
+    futures::executor::block_on(async move {
+        let role = UserRole {
+            user_id: 0,
+            role_id: 2,
+        };
+        let mut obj = DbState::new_uncreated(role);
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        obj.save(&client).await.unwrap();
+        let ran_sql = client.last_sql().unwrap();
+        let expected =
+            "INSERT INTO user_roles (\"user_id\", \"role_id\") VALUES ($1, $2) RETURNING *";
+        assert_eq!(expected, &ran_sql);
+    });
+}
+
+#[cfg(feature = "serde")]
+mod json {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use welds_connections::{debug_literal, AsJson};
+
+    #[derive(Debug, Default, Serialize, Deserialize)]
+    struct Settings {
+        pub theme: String,
+    }
+
+    #[derive(Debug, Default, WeldsModel)]
+    #[welds(table = "users")]
+    #[welds_path(crate)] // needed only within the welds crate.
+    struct User {
+        #[welds(primary_key)]
+        pub id: i32,
+        #[welds(json)]
+        pub settings: Settings,
+        #[welds(json)]
+        pub tags: Option<Vec<String>>,
+    }
+
+    #[test]
+    fn should_bind_json_fields_as_json() {
+        futures::executor::block_on(async move {
+            let mut obj = DbState::new_uncreated(User::default());
+            obj.settings.theme = "dark".to_owned();
+            let client = welds_connections::noop::build(Syntax::Postgres);
+            let _ = obj.save(&client).await;
+            let ran_sql = client.last_sql().unwrap();
+            let expected = "INSERT INTO users (\"settings\", \"tags\") VALUES ($1, $2) RETURNING *";
+            assert_eq!(expected, &ran_sql);
+            assert_eq!(client.args_count(), Some(2));
+        });
+    }
+
+    #[test]
+    fn should_filter_json_fields_by_their_paths() {
+        let q = User::where_col(|u| u.settings.json_get("theme").equal("dark"));
+        let sql = q.debug_sql(Syntax::Postgres);
+        assert!(
+            sql.ends_with("WHERE ( t1.settings #>> '{theme}' = 'dark' )"),
+            "{}",
+            sql
+        );
+    }
+
+    #[test]
+    fn should_write_json_values_and_nulls() {
+        let settings = Settings {
+            theme: "dark".to_owned(),
+        };
+        let json = debug_literal(Syntax::Mssql, AsJson::from_ref(&settings));
+        assert_eq!(Some("N'{\"theme\":\"dark\"}'"), json.as_deref());
+        let tags: Option<Vec<String>> = None;
+        let json = debug_literal(Syntax::Mssql, AsJson::from_ref(&tags));
+        assert_eq!(Some("NULL"), json.as_deref());
+    }
+}
@@ -0,0 +1,330 @@
+use super::builder::QueryBuilder;
+use super::clause::{write_compare, AsFieldName, OrderBy, ParamArgs};
+use crate::errors::Result;
+use crate::model_traits::{HasSchema, PrimaryKeyValues, TableColumns, TableIdent, TableInfo};
+use crate::query::helpers::{self, build_where_clauses, join_sql_parts};
+use crate::query::tail;
+use crate::state::DbState;
+use crate::writers::hint;
+use crate::writers::{ColumnWriter, NextParam};
+use crate::{Syntax, WeldsError};
+use std::future::Future;
+use welds_connections::{Client, Decodable, Param, Row};
+
+#[cfg(test)]
+mod tests;
+
+/// An un-executed Query reading one page of rows at a time using keyset (cursor) pagination.
+///
+/// Rows are ordered by the keys and each page starts after the last row of the page before it.
+/// Unlike OFFSET the database doesn't need to walk over the skipped rows,
+/// and rows added while paging don't shift the pages.
+/// ```rust,ignore
+/// let page = Post::all()
+///     .page_by(|p| (p.created_at, p.id))
+///     .after_cursor(cursor)
+///     .limit(20)
+///     .run(client)
+///     .await?;
+/// let cursor = page.next_cursor;
+/// ```
+///
+/// NOTE: the keys should uniquely identify a row, end them with the primary key
+pub struct KeysetBuilder<T, V> {
+    qb: QueryBuilder<T>,
+    keys: Vec<KeyColumn>,
+    desc: bool,
+    cursor: Option<Cursor<V>>,
+    limit: i64,
+}
+
+/// A column a page is ordered by
+pub struct KeyColumn {
+    col: String,
+}
+
+/// The position in the results a page starts after.
+/// Holds the keys of the last row of the page before it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cursor<V> {
+    values: V,
+}
+
+/// A page of rows returned from a keyset query
+pub struct Page<T, V> {
+    pub items: Vec<DbState<T>>,
+    /// The cursor to read the next page. None when this was the last page
+    pub next_cursor: Option<Cursor<V>>,
+}
+
+/// The columns a keyset query is ordered by. Implemented for tuples of columns
+pub trait PageKeys<V> {
+    fn keys(&self) -> Vec<KeyColumn>;
+}
+
+/// The values of the keys of a row. Implemented for tuples of values
+pub trait PageValues: Sized + Clone + Send + Sync {
+    fn read(row: &Row, keys: &[KeyColumn]) -> Result<Self>;
+    fn params(&self) -> Vec<&(dyn Param + Sync)>;
+}
+
+macro_rules! impl_page_keys {
+    ($($field:ident $value:ident $idx:tt),+) => {
+        impl<$($field, $value),+> PageKeys<($($value,)+)> for ($($field,)+)
+        where
+            $($field: AsFieldName<$value>),+
+        {
+            fn keys(&self) -> Vec<KeyColumn> {
+                vec![$(KeyColumn {
+                    col: self.$idx.colname().to_string(),
+                }),+]
+            }
+        }
+
+        impl<$($value),+> PageValues for ($($value,)+)
+        where
+            $($value: 'static + Param + Decodable + Clone + Send + Sync),+
+        {
+            fn read(row: &Row, keys: &[KeyColumn]) -> Result<Self> {
+                // the values are read by the columns' names in the database, not the fields'
+                Ok(($(row.get::<$value>(&keys[$idx].col)?,)+))
+            }
+
+            fn params(&self) -> Vec<&(dyn Param + Sync)> {
+                vec![$(&self.$idx),+]
+            }
+        }
+    };
+}
+
+impl_page_keys!(A VA 0);
+impl_page_keys!(A VA 0, B VB 1);
+impl_page_keys!(A VA 0, B VB 1, C VC 2);
+impl_page_keys!(A VA 0, B VB 1, C VC 2, D VD 3);
+
+impl<T> QueryBuilder<T>
+where
+    T: Send + HasSchema,
+{
+    /// Read the results of this query a page at a time, ordered by a tuple of columns.
+    /// See `KeysetBuilder`
+    pub fn page_by<K, V>(self, lam: impl Fn(<T as HasSchema>::Schema) -> K) -> KeysetBuilder<T, V>
+    where
+        K: PageKeys<V>,
+    {
+        let keys = lam(Default::default()).keys();
+        KeysetBuilder {
+            qb: self,
+            keys,
+            desc: false,
+            cursor: None,
+            limit: 20,
+        }
+    }
+
+    /// Runs the query a batch of rows at a time, walking the rows in primary key order.
+    ///
+    /// Each batch starts after the last primary key of the batch before it,
+    /// so rows added or removed while the batches are processed don't shift the batches.
+    /// The limit, offset and order of the query are not used
+    /// ```rust,ignore
+    /// Order::all()
+    ///     .for_each_batch(client, 1000, |batch| async move {
+    ///         archive(batch).await
+    ///     })
+    ///     .await?;
+    /// ```
+    pub async fn for_each_batch<F, Fut>(
+        &self,
+        client: &dyn Client,
+        size: i64,
+        mut f: F,
+    ) -> Result<()>
+    where
+        T: PrimaryKeyValues + TryFrom<Row>,
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+        WeldsError: From<<T as TryFrom<Row>>::Error>,
+        F: FnMut(Vec<DbState<T>>) -> Fut,
+        Fut: Future<Output = Result<()>>,
+    {
+        let keys = <<T as HasSchema>::Schema as TableColumns>::primary_keys()
+            .iter()
+            .map(|pk| KeyColumn {
+                col: pk.name().to_string(),
+            })
+            .collect();
+        let mut batches: KeysetBuilder<T, T::Values> = KeysetBuilder {
+            qb: self.clone(),
+            keys,
+            desc: false,
+            cursor: None,
+            limit: size,
+        };
+        loop {
+            let page = batches.run(client).await?;
+            if !page.items.is_empty() {
+                f(page.items).await?;
+            }
+            match page.next_cursor {
+                Some(cursor) => batches.cursor = Some(cursor),
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+impl<T, V> KeysetBuilder<T, V>
+where
+    T: Send + HasSchema,
+    V: PageValues,
+{
+    /// Order the pages from the largest keys to the smallest. I.E. newest first
+    pub fn desc(mut self) -> Self {
+        self.desc = true;
+        self
+    }
+
+    /// Start the page after this cursor. None reads the first page
+    pub fn after_cursor(mut self, cursor: impl Into<Option<Cursor<V>>>) -> Self {
+        self.cursor = cursor.into();
+        self
+    }
+
+    /// The number of rows in a page (default 20)
+    pub fn limit(mut self, x: i64) -> Self {
+        self.limit = x;
+        self
+    }
+
+    fn sql_internal<'s, 'args, 'p>(
+        &'s self,
+        syntax: Syntax,
+        args: &'args mut Option<ParamArgs<'p>>,
+    ) -> String
+    where
+        's: 'p,
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        let next_params = NextParam::new(syntax);
+        let writer = ColumnWriter::new(syntax);
+        let table = TableIdent::from_model::<T>().to_string();
+        let alias = &self.qb.alias;
+        let columns = <T as HasSchema>::Schema::columns();
+        let cols: Vec<String> = columns.iter().map(|c| writer.write(alias, c)).collect();
+
+        let mut wheres = build_where_clauses(
+            syntax,
+            &next_params,
+            alias,
+            &self.qb.wheres,
+            args,
+            &self.qb.exist_ins,
+        );
+        if let Some(cursor) = &self.cursor {
+            let values = cursor.values.params();
+            wheres.push(self.write_after(syntax, &next_params, &values, args));
+        }
+        let where_sql = if wheres.is_empty() {
+            None
+        } else {
+            Some(format!("WHERE ( {} )", wheres.join(" AND ")))
+        };
+
+        let direction = if self.desc { "DESC" } else { "ASC" };
+        let orders: Vec<OrderBy> = self
+            .keys
+            .iter()
+            .map(|k| OrderBy::new(k.col.as_str(), direction))
+            .collect();
+
+        let lock = self.qb.lock;
+        let hints = &self.qb.hints;
+        join_sql_parts(&[
+            Some(format!(
+                "{} {} FROM {} {}",
+                hint::select(hints),
+                cols.join(", "),
+                table,
+                alias
+            )),
+            hint::table(syntax, hints, &lock),
+            where_sql,
+            tail::write(
+                syntax,
+                &Some(self.limit),
+                &None,
+                &orders,
+                alias,
+                &next_params,
+                &mut None,
+            ),
+            lock.and_then(|l| l.suffix(syntax)),
+            hint::options(syntax, hints),
+        ])
+    }
+
+    /// writes the check for rows after the cursor. I.E. `(t1.a, t1.b) > ($1, $2)`
+    fn write_after<'p>(
+        &self,
+        syntax: Syntax,
+        next_params: &NextParam,
+        values: &[&'p (dyn Param + Sync)],
+        args: &mut Option<ParamArgs<'p>>,
+    ) -> String {
+        let alias = &self.qb.alias;
+        let op = if self.desc { "<" } else { ">" };
+        let mut bind = |v: &'p (dyn Param + Sync)| {
+            if let Some(args) = args {
+                args.push(v);
+            }
+            next_params.next()
+        };
+
+        let cols: Vec<String> = self
+            .keys
+            .iter()
+            .map(|k| format!("{}.{}", alias, k.col))
+            .collect();
+        write_compare(syntax, &cols, op, |i| bind(values[i]))
+    }
+
+    /// Get a copy of the SQL that will be executed when this query runs
+    pub fn to_sql(&self, syntax: Syntax) -> String
+    where
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        self.sql_internal(syntax, &mut None)
+    }
+
+    /// Executes the query in the database returning a page of rows
+    /// and the cursor to read the next page
+    pub async fn run<'q, 'c>(&'q self, client: &'c dyn Client) -> Result<Page<T, V>>
+    where
+        'q: 'c,
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+        T: TryFrom<Row>,
+        WeldsError: From<<T as TryFrom<Row>>::Error>,
+    {
+        let syntax = client.syntax();
+        let mut args: Option<ParamArgs> = Some(Vec::default());
+        let sql = self.sql_internal(syntax, &mut args);
+        let args = args.unwrap();
+        let rows = helpers::fetch_rows(client, &sql, &args, self.qb.timeout).await?;
+
+        // a short page is the last page
+        let next_cursor = match rows.last() {
+            Some(last) if rows.len() as i64 >= self.limit => Some(Cursor {
+                values: V::read(last, &self.keys)?,
+            }),
+            _ => None,
+        };
+
+        let mut items = Vec::default();
+        for row in rows {
+            let obj: T = T::try_from(row)?;
+            items.push(DbState::db_loaded(obj));
+        }
+        Ok(Page { items, next_cursor })
+    }
+}
@@ -0,0 +1,138 @@
+use super::Cursor;
+use crate::Syntax;
+use crate::WeldsModel;
+
+#[derive(Debug, WeldsModel)]
+#[welds(table = "posts")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Post {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub created_at: i64,
+    pub title: String,
+}
+
+#[test]
+fn should_order_the_first_page_by_the_keys() {
+    let sql = Post::all()
+        .page_by(|p| (p.created_at, p.id))
+        .limit(10)
+        .to_sql(Syntax::Postgres);
+    assert_eq!(
+        sql,
+        "SELECT t1.\"id\", t1.\"created_at\", t1.\"title\" FROM posts t1 ORDER BY created_at ASC, id ASC OFFSET 0 LIMIT 10"
+    );
+}
+
+#[test]
+fn should_compare_tuples_after_the_cursor() {
+    let cursor = Cursor { values: (100, 7) };
+    let sql = Post::where_col(|p| p.title.like("a%"))
+        .page_by(|p| (p.created_at, p.id))
+        .desc()
+        .after_cursor(cursor)
+        .limit(10)
+        .to_sql(Syntax::Postgres);
+    assert_eq!(
+        sql,
+        "SELECT t1.\"id\", t1.\"created_at\", t1.\"title\" FROM posts t1 WHERE ( t1.title like $1 AND (t1.created_at, t1.id) < ($2, $3) ) ORDER BY created_at DESC, id DESC OFFSET 0 LIMIT 10"
+    );
+}
+
+#[test]
+fn should_expand_the_tuple_compare_on_mssql() {
+    let cursor = Cursor { values: (100, 7) };
+    let sql = Post::all()
+        .page_by(|p| (p.created_at, p.id))
+        .after_cursor(cursor)
+        .to_sql(Syntax::Mssql);
+    assert!(sql.contains(
+        "WHERE ( ( ( t1.created_at > @p1 ) OR ( t1.created_at = @p2 AND t1.id > @p3 ) ) )"
+    ));
+}
+
+#[test]
+fn should_read_batches_in_primary_key_order() {
+    futures::executor::block_on(async move {
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        let mut batches = 0;
+        Post::where_col(|p| p.created_at.gt(5))
+            .for_each_batch(&client, 500, |_batch| {
+                batches += 1;
+                async { Ok(()) }
+            })
+            .await
+            .unwrap();
+        // the noop client returns no rows, there is nothing to process
+        assert_eq!(batches, 0);
+        assert_eq!(
+            client.last_sql().unwrap(),
+            "SELECT t1.\"id\", t1.\"created_at\", t1.\"title\" FROM posts t1 WHERE ( t1.created_at > $1 ) ORDER BY id ASC OFFSET 0 LIMIT 500"
+        );
+    });
+}
+
+#[test]
+fn should_limit_each_page_to_the_timeout_of_the_query() {
+    futures::executor::block_on(async move {
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        Post::all()
+            .timeout(std::time::Duration::from_secs(1))
+            .page_by(|p| (p.created_at, p.id))
+            .limit(10)
+            .run(&client)
+            .await
+            .unwrap();
+        assert_eq!(
+            client.last_sql().unwrap(),
+            "SET statement_timeout = 1000;SELECT t1.\"id\", t1.\"created_at\", t1.\"title\" FROM posts t1 ORDER BY created_at ASC, id ASC OFFSET 0 LIMIT 10;SET statement_timeout = DEFAULT"
+        );
+    });
+}
+
+#[derive(Debug, WeldsModel)]
+#[welds(table = "notes")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Note {
+    #[welds(primary_key)]
+    #[welds(rename = "note_id")]
+    pub id: i32,
+    #[welds(rename = "posted_at")]
+    pub posted: i64,
+}
+
+#[test]
+fn should_read_the_cursor_of_renamed_keys() {
+    use welds_connections::{Client, TransactStart};
+    futures::executor::block_on(async move {
+        let client = welds_connections::sqlite::connect("sqlite::memory:")
+            .await
+            .unwrap();
+        // a transaction keeps everything on the same in memory database
+        let trans = client.begin().await.unwrap();
+        let create = "CREATE TABLE notes (note_id INTEGER PRIMARY KEY, posted_at INT)";
+        trans.execute(create, &[]).await.unwrap();
+        let rows = "INSERT INTO notes VALUES (1, 100), (2, 200)";
+        trans.execute(rows, &[]).await.unwrap();
+
+        let page = Note::all()
+            .page_by(|n| (n.posted, n.id))
+            .limit(1)
+            .run(&trans)
+            .await
+            .unwrap();
+        let cursor = page.next_cursor.unwrap();
+        assert_eq!(cursor.values, (100, 1));
+
+        let mut batches = Vec::default();
+        Note::all()
+            .for_each_batch(&trans, 1, |batch| {
+                batches.push(batch.len());
+                async { Ok(()) }
+            })
+            .await
+            .unwrap();
+        assert_eq!(batches, vec![1, 1]);
+        trans.rollback().await.unwrap();
+    });
+}
@@ -3,8 +3,13 @@ pub mod clause;
 pub(crate) mod delete;
 pub(crate) mod helpers;
 pub mod insert;
+pub mod keyset;
 pub mod optional;
+pub mod raw;
+pub mod recursive;
 pub mod select;
 pub mod select_cols;
 pub(crate) mod tail;
+pub mod union;
 pub(crate) mod update;
+pub mod upsert;
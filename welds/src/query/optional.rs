@@ -8,7 +8,6 @@
 /// and now having to know if the DB column is null
 ///
 /// This is for writing sql only.
-
 pub enum Optional<T> {
     Some(T),
     None,
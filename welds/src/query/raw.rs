@@ -0,0 +1,73 @@
+use crate::errors::Result;
+use crate::{Client, Row, WeldsError};
+use async_trait::async_trait;
+use welds_connections::Param;
+
+/// Run any SQL reading the rows into a struct.
+///
+/// Useful for reporting queries that don't map to a model.
+/// Any struct that can be made from a Row works, `#[derive(WeldsRow)]` is the simplest
+/// ```rust,ignore
+/// #[derive(WeldsRow)]
+/// struct SalesReport {
+///     pub seller: String,
+///     pub total: f64,
+/// }
+/// let sql = "SELECT seller, sum(price) as total FROM orders WHERE price > $1 GROUP BY seller";
+/// let report = client.query_as::<SalesReport>(sql, &[&min_price]).await?;
+/// ```
+#[async_trait]
+pub trait QueryAs {
+    /// Executes the SQL reading each row into a `T`
+    async fn query_as<T>(&self, sql: &str, params: &[&(dyn Param + Sync)]) -> Result<Vec<T>>
+    where
+        T: TryFrom<Row>,
+        WeldsError: From<<T as TryFrom<Row>>::Error>;
+}
+
+#[async_trait]
+impl<C> QueryAs for C
+where
+    C: Client + ?Sized,
+{
+    async fn query_as<T>(&self, sql: &str, params: &[&(dyn Param + Sync)]) -> Result<Vec<T>>
+    where
+        T: TryFrom<Row>,
+        WeldsError: From<<T as TryFrom<Row>>::Error>,
+    {
+        let rows = self.fetch_rows(sql, params).await?;
+        let mut objs = Vec::default();
+        for row in rows {
+            objs.push(T::try_from(row)?);
+        }
+        Ok(objs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Syntax;
+
+    #[derive(crate::WeldsRow)]
+    #[welds_path(crate)] // needed only within the welds crate.
+    #[allow(dead_code)] // only the SQL is tested
+    struct Report {
+        #[welds(rename = "seller_name")]
+        pub seller: String,
+        pub total: f64,
+    }
+
+    #[test]
+    fn should_run_the_sql_as_given() {
+        let sql = "SELECT seller_name, sum(price) as total FROM orders WHERE price > $1";
+        let ran_sql = futures::executor::block_on(async move {
+            let client = welds_connections::noop::build(Syntax::Postgres);
+            let reports = client.query_as::<Report>(sql, &[&5]).await.unwrap();
+            assert!(reports.is_empty());
+            client.last_sql()
+        })
+        .unwrap();
+        assert_eq!(ran_sql, sql);
+    }
+}
@@ -0,0 +1,223 @@
+use super::builder::QueryBuilder;
+use super::clause::{AsFieldName, OrderBy, ParamArgs};
+use crate::errors::Result;
+use crate::model_traits::{HasSchema, TableColumns, TableIdent, TableInfo};
+use crate::query::helpers::{self, build_where, join_sql_parts};
+use crate::query::tail;
+use crate::state::DbState;
+use crate::writers::{ColumnWriter, NextParam};
+use crate::{Syntax, WeldsError};
+use welds_connections::{Client, Row};
+
+#[cfg(test)]
+mod tests;
+
+/// name given to the CTE walking the tree
+const TREE: &str = "welds_tree";
+/// name of the column holding how far a row is from the starting rows
+const DEPTH: &str = "welds_depth";
+
+/// Which way to walk a self-referencing table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Ancestors,
+    Descendants,
+}
+
+/// An un-executed Query walking a self-referencing table (categories, org charts, etc)
+///
+/// Starts from the rows matching the query and follows the parent column
+/// up (ancestors) or down (descendants) using a `WITH RECURSIVE` query.
+/// The starting rows are included in the results.
+pub struct RecursiveBuilder<T> {
+    qb: QueryBuilder<T>,
+    id_col: String,
+    parent_col: String,
+    direction: Direction,
+    max_depth: Option<u32>,
+    step_alias: String,
+}
+
+impl<T> QueryBuilder<T>
+where
+    T: Send + HasSchema,
+{
+    /// Walk a self-referencing table starting from the rows in this query.
+    ///
+    /// `id` is the column being referenced and `parent` is the column referencing it.
+    /// ```rust,ignore
+    /// let children = Category::where_col(|c| c.id.equal(5))
+    ///     .recursive(|c| c.id, |c| c.parent_id)
+    ///     .descendants()
+    ///     .max_depth(3)
+    ///     .run(client)
+    ///     .await?;
+    /// ```
+    pub fn recursive<V1, V2, ID, PARENT>(
+        self,
+        id: impl Fn(<T as HasSchema>::Schema) -> ID,
+        parent: impl Fn(<T as HasSchema>::Schema) -> PARENT,
+    ) -> RecursiveBuilder<T>
+    where
+        ID: AsFieldName<V1>,
+        PARENT: AsFieldName<V2>,
+    {
+        let id_col = id(Default::default()).colname().to_string();
+        let parent_col = parent(Default::default()).colname().to_string();
+        let step_alias = self.alias_asigner.next();
+        RecursiveBuilder {
+            qb: self,
+            id_col,
+            parent_col,
+            direction: Direction::Descendants,
+            max_depth: None,
+            step_alias,
+        }
+    }
+}
+
+impl<T> RecursiveBuilder<T>
+where
+    T: Send + HasSchema,
+{
+    /// Walk up the tree, returning the parents of the starting rows
+    pub fn ancestors(mut self) -> Self {
+        self.direction = Direction::Ancestors;
+        self
+    }
+
+    /// Walk down the tree, returning the children of the starting rows (default)
+    pub fn descendants(mut self) -> Self {
+        self.direction = Direction::Descendants;
+        self
+    }
+
+    /// Stop walking the tree after this many steps from the starting rows.
+    ///
+    /// NOTE: without a max depth a cycle in the data will never finish
+    pub fn max_depth(mut self, depth: u32) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    fn sql_internal<'s, 'args, 'p>(
+        &'s self,
+        syntax: Syntax,
+        args: &'args mut Option<ParamArgs<'p>>,
+    ) -> String
+    where
+        's: 'p,
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        let next_params = NextParam::new(syntax);
+        let writer = ColumnWriter::new(syntax);
+        let table = TableIdent::from_model::<T>().to_string();
+        let columns = <T as HasSchema>::Schema::columns();
+        let write_cols = |alias: &str| -> String {
+            let cols: Vec<String> = columns.iter().map(|c| writer.write(alias, c)).collect();
+            cols.join(", ")
+        };
+        let alias = &self.qb.alias;
+        let step = &self.step_alias;
+
+        // the rows the walk starts from
+        let anchor = join_sql_parts(&[
+            Some(format!(
+                "SELECT {}, 0 AS {DEPTH} FROM {table} {alias}",
+                write_cols(alias)
+            )),
+            build_where(
+                syntax,
+                &next_params,
+                alias,
+                &self.qb.wheres,
+                args,
+                &self.qb.exist_ins,
+            ),
+        ]);
+
+        // each step of the walk
+        let (step_col, tree_col) = match self.direction {
+            Direction::Descendants => (&self.parent_col, &self.id_col),
+            Direction::Ancestors => (&self.id_col, &self.parent_col),
+        };
+        let depth_limit = self
+            .max_depth
+            .map(|max| format!("WHERE {TREE}.{DEPTH} < {max}"));
+        let walk = join_sql_parts(&[
+            Some(format!(
+                "SELECT {}, {TREE}.{DEPTH} + 1 FROM {table} {step} JOIN {TREE} ON {step}.{} = {TREE}.{}",
+                write_cols(step),
+                writer.excape(step_col),
+                writer.excape(tree_col),
+            )),
+            depth_limit,
+        ]);
+
+        let with = match syntax {
+            Syntax::Mssql => "WITH",
+            _ => "WITH RECURSIVE",
+        };
+
+        // closest rows first unless told otherwise
+        let default_order = [OrderBy::new(DEPTH, "ASC")];
+        let tail = match self.qb.orderby.is_empty() {
+            true => tail::write(
+                syntax,
+                &self.qb.limit,
+                &self.qb.offset,
+                &default_order,
+                alias,
+                &next_params,
+                &mut None,
+            ),
+            false => tail::write(
+                syntax,
+                &self.qb.limit,
+                &self.qb.offset,
+                &self.qb.orderby,
+                alias,
+                &next_params,
+                args,
+            ),
+        };
+
+        join_sql_parts(&[
+            Some(format!(
+                "{with} {TREE} AS ( {anchor} UNION ALL {walk} ) SELECT {} FROM {TREE} {alias}",
+                write_cols(alias)
+            )),
+            tail,
+        ])
+    }
+
+    /// Get a copy of the SQL that will be executed when this query runs
+    pub fn to_sql(&self, syntax: Syntax) -> String
+    where
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        self.sql_internal(syntax, &mut None)
+    }
+
+    /// Executes the query in the database returning the rows found walking the tree
+    pub async fn run<'q, 'c>(&'q self, client: &'c dyn Client) -> Result<Vec<DbState<T>>>
+    where
+        'q: 'c,
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+        T: TryFrom<Row>,
+        WeldsError: From<<T as TryFrom<Row>>::Error>,
+    {
+        let syntax = client.syntax();
+        let mut args: Option<ParamArgs> = Some(Vec::default());
+        let sql = self.sql_internal(syntax, &mut args);
+        let args = args.unwrap();
+        let rows = helpers::fetch_rows(client, &sql, &args, self.qb.timeout).await?;
+
+        let mut objs = Vec::default();
+        for row in rows {
+            let obj: T = T::try_from(row)?;
+            objs.push(DbState::db_loaded(obj));
+        }
+        Ok(objs)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::Syntax;
+use crate::WeldsModel;
+
+#[derive(Debug, WeldsModel)]
+#[welds(table = "categories")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Category {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub parent_id: Option<i32>,
+    pub name: String,
+}
+
+#[test]
+fn should_walk_down_to_the_descendants() {
+    let sql = Category::where_col(|c| c.id.equal(5))
+        .recursive(|c| c.id, |c| c.parent_id)
+        .descendants()
+        .to_sql(Syntax::Postgres);
+    assert_eq!(
+        sql,
+        "WITH RECURSIVE welds_tree AS ( SELECT t1.\"id\", t1.\"parent_id\", t1.\"name\", 0 AS welds_depth FROM categories t1 WHERE ( t1.id = $1 ) UNION ALL SELECT t2.\"id\", t2.\"parent_id\", t2.\"name\", welds_tree.welds_depth + 1 FROM categories t2 JOIN welds_tree ON t2.\"parent_id\" = welds_tree.\"id\" ) SELECT t1.\"id\", t1.\"parent_id\", t1.\"name\" FROM welds_tree t1 ORDER BY welds_depth ASC"
+    );
+}
+
+#[test]
+fn should_walk_up_to_the_ancestors_with_a_depth_limit() {
+    let sql = Category::where_col(|c| c.id.equal(5))
+        .recursive(|c| c.id, |c| c.parent_id)
+        .ancestors()
+        .max_depth(2)
+        .to_sql(Syntax::Mssql);
+    assert!(sql.starts_with("WITH welds_tree AS ("));
+    assert!(sql.contains(
+        "JOIN welds_tree ON t2.\"id\" = welds_tree.\"parent_id\" WHERE welds_tree.welds_depth < 2 )"
+    ));
+}
@@ -0,0 +1,86 @@
+use super::super::builder::QueryBuilder;
+use crate::errors::Result;
+use crate::model_traits::{HasSchema, TableColumns, TableInfo};
+use crate::query::clause::ParamArgs;
+use crate::Syntax;
+use welds_connections::{Client, Param};
+
+// ******************************************************************************************
+// This file contains the code to ask the database how it will run a query
+// ******************************************************************************************
+
+impl<T> QueryBuilder<T>
+where
+    T: Send + HasSchema,
+{
+    /// Returns the plan the database will use to run this query, one line of text per step.
+    ///
+    /// Useful to check a query is using the index you expect
+    /// ```rust,ignore
+    /// let plan = Order::where_col(|o| o.customer_id.equal(1)).explain(client).await?;
+    /// assert!(plan.contains("idx_orders_customer_id"));
+    /// ```
+    /// * Postgres: `EXPLAIN`
+    /// * MySQL: `EXPLAIN FORMAT=TREE` (MySQL 8.0.16+)
+    /// * Sqlite: `EXPLAIN QUERY PLAN`
+    /// * MSSQL: not supported, the plan can only be turned on for a whole connection
+    pub async fn explain(&self, client: &dyn Client) -> Result<String>
+    where
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        let mut args: Option<ParamArgs> = Some(Vec::default());
+        let sql = self.sql_internal(client.syntax(), &mut args);
+        explain(client, &sql, &args.unwrap(), false).await
+    }
+
+    /// Runs the query and returns the plan the database used with the real timings and row counts.
+    ///
+    /// The query is executed, but it is only a SELECT so nothing is changed.
+    /// Only Postgres and MySQL (8.0.18+) can analyze a query.
+    pub async fn explain_analyze(&self, client: &dyn Client) -> Result<String>
+    where
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        let mut args: Option<ParamArgs> = Some(Vec::default());
+        let sql = self.sql_internal(client.syntax(), &mut args);
+        explain(client, &sql, &args.unwrap(), true).await
+    }
+}
+
+/// Runs the EXPLAIN of the sql and joins the plan into lines of text
+pub(crate) async fn explain(
+    client: &dyn Client,
+    sql: &str,
+    args: &[&(dyn Param + Sync)],
+    analyze: bool,
+) -> Result<String> {
+    let syntax = client.syntax();
+    let sql = explain_sql(syntax, sql, analyze)?;
+    let rows = client.fetch_rows(&sql, args).await?;
+    let mut lines: Vec<String> = Vec::default();
+    for row in rows {
+        let line: String = match syntax {
+            Syntax::Sqlite => row.get("detail")?,
+            _ => row.get_by_position(0)?,
+        };
+        lines.push(line);
+    }
+    Ok(lines.join("\n"))
+}
+
+fn explain_sql(syntax: Syntax, sql: &str, analyze: bool) -> Result<String> {
+    let head = match (syntax, analyze) {
+        (Syntax::Postgres, false) => "EXPLAIN",
+        (Syntax::Postgres, true) => "EXPLAIN ANALYZE",
+        (Syntax::Mysql, false) => "EXPLAIN FORMAT=TREE",
+        (Syntax::Mysql, true) => "EXPLAIN ANALYZE",
+        (Syntax::Sqlite, false) => "EXPLAIN QUERY PLAN",
+        (Syntax::Sqlite, true) => {
+            return Err(anyhow::anyhow!("Sqlite can not analyze a query").into())
+        }
+        (Syntax::Mssql, _) => {
+            return Err(anyhow::anyhow!("Explaining a query is not supported on MSSQL").into())
+        }
+    };
+    Ok(format!("{} {}", head, sql))
+}
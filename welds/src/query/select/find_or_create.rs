@@ -0,0 +1,89 @@
+use crate::errors::Result;
+use crate::model_traits::hooks::{AfterCreate, BeforeCreate};
+use crate::model_traits::{ColumnDefaultCheck, UpdateFromRow};
+use crate::model_traits::{HasSchema, TableColumns, TableInfo, WriteToArgs};
+use crate::query::builder::QueryBuilder;
+use crate::query::insert;
+use crate::query::upsert::{self, OnConflict};
+use crate::state::DbState;
+use crate::WeldsError;
+use welds_connections::{Client, Row};
+
+impl<T> QueryBuilder<T>
+where
+    T: Send + HasSchema,
+{
+    async fn first<'q, 'c>(&'q self, client: &'c dyn Client) -> Result<Option<DbState<T>>>
+    where
+        'q: 'c,
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+        T: TryFrom<Row>,
+        WeldsError: From<<T as TryFrom<Row>>::Error>,
+    {
+        let mut found = self.clone().limit(1).run(client).await?;
+        Ok(found.pop())
+    }
+
+    /// Returns the first row of the query, inserting the model made by `create` if there isn't one.
+    /// ```rust,ignore
+    /// let user = User::where_col(|u| u.email.equal(email))
+    ///     .first_or_insert(client, || User { id: 0, email: email.to_owned() })
+    ///     .await?;
+    /// ```
+    /// If the insert fails, because another connection inserted the row first,
+    /// the query is ran again and the row it finds is returned.
+    /// See `first_or_upsert` to do it in a single statement with a unique index
+    pub async fn first_or_insert(
+        self,
+        client: &dyn Client,
+        create: impl FnOnce() -> T,
+    ) -> Result<DbState<T>>
+    where
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+        T: TryFrom<Row>,
+        WeldsError: From<<T as TryFrom<Row>>::Error>,
+        T: WriteToArgs + ColumnDefaultCheck + UpdateFromRow,
+        T: BeforeCreate + AfterCreate,
+    {
+        if let Some(found) = self.first(client).await? {
+            return Ok(found);
+        }
+        let mut obj = create();
+        match insert::insert_one(&mut obj, client).await {
+            Ok(_) => Ok(DbState::db_loaded(obj)),
+            // lost the race to another insert
+            Err(err) => self.first(client).await?.ok_or(err),
+        }
+    }
+
+    /// Returns the first row of the query, upserting the model made by `create` if there isn't one.
+    ///
+    /// When the row was inserted since the query ran, the conflict on `on` returns it
+    /// instead of failing. Unless `on` picks columns to update, the row is left as it is.
+    /// ```rust,ignore
+    /// let user = User::where_col(|u| u.email.equal(email))
+    ///     .first_or_upsert(client, |on| on.target(|u| (u.email,)), || User { id: 0, email: email.to_owned() })
+    ///     .await?;
+    /// ```
+    pub async fn first_or_upsert(
+        self,
+        client: &dyn Client,
+        on: impl Fn(OnConflict<T>) -> OnConflict<T>,
+        create: impl FnOnce() -> T,
+    ) -> Result<DbState<T>>
+    where
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+        T: TryFrom<Row>,
+        WeldsError: From<<T as TryFrom<Row>>::Error>,
+        T: WriteToArgs + ColumnDefaultCheck + UpdateFromRow,
+        T: BeforeCreate + AfterCreate,
+    {
+        if let Some(found) = self.first(client).await? {
+            return Ok(found);
+        }
+        let mut obj = create();
+        let on = on(OnConflict::new()).or_keep_existing();
+        upsert::upsert_one(&mut obj, &on, client).await?;
+        Ok(DbState::db_loaded(obj))
+    }
+}
@@ -0,0 +1,192 @@
+use crate::errors::Result;
+use crate::model_traits::{Column, FromPrefixedRow, UniqueIdentifier};
+use crate::model_traits::{HasSchema, TableColumns, TableInfo};
+use crate::query::builder::QueryBuilder;
+use crate::query::clause::exists::ExistIn;
+use crate::query::clause::{ClauseAdder, ParamArgs};
+use crate::query::helpers::{
+    self, build_tail, build_top, build_where_clauses, join_sql_parts, primary_keys,
+};
+use crate::relations::{HasRelations, Relationship};
+use crate::state::DbState;
+use crate::writers::hint;
+use crate::writers::{ColumnWriter, NextParam};
+use crate::Syntax;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use welds_connections::Client;
+
+// ******************************************************************************************
+// This file contains code to select two related models out of a single joined SELECT
+// ******************************************************************************************
+
+/// An un-executed Sql Select that joins in a related table and reads both models from each row.
+/// ```rust,ignore
+/// let pairs: Vec<(DbState<Order>, DbState<Customer>)> = Order::all()
+///     .select_join(|o| o.customer, Customer::where_col(|c| c.active.equal(true)))
+///     .run(client)
+///     .await?;
+/// ```
+///
+/// The columns of each table are selected with the alias of their table as a prefix,
+/// I.E. `t1."id" as "t1_id"`, so the columns both tables have don't collide.
+///
+/// NOTE: the limit and order of this query are used for the joined rows.
+/// Ordering by a column both tables have is ambiguous
+pub struct SelectJoinBuilder<T, R> {
+    qb: QueryBuilder<T>,
+    tablename: String,
+    alias: String,
+    outer_column: String,
+    inner_column: String,
+    wheres: Vec<Arc<dyn ClauseAdder>>,
+    exist_ins: Vec<ExistIn>,
+    _r: PhantomData<R>,
+}
+
+impl<T> QueryBuilder<T>
+where
+    T: Send + HasSchema,
+{
+    /// Select the rows of this query along with the related row of a relationship
+    /// matching the filter. See `SelectJoinBuilder`
+    pub fn select_join<R, Ship>(
+        self,
+        relationship: impl Fn(<T as HasRelations>::Relation) -> Ship,
+        mut filter: QueryBuilder<R>,
+    ) -> SelectJoinBuilder<T, R>
+    where
+        T: HasRelations,
+        Ship: Relationship<R>,
+        R: Send + Sync + HasSchema,
+        <R as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasRelations>::Relation: Default,
+    {
+        let ship = relationship(Default::default());
+        filter.set_aliases(&self.alias_asigner);
+        SelectJoinBuilder {
+            tablename: <R as HasSchema>::Schema::identifier().join("."),
+            alias: filter.alias,
+            outer_column: ship.my_key::<R::Schema, T::Schema>(),
+            inner_column: ship.their_key::<R::Schema, T::Schema>(),
+            wheres: filter.wheres,
+            exist_ins: filter.exist_ins,
+            qb: self,
+            _r: PhantomData,
+        }
+    }
+}
+
+impl<T, R> SelectJoinBuilder<T, R>
+where
+    T: Send + HasSchema,
+    R: Send + HasSchema,
+    <T as HasSchema>::Schema: TableInfo + TableColumns,
+    <R as HasSchema>::Schema: TableInfo + TableColumns,
+{
+    fn sql_internal<'s, 'args, 'p>(
+        &'s self,
+        syntax: Syntax,
+        args: &'args mut Option<ParamArgs<'p>>,
+    ) -> String
+    where
+        's: 'p,
+    {
+        let next_params = NextParam::new(syntax);
+        let writer = ColumnWriter::new(syntax);
+        let qb = &self.qb;
+        let tn = <T as HasSchema>::Schema::identifier().join(".");
+
+        let mut cols = prefixed_columns(&writer, &qb.alias, &<T as HasSchema>::Schema::columns());
+        cols.extend(prefixed_columns(
+            &writer,
+            &self.alias,
+            &<R as HasSchema>::Schema::columns(),
+        ));
+
+        let mut select = vec![hint::select(&qb.hints)];
+        select.extend(build_top(syntax, qb));
+        let mut head = vec![format!(
+            "{} {} FROM {} {}",
+            select.join(" "),
+            cols.join(", "),
+            tn,
+            qb.alias
+        )];
+        head.extend(hint::table(syntax, &qb.hints, &qb.lock));
+        head.push(format!(
+            "JOIN {} {} ON {}.{} = {}.{}",
+            self.tablename, self.alias, qb.alias, self.outer_column, self.alias, self.inner_column
+        ));
+
+        // the filters of this table come first, then the filters of the joined table
+        let mut wheres = build_where_clauses(
+            syntax,
+            &next_params,
+            &qb.alias,
+            &qb.wheres,
+            args,
+            &qb.exist_ins,
+        );
+        wheres.extend(build_where_clauses(
+            syntax,
+            &next_params,
+            &self.alias,
+            &self.wheres,
+            args,
+            &self.exist_ins,
+        ));
+        let wheres = match wheres.is_empty() {
+            true => None,
+            false => Some(format!("WHERE ( {} )", wheres.join(" AND "))),
+        };
+
+        join_sql_parts(&[
+            Some(head.join(" ")),
+            wheres,
+            build_tail(syntax, qb, &primary_keys(qb), &next_params, args),
+            qb.lock.and_then(|l| l.suffix(syntax)),
+            hint::options(syntax, &qb.hints),
+        ])
+    }
+
+    /// Get a copy of the SQL that will be executed when this query runs
+    pub fn to_sql(&self, syntax: Syntax) -> String {
+        self.sql_internal(syntax, &mut None)
+    }
+
+    /// Executes the query in the database returning a pair of models for each row
+    pub async fn run(&self, client: &dyn Client) -> Result<Vec<(DbState<T>, DbState<R>)>>
+    where
+        T: FromPrefixedRow,
+        R: FromPrefixedRow,
+    {
+        let syntax = client.syntax();
+        let mut args: Option<ParamArgs> = Some(Vec::default());
+        let sql = self.sql_internal(syntax, &mut args);
+        let args = args.unwrap();
+        let rows = helpers::fetch_rows(client, &sql, &args, self.qb.timeout).await?;
+
+        let outer = format!("{}_", self.qb.alias);
+        let inner = format!("{}_", self.alias);
+        let mut pairs = Vec::default();
+        for row in rows {
+            let left = T::from_prefixed_row(&row, &outer)?;
+            let right = R::from_prefixed_row(&row, &inner)?;
+            pairs.push((DbState::db_loaded(left), DbState::db_loaded(right)));
+        }
+        Ok(pairs)
+    }
+}
+
+/// writes the columns of a table named with the alias of the table in front. I.E. `t1."id" as "t1_id"`
+fn prefixed_columns(writer: &ColumnWriter, alias: &str, columns: &[Column]) -> Vec<String> {
+    columns
+        .iter()
+        .map(|col| {
+            let name = writer.excape(&format!("{}_{}", alias, col.name()));
+            format!("{} as {}", writer.write(alias, col), name)
+        })
+        .collect()
+}
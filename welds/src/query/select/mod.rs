@@ -1,15 +1,20 @@
 use super::builder::QueryBuilder;
-use super::clause::ParamArgs;
-use super::helpers::{build_tail, build_where, join_sql_parts};
+use super::clause::{AsFieldName, ParamArgs};
+use super::helpers;
 use crate::errors::Result;
 use crate::model_traits::{HasSchema, TableColumns, TableIdent, TableInfo};
 use crate::state::DbState;
-use crate::writers::column::ColumnWriter;
-use crate::writers::nextparam::NextParam;
+use crate::writers::debug::inline_params;
+use crate::writers::CountWriter;
 use crate::{Syntax, WeldsError};
 use welds_connections::Client;
+use welds_connections::Decodable;
 use welds_connections::Row;
 
+pub(crate) mod explain;
+mod find_or_create;
+pub mod join;
+mod stream;
 mod writer;
 pub use writer::SelectWriter;
 
@@ -26,17 +31,44 @@ where
     where
         T: HasSchema,
         <T as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        self.sql_count_internal(syntax, &mut None)
+    }
+
+    fn sql_count_internal<'s, 'args, 'p>(
+        &'s self,
+        syntax: Syntax,
+        args: &'args mut Option<ParamArgs<'p>>,
+    ) -> String
+    where
+        's: 'p,
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
     {
         let table = TableIdent::from_model::<T>();
-        let writer = SelectWriter::new_with_alias(syntax, &table, &self.alias);
-        writer.sql_count(
+        let writer = SelectWriter::new_with_alias(syntax, &table, &self.alias).hints(&self.hints);
+        if self.distinct.is_none() {
+            return writer.sql_count(
+                &self.wheres,
+                &self.exist_ins,
+                &self.limit,
+                &self.offset,
+                &self.orderby,
+                args,
+            );
+        }
+        // the unique rows must be found before they can be counted
+        let columns = <T as HasSchema>::Schema::columns();
+        let inner = writer.distinct(&self.distinct).sql(
+            &columns,
             &self.wheres,
             &self.exist_ins,
             &self.limit,
             &self.offset,
             &self.orderby,
-            &mut None,
-        )
+            args,
+        );
+        let count_star = CountWriter::new(syntax).count(None, Some("*"));
+        format!("SELECT {} FROM ( {} ) welds_distinct", count_star, inner)
     }
 
     /// Executes a `select count(...) FROM ... `
@@ -48,34 +80,68 @@ where
         <T as HasSchema>::Schema: TableInfo + TableColumns,
     {
         let syntax = client.syntax();
-        let mut args: Option<ParamArgs> = Some(Vec::default());
+        let queries = self.split_for_params(syntax)?;
+        let queries = queries.as_deref().unwrap_or(std::slice::from_ref(self));
 
-        let table = TableIdent::from_model::<T>();
-        let writer = SelectWriter::new_with_alias(syntax, &table, &self.alias);
-        let sql = writer.sql_count(
-            &self.wheres,
-            &self.exist_ins,
-            &self.limit,
-            &self.offset,
-            &self.orderby,
-            &mut args,
-        );
-
-        let args = args.unwrap();
-        let rows = client.fetch_rows(&sql, &args).await?;
-        let row = rows.first().ok_or(WeldsError::RowNowFound)?;
-        let count: i64 = row.get_by_position(0)?;
-        Ok(count as u64)
+        let mut total = 0;
+        for q in queries {
+            let mut args: Option<ParamArgs> = Some(Vec::default());
+            let sql = q.sql_count_internal(syntax, &mut args);
+            let args = args.unwrap();
+            let rows = helpers::fetch_rows(client, &sql, &args, q.timeout).await?;
+            let row = rows.first().ok_or(WeldsError::RowNowFound)?;
+            let count: i64 = row.get_by_position(0)?;
+            total += count as u64;
+        }
+        Ok(total)
     }
 
     /// Get a copy of the SQL that will be executed when this query runs
     pub fn to_sql(&self, syntax: Syntax) -> String
     where
         <T as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        self.sql_internal(syntax, &mut None)
+    }
+
+    /// Get the SQL that will be executed and the params that will be sent with it, in order
+    pub fn to_sql_with_params(&self, syntax: Syntax) -> (String, ParamArgs<'_>)
+    where
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        let mut args: Option<ParamArgs> = Some(Vec::default());
+        let sql = self.sql_internal(syntax, &mut args);
+        (sql, args.unwrap())
+    }
+
+    /// FOR DEBUGGING ONLY: the SQL of this query with the params written into it.
+    ///
+    /// Handy to paste into psql or another console. Always run the query with `run`,
+    /// the params are only safe when they are sent to the database on their own
+    pub fn debug_sql(&self, syntax: Syntax) -> String
+    where
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        let (sql, args) = self.to_sql_with_params(syntax);
+        inline_params(syntax, &sql, &args)
+    }
+
+    fn sql_internal<'s, 'args, 'p>(
+        &'s self,
+        syntax: Syntax,
+        args: &'args mut Option<ParamArgs<'p>>,
+    ) -> String
+    where
+        's: 'p,
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
     {
         let table = TableIdent::from_model::<T>();
         let columns = <T as HasSchema>::Schema::columns();
-        let writer = SelectWriter::new_with_alias(syntax, &table, &self.alias);
+        let writer = SelectWriter::new_with_alias(syntax, &table, &self.alias)
+            .distinct(&self.distinct)
+            .lock(&self.lock)
+            .hints(&self.hints)
+            .primary_keys(helpers::primary_keys(self));
         writer.sql(
             &columns,
             &self.wheres,
@@ -83,7 +149,7 @@ where
             &self.limit,
             &self.offset,
             &self.orderby,
-            &mut None,
+            args,
         )
     }
 
@@ -96,32 +162,60 @@ where
         WeldsError: From<<T as TryFrom<Row>>::Error>,
     {
         let syntax = client.syntax();
-        let mut args: Option<ParamArgs> = Some(Vec::default());
-
-        let table = TableIdent::from_model::<T>();
-        let columns = <T as HasSchema>::Schema::columns();
-        let writer = SelectWriter::new_with_alias(syntax, &table, &self.alias);
-        let sql = writer.sql(
-            &columns,
-            &self.wheres,
-            &self.exist_ins,
-            &self.limit,
-            &self.offset,
-            &self.orderby,
-            &mut args,
-        );
-
-        let args = args.unwrap();
-        let rows = client.fetch_rows(&sql, &args).await?;
+        // a list of values too long for the database is run in chunks
+        let queries = self.split_for_params(syntax)?;
+        let queries = queries.as_deref().unwrap_or(std::slice::from_ref(self));
 
         let mut objs = Vec::default();
-        for row in rows {
-            let obj: T = T::try_from(row)?;
-            objs.push(DbState::db_loaded(obj));
+        for q in queries {
+            let mut args: Option<ParamArgs> = Some(Vec::default());
+            let sql = q.sql_internal(syntax, &mut args);
+            let args = args.unwrap();
+            let rows = helpers::fetch_rows(client, &sql, &args, q.timeout).await?;
+            for row in rows {
+                let obj: T = T::try_from(row)?;
+                objs.push(DbState::db_loaded(obj));
+            }
         }
         Ok(objs)
     }
+
+    /// Counts the rows of your query for each value of a column in a single `GROUP BY`
+    /// ```rust,ignore
+    /// let counts: Vec<(String, i64)> = Order::all().count_by(|o| o.status, client).await?;
+    /// ```
+    pub async fn count_by<V, FN: AsFieldName<V>>(
+        self,
+        lam: impl Fn(<T as HasSchema>::Schema) -> FN,
+        client: &dyn Client,
+    ) -> Result<Vec<(V, i64)>>
+    where
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+        V: Decodable,
+    {
+        let field = lam(Default::default()).fieldname().to_string();
+        let rows = self
+            .group_by(&lam)
+            .select(&lam)
+            .select_count_all(COUNT_BY)
+            .run(client)
+            .await?;
+        let mut counts = Vec::default();
+        for row in rows {
+            let key: V = row.get(&field)?;
+            // MSSQL counts as an INT
+            let count: i64 = match client.syntax() {
+                Syntax::Mssql => row.get::<i32>(COUNT_BY)? as i64,
+                _ => row.get(COUNT_BY)?,
+            };
+            counts.push((key, count));
+        }
+        Ok(counts)
+    }
 }
 
+/// the alias given to the count in `count_by`
+const COUNT_BY: &str = "welds_count";
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,131 @@
+use crate::errors::Result;
+use crate::model_traits::{HasSchema, TableColumns, TableInfo};
+use crate::query::builder::QueryBuilder;
+use crate::query::clause::{OrderBy, ParamArgs};
+use crate::state::DbState;
+use crate::WeldsError;
+use futures::channel::mpsc::{channel, Sender};
+use futures::{future, FutureExt, SinkExt, Stream, StreamExt};
+use welds_connections::{Client, Row};
+
+// ******************************************************************************************
+// This file contains the code to stream the results of a SELECT instead of collecting them
+// ******************************************************************************************
+
+/// The number of rows read in each page when the database can't stream
+const PAGE_SIZE: i64 = 1000;
+
+/// The number of rows read ahead of the consumer of the stream
+const BUFFER_SIZE: usize = 100;
+
+type Results<T> = Sender<Result<DbState<T>>>;
+
+impl<T> QueryBuilder<T>
+where
+    T: Send + HasSchema,
+{
+    /// Executes the query in the database, streaming back the results one at a time.
+    ///
+    /// The rows are not all held in memory, useful for exports of large tables.
+    /// Postgres and MySQL stream the rows from the server as they are read,
+    /// other connections page through the rows `PAGE_SIZE` at a time.
+    /// The `timeout` of the query limits each page, rows streamed from the server are not limited
+    /// ```rust,ignore
+    /// let mut orders = Order::all().stream(client);
+    /// while let Some(order) = orders.next().await {
+    ///     write_line(order?);
+    /// }
+    /// ```
+    pub fn stream<'q>(
+        &'q self,
+        client: &'q dyn Client,
+    ) -> impl Stream<Item = Result<DbState<T>>> + Send + 'q
+    where
+        T: 'q + TryFrom<Row>,
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+        WeldsError: From<<T as TryFrom<Row>>::Error>,
+    {
+        // The rows borrow the SQL while they are being read.
+        // They are read into a channel from here so the stream can own everything it needs
+        let (tx, rx) = channel(BUFFER_SIZE);
+        let read = async move {
+            let mut tx = tx;
+            let syntax = client.syntax();
+            let mut args: Option<ParamArgs> = Some(Vec::default());
+            let sql = self.sql_internal(syntax, &mut args);
+            let args = args.unwrap();
+            let rows = client.stream_rows(&sql, &args);
+            match rows {
+                Some(mut rows) => {
+                    while let Some(row) = rows.next().await {
+                        let obj = match row {
+                            Ok(row) => as_state(row),
+                            Err(err) => Err(err.into()),
+                        };
+                        if tx.send(obj).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+                None => self.read_pages(client, &mut tx).await,
+            };
+        };
+
+        // the reader doesn't return anything, it is only polled to fill up the channel
+        let read = read.into_stream().filter_map(|_| future::ready(None));
+        futures::stream::select(rx, read)
+    }
+
+    /// reads page by page, ordered by the primary keys if there is no order.
+    async fn read_pages(&self, client: &dyn Client, tx: &mut Results<T>)
+    where
+        T: TryFrom<Row>,
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+        WeldsError: From<<T as TryFrom<Row>>::Error>,
+    {
+        let mut q = self.clone();
+        if q.orderby.is_empty() {
+            for pk in <<T as HasSchema>::Schema as TableColumns>::primary_keys() {
+                q.orderby.push(OrderBy::new(pk.name(), "ASC"));
+            }
+        }
+
+        let start = self.offset.unwrap_or_default();
+        let mut read = 0;
+        loop {
+            let size = match self.limit {
+                Some(limit) => PAGE_SIZE.min(limit - read),
+                None => PAGE_SIZE,
+            };
+            if size <= 0 {
+                return;
+            }
+            let page = q.clone().limit(size).offset(start + read);
+            let rows = match page.run(client).await {
+                Ok(rows) => rows,
+                Err(err) => {
+                    let _ = tx.send(Err(err)).await;
+                    return;
+                }
+            };
+            let count = rows.len() as i64;
+            for row in rows {
+                if tx.send(Ok(row)).await.is_err() {
+                    return;
+                }
+            }
+            if count < size {
+                return;
+            }
+            read += count;
+        }
+    }
+}
+
+fn as_state<T>(row: Row) -> Result<DbState<T>>
+where
+    T: TryFrom<Row>,
+    WeldsError: From<<T as TryFrom<Row>>::Error>,
+{
+    Ok(DbState::db_loaded(T::try_from(row)?))
+}
@@ -1,6 +1,7 @@
 use super::*;
 
 // Test Object that can be used to write SQL
+use crate::query::clause::DatePart;
 use crate::WeldsModel;
 
 #[derive(Debug, WeldsModel)]
@@ -101,3 +102,410 @@ fn should_exec_basic_with_where() {
         &ran_sql
     );
 }
+
+#[test]
+fn should_write_select_distinct() {
+    let q = QueryBuilder::<Product>::new().distinct();
+    assert_eq!(
+        "SELECT DISTINCT t1.\"dbname\" FROM da_schemaname.da_tablename t1",
+        q.to_sql(Syntax::Postgres)
+    );
+}
+
+#[test]
+fn should_count_the_distinct_rows() {
+    let q = QueryBuilder::<Product>::new().distinct();
+    assert_eq!(
+        "SELECT CAST( COUNT(*) as BIGINT ) FROM ( SELECT DISTINCT t1.\"dbname\" FROM da_schemaname.da_tablename t1 ) welds_distinct",
+        q.to_sql_count(Syntax::Postgres)
+    );
+}
+
+#[test]
+fn should_write_select_distinct_on() {
+    let q = QueryBuilder::<Product>::new()
+        .distinct_on(|p| p.name)
+        .order_by_asc(|p| p.name);
+    assert_eq!(
+        "SELECT DISTINCT ON (t1.\"dbname\") t1.\"dbname\" FROM da_schemaname.da_tablename t1 ORDER BY dbname ASC",
+        q.to_sql(Syntax::Postgres)
+    );
+}
+
+#[test]
+fn should_write_raw_where_with_params() {
+    let name = "bob".to_string();
+    let q = QueryBuilder::<Product>::new()
+        .where_col(|p| p.name.not_equal("alice"))
+        .where_raw(
+            "lower($.dbname) = {} OR length($.dbname) > {}",
+            &[&name, &3],
+        );
+    assert_eq!(
+        "SELECT t1.\"dbname\" FROM da_schemaname.da_tablename t1 WHERE ( t1.dbname != $1 AND ( lower(t1.dbname) = $2 OR length(t1.dbname) > $3 ) )",
+        q.to_sql(Syntax::Postgres)
+    );
+}
+
+#[derive(Debug, WeldsModel)]
+#[welds(table = "orders")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Order {
+    pub id: i32,
+    pub status: String,
+    pub price: f64,
+}
+
+#[test]
+fn should_write_nested_and_or_groups() {
+    let q = QueryBuilder::<Order>::new()
+        .where_col(|o| o.id.gt(1))
+        .where_any(|g| {
+            g.all(|g| g.col(|o| o.status.equal("open")).col(|o| o.price.gt(10.0)))
+                .all(|g| g.col(|o| o.status.equal("new")).col(|o| o.price.lt(5.0)))
+        });
+    assert_eq!(
+        "SELECT t1.\"id\", t1.\"status\", t1.\"price\" FROM orders t1 WHERE ( t1.id > $1 AND ( ( t1.status = $2 AND t1.price > $3 ) OR ( t1.status = $4 AND t1.price < $5 ) ) )",
+        q.to_sql(Syntax::Postgres)
+    );
+}
+
+#[test]
+fn should_skip_empty_groups() {
+    let q = QueryBuilder::<Order>::new().where_any(|g| g);
+    assert_eq!(
+        "SELECT t1.\"id\", t1.\"status\", t1.\"price\" FROM orders t1",
+        q.to_sql(Syntax::Postgres)
+    );
+}
+
+#[test]
+fn should_write_ilike_for_each_syntax() {
+    let q = QueryBuilder::<Order>::new().where_col(|o| o.status.ilike("op%"));
+    let sql = |syntax| q.to_sql(syntax);
+    assert!(sql(Syntax::Postgres).ends_with("WHERE ( t1.status ilike $1 )"));
+    assert!(sql(Syntax::Sqlite).ends_with("WHERE ( t1.status like ? COLLATE NOCASE )"));
+    assert!(sql(Syntax::Mysql).ends_with("WHERE ( LOWER(t1.status) like LOWER(?) )"));
+    assert!(sql(Syntax::Mssql).ends_with("WHERE ( LOWER(t1.status) like LOWER(@p1) )"));
+}
+
+#[test]
+fn should_write_contains_ci() {
+    let q = QueryBuilder::<Order>::new().where_col(|o| o.status.contains_ci("pen"));
+    assert!(q
+        .to_sql(Syntax::Sqlite)
+        .ends_with("WHERE ( t1.status like ? COLLATE NOCASE )"));
+}
+
+#[test]
+fn should_count_by_a_column_in_one_group_by() {
+    let ran_sql = futures::executor::block_on(async move {
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        let counts = Order::all().count_by(|o| o.status, &client).await.unwrap();
+        assert!(counts.is_empty());
+        client.last_sql()
+    })
+    .unwrap();
+    assert_eq!(
+        ran_sql,
+        "SELECT t1.\"status\", COUNT(*) as \"welds_count\" FROM orders t1  GROUP BY t1.\"status\""
+    );
+}
+
+#[derive(Debug, WeldsModel)]
+#[welds(table = "posts")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Post {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub created_at: i64,
+    pub updated_at: Option<i64>,
+    pub title: String,
+    pub slug: String,
+}
+
+#[test]
+fn should_compare_two_columns_of_the_row() {
+    let q = QueryBuilder::<Post>::new()
+        .where_col(|c| c.updated_at.gt_col(c.created_at))
+        .where_col(|c| c.title.not_equal_col(c.slug));
+    assert_eq!(
+        "SELECT t1.\"id\", t1.\"created_at\", t1.\"updated_at\", t1.\"title\", t1.\"slug\" FROM posts t1 WHERE ( t1.updated_at > t1.created_at AND t1.title != t1.slug )",
+        q.to_sql(Syntax::Postgres)
+    );
+}
+
+#[test]
+fn should_filter_on_a_truncated_date() {
+    let sql = |syntax| {
+        QueryBuilder::<Post>::new()
+            .where_col(|c| c.created_at.trunc(DatePart::Day).equal(5))
+            .to_sql(syntax)
+    };
+    let head = "SELECT t1.\"id\", t1.\"created_at\", t1.\"updated_at\", t1.\"title\", t1.\"slug\" FROM posts t1";
+    assert_eq!(
+        format!("{head} WHERE ( date_trunc('day', t1.created_at) = $1 )"),
+        sql(Syntax::Postgres)
+    );
+    assert_eq!(
+        format!("{head} WHERE ( strftime('%Y-%m-%d 00:00:00', t1.created_at) = ? )"),
+        sql(Syntax::Sqlite)
+    );
+    assert_eq!(
+        format!("{head} WHERE ( DATEADD(day, DATEDIFF(day, 0, t1.created_at), 0) = @p1 )"),
+        sql(Syntax::Mssql)
+    );
+}
+
+#[test]
+fn should_filter_on_parts_and_age_of_a_date() {
+    let q = QueryBuilder::<Post>::new()
+        .where_col(|c| c.updated_at.extract(DatePart::Month).equal(12))
+        .where_col(|c| c.created_at.age_days().gt(30));
+    assert_eq!(
+        "SELECT t1.id, t1.created_at, t1.updated_at, t1.title, t1.slug FROM posts t1 WHERE ( EXTRACT(MONTH FROM t1.updated_at) = ? AND DATEDIFF(CURRENT_DATE, t1.created_at) > ? )",
+        q.to_sql(Syntax::Mysql)
+    );
+}
+
+#[test]
+fn should_explain_a_select() {
+    futures::executor::block_on(async move {
+        let q = QueryBuilder::<Product>::new().where_col(|p| p.name.equal("bob"));
+        let client = welds_connections::noop::build(Syntax::Sqlite);
+        let plan = q.explain(&client).await.unwrap();
+        assert_eq!("", plan);
+        let expected = "EXPLAIN QUERY PLAN SELECT t1.\"dbname\" FROM da_schemaname.da_tablename t1 WHERE ( t1.dbname = ? )";
+        assert_eq!(expected, client.last_sql().unwrap());
+        assert_eq!(1, client.args_count().unwrap());
+    });
+}
+
+#[test]
+fn should_explain_analyze_a_select() {
+    futures::executor::block_on(async move {
+        let q = QueryBuilder::<Product>::new();
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        q.explain_analyze(&client).await.unwrap();
+        let expected = "EXPLAIN ANALYZE SELECT t1.\"dbname\" FROM da_schemaname.da_tablename t1";
+        assert_eq!(expected, client.last_sql().unwrap());
+    });
+}
+
+#[test]
+fn should_not_explain_analyze_on_sqlite() {
+    futures::executor::block_on(async move {
+        let q = QueryBuilder::<Product>::new();
+        let client = welds_connections::noop::build(Syntax::Sqlite);
+        assert!(q.explain_analyze(&client).await.is_err());
+        assert!(client.last_sql().is_none());
+    });
+}
+
+#[test]
+fn should_write_debug_sql_with_the_params_inlined() {
+    let q = QueryBuilder::<Product>::new().where_col(|p| p.name.equal("bob"));
+    assert_eq!(
+        "SELECT t1.\"dbname\" FROM da_schemaname.da_tablename t1 WHERE ( t1.dbname = 'bob' )",
+        q.debug_sql(Syntax::Postgres)
+    );
+    assert_eq!(
+        "SELECT t1.dbname FROM da_schemaname.da_tablename t1 WHERE ( t1.dbname = 'bob' )",
+        q.debug_sql(Syntax::Mysql)
+    );
+    assert_eq!(
+        "SELECT t1.\"dbname\" FROM da_schemaname.da_tablename t1 WHERE ( t1.dbname = N'bob' )",
+        q.debug_sql(Syntax::Mssql)
+    );
+    let (sql, params) = q.to_sql_with_params(Syntax::Postgres);
+    assert_eq!(q.to_sql(Syntax::Postgres), sql);
+    assert_eq!(1, params.len());
+}
+
+#[test]
+fn should_write_an_in_list() {
+    let names = ["a", "b", "c"];
+    let q = QueryBuilder::<Product>::new().where_col(|x| x.name.in_list(&names));
+    assert_eq!(
+        "SELECT t1.\"dbname\" FROM da_schemaname.da_tablename t1 WHERE ( t1.dbname IN ($1, $2, $3) )",
+        q.to_sql(Syntax::Postgres)
+    );
+    let q = QueryBuilder::<Product>::new()
+        .where_col(|x| x.name.not_in_list(&names))
+        .where_col(|x| x.name.in_list::<&str>(&[]));
+    assert_eq!(
+        "SELECT t1.dbname FROM da_schemaname.da_tablename t1 WHERE ( t1.dbname NOT IN (?, ?, ?) AND 1=0 )",
+        q.to_sql(Syntax::Mysql)
+    );
+}
+
+#[test]
+fn should_write_a_long_in_list_as_values() {
+    use crate::query::clause::InList;
+    let names = ["a", "b"];
+    let q = QueryBuilder::<Product>::new()
+        .where_col(|x| x.name.in_list_with(&names, InList::ValuesOver(1)));
+    assert_eq!(
+        "SELECT t1.\"dbname\" FROM da_schemaname.da_tablename t1 WHERE ( t1.dbname IN (SELECT v FROM (VALUES ($1), ($2)) AS x(v)) )",
+        q.to_sql(Syntax::Postgres)
+    );
+    assert_eq!(
+        "SELECT t1.dbname FROM da_schemaname.da_tablename t1 WHERE ( t1.dbname IN (SELECT column_0 FROM (VALUES ROW(?), ROW(?)) AS x) )",
+        q.to_sql(Syntax::Mysql)
+    );
+    assert_eq!(
+        "SELECT t1.\"dbname\" FROM da_schemaname.da_tablename t1 WHERE ( t1.dbname IN (SELECT column1 FROM (VALUES (?), (?))) )",
+        q.to_sql(Syntax::Sqlite)
+    );
+    let q = QueryBuilder::<Product>::new()
+        .where_col(|x| x.name.in_list_with(&names, InList::ValuesOver(2)));
+    assert_eq!(
+        "SELECT t1.\"dbname\" FROM da_schemaname.da_tablename t1 WHERE ( t1.dbname IN ($1, $2) )",
+        q.to_sql(Syntax::Postgres)
+    );
+}
+
+#[test]
+fn should_run_an_in_list_too_long_for_the_database_in_chunks() {
+    let names: Vec<String> = (0..4500).map(|i| i.to_string()).collect();
+    let q = QueryBuilder::<Product>::new()
+        .where_col(|x| x.name.not_equal("z"))
+        .where_col(|x| x.name.in_list(&names));
+    // each chunk has the other params of the query
+    let chunks = q.split_for_params(Syntax::Mssql).unwrap().unwrap();
+    assert_eq!(chunks.len(), 3);
+    assert!(q.split_for_params(Syntax::Postgres).unwrap().is_none());
+
+    let client = welds_connections::noop::build(Syntax::Mssql);
+    futures::executor::block_on(async {
+        q.run(&client).await.unwrap();
+    });
+    assert_eq!(client.args_count().unwrap(), 4500 - 2099 * 2 + 1);
+
+    // an ordered or limited query can't be split, it fails before it is sent
+    let q = q.order_by_asc(|x| x.name);
+    let err = futures::executor::block_on(q.run(&client)).unwrap_err();
+    assert!(
+        matches!(err, WeldsError::TooManyParams(4501, 2100)),
+        "{}",
+        err
+    );
+    assert!(q.limit(5).split_for_params(Syntax::Mssql).is_err());
+}
+
+#[derive(Debug, WeldsModel)]
+#[welds(table = "items")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Item {
+    #[welds(primary_key)]
+    pub id: i32,
+}
+
+#[test]
+fn should_count_a_repeated_value_once_across_chunks() {
+    use welds_connections::{Client, TransactStart};
+    futures::executor::block_on(async move {
+        let client = welds_connections::sqlite::connect("sqlite::memory:")
+            .await
+            .unwrap();
+        // a transaction keeps everything on the same in memory database
+        let trans = client.begin().await.unwrap();
+        let create = "CREATE TABLE items (id INTEGER PRIMARY KEY)";
+        trans.execute(create, &[]).await.unwrap();
+        let insert = "WITH RECURSIVE n(id) AS (SELECT 0 UNION ALL SELECT id + 1 FROM n WHERE id < 1499) INSERT INTO items SELECT id FROM n";
+        trans.execute(insert, &[]).await.unwrap();
+
+        // every id is in the list twice, far enough apart to land in different chunks
+        let ids: Vec<i32> = (0..1500).chain(0..1500).collect();
+        let q = Item::where_col(|i| i.id.in_list(&ids));
+        assert_eq!(q.count(&trans).await.unwrap(), 1500);
+        assert_eq!(q.run(&trans).await.unwrap().len(), 1500);
+        trans.rollback().await.unwrap();
+    });
+}
+
+#[test]
+fn should_look_again_when_first_or_insert_fails_to_insert() {
+    let q = QueryBuilder::<Product>::new().where_col(|p| p.name.equal("bob"));
+    let ran_sql = futures::executor::block_on(async move {
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        let created = q.first_or_insert(&client, || Product {
+            name: "bob".to_owned(),
+        });
+        // nothing comes back from the noop client, not even the inserted row
+        assert!(created.await.is_err());
+        client.last_sql()
+    })
+    .unwrap();
+    assert_eq!(
+        "SELECT t1.\"dbname\" FROM da_schemaname.da_tablename t1 WHERE ( t1.dbname = $1 ) ORDER BY 1 OFFSET 0 LIMIT 1",
+        &ran_sql
+    );
+}
+
+#[test]
+fn should_run_a_query_with_a_timeout() {
+    let q = QueryBuilder::<Product>::new().timeout(std::time::Duration::from_secs(1));
+    let ran_sql = futures::executor::block_on(async move {
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        q.run(&client).await.unwrap();
+        client.last_sql()
+    })
+    .unwrap();
+    // the limit is set on the connection, and taken back off after
+    assert_eq!(
+        "SET statement_timeout = 1000;SELECT t1.\"dbname\" FROM da_schemaname.da_tablename t1;SET statement_timeout = DEFAULT",
+        &ran_sql
+    );
+}
+
+#[test]
+fn should_set_the_timeout_of_each_database() {
+    let timeout = std::time::Duration::from_millis(250);
+    let expected = [
+        (
+            Syntax::Mysql,
+            "SET SESSION max_execution_time = 250",
+            "SET SESSION max_execution_time = DEFAULT",
+        ),
+        (Syntax::Mssql, "SET LOCK_TIMEOUT 250", "SET LOCK_TIMEOUT -1"),
+    ];
+    for (syntax, limit, reset) in expected {
+        let q = QueryBuilder::<Product>::new().timeout(timeout);
+        let ran_sql = futures::executor::block_on(async move {
+            let client = welds_connections::noop::build(syntax);
+            q.run(&client).await.unwrap();
+            client.last_sql()
+        })
+        .unwrap();
+        let statements: Vec<&str> = ran_sql.split(';').collect();
+        assert_eq!(statements.len(), 3);
+        assert_eq!(statements[0], limit);
+        assert_eq!(statements[2], reset);
+    }
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "user_roles")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct UserRole {
+    #[welds(primary_key)]
+    pub user_id: i32,
+    #[welds(primary_key)]
+    pub role_id: i32,
+}
+
+#[test]
+fn should_find_by_a_composite_primary_key() {
+    let ran_sql = futures::executor::block_on(async move {
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        let found = UserRole::find_by_id(&client, (1, 2)).await.unwrap();
+        assert!(found.is_none());
+        client.last_sql()
+    })
+    .unwrap();
+    assert_eq!(
+        "SELECT t1.\"user_id\", t1.\"role_id\" FROM user_roles t1 WHERE ( t1.user_id = $1 AND t1.role_id = $2 ) ORDER BY 1 OFFSET 0 LIMIT 1",
+        &ran_sql
+    );
+}
@@ -11,13 +11,14 @@ use crate::query::clause::BasicOpt;
 
 // model with option
 
+#[allow(dead_code)]
 struct Product2 {
     pub name: Option<String>,
 }
 
 impl TryFrom<Row> for Product2 {
     type Error = crate::WeldsError;
-    fn try_from(value: Row) -> std::result::Result<Self, Self::Error> {
+    fn try_from(_value: Row) -> std::result::Result<Self, Self::Error> {
         Ok(Product2 { name: None })
     }
 }
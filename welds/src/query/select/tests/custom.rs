@@ -0,0 +1,84 @@
+use super::*;
+
+use crate::{WeldsModel, WeldsType};
+
+#[derive(Debug, Clone, Default, PartialEq, WeldsType)]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Cents(i64);
+
+#[derive(Debug, Clone, Default, PartialEq, WeldsType)]
+#[welds(custom)]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Email(String);
+
+impl WeldsType for Email {
+    type Stored = String;
+    fn to_stored(&self) -> String {
+        self.0.to_lowercase()
+    }
+    fn from_stored(
+        stored: String,
+    ) -> std::result::Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        match stored.contains('@') {
+            true => Ok(Email(stored)),
+            false => Err(format!("not an email: {}", stored).into()),
+        }
+    }
+}
+
+#[derive(Debug, WeldsModel)]
+#[welds(table = "accounts")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Account {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub email: Email,
+    pub balance: Cents,
+}
+
+// Tests
+
+#[test]
+fn should_store_a_newtype_as_its_field() {
+    assert_eq!(5, Cents(5).to_stored());
+    assert_eq!(Cents(7), Cents::from_stored(7).unwrap());
+}
+
+#[test]
+fn should_store_a_custom_type_with_its_own_logic() {
+    assert_eq!(
+        "bob@example.com",
+        Email("Bob@Example.com".to_owned()).to_stored()
+    );
+    assert!(Email::from_stored("bob".to_owned()).is_err());
+}
+
+#[test]
+fn should_filter_by_a_custom_type() {
+    let q = Account::where_col(|a| a.email.equal(Email("Bob@Example.com".to_owned())));
+    let sql = q.debug_sql(Syntax::Sqlite);
+    assert!(
+        sql.ends_with("FROM accounts t1 WHERE ( t1.email = 'bob@example.com' )"),
+        "{}",
+        sql
+    );
+    let sql = q.debug_sql(Syntax::Mssql);
+    assert!(
+        sql.ends_with("FROM accounts t1 WHERE ( t1.email = N'bob@example.com' )"),
+        "{}",
+        sql
+    );
+}
+
+#[test]
+fn should_filter_by_a_newtype() {
+    let q = Account::where_col(|a| a.balance.in_list(&[Cents(100), Cents(250)]));
+    for syntax in [Syntax::Mysql, Syntax::Postgres] {
+        let sql = q.debug_sql(syntax);
+        assert!(
+            sql.ends_with("FROM accounts t1 WHERE ( t1.balance IN (100, 250) )"),
+            "{}",
+            sql
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use super::*;
+
+use crate::{WeldsEnum, WeldsModel};
+
+#[derive(Debug, Clone, Default, PartialEq, WeldsEnum)]
+#[welds_path(crate)] // needed only within the welds crate.
+enum Status {
+    #[default]
+    Open,
+    #[welds(rename = "done")]
+    Closed,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, WeldsEnum)]
+#[welds(store = "int")]
+#[welds_path(crate)] // needed only within the welds crate.
+enum Level {
+    #[default]
+    Low = 1,
+    High = 5,
+    Top,
+}
+
+#[derive(Debug, WeldsModel)]
+#[welds(table = "tasks")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Task {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub status: Status,
+    pub level: Level,
+}
+
+// Tests
+
+#[test]
+fn should_store_variants_as_their_name_or_rename() {
+    assert_eq!("Open", Status::Open.as_text());
+    assert_eq!("done", Status::Closed.as_text());
+    assert_eq!(Some(Status::Closed), Status::from_text("done"));
+    assert_eq!(None, Status::from_text("Closed"));
+}
+
+#[test]
+fn should_store_variants_as_their_discriminant() {
+    assert_eq!(1, Level::Low.as_int());
+    assert_eq!(5, Level::High.as_int());
+    assert_eq!(6, Level::Top.as_int());
+    assert_eq!(Some(Level::Top), Level::from_int(6));
+    assert_eq!(None, Level::from_int(2));
+}
+
+#[test]
+fn should_filter_by_a_text_enum() {
+    let q = Task::where_col(|t| t.status.equal(Status::Closed));
+    let sql = q.debug_sql(Syntax::Sqlite);
+    assert!(
+        sql.ends_with("FROM tasks t1 WHERE ( t1.status = 'done' )"),
+        "{}",
+        sql
+    );
+    let sql = q.debug_sql(Syntax::Mssql);
+    assert!(
+        sql.ends_with("FROM tasks t1 WHERE ( t1.status = N'done' )"),
+        "{}",
+        sql
+    );
+}
+
+#[test]
+fn should_filter_by_an_int_enum() {
+    let q = Task::where_col(|t| t.level.in_list(&[Level::High, Level::Top]));
+    for syntax in [Syntax::Mysql, Syntax::Postgres] {
+        let sql = q.debug_sql(syntax);
+        assert!(
+            sql.ends_with("FROM tasks t1 WHERE ( t1.level IN (5, 6) )"),
+            "{}",
+            sql
+        );
+    }
+}
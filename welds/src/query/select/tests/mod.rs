@@ -1,5 +1,9 @@
 use super::*;
 mod basic;
 mod basicopt;
+mod custom;
+mod enums;
 mod relationships;
+mod scopes;
 mod sql_tails;
+mod stream;
@@ -51,3 +51,86 @@ fn should_be_able_to_query_with_sub_queries() {
         assert_eq!(expected, &ran_sql);
     });
 }
+
+#[test]
+fn should_filter_with_a_correlated_exists() {
+    let orders = QueryBuilder::<Order>::new().where_col(|o| o.oid.gt(5));
+    let q = QueryBuilder::<Product>::new().where_exists(orders, |p, o| (p.pid, o.p_fk_id));
+    let expected = r#"SELECT t1.pid FROM product t1 WHERE ( EXISTS ( SELECT p_fk_id FROM order t2 WHERE t2.oid > ? AND t2.p_fk_id = t1.pid ) )"#;
+    assert_eq!(expected, q.to_sql(Syntax::Mysql));
+}
+
+#[test]
+fn should_filter_with_a_correlated_not_exists() {
+    let q = QueryBuilder::<Product>::new()
+        .where_col(|p| p.pid.gt(1))
+        .where_not_exists(QueryBuilder::<Order>::new(), |p, o| (p.pid, o.p_fk_id));
+    let expected = r#"SELECT t1.pid FROM product t1 WHERE ( t1.pid > ? AND NOT EXISTS ( SELECT p_fk_id FROM order t2 WHERE t2.p_fk_id = t1.pid ) )"#;
+    assert_eq!(expected, q.to_sql(Syntax::Mysql));
+}
+
+#[test]
+fn should_filter_to_rows_not_in_a_relation() {
+    let orders = QueryBuilder::<Order>::new().where_col(|o| o.oid.gt(5));
+    let q = QueryBuilder::<Product>::new().where_not_in_relation(|p| p.orders, orders);
+    let expected = r#"SELECT t1."pid" FROM product t1 WHERE ( NOT EXISTS ( SELECT p_fk_id FROM order t2 WHERE t2.oid > $1 AND t2.p_fk_id = t1.pid ) )"#;
+    assert_eq!(expected, q.to_sql(Syntax::Postgres));
+}
+
+#[test]
+fn should_filter_to_rows_that_have_a_matching_relation() {
+    let q =
+        QueryBuilder::<Product>::new().where_has(|p| p.orders, |q| q.where_col(|o| o.oid.gt(5)));
+    let expected = r#"SELECT t1.pid FROM product t1 WHERE ( EXISTS ( SELECT p_fk_id FROM order t2 WHERE t2.oid > ? AND t2.p_fk_id = t1.pid ) )"#;
+    assert_eq!(expected, q.to_sql(Syntax::Mysql));
+}
+
+#[test]
+fn should_filter_to_rows_that_dont_have_a_matching_relation() {
+    let q = QueryBuilder::<Order>::new().where_doesnt_have(|o| o.product, |q| q);
+    let expected = r#"SELECT t1.oid, t1.p_fk_id FROM order t1 WHERE ( NOT EXISTS ( SELECT pid FROM product t2 WHERE t2.pid = t1.p_fk_id ) )"#;
+    assert_eq!(expected, q.to_sql(Syntax::Mysql));
+}
+
+#[test]
+fn should_filter_on_the_count_of_a_relationship() {
+    let q = QueryBuilder::<Product>::new().where_relation_count(|p| p.orders, |c| c.gt(5));
+    let expected = r#"SELECT t1.pid FROM product t1 WHERE ( ( SELECT COUNT(*) FROM order t1_count WHERE t1_count.p_fk_id = t1.pid ) > ? )"#;
+    assert_eq!(expected, q.to_sql(Syntax::Mysql));
+}
+
+#[test]
+fn should_filter_on_the_count_of_a_belongs_to() {
+    let q = QueryBuilder::<Order>::new()
+        .where_col(|o| o.oid.gt(1))
+        .where_relation_count(|o| o.product, |c| c.equal(0));
+    let expected = r#"SELECT t1."oid", t1."p_fk_id" FROM order t1 WHERE ( t1.oid > $1 AND ( SELECT COUNT(*) FROM product t1_count WHERE t1_count.pid = t1.p_fk_id ) = $2 )"#;
+    assert_eq!(expected, q.to_sql(Syntax::Postgres));
+}
+
+#[test]
+fn should_select_both_sides_of_a_join_with_prefixed_columns() {
+    let q = QueryBuilder::<Order>::new()
+        .where_col(|o| o.oid.gt(5))
+        .select_join(
+            |o| o.product,
+            QueryBuilder::<Product>::new().where_col(|p| p.pid.equal(1)),
+        );
+    let expected = r#"SELECT t1."oid" as "t1_oid", t1."p_fk_id" as "t1_p_fk_id", t2."pid" as "t2_pid" FROM order t1 JOIN product t2 ON t1.p_fk_id = t2.pid WHERE ( t1.oid > $1 AND t2.pid = $2 )"#;
+    assert_eq!(expected, q.to_sql(Syntax::Postgres));
+}
+
+#[test]
+fn should_select_the_many_side_of_a_join() {
+    futures::executor::block_on(async move {
+        let q = QueryBuilder::<Product>::new()
+            .limit(10)
+            .select_join(|p| p.orders, QueryBuilder::<Order>::new());
+        let client = welds_connections::noop::build(Syntax::Mysql);
+        let pairs = q.run(&client).await.unwrap();
+        assert!(pairs.is_empty());
+        let ran_sql = client.last_sql().unwrap();
+        let expected = r#"SELECT t1.pid as t1_pid, t2.oid as t2_oid, t2.p_fk_id as t2_p_fk_id FROM product t1 JOIN order t2 ON t1.pid = t2.p_fk_id ORDER BY 1 LIMIT 0, 10"#;
+        assert_eq!(expected, &ran_sql);
+    });
+}
@@ -0,0 +1,82 @@
+use super::*;
+use crate::query::builder::Scope;
+use crate::WeldsModel;
+
+#[derive(Debug, WeldsModel)]
+#[welds_path(crate)] // needed only within the welds crate.
+#[welds(table = "users")]
+#[welds(scope(active = "$.active = true", recent = "$.created_at > 100"))]
+struct User {
+    #[welds(primary_key)]
+    pub id: i64,
+    pub active: bool,
+    pub created_at: i64,
+}
+
+fn active(q: QueryBuilder<User>) -> QueryBuilder<User> {
+    q.where_col(|u| u.active.equal(true))
+}
+
+struct CreatedAfter(i64);
+
+impl Scope<User> for CreatedAfter {
+    fn apply(self, q: QueryBuilder<User>) -> QueryBuilder<User> {
+        q.where_col(|u| u.created_at.gt(self.0))
+    }
+}
+
+#[test]
+fn should_apply_a_function_as_a_scope() {
+    let q = QueryBuilder::<User>::new().scope(active);
+    let expected =
+        r#"SELECT t1.id, t1.active, t1.created_at FROM users t1 WHERE ( t1.active = ? )"#;
+    assert_eq!(expected, q.to_sql(Syntax::Mysql));
+}
+
+#[test]
+fn should_chain_scopes() {
+    let q = QueryBuilder::<User>::new()
+        .scope(active)
+        .scope(CreatedAfter(10))
+        .scope(|q: QueryBuilder<User>| q.limit(5));
+    let expected = r#"SELECT t1.id, t1.active, t1.created_at FROM users t1 WHERE ( t1.active = ? AND t1.created_at > ? ) ORDER BY 1 LIMIT 0, 5"#;
+    assert_eq!(expected, q.to_sql(Syntax::Mysql));
+}
+
+#[test]
+fn should_keep_clones_independent() {
+    let base = QueryBuilder::<User>::new().scope(active);
+    let recent = base.clone().scope(CreatedAfter(10));
+    let expected =
+        r#"SELECT t1.id, t1.active, t1.created_at FROM users t1 WHERE ( t1.active = ? )"#;
+    assert_eq!(expected, base.to_sql(Syntax::Mysql));
+    let expected = r#"SELECT t1.id, t1.active, t1.created_at FROM users t1 WHERE ( t1.active = ? AND t1.created_at > ? )"#;
+    assert_eq!(expected, recent.to_sql(Syntax::Mysql));
+}
+
+#[test]
+fn should_bind_the_params_of_a_cloned_query() {
+    futures::executor::block_on(async move {
+        let base = QueryBuilder::<User>::new().scope(CreatedAfter(10));
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        base.clone().scope(active).run(&client).await.unwrap();
+        assert_eq!(client.args_count(), Some(2));
+        base.run(&client).await.unwrap();
+        assert_eq!(client.args_count(), Some(1));
+    });
+}
+
+#[test]
+fn should_filter_with_a_scope_declared_on_the_model() {
+    let q = User::active();
+    let expected =
+        r#"SELECT t1.id, t1.active, t1.created_at FROM users t1 WHERE ( ( t1.active = true ) )"#;
+    assert_eq!(expected, q.to_sql(Syntax::Mysql));
+}
+
+#[test]
+fn should_add_to_a_scope_declared_on_the_model() {
+    let q = User::recent().scope(active);
+    let expected = r#"SELECT t1.id, t1.active, t1.created_at FROM users t1 WHERE ( ( t1.created_at > 100 ) AND t1.active = ? )"#;
+    assert_eq!(expected, q.to_sql(Syntax::Mysql));
+}
@@ -9,6 +9,7 @@ use crate::query::clause::NumericOpt;
 // Testing that the tail end of the SQL is correct
 // Limits / skips / orders
 
+#[allow(dead_code)]
 struct Product {
     pub a: i32,
     pub b: i32,
@@ -16,7 +17,7 @@ struct Product {
 
 impl TryFrom<Row> for Product {
     type Error = crate::WeldsError;
-    fn try_from(value: Row) -> std::result::Result<Self, Self::Error> {
+    fn try_from(_value: Row) -> std::result::Result<Self, Self::Error> {
         Ok(Product { a: 0, b: 0 })
     }
 }
@@ -91,6 +92,20 @@ fn should_order_by_two_columns() {
     );
 }
 
+#[test]
+fn should_order_by_an_expression_with_nulls_last() {
+    use crate::query::clause::OrderExpr;
+    let q = QueryBuilder::<Product>::new().order_by(|x| OrderExpr::col(x.b).desc().nulls_last());
+    assert_eq!(
+        "SELECT t1.\"a\", t1.\"b\" FROM nums t1 ORDER BY b DESC NULLS LAST",
+        q.to_sql(Syntax::Postgres)
+    );
+    assert_eq!(
+        "SELECT t1.\"a\", t1.\"b\" FROM nums t1 ORDER BY CASE WHEN b IS NULL THEN 1 ELSE 0 END ASC, b DESC",
+        q.to_sql(Syntax::Mssql)
+    );
+}
+
 #[test]
 fn should_be_able_to_limit() {
     let q = QueryBuilder::<Product>::new().limit(10);
@@ -120,3 +135,145 @@ fn should_be_able_to_offset() {
         &ran_sql
     );
 }
+
+#[test]
+fn should_limit_with_top_on_mssql() {
+    let q = QueryBuilder::<Product>::new().limit(10);
+    assert_eq!(
+        "SELECT TOP (10) t1.\"a\", t1.\"b\" FROM nums t1",
+        q.to_sql(Syntax::Mssql)
+    );
+    // an order or offset needs OFFSET/FETCH
+    let q = QueryBuilder::<Product>::new()
+        .limit(10)
+        .order_by_asc(|x| x.a);
+    assert_eq!(
+        "SELECT t1.\"a\", t1.\"b\" FROM nums t1 ORDER BY a ASC OFFSET 0 ROWS FETCH FIRST 10 ROWS ONLY",
+        q.to_sql(Syntax::Mssql)
+    );
+}
+
+#[test]
+fn should_lock_for_update_skip_locked() {
+    use crate::query::builder::LockMode;
+    let q = QueryBuilder::<Product>::new()
+        .limit(1)
+        .lock(LockMode::ForUpdate)
+        .skip_locked();
+    assert_eq!(
+        "SELECT t1.\"a\", t1.\"b\" FROM nums t1 ORDER BY 1 OFFSET 0 LIMIT 1 FOR UPDATE SKIP LOCKED",
+        q.to_sql(Syntax::Postgres)
+    );
+    assert_eq!(
+        "SELECT TOP (1) t1.\"a\", t1.\"b\" FROM nums t1 WITH (UPDLOCK, ROWLOCK, READPAST)",
+        q.to_sql(Syntax::Mssql)
+    );
+    assert_eq!(
+        "SELECT t1.\"a\", t1.\"b\" FROM nums t1 ORDER BY 1 LIMIT 1 OFFSET 0 ",
+        q.to_sql(Syntax::Sqlite)
+    );
+}
+
+#[test]
+fn should_lock_for_share_nowait() {
+    use crate::query::builder::LockMode;
+    let q = QueryBuilder::<Product>::new()
+        .lock(LockMode::ForShare)
+        .nowait();
+    assert_eq!(
+        "SELECT t1.a, t1.b FROM nums t1 FOR SHARE NOWAIT",
+        q.to_sql(Syntax::Mysql)
+    );
+}
+
+#[test]
+fn should_write_hints_where_each_database_reads_them() {
+    use crate::query::builder::Hint;
+    let q = QueryBuilder::<Product>::new()
+        .hint(Hint::use_index("idx_nums_a"))
+        .hint(Hint::comment("NO_ICP(t1)"))
+        .hint(Hint::query_option("RECOMPILE"));
+    assert_eq!(
+        "SELECT /*+ NO_ICP(t1) */ t1.a, t1.b FROM nums t1 USE INDEX (idx_nums_a)",
+        q.to_sql(Syntax::Mysql)
+    );
+    assert_eq!(
+        "SELECT /*+ NO_ICP(t1) */ t1.\"a\", t1.\"b\" FROM nums t1 WITH (INDEX(idx_nums_a)) OPTION (RECOMPILE)",
+        q.to_sql(Syntax::Mssql)
+    );
+    assert_eq!(
+        "SELECT /*+ NO_ICP(t1) */ t1.\"a\", t1.\"b\" FROM nums t1 INDEXED BY idx_nums_a",
+        q.to_sql(Syntax::Sqlite)
+    );
+    assert_eq!(
+        "SELECT /*+ NO_ICP(t1) */ t1.\"a\", t1.\"b\" FROM nums t1",
+        q.to_sql(Syntax::Postgres)
+    );
+}
+
+#[test]
+fn should_merge_index_hints_with_locks_on_mssql() {
+    use crate::query::builder::{Hint, LockMode};
+    let q = QueryBuilder::<Product>::new()
+        .lock(LockMode::ForUpdate)
+        .hint(Hint::force_index("idx_nums_a"));
+    assert_eq!(
+        "SELECT t1.\"a\", t1.\"b\" FROM nums t1 WITH (UPDLOCK, ROWLOCK, INDEX(idx_nums_a))",
+        q.to_sql(Syntax::Mssql)
+    );
+    assert_eq!(
+        "SELECT COUNT(*) FROM nums t1 FORCE INDEX (idx_nums_a)",
+        q.to_sql_count(Syntax::Mysql)
+    );
+}
+
+#[test]
+fn should_order_randomly() {
+    let q = QueryBuilder::<Product>::new().order_random();
+    assert_eq!(
+        "SELECT t1.a, t1.b FROM nums t1 ORDER BY RAND()",
+        q.to_sql(Syntax::Mysql)
+    );
+}
+
+#[test]
+fn should_sample_n_rows() {
+    let q = QueryBuilder::<Product>::new().sample(5);
+    assert_eq!(
+        r#"SELECT t1."a", t1."b" FROM nums t1 ORDER BY NEWID() OFFSET 0 ROWS FETCH FIRST 5 ROWS ONLY"#,
+        q.to_sql(Syntax::Mssql)
+    );
+}
+
+#[test]
+fn should_sample_a_percent_of_the_rows() {
+    let q = QueryBuilder::<Product>::new()
+        .where_col(|p| p.a.gt(1))
+        .sample_percent(10.0);
+    assert_eq!(
+        r#"SELECT t1."a", t1."b" FROM nums t1 WHERE ( t1.a > $1 AND RANDOM() < 0.1 )"#,
+        q.to_sql(Syntax::Postgres)
+    );
+    assert_eq!(
+        r#"SELECT t1."a", t1."b" FROM nums t1 WHERE ( t1.a > ? AND ABS(RANDOM() % 1000000) < 100000 )"#,
+        q.to_sql(Syntax::Sqlite)
+    );
+}
+
+#[test]
+fn should_order_by_a_case_after_the_where_params() {
+    use crate::query::clause::{Case, OrderExpr};
+    let q = QueryBuilder::<Product>::new()
+        .where_col(|x| x.a.gt(1))
+        .order_by(|x| OrderExpr::case(Case::new().when(x.a.equal(5), 0).otherwise(1)))
+        .order_by_asc(|x| x.a);
+    assert_eq!(
+        "SELECT t1.\"a\", t1.\"b\" FROM nums t1 WHERE ( t1.a > $1 ) ORDER BY CASE WHEN t1.a = $2 THEN $3 ELSE $4 END ASC, a ASC",
+        q.to_sql(Syntax::Postgres)
+    );
+    let client = welds_connections::noop::build(Syntax::Postgres);
+    futures::executor::block_on(async {
+        let _ = q.limit(2).run(&client).await;
+    });
+    assert_eq!(client.args_count().unwrap(), 4);
+}
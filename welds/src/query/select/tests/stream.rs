@@ -0,0 +1,41 @@
+use super::*;
+use crate::WeldsModel;
+use futures::StreamExt;
+
+#[derive(Debug, WeldsModel)]
+#[welds_path(crate)] // needed only within the welds crate.
+#[welds(table = "orders")]
+struct Order {
+    #[welds(primary_key)]
+    pub id: i64,
+    pub total: i64,
+}
+
+#[test]
+fn should_page_through_the_rows_ordered_by_the_pk() {
+    futures::executor::block_on(async move {
+        let client = welds_connections::noop::build(Syntax::Sqlite);
+        let q = QueryBuilder::<Order>::new().where_col(|o| o.total.gt(5));
+        let rows: Vec<_> = q.stream(&client).collect().await;
+        assert!(rows.is_empty());
+        let expected = r#"SELECT t1."id", t1."total" FROM orders t1 WHERE ( t1.total > ? ) ORDER BY id ASC LIMIT 1000 OFFSET 0 "#;
+        assert_eq!(expected, client.last_sql().unwrap());
+        assert_eq!(client.args_count(), Some(1));
+    });
+}
+
+#[test]
+fn should_page_within_the_limit_and_offset_of_the_query() {
+    futures::executor::block_on(async move {
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        let q = QueryBuilder::<Order>::new()
+            .order_by_desc(|o| o.total)
+            .limit(10)
+            .offset(20);
+        let rows: Vec<_> = q.stream(&client).collect().await;
+        assert!(rows.is_empty());
+        let expected =
+            r#"SELECT t1."id", t1."total" FROM orders t1 ORDER BY total DESC OFFSET 20 LIMIT 10"#;
+        assert_eq!(expected, client.last_sql().unwrap());
+    });
+}
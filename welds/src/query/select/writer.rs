@@ -7,10 +7,14 @@ use crate::query::clause::ParamArgs;
 use crate::query::helpers::{build_where, join_sql_parts};
 use crate::query::tail;
 use crate::writers::alias::TableAlias;
+use crate::writers::distinct::Distinct;
+use crate::writers::hint::{self, Hint};
+use crate::writers::lock::Lock;
 use crate::writers::ColumnWriter;
 use crate::writers::CountWriter;
 use crate::writers::NextParam;
 use crate::Syntax;
+use std::sync::Arc;
 
 /// take all info from a query and translates it into SQL
 /// can build the params Vec as needed
@@ -18,6 +22,10 @@ pub struct SelectWriter {
     syntax: Syntax,
     table_ident: TableIdent,
     tablealias: String,
+    distinct: Option<Distinct>,
+    lock: Option<Lock>,
+    hints: Vec<Hint>,
+    primary_keys: Vec<String>,
 }
 
 impl SelectWriter {
@@ -28,6 +36,10 @@ impl SelectWriter {
             syntax,
             table_ident: table_ident.clone(),
             tablealias: ta.next(),
+            distinct: None,
+            lock: None,
+            hints: Vec::default(),
+            primary_keys: Vec::default(),
         }
     }
 
@@ -41,27 +53,61 @@ impl SelectWriter {
             syntax,
             table_ident: table_ident.clone(),
             tablealias: alias.into(),
+            distinct: None,
+            lock: None,
+            hints: Vec::default(),
+            primary_keys: Vec::default(),
         }
     }
 
+    /// de-duplicate the rows of the SELECT
+    pub(crate) fn distinct(mut self, distinct: &Option<Distinct>) -> Self {
+        self.distinct = distinct.clone();
+        self
+    }
+
+    /// lock the rows read by the SELECT
+    pub(crate) fn lock(mut self, lock: &Option<Lock>) -> Self {
+        self.lock = *lock;
+        self
+    }
+
+    /// hint to the query planner how to run the SELECT
+    pub(crate) fn hints(mut self, hints: &[Hint]) -> Self {
+        self.hints = hints.to_vec();
+        self
+    }
+
+    /// the columns to order by when limiting without an order
+    pub(crate) fn primary_keys(mut self, primary_keys: Vec<String>) -> Self {
+        self.primary_keys = primary_keys;
+        self
+    }
+
     /// Write a `Select count` SQL String from its parts
     /// Will fill in the args to be sent to the database if provided
-    pub fn sql_count<'lam, 'exist, 'args, 'p>(
+    pub fn sql_count<'lam, 'exist, 'ord, 'args, 'p>(
         &self,
-        wheres: &'lam [Box<dyn ClauseAdder>],
+        wheres: &'lam [Arc<dyn ClauseAdder>],
         exist_ins: &'exist [ExistIn],
         limit: &Option<i64>,
         offset: &Option<i64>,
-        orders: &[OrderBy],
+        orders: &'ord [OrderBy],
         args: &'args mut Option<ParamArgs<'p>>,
     ) -> String
     where
         'lam: 'p,
         'exist: 'p,
+        'ord: 'p,
     {
         let next_params = NextParam::new(self.syntax);
         join_sql_parts(&[
-            build_head_count(&self.table_ident, &self.tablealias, self.syntax),
+            build_head_count(
+                &self.table_ident,
+                &self.tablealias,
+                self.syntax,
+                &self.hints,
+            ),
             build_where(
                 self.syntax,
                 &next_params,
@@ -70,29 +116,49 @@ impl SelectWriter {
                 args,
                 exist_ins,
             ),
-            tail::write(self.syntax, limit, offset, orders),
+            tail::write(
+                self.syntax,
+                limit,
+                offset,
+                orders,
+                &self.tablealias,
+                &next_params,
+                args,
+            ),
+            hint::options(self.syntax, &self.hints),
         ])
     }
 
     /// Write a `Select ... FROM ...` SQL String from its parts
     /// Will fill in the args to be sent to the database if provided
-    pub fn sql<'col, 'lam, 'exist, 'args, 'p>(
+    #[allow(clippy::too_many_arguments)]
+    pub fn sql<'col, 'lam, 'exist, 'ord, 'args, 'p>(
         &self,
         columns: &'col [Column],
-        wheres: &'lam [Box<dyn ClauseAdder>],
+        wheres: &'lam [Arc<dyn ClauseAdder>],
         exist_ins: &'exist [ExistIn],
         limit: &Option<i64>,
         offset: &Option<i64>,
-        orders: &[OrderBy],
+        orders: &'ord [OrderBy],
         args: &'args mut Option<ParamArgs<'p>>,
     ) -> String
     where
         'lam: 'p,
         'exist: 'p,
+        'ord: 'p,
     {
         let next_params = NextParam::new(self.syntax);
         join_sql_parts(&[
-            build_head_select(self.syntax, &self.table_ident, &self.tablealias, columns),
+            build_head_select(
+                self.syntax,
+                &self.table_ident,
+                &self.tablealias,
+                &self.distinct,
+                &self.lock,
+                &self.hints,
+                tail::top(self.syntax, limit, offset, orders),
+                columns,
+            ),
             build_where(
                 self.syntax,
                 &next_params,
@@ -101,28 +167,62 @@ impl SelectWriter {
                 args,
                 exist_ins,
             ),
-            tail::write(self.syntax, limit, offset, orders),
+            tail::write_for_table(
+                self.syntax,
+                limit,
+                offset,
+                orders,
+                &self.primary_keys,
+                &self.tablealias,
+                &next_params,
+                args,
+            ),
+            self.lock.and_then(|l| l.suffix(self.syntax)),
+            hint::options(self.syntax, &self.hints),
         ])
     }
 }
 
-fn build_head_count(table: &TableIdent, tablealias: &str, syntax: Syntax) -> Option<String> {
+fn build_head_count(
+    table: &TableIdent,
+    tablealias: &str,
+    syntax: Syntax,
+    hints: &[Hint],
+) -> Option<String> {
     let tn = table.to_string();
     let identifier = format!("{} {}", tn, &tablealias);
     let cw = CountWriter::new(syntax);
     let count_star = cw.count(Some(tablealias), Some("*"));
-    Some(format!("SELECT {} FROM {}", count_star, identifier))
+    let select = hint::select(hints);
+    let head = format!("{} {} FROM {}", select, count_star, identifier);
+    match hint::table(syntax, hints, &None) {
+        Some(table_hint) => Some(format!("{} {}", head, table_hint)),
+        None => Some(head),
+    }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn build_head_select(
     syntax: Syntax,
     table: &TableIdent,
     tablealias: &str,
+    distinct: &Option<Distinct>,
+    lock: &Option<Lock>,
+    hints: &[Hint],
+    top: Option<String>,
     cols_info: &[Column],
 ) -> Option<String> {
     let writer = ColumnWriter::new(syntax);
     let mut head: Vec<&str> = Vec::default();
-    head.push("SELECT");
+    let select = hint::select(hints);
+    head.push(&select);
+    let distinct = distinct.as_ref().map(|d| d.write(syntax, tablealias));
+    if let Some(distinct) = &distinct {
+        head.push(distinct);
+    }
+    if let Some(top) = &top {
+        head.push(top);
+    }
     //let cols_info = S::columns();
     let cols: Vec<_> = cols_info
         .iter()
@@ -134,5 +234,9 @@ fn build_head_select(
     let tn = table.to_string();
     let identifier = format!("{} {}", tn, tablealias);
     head.push(&identifier);
+    let hint = hint::table(syntax, hints, lock);
+    if let Some(hint) = &hint {
+        head.push(hint);
+    }
     Some(head.join(" "))
 }
@@ -0,0 +1,81 @@
+use super::SelectBuilder;
+use crate::model_traits::{HasSchema, TableColumns, TableInfo};
+use crate::query::clause::ParamArgs;
+use crate::writers::{ColumnWriter, NextParam};
+use crate::Syntax;
+
+/// A query that can be written inside of another query
+pub(crate) trait SubQuery: Send + Sync {
+    fn write_sql<'s, 'args, 'p>(
+        &'s self,
+        syntax: Syntax,
+        next_params: &NextParam,
+        args: &'args mut Option<ParamArgs<'p>>,
+    ) -> String
+    where
+        's: 'p;
+
+    /// true if the query has its own ORDER BY / LIMIT / OFFSET
+    fn has_tail(&self) -> bool;
+}
+
+impl<R> SubQuery for SelectBuilder<R>
+where
+    R: Send + Sync + HasSchema,
+    <R as HasSchema>::Schema: TableInfo + TableColumns,
+{
+    fn write_sql<'s, 'args, 'p>(
+        &'s self,
+        syntax: Syntax,
+        next_params: &NextParam,
+        args: &'args mut Option<ParamArgs<'p>>,
+    ) -> String
+    where
+        's: 'p,
+    {
+        self.sql_with_params(syntax, next_params, args)
+    }
+
+    fn has_tail(&self) -> bool {
+        self.qb.limit.is_some() || self.qb.offset.is_some() || !self.qb.orderby.is_empty()
+    }
+}
+
+/// A named query in the WITH block of a SELECT (Common Table Expression)
+pub(crate) struct Cte {
+    name: String,
+    query: Box<dyn SubQuery>,
+}
+
+impl Cte {
+    pub(crate) fn new(name: impl Into<String>, query: Box<dyn SubQuery>) -> Self {
+        Self {
+            name: name.into(),
+            query,
+        }
+    }
+}
+
+/// writes the WITH block for a list of CTEs. None when there are no CTEs
+pub(crate) fn build_with<'s, 'args, 'p>(
+    syntax: Syntax,
+    ctes: &'s [Cte],
+    next_params: &NextParam,
+    args: &'args mut Option<ParamArgs<'p>>,
+) -> Option<String>
+where
+    's: 'p,
+{
+    if ctes.is_empty() {
+        return None;
+    }
+    let writer = ColumnWriter::new(syntax);
+    let parts: Vec<String> = ctes
+        .iter()
+        .map(|cte| {
+            let sql = cte.query.write_sql(syntax, next_params, args);
+            format!("{} AS ( {} )", writer.excape(&cte.name), sql)
+        })
+        .collect();
+    Some(format!("WITH {}", parts.join(", ")))
+}
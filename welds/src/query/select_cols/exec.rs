@@ -1,10 +1,16 @@
-use crate::errors::Result;
+use crate::errors::{Result, WeldsError};
 use crate::model_traits::{HasSchema, TableColumns, TableInfo};
+use crate::query::clause::search::SearchRank;
 use crate::query::clause::ParamArgs;
-use crate::query::helpers::{build_tail, build_where_clauses, join_sql_parts};
-use crate::query::select_cols::SelectBuilder;
+use crate::query::helpers::{
+    self, build_tail, build_top, build_where_clauses, join_sql_parts, primary_keys,
+};
+use crate::query::select::explain::explain;
+use crate::query::select_cols::cte::build_with;
+use crate::query::select_cols::{PluckValues, SelectBuilder};
+use crate::writers::debug::inline_params;
+use crate::writers::hint;
 use crate::writers::ColumnWriter;
-use crate::writers::LimitSkipWriter;
 use crate::writers::NextParam;
 use crate::Client;
 use crate::Row;
@@ -28,13 +34,33 @@ where
         <T as HasSchema>::Schema: TableInfo + TableColumns,
     {
         let next_params = NextParam::new(syntax);
+        self.sql_with_params(syntax, &next_params, args)
+    }
+
+    /// writes the SQL for this query continuing on from the params of an outer query.
+    /// Used when this query is a sub-query of another
+    pub(crate) fn sql_with_params<'s, 'args, 'p>(
+        &'s self,
+        syntax: Syntax,
+        next_params: &NextParam,
+        args: &'args mut Option<ParamArgs<'p>>,
+    ) -> String
+    where
+        's: 'p,
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        // the WITH block is written first so its params come first
+        let with_sql = build_with(syntax, &self.ctes, next_params, args);
+        // followed by the SELECT list
+        let head_sql = build_head_select(syntax, self, next_params, args);
+
         let wheres = self.qb.wheres.as_slice();
         let exists_in = self.qb.exist_ins.as_slice();
         let alias = &self.qb.alias;
 
-        let mut wheres = build_where_clauses(syntax, &next_params, alias, wheres, args, exists_in);
+        let mut wheres = build_where_clauses(syntax, next_params, alias, wheres, args, exists_in);
         for j in &self.joins {
-            j.append_where(syntax, &mut wheres, &next_params, args);
+            j.append_where(syntax, &mut wheres, next_params, args);
         }
         let where_sql = if wheres.is_empty() {
             None
@@ -42,11 +68,37 @@ where
             Some(format!("WHERE ( {} )", wheres.join(" AND ")))
         };
 
+        let mut havings = Vec::default();
+        for clause in &self.havings {
+            if let Some(args) = args {
+                clause.bind(args);
+            }
+            if let Some(p) = clause.clause(syntax, alias, next_params) {
+                havings.push(p);
+            }
+        }
+        let having_sql = if havings.is_empty() {
+            None
+        } else {
+            Some(format!("HAVING ( {} )", havings.join(" AND ")))
+        };
+
+        // grouped rows can only be ordered by what they are grouped by
+        let keys = match self.group_bys.is_empty() {
+            true => primary_keys(&self.qb),
+            false => Vec::default(),
+        };
+
         join_sql_parts(&[
-            build_head_select(syntax, self),
+            with_sql,
+            head_sql,
             build_joins(syntax, self),
             where_sql,
-            build_tail(syntax, &self.qb),
+            build_group_by(syntax, self),
+            having_sql,
+            build_tail(syntax, &self.qb, &keys, next_params, args),
+            self.qb.lock.and_then(|l| l.suffix(syntax)),
+            hint::options(syntax, &self.qb.hints),
         ])
         .trim()
         .to_owned()
@@ -60,8 +112,28 @@ where
         self.sql_internal(syntax, &mut None)
     }
 
+    /// Get the SQL that will be executed and the params that will be sent with it, in order
+    pub fn to_sql_with_params(&self, syntax: Syntax) -> (String, ParamArgs<'_>)
+    where
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        let mut args: Option<ParamArgs> = Some(Vec::default());
+        let sql = self.sql_internal(syntax, &mut args);
+        (sql, args.unwrap())
+    }
+
+    /// FOR DEBUGGING ONLY: the SQL of this query with the params written into it.
+    /// See `QueryBuilder::debug_sql`
+    pub fn debug_sql(&self, syntax: Syntax) -> String
+    where
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        let (sql, args) = self.to_sql_with_params(syntax);
+        inline_params(syntax, &sql, &args)
+    }
+
     /// Executes the query in the database returning the results
-    pub async fn run<'s, 'c>(&'s self, client: &'c dyn Client) -> Result<Vec<Row>>
+    pub async fn run(&self, client: &dyn Client) -> Result<Vec<Row>>
     where
         <T as HasSchema>::Schema: TableInfo + TableColumns,
     {
@@ -69,34 +141,130 @@ where
         let mut args: Option<ParamArgs> = Some(Vec::default());
         let sql = self.sql_internal(syntax, &mut args);
         let args = args.unwrap();
-        let rows = client.fetch_rows(&sql, &args).await?;
+        let rows = helpers::fetch_rows(client, &sql, &args, self.qb.timeout).await?;
         Ok(rows)
     }
+
+    /// Executes the query in the database reading each row into a `R`.
+    /// Useful for reading the results of a `group_by` into a struct, `run_as_tuple` reads them into tuples
+    pub async fn run_as<R>(&self, client: &dyn Client) -> Result<Vec<R>>
+    where
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+        R: TryFrom<Row>,
+        WeldsError: From<<R as TryFrom<Row>>::Error>,
+    {
+        let rows = self.run(client).await?;
+        let mut objs = Vec::default();
+        for row in rows {
+            objs.push(R::try_from(row)?);
+        }
+        Ok(objs)
+    }
+
+    /// Executes the query in the database reading each row into a tuple.
+    /// The values are read in the order they are selected
+    /// ```rust,ignore
+    /// let totals: Vec<(String, i64)> = Order::all()
+    ///     .group_by(|o| o.status)
+    ///     .select(|o| o.status)
+    ///     .select_count_all("total")
+    ///     .run_as_tuple(client)
+    ///     .await?;
+    /// ```
+    pub async fn run_as_tuple<V>(&self, client: &dyn Client) -> Result<Vec<V>>
+    where
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+        V: PluckValues,
+    {
+        let rows = self.run(client).await?;
+        let mut values = Vec::default();
+        for row in rows {
+            values.push(V::read(&row)?);
+        }
+        Ok(values)
+    }
+
+    /// Returns the plan the database will use to run this query.
+    /// See `QueryBuilder::explain`
+    pub async fn explain(&self, client: &dyn Client) -> Result<String>
+    where
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        let mut args: Option<ParamArgs> = Some(Vec::default());
+        let sql = self.sql_internal(client.syntax(), &mut args);
+        explain(client, &sql, &args.unwrap(), false).await
+    }
+
+    /// Runs the query and returns the plan the database used.
+    /// See `QueryBuilder::explain_analyze`
+    pub async fn explain_analyze(&self, client: &dyn Client) -> Result<String>
+    where
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        let mut args: Option<ParamArgs> = Some(Vec::default());
+        let sql = self.sql_internal(client.syntax(), &mut args);
+        explain(client, &sql, &args.unwrap(), true).await
+    }
 }
 
-fn build_head_select<T>(syntax: Syntax, sb: &SelectBuilder<T>) -> Option<String>
+fn build_head_select<'s, 'args, 'p, T>(
+    syntax: Syntax,
+    sb: &'s SelectBuilder<T>,
+    next_params: &NextParam,
+    args: &'args mut Option<ParamArgs<'p>>,
+) -> Option<String>
 where
+    's: 'p,
     T: HasSchema,
     <T as HasSchema>::Schema: TableInfo + TableColumns,
 {
     let writer = ColumnWriter::new(syntax);
     let mut head: Vec<&str> = Vec::default();
-    head.push("SELECT");
+    let select = hint::select(&sb.qb.hints);
+    head.push(&select);
 
     let mut cols: Vec<_> = Vec::default();
     let alias = &sb.qb.alias;
 
+    let distinct = sb.qb.distinct.as_ref().map(|d| d.write(syntax, alias));
+    if let Some(distinct) = &distinct {
+        head.push(distinct);
+    }
+    let top = build_top(syntax, &sb.qb);
+    if let Some(top) = &top {
+        head.push(top);
+    }
+
     // Add these columns
     for col in &sb.selects {
-        let colname = writer.excape(&col.col_name);
-        let fieldname = writer.excape(&col.field_name);
-        if colname == fieldname {
-            let col = format!("{}.{}", alias, colname);
-            cols.push(col);
-        } else {
-            let col = format!("{}.{} as {}", alias, colname, fieldname);
-            cols.push(col);
+        cols.push(col.write(syntax, &writer, alias));
+    }
+
+    // Add the ranks of full-text searches
+    for rank in &sb.ranks {
+        if let Some(args) = args {
+            if SearchRank::has_param(syntax) {
+                args.push(&rank.query);
+            }
+        }
+        cols.push(rank.write(syntax, &writer, alias, next_params));
+    }
+
+    // Add the expressions
+    for (expr, field_name) in &sb.exprs {
+        if let Some(args) = args {
+            expr.bind(args);
+        }
+        let expr = expr.write_qualified(syntax, alias, next_params);
+        cols.push(format!("{} as {}", expr, writer.excape(field_name)));
+    }
+
+    // Add the aggregates of filtered rows
+    for filtered in &sb.filtered {
+        if let Some(args) = args {
+            filtered.bind(args);
         }
+        cols.push(filtered.write(syntax, alias, next_params));
     }
 
     // Add columns from joins
@@ -111,9 +279,30 @@ where
     let tn = <T as HasSchema>::Schema::identifier().join(".");
     let identifier = format!("{} {}", tn, alias);
     head.push(&identifier);
+    let hint = hint::table(syntax, &sb.qb.hints, &sb.qb.lock);
+    if let Some(hint) = &hint {
+        head.push(hint);
+    }
     Some(head.join(" "))
 }
 
+fn build_group_by<T>(syntax: Syntax, sb: &SelectBuilder<T>) -> Option<String>
+where
+    T: HasSchema,
+{
+    if sb.group_bys.is_empty() {
+        return None;
+    }
+    let writer = ColumnWriter::new(syntax);
+    let alias = &sb.qb.alias;
+    let cols: Vec<String> = sb
+        .group_bys
+        .iter()
+        .map(|col| format!("{}.{}", alias, writer.excape(col)))
+        .collect();
+    Some(format!("GROUP BY {}", cols.join(", ")))
+}
+
 fn build_joins<T>(syntax: Syntax, sb: &SelectBuilder<T>) -> Option<String>
 where
     T: HasSchema,
@@ -0,0 +1,199 @@
+use super::select_column::Aggregate;
+use super::string_agg::StringAgg;
+use crate::query::clause::{AsFieldName, ClauseAdder, ParamArgs};
+use crate::writers::{ColumnWriter, NextParam};
+use crate::Syntax;
+use std::marker::PhantomData;
+use welds_connections::Param;
+
+/// Picks the aggregate of a group to filter on in a `having` clause
+pub struct AggregateSelector<S> {
+    _s: PhantomData<S>,
+}
+
+impl<S: Default> AggregateSelector<S> {
+    pub(crate) fn new() -> Self {
+        Self {
+            _s: Default::default(),
+        }
+    }
+
+    /// The number of rows in the group. `COUNT(*)`
+    pub fn count(&self) -> AggregateValue {
+        AggregateValue::new(Aggregate::Count, "*")
+    }
+
+    /// The number of non-null values of a column in the group
+    pub fn count_of<V, FN: AsFieldName<V>>(&self, lam: impl Fn(S) -> FN) -> AggregateValue {
+        AggregateValue::new(Aggregate::Count, lam(Default::default()).colname())
+    }
+
+    /// The total of a column in the group
+    pub fn sum<V, FN: AsFieldName<V>>(&self, lam: impl Fn(S) -> FN) -> AggregateValue {
+        AggregateValue::new(Aggregate::Sum, lam(Default::default()).colname())
+    }
+
+    /// The average of a column in the group
+    pub fn avg<V, FN: AsFieldName<V>>(&self, lam: impl Fn(S) -> FN) -> AggregateValue {
+        AggregateValue::new(Aggregate::Avg, lam(Default::default()).colname())
+    }
+
+    /// The smallest value of a column in the group
+    pub fn min<V, FN: AsFieldName<V>>(&self, lam: impl Fn(S) -> FN) -> AggregateValue {
+        AggregateValue::new(Aggregate::Min, lam(Default::default()).colname())
+    }
+
+    /// The largest value of a column in the group
+    pub fn max<V, FN: AsFieldName<V>>(&self, lam: impl Fn(S) -> FN) -> AggregateValue {
+        AggregateValue::new(Aggregate::Max, lam(Default::default()).colname())
+    }
+
+    /// The text of a column in the group joined with the separator. Used with `select_string_agg`
+    pub fn string_agg<V, FN: AsFieldName<V>>(
+        &self,
+        lam: impl Fn(S) -> FN,
+        separator: &str,
+    ) -> StringAgg<S> {
+        StringAgg::new(lam(Default::default()).colname(), separator)
+    }
+}
+
+/// An aggregate of a group that can be compared to a value
+pub struct AggregateValue {
+    aggregate: Aggregate,
+    col: String,
+}
+
+impl AggregateValue {
+    fn new(aggregate: Aggregate, col: &str) -> Self {
+        Self {
+            aggregate,
+            col: col.to_string(),
+        }
+    }
+
+    fn compare<V>(self, operator: &'static str, val: V) -> Box<dyn ClauseAdder>
+    where
+        V: 'static + Clone + Send + Sync + Param,
+    {
+        Box::new(HavingClause {
+            aggregate: self.aggregate,
+            col: self.col,
+            operator,
+            val,
+        })
+    }
+
+    /// Will write SQL checking the aggregate is equal to this (==)
+    pub fn equal<V>(self, v: V) -> Box<dyn ClauseAdder>
+    where
+        V: 'static + Clone + Send + Sync + Param,
+    {
+        self.compare("=", v)
+    }
+
+    /// Will write SQL checking the aggregate is not equal to this (!=)
+    pub fn not_equal<V>(self, v: V) -> Box<dyn ClauseAdder>
+    where
+        V: 'static + Clone + Send + Sync + Param,
+    {
+        self.compare("!=", v)
+    }
+
+    /// Will write SQL checking the aggregate is greater than this (>)
+    pub fn gt<V>(self, v: V) -> Box<dyn ClauseAdder>
+    where
+        V: 'static + Clone + Send + Sync + Param,
+    {
+        self.compare(">", v)
+    }
+
+    /// Will write SQL checking the aggregate is greater than or equal to this (>=)
+    pub fn gte<V>(self, v: V) -> Box<dyn ClauseAdder>
+    where
+        V: 'static + Clone + Send + Sync + Param,
+    {
+        self.compare(">=", v)
+    }
+
+    /// Will write SQL checking the aggregate is less than this (<)
+    pub fn lt<V>(self, v: V) -> Box<dyn ClauseAdder>
+    where
+        V: 'static + Clone + Send + Sync + Param,
+    {
+        self.compare("<", v)
+    }
+
+    /// Will write SQL checking the aggregate is less than or equal to this (<=)
+    pub fn lte<V>(self, v: V) -> Box<dyn ClauseAdder>
+    where
+        V: 'static + Clone + Send + Sync + Param,
+    {
+        self.compare("<=", v)
+    }
+}
+
+struct HavingClause<V> {
+    aggregate: Aggregate,
+    col: String,
+    operator: &'static str,
+    val: V,
+}
+
+impl<V> ClauseAdder for HavingClause<V>
+where
+    V: Clone + Send + Sync + Param,
+{
+    fn bind<'lam, 'args, 'p>(&'lam self, args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+        args.push(&self.val);
+    }
+
+    fn clause(&self, syntax: Syntax, alias: &str, next_params: &NextParam) -> Option<String> {
+        let writer = ColumnWriter::new(syntax);
+        let call = self.aggregate.write(&writer, alias, &self.col);
+        Some(format!("{} {} {}", call, self.operator, next_params.next()))
+    }
+}
+
+/// An aggregate of only the rows of the group matching a filter.
+/// I.E. `COUNT(*) FILTER (WHERE t1.active = $1)`
+pub(crate) struct FilteredAggregate {
+    aggregate: Aggregate,
+    col: String,
+    filter: Box<dyn ClauseAdder>,
+    pub(crate) field_name: String,
+}
+
+impl FilteredAggregate {
+    pub(crate) fn new(value: AggregateValue, filter: Box<dyn ClauseAdder>, alias: &str) -> Self {
+        Self {
+            aggregate: value.aggregate,
+            col: value.col,
+            filter,
+            field_name: alias.to_string(),
+        }
+    }
+
+    pub(crate) fn bind<'lam, 'args, 'p>(&'lam self, args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+        self.filter.bind(args);
+    }
+
+    /// writes the aggregate as it goes in the SELECT list of a query
+    pub(crate) fn write(&self, syntax: Syntax, alias: &str, next_params: &NextParam) -> String {
+        let writer = ColumnWriter::new(syntax);
+        let condition = self
+            .filter
+            .clause(syntax, alias, next_params)
+            .unwrap_or_else(|| "1=1".to_owned());
+        let call = self
+            .aggregate
+            .write_filtered(syntax, &writer, alias, &self.col, &condition);
+        format!("{} as {}", call, writer.excape(&self.field_name))
+    }
+}
@@ -15,7 +15,7 @@ pub(crate) struct JoinBuilder {
     pub(crate) inner_alias: String,
     pub(crate) inner_table: String,
     pub(crate) inner_key: String,
-    pub(crate) wheres: Vec<Box<dyn ClauseAdder>>,
+    pub(crate) wheres: Vec<Arc<dyn ClauseAdder>>,
     pub(crate) selects: Vec<SelectColumn>,
     pub(crate) ty: Join,
     pub(crate) subs: Vec<JoinBuilder>,
@@ -51,15 +51,7 @@ impl JoinBuilder {
         let alias = &self.inner_alias;
         // Add these columns
         for col in &self.selects {
-            let colname = writer.excape(&col.col_name);
-            let fieldname = writer.excape(&col.field_name);
-            if colname == fieldname {
-                let col = format!("{}.{}", alias, colname);
-                list.push(col);
-            } else {
-                let col = format!("{}.{} as {}", alias, colname, fieldname);
-                list.push(col);
-            }
+            list.push(col.write(syntax, &writer, alias));
         }
         for sub in &self.subs {
             sub.append_columns(syntax, list);
@@ -1,16 +1,34 @@
-use crate::model_traits::{HasSchema, TableColumns, TableInfo, UniqueIdentifier};
-use crate::query::builder::QueryBuilder;
-use crate::query::clause::{AsFieldName, ClauseAdder};
+use crate::model_traits::{HasSchema, SoftDelete, TableColumns, TableInfo, UniqueIdentifier};
+use crate::query::builder::{QueryBuilder, Scope};
+use crate::query::clause::search::SearchRank;
+use crate::query::clause::{
+    AsFieldName, Case, ClauseAdder, Expr, OrderExpr, RawParam, RelatedCount, WhereGroup,
+};
 use crate::relations::{HasRelations, Relationship};
 use crate::writers::alias::TableAlias;
+use crate::writers::{Hint, LockMode};
+use cte::Cte;
+use having::FilteredAggregate;
+pub use having::{AggregateSelector, AggregateValue};
 pub use join::Join;
 use join::JoinBuilder;
-use select_column::SelectColumn;
+pub use pluck::PluckValues;
+use select_column::{Aggregate, SelectColumn};
 use std::sync::Arc;
+use std::time::Duration;
+pub use string_agg::StringAgg;
+pub use window::{Window, WindowSelector};
 
+pub(crate) mod cte;
 mod exec;
+mod having;
 mod join;
+mod pluck;
 mod select_column;
+mod string_agg;
+#[cfg(test)]
+mod tests;
+mod window;
 
 /// An un-executed Query Selecting specific columns.
 ///
@@ -20,9 +38,15 @@ mod select_column;
 ///
 /// Can be mapped into other queries to make more complex queries.
 pub struct SelectBuilder<T> {
-    qb: QueryBuilder<T>,
+    pub(crate) qb: QueryBuilder<T>,
     selects: Vec<SelectColumn>,
     joins: Vec<JoinBuilder>,
+    group_bys: Vec<String>,
+    havings: Vec<Box<dyn ClauseAdder>>,
+    ctes: Vec<Cte>,
+    ranks: Vec<SearchRank>,
+    exprs: Vec<(Expr, String)>,
+    filtered: Vec<FilteredAggregate>,
 }
 
 impl<T> SelectBuilder<T>
@@ -34,6 +58,12 @@ where
             qb,
             selects: Vec::default(),
             joins: Vec::default(),
+            group_bys: Vec::default(),
+            havings: Vec::default(),
+            ctes: Vec::default(),
+            ranks: Vec::default(),
+            exprs: Vec::default(),
+            filtered: Vec::default(),
         }
     }
 
@@ -46,6 +76,358 @@ where
         self.selects.push(SelectColumn {
             col_name: field.colname().to_string(),
             field_name: field.fieldname().to_string(),
+            aggregate: None,
+            window: None,
+            string_agg: None,
+        });
+        self
+    }
+
+    /// Add all the columns of the model to the list of columns that will be selected
+    pub fn select_all(mut self) -> SelectBuilder<T>
+    where
+        <T as HasSchema>::Schema: TableColumns,
+    {
+        for col in <T as HasSchema>::Schema::columns() {
+            self.selects.push(SelectColumn {
+                col_name: col.name().to_string(),
+                field_name: col.name().to_string(),
+                aggregate: None,
+                window: None,
+                string_agg: None,
+            });
+        }
+        self
+    }
+
+    /// Group the rows of this query by a column.
+    /// Use with the `select_count`/`select_sum`/... functions to select values for each group
+    ///
+    /// multiple calls will group by multiple columns
+    pub fn group_by<V, FN: AsFieldName<V>>(
+        mut self,
+        lam: impl Fn(<T as HasSchema>::Schema) -> FN,
+    ) -> SelectBuilder<T> {
+        let field = lam(Default::default());
+        self.group_bys.push(field.colname().to_string());
+        self
+    }
+
+    /// Lock the rows read by this query until the end of the transaction.
+    /// See `QueryBuilder::lock`
+    pub fn lock(mut self, mode: LockMode) -> SelectBuilder<T> {
+        self.qb = self.qb.lock(mode);
+        self
+    }
+
+    /// Error instead of waiting when a row is already locked (NOWAIT)
+    pub fn nowait(mut self) -> SelectBuilder<T> {
+        self.qb = self.qb.nowait();
+        self
+    }
+
+    /// Leave rows locked by other transactions out of the results (SKIP LOCKED / READPAST)
+    pub fn skip_locked(mut self) -> SelectBuilder<T> {
+        self.qb = self.qb.skip_locked();
+        self
+    }
+
+    /// Give the query planner a hint. See `QueryBuilder::hint`
+    pub fn hint(mut self, hint: Hint) -> SelectBuilder<T> {
+        self.qb = self.qb.hint(hint);
+        self
+    }
+
+    /// Limit how long the database can spend running this query. See `QueryBuilder::timeout`
+    pub fn timeout(mut self, timeout: Duration) -> SelectBuilder<T> {
+        self.qb = self.qb.timeout(timeout);
+        self
+    }
+
+    /// Only return unique rows (SELECT DISTINCT)
+    pub fn distinct(mut self) -> SelectBuilder<T> {
+        self.qb = self.qb.distinct();
+        self
+    }
+
+    /// Only return the first row for each unique value of a column (SELECT DISTINCT ON)
+    ///
+    /// NOTE: only supported by Postgres. The ORDER BY of the query must start with the same columns
+    pub fn distinct_on<V, FN: AsFieldName<V>>(
+        mut self,
+        lam: impl Fn(<T as HasSchema>::Schema) -> FN,
+    ) -> SelectBuilder<T> {
+        self.qb = self.qb.distinct_on(lam);
+        self
+    }
+
+    /// Add a named query to the WITH block of this query (Common Table Expression)
+    ///
+    /// The rest of the query can reference it by name
+    /// ```rust,ignore
+    /// let recent = Order::where_col(|o| o.placed.gt(yesterday)).select(|o| o.customer_id);
+    /// Customer::where_col(|c| c.id.in_cte("recent_orders", "customer_id"))
+    ///     .select(|c| c.name)
+    ///     .with("recent_orders", recent)
+    /// // WITH "recent_orders" AS ( SELECT ... ) SELECT ... WHERE ( t1.id IN ( SELECT "customer_id" FROM "recent_orders" ) )
+    /// ```
+    pub fn with<R>(mut self, name: &str, query: SelectBuilder<R>) -> SelectBuilder<T>
+    where
+        R: 'static + Send + Sync + HasSchema,
+        <R as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        self.ctes.push(Cte::new(name, Box::new(query)));
+        self
+    }
+
+    /// Filter the groups returned by this query on their aggregates.
+    ///
+    /// multiple calls will AND the filters together
+    /// ```rust,ignore
+    /// let busy = Order::all()
+    ///     .group_by(|o| o.status)
+    ///     .select(|o| o.status)
+    ///     .having(|agg| agg.count().gt(5));
+    /// ```
+    pub fn having(
+        mut self,
+        lam: impl Fn(AggregateSelector<<T as HasSchema>::Schema>) -> Box<dyn ClauseAdder>,
+    ) -> SelectBuilder<T>
+    where
+        <T as HasSchema>::Schema: Default,
+    {
+        self.havings.push(lam(AggregateSelector::new()));
+        self
+    }
+
+    /// Select the number of rows (in each group) as `alias`
+    pub fn select_count_all(mut self, alias: &str) -> SelectBuilder<T> {
+        self.selects.push(SelectColumn {
+            col_name: "*".to_string(),
+            field_name: alias.to_string(),
+            aggregate: Some(Aggregate::Count),
+            window: None,
+            string_agg: None,
+        });
+        self
+    }
+
+    /// Select the number of non-null values of a column (in each group) as `alias`
+    pub fn select_count<V, FN: AsFieldName<V>>(
+        self,
+        lam: impl Fn(<T as HasSchema>::Schema) -> FN,
+        alias: &str,
+    ) -> SelectBuilder<T> {
+        self.select_aggregate(Aggregate::Count, lam, alias)
+    }
+
+    /// Select the total of a column (in each group) as `alias`
+    pub fn select_sum<V, FN: AsFieldName<V>>(
+        self,
+        lam: impl Fn(<T as HasSchema>::Schema) -> FN,
+        alias: &str,
+    ) -> SelectBuilder<T> {
+        self.select_aggregate(Aggregate::Sum, lam, alias)
+    }
+
+    /// Select the average of a column (in each group) as `alias`
+    pub fn select_avg<V, FN: AsFieldName<V>>(
+        self,
+        lam: impl Fn(<T as HasSchema>::Schema) -> FN,
+        alias: &str,
+    ) -> SelectBuilder<T> {
+        self.select_aggregate(Aggregate::Avg, lam, alias)
+    }
+
+    /// Select the smallest value of a column (in each group) as `alias`
+    pub fn select_min<V, FN: AsFieldName<V>>(
+        self,
+        lam: impl Fn(<T as HasSchema>::Schema) -> FN,
+        alias: &str,
+    ) -> SelectBuilder<T> {
+        self.select_aggregate(Aggregate::Min, lam, alias)
+    }
+
+    /// Select the largest value of a column (in each group) as `alias`
+    pub fn select_max<V, FN: AsFieldName<V>>(
+        self,
+        lam: impl Fn(<T as HasSchema>::Schema) -> FN,
+        alias: &str,
+    ) -> SelectBuilder<T> {
+        self.select_aggregate(Aggregate::Max, lam, alias)
+    }
+
+    /// Select an aggregate of only the rows (in each group) matching a filter as `alias`.
+    /// Written with `FILTER (WHERE ...)` on Postgres and a `CASE` on the others
+    /// ```rust,ignore
+    /// let rows = User::all()
+    ///     .group_by(|u| u.team_id)
+    ///     .select(|u| u.team_id)
+    ///     .select_filtered("active", |agg| agg.count(), |u| u.active.equal(true))
+    ///     .select_filtered("inactive", |agg| agg.count(), |u| u.active.equal(false))
+    ///     .run(client)
+    ///     .await?;
+    /// ```
+    pub fn select_filtered(
+        mut self,
+        alias: &str,
+        aggregate: impl Fn(AggregateSelector<<T as HasSchema>::Schema>) -> AggregateValue,
+        filter: impl Fn(<T as HasSchema>::Schema) -> Box<dyn ClauseAdder>,
+    ) -> SelectBuilder<T>
+    where
+        <T as HasSchema>::Schema: Default,
+    {
+        let value = aggregate(AggregateSelector::new());
+        let filter = filter(Default::default());
+        self.filtered
+            .push(FilteredAggregate::new(value, filter, alias));
+        self
+    }
+
+    /// Select the result of a window function as `alias`
+    /// ```rust,ignore
+    /// let ranked = Order::all()
+    ///     .select(|o| o.id)
+    ///     .select_window(
+    ///         |w| w.row_number().partition_by(|o| o.status).order_by_desc(|o| o.price),
+    ///         "rank_in_status",
+    ///     );
+    /// ```
+    pub fn select_window(
+        mut self,
+        lam: impl Fn(WindowSelector<<T as HasSchema>::Schema>) -> Window<<T as HasSchema>::Schema>,
+        alias: &str,
+    ) -> SelectBuilder<T>
+    where
+        <T as HasSchema>::Schema: Default,
+    {
+        let window = lam(WindowSelector::new());
+        self.selects.push(SelectColumn {
+            col_name: String::default(),
+            field_name: alias.to_string(),
+            aggregate: None,
+            window: Some(window.into_expr()),
+            string_agg: None,
+        });
+        self
+    }
+
+    /// Select the text of a column (in each group) joined into one String as `alias`
+    /// ```rust,ignore
+    /// let rows = Order::all()
+    ///     .group_by(|o| o.customer_id)
+    ///     .select(|o| o.customer_id)
+    ///     .select_string_agg(|agg| agg.string_agg(|o| o.status, ", ").order_by_asc(|o| o.id), "statuses")
+    ///     .run(client)
+    ///     .await?;
+    /// ```
+    pub fn select_string_agg(
+        mut self,
+        lam: impl Fn(AggregateSelector<<T as HasSchema>::Schema>) -> StringAgg<<T as HasSchema>::Schema>,
+        alias: &str,
+    ) -> SelectBuilder<T>
+    where
+        <T as HasSchema>::Schema: Default,
+    {
+        let string_agg = lam(AggregateSelector::new());
+        self.selects.push(SelectColumn {
+            col_name: String::default(),
+            field_name: alias.to_string(),
+            aggregate: None,
+            window: None,
+            string_agg: Some(string_agg.into_expr()),
+        });
+        self
+    }
+
+    /// Select how relevant each row is to a full-text search of a column as `alias`.
+    /// Higher values are more relevant. Use with `search` in a where clause.
+    /// ```rust,ignore
+    /// let posts = Post::where_col(|p| p.body.search("rust orm"))
+    ///     .select(|p| p.id)
+    ///     .select_search_rank(|p| p.body, "rust orm", "relevance")
+    ///     .order_by_search_rank("relevance");
+    /// ```
+    ///
+    /// NOTE: Sqlite ranks the MATCH from the where clause.
+    /// MSSQL uses CONTAINSTABLE and needs the table's full-text key to be its id
+    pub fn select_search_rank<V, FN: AsFieldName<V>>(
+        mut self,
+        lam: impl Fn(<T as HasSchema>::Schema) -> FN,
+        query: impl Into<String>,
+        alias: &str,
+    ) -> SelectBuilder<T>
+    where
+        <T as HasSchema>::Schema: TableInfo + UniqueIdentifier,
+    {
+        let field = lam(Default::default());
+        let identifier = <T as HasSchema>::Schema::identifier();
+        self.ranks.push(SearchRank {
+            col: field.colname().to_string(),
+            field_name: alias.to_string(),
+            query: query.into(),
+            table: identifier.last().unwrap_or(&"").to_string(),
+            identifier: identifier.join("."),
+            key: <T as HasSchema>::Schema::id_column().name().to_string(),
+        });
+        self
+    }
+
+    /// Select a `CASE` expression as `alias`. See `Case`
+    /// ```rust,ignore
+    /// let rows = Order::all()
+    ///     .select(|o| o.id)
+    ///     .select_case("label", |o| Case::new().when(o.total.gt(100), "big").otherwise("small"))
+    ///     .run(client)
+    ///     .await?;
+    /// ```
+    pub fn select_case(
+        mut self,
+        alias: &str,
+        lam: impl Fn(<T as HasSchema>::Schema) -> Case,
+    ) -> SelectBuilder<T>
+    where
+        <T as HasSchema>::Schema: Default,
+    {
+        self.exprs
+            .push((lam(Default::default()).into(), alias.to_string()));
+        self
+    }
+
+    /// Select an `Expr` as `alias`
+    /// ```rust,ignore
+    /// let rows = Person::all()
+    ///     .select(|p| p.id)
+    ///     .select_expr("display_name", |p| Expr::col(p.nickname).coalesce(p.name))
+    ///     .run(client)
+    ///     .await?;
+    /// ```
+    pub fn select_expr(
+        mut self,
+        alias: &str,
+        lam: impl Fn(<T as HasSchema>::Schema) -> Expr,
+    ) -> SelectBuilder<T>
+    where
+        <T as HasSchema>::Schema: Default,
+    {
+        self.exprs
+            .push((lam(Default::default()), alias.to_string()));
+        self
+    }
+
+    fn select_aggregate<V, FN: AsFieldName<V>>(
+        mut self,
+        aggregate: Aggregate,
+        lam: impl Fn(<T as HasSchema>::Schema) -> FN,
+        alias: &str,
+    ) -> SelectBuilder<T> {
+        let field = lam(Default::default());
+        self.selects.push(SelectColumn {
+            col_name: field.colname().to_string(),
+            field_name: alias.to_string(),
+            aggregate: Some(aggregate),
+            window: None,
+            string_agg: None,
         });
         self
     }
@@ -63,6 +445,73 @@ where
         self
     }
 
+    /// Filter the results with a group of clauses where any of them can be true (OR)
+    pub fn where_any(mut self, lam: impl FnOnce(WhereGroup<T>) -> WhereGroup<T>) -> Self
+    where
+        T: 'static,
+    {
+        self.qb = self.qb.where_any(lam);
+        self
+    }
+
+    /// Filter the results with a group of clauses where every one of them must be true (AND)
+    pub fn where_all(mut self, lam: impl FnOnce(WhereGroup<T>) -> WhereGroup<T>) -> Self
+    where
+        T: 'static,
+    {
+        self.qb = self.qb.where_all(lam);
+        self
+    }
+
+    /// Apply a reusable set of filters to this query. See `Scope`
+    pub fn scope(mut self, scope: impl Scope<T>) -> Self {
+        self.qb = self.qb.scope(scope);
+        self
+    }
+
+    /// write a raw SQL clause in the where block, binding the params safely
+    /// NOTE: use '{}' for params and '$' for the table alias
+    pub fn where_raw(mut self, sql: impl Into<String>, params: &[&dyn RawParam]) -> Self {
+        self.qb = self.qb.where_raw(sql, params);
+        self
+    }
+
+    /// Filter to the rows where the sub-query finds at least one row (EXISTS)
+    ///
+    /// `on` correlates the sub-query to this query, (column of this table, column of the sub-query)
+    pub fn where_exists<R, V1, V2, OUTER, INNER>(
+        mut self,
+        subquery: QueryBuilder<R>,
+        on: impl Fn(<T as HasSchema>::Schema, <R as HasSchema>::Schema) -> (OUTER, INNER),
+    ) -> Self
+    where
+        R: Send + HasSchema,
+        <R as HasSchema>::Schema: TableInfo,
+        OUTER: AsFieldName<V1>,
+        INNER: AsFieldName<V2>,
+    {
+        self.qb = self.qb.where_exists(subquery, on);
+        self
+    }
+
+    /// Filter to the rows where the sub-query doesn't find any rows (NOT EXISTS)
+    ///
+    /// `on` correlates the sub-query to this query, (column of this table, column of the sub-query)
+    pub fn where_not_exists<R, V1, V2, OUTER, INNER>(
+        mut self,
+        subquery: QueryBuilder<R>,
+        on: impl Fn(<T as HasSchema>::Schema, <R as HasSchema>::Schema) -> (OUTER, INNER),
+    ) -> Self
+    where
+        R: Send + HasSchema,
+        <R as HasSchema>::Schema: TableInfo,
+        OUTER: AsFieldName<V1>,
+        INNER: AsFieldName<V2>,
+    {
+        self.qb = self.qb.where_not_exists(subquery, on);
+        self
+    }
+
     /// Add a query to this query (JOIN on a relationship)
     /// results on a query that is filtered using the results of both queries
     pub fn where_relation<R, Ship>(
@@ -84,6 +533,78 @@ where
         self
     }
 
+    /// Filter to the rows with no rows in the relationship matching the query (an anti-join)
+    pub fn where_not_in_relation<R, Ship>(
+        mut self,
+        relationship: impl Fn(<T as HasRelations>::Relation) -> Ship,
+        filter: QueryBuilder<R>,
+    ) -> Self
+    where
+        T: HasRelations,
+        Ship: Relationship<R>,
+        R: Send + Sync + HasSchema,
+        <R as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasRelations>::Relation: Default,
+    {
+        self.qb = self.qb.where_not_in_relation(relationship, filter);
+        self
+    }
+
+    /// Filter to the rows that have at least one related row matching the filter (EXISTS)
+    pub fn where_has<R, Ship>(
+        mut self,
+        relationship: impl Fn(<T as HasRelations>::Relation) -> Ship,
+        filter: impl FnOnce(QueryBuilder<R>) -> QueryBuilder<R>,
+    ) -> Self
+    where
+        T: HasRelations,
+        Ship: Relationship<R>,
+        R: Send + Sync + HasSchema + SoftDelete,
+        <R as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasRelations>::Relation: Default,
+    {
+        self.qb = self.qb.where_has(relationship, filter);
+        self
+    }
+
+    /// Filter to the rows that don't have any related rows matching the filter (NOT EXISTS)
+    pub fn where_doesnt_have<R, Ship>(
+        mut self,
+        relationship: impl Fn(<T as HasRelations>::Relation) -> Ship,
+        filter: impl FnOnce(QueryBuilder<R>) -> QueryBuilder<R>,
+    ) -> Self
+    where
+        T: HasRelations,
+        Ship: Relationship<R>,
+        R: Send + Sync + HasSchema + SoftDelete,
+        <R as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasRelations>::Relation: Default,
+    {
+        self.qb = self.qb.where_doesnt_have(relationship, filter);
+        self
+    }
+
+    /// Filter on the number of rows in a relationship, counted with a correlated sub-query
+    pub fn where_relation_count<R, Ship>(
+        mut self,
+        relationship: impl Fn(<T as HasRelations>::Relation) -> Ship,
+        lam: impl Fn(RelatedCount) -> Box<dyn ClauseAdder>,
+    ) -> Self
+    where
+        T: HasRelations,
+        Ship: Relationship<R>,
+        R: Send + Sync + HasSchema,
+        <R as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasRelations>::Relation: Default,
+    {
+        self.qb = self.qb.where_relation_count(relationship, lam);
+        self
+    }
+
     /// Inner Join to another table to be able to select additional columns
     pub fn join<R, Ship>(
         self,
@@ -178,6 +699,36 @@ where
         self
     }
 
+    /// Order the results of the query by an expression.
+    /// NULLS FIRST/LAST is emulated on databases without it
+    ///
+    /// multiple calls will result in multiple OrderBys
+    pub fn order_by(mut self, lam: impl Fn(<T as HasSchema>::Schema) -> OrderExpr) -> Self {
+        self.qb = self.qb.order_by(lam);
+        self
+    }
+
+    /// Shuffle the results of the query into a random order
+    pub fn order_random(mut self) -> Self {
+        self.qb = self.qb.order_random();
+        self
+    }
+
+    /// Order the results by a rank selected with `select_search_rank`, most relevant first
+    pub fn order_by_search_rank(mut self, alias: &str) -> Self {
+        self.qb = self.qb.order_manual(&format!("{} DESC", alias));
+        self
+    }
+
+    /// The names of the columns in the SELECT list, in order
+    pub(crate) fn selected_names(&self) -> Vec<&str> {
+        let selects = self.selects.iter().map(|s| s.field_name.as_str());
+        let ranks = self.ranks.iter().map(|r| r.field_name.as_str());
+        let exprs = self.exprs.iter().map(|(_, alias)| alias.as_str());
+        let filtered = self.filtered.iter().map(|f| f.field_name.as_str());
+        selects.chain(ranks).chain(exprs).chain(filtered).collect()
+    }
+
     pub(crate) fn set_aliases(&mut self, alias_asigner: &Arc<TableAlias>) {
         self.qb.set_aliases(alias_asigner);
         for join in &mut self.joins {
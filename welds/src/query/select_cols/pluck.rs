@@ -0,0 +1,91 @@
+use super::select_column::SelectColumn;
+use super::SelectBuilder;
+use crate::errors::Result;
+use crate::model_traits::{HasSchema, TableColumns, TableInfo};
+use crate::query::builder::QueryBuilder;
+use crate::query::clause::{AsFieldName, ColumnList};
+use welds_connections::{Client, Decodable, Row};
+
+/// The values of the columns of a `pluck_tuple`. Implemented for tuples of values
+pub trait PluckValues: Sized {
+    fn read(row: &Row) -> Result<Self>;
+}
+
+macro_rules! impl_pluck_values {
+    ($($value:ident $idx:tt),+) => {
+        impl<$($value),+> PluckValues for ($($value,)+)
+        where
+            $($value: Decodable),+
+        {
+            fn read(row: &Row) -> Result<Self> {
+                Ok(($(row.get_by_position::<$value>($idx)?,)+))
+            }
+        }
+    };
+}
+
+impl_pluck_values!(VA 0);
+impl_pluck_values!(VA 0, VB 1);
+impl_pluck_values!(VA 0, VB 1, VC 2);
+impl_pluck_values!(VA 0, VB 1, VC 2, VD 3);
+impl_pluck_values!(VA 0, VB 1, VC 2, VD 3, VE 4);
+impl_pluck_values!(VA 0, VB 1, VC 2, VD 3, VE 4, VF 5);
+
+impl<T> QueryBuilder<T>
+where
+    T: Send + HasSchema,
+{
+    /// Selects only a single column, returning its value for each row
+    /// ```rust,ignore
+    /// let emails: Vec<String> = User::all().pluck(|u| u.email, client).await?;
+    /// ```
+    pub async fn pluck<V, FN: AsFieldName<V>>(
+        self,
+        lam: impl Fn(<T as HasSchema>::Schema) -> FN,
+        client: &dyn Client,
+    ) -> Result<Vec<V>>
+    where
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+        V: Decodable,
+    {
+        let rows = self.select(lam).run(client).await?;
+        let mut values = Vec::default();
+        for row in rows {
+            values.push(row.get_by_position(0)?);
+        }
+        Ok(values)
+    }
+
+    /// Selects only a few columns, returning their values for each row as a tuple
+    /// ```rust,ignore
+    /// let logins: Vec<(String, bool)> = User::all().pluck_tuple(|u| (u.email, u.active), client).await?;
+    /// ```
+    pub async fn pluck_tuple<V, C>(
+        self,
+        lam: impl Fn(<T as HasSchema>::Schema) -> C,
+        client: &dyn Client,
+    ) -> Result<Vec<V>>
+    where
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+        C: ColumnList<V>,
+        V: PluckValues,
+    {
+        let mut sb = SelectBuilder::new(self);
+        // the values are read by position, the names don't matter
+        for col_name in lam(Default::default()).colnames() {
+            sb.selects.push(SelectColumn {
+                field_name: col_name.clone(),
+                col_name,
+                aggregate: None,
+                window: None,
+                string_agg: None,
+            });
+        }
+        let rows = sb.run(client).await?;
+        let mut values = Vec::default();
+        for row in rows {
+            values.push(V::read(&row)?);
+        }
+        Ok(values)
+    }
+}
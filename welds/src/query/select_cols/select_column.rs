@@ -1,4 +1,97 @@
+use super::string_agg::StringAggExpr;
+use super::window::WindowExpr;
+use crate::writers::ColumnWriter;
+use crate::Syntax;
+
 pub(crate) struct SelectColumn {
     pub(crate) col_name: String,
     pub(crate) field_name: String,
+    pub(crate) aggregate: Option<Aggregate>,
+    pub(crate) window: Option<WindowExpr>,
+    pub(crate) string_agg: Option<StringAggExpr>,
+}
+
+/// An aggregate function a column can be selected through
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum Aggregate {
+    Count,
+    Sum,
+    Avg,
+    Min,
+    Max,
+}
+
+impl Aggregate {
+    /// writes the call to the aggregate function on a column. I.E. `SUM(t1."price")`
+    pub(crate) fn write(self, writer: &ColumnWriter, alias: &str, col_name: &str) -> String {
+        // COUNT(*) isn't on a column
+        let col = match col_name {
+            "*" => "*".to_string(),
+            name => format!("{}.{}", alias, writer.excape(name)),
+        };
+        format!("{}({})", self.to_sql(), col)
+    }
+
+    /// writes the call to the aggregate function on only the rows matching the condition.
+    /// Postgres has `FILTER (WHERE ...)`, the others only give the aggregate the value
+    /// of the matching rows, the rest are NULL and skipped
+    pub(crate) fn write_filtered(
+        self,
+        syntax: Syntax,
+        writer: &ColumnWriter,
+        alias: &str,
+        col_name: &str,
+        condition: &str,
+    ) -> String {
+        if syntax == Syntax::Postgres {
+            let call = self.write(writer, alias, col_name);
+            return format!("{} FILTER (WHERE {})", call, condition);
+        }
+        // COUNT of a value keeps the type of a COUNT(*), a SUM of 1s becomes a DECIMAL on MySQL
+        let value = match col_name {
+            "*" => "1".to_string(),
+            name => format!("{}.{}", alias, writer.excape(name)),
+        };
+        format!(
+            "{}(CASE WHEN {} THEN {} END)",
+            self.to_sql(),
+            condition,
+            value
+        )
+    }
+
+    fn to_sql(self) -> &'static str {
+        match self {
+            Aggregate::Count => "COUNT",
+            Aggregate::Sum => "SUM",
+            Aggregate::Avg => "AVG",
+            Aggregate::Min => "MIN",
+            Aggregate::Max => "MAX",
+        }
+    }
+}
+
+impl SelectColumn {
+    /// writes out this column as it goes in the SELECT list of a query
+    pub(crate) fn write(&self, syntax: Syntax, writer: &ColumnWriter, alias: &str) -> String {
+        let fieldname = writer.excape(&self.field_name);
+        if let Some(window) = &self.window {
+            let call = window.write(syntax, writer, alias);
+            return format!("{} as {}", call, fieldname);
+        }
+        if let Some(string_agg) = &self.string_agg {
+            let call = string_agg.write(syntax, writer, alias);
+            return format!("{} as {}", call, fieldname);
+        }
+        if let Some(agg) = self.aggregate {
+            let call = agg.write(writer, alias, &self.col_name);
+            return format!("{} as {}", call, fieldname);
+        }
+        let colname = writer.excape(&self.col_name);
+        if colname == fieldname {
+            format!("{}.{}", alias, colname)
+        } else {
+            format!("{}.{} as {}", alias, colname, fieldname)
+        }
+    }
 }
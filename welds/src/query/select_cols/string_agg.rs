@@ -0,0 +1,105 @@
+use crate::query::clause::AsFieldName;
+use crate::writers::ColumnWriter;
+use crate::Syntax;
+use std::marker::PhantomData;
+
+/// The text of a column from each row of a group joined into one String.
+///
+/// `string_agg` on Postgres, `GROUP_CONCAT` on MySQL and Sqlite, `STRING_AGG` on MSSQL.
+/// NULLs are skipped, a group of only NULLs is NULL.
+/// NOTE: ordering needs Sqlite 3.44 or newer
+pub struct StringAgg<S> {
+    col: String,
+    separator: String,
+    orders: Vec<(String, &'static str)>,
+    _s: PhantomData<S>,
+}
+
+impl<S: Default> StringAgg<S> {
+    pub(crate) fn new(col: &str, separator: &str) -> Self {
+        Self {
+            col: col.to_string(),
+            separator: separator.to_string(),
+            orders: Vec::default(),
+            _s: Default::default(),
+        }
+    }
+
+    /// Join the text of the rows in the order of this column
+    ///
+    /// multiple calls will result in multiple OrderBys
+    pub fn order_by_asc<V, FN: AsFieldName<V>>(mut self, lam: impl Fn(S) -> FN) -> Self {
+        let field = lam(Default::default());
+        self.orders.push((field.colname().to_string(), "ASC"));
+        self
+    }
+
+    /// Join the text of the rows in the order of this column
+    ///
+    /// multiple calls will result in multiple OrderBys
+    pub fn order_by_desc<V, FN: AsFieldName<V>>(mut self, lam: impl Fn(S) -> FN) -> Self {
+        let field = lam(Default::default());
+        self.orders.push((field.colname().to_string(), "DESC"));
+        self
+    }
+
+    pub(crate) fn into_expr(self) -> StringAggExpr {
+        StringAggExpr {
+            col: self.col,
+            separator: self.separator,
+            orders: self.orders,
+        }
+    }
+}
+
+/// A string aggregate ready to be written into the select list
+pub(crate) struct StringAggExpr {
+    col: String,
+    separator: String,
+    orders: Vec<(String, &'static str)>,
+}
+
+impl StringAggExpr {
+    /// writes the call. I.E. `string_agg(CAST(t1."name" AS TEXT), ', ' ORDER BY t1."name" ASC)`
+    pub(crate) fn write(&self, syntax: Syntax, writer: &ColumnWriter, alias: &str) -> String {
+        let qualify = |col: &str| format!("{}.{}", alias, writer.excape(col));
+        let col = qualify(&self.col);
+        // MySQL only takes a literal for the SEPARATOR, so it is written into the SQL
+        let mut separator = self.separator.replace('\'', "''");
+        if syntax == Syntax::Mysql {
+            separator = separator.replace('\\', "\\\\");
+        }
+        let separator = format!("'{}'", separator);
+
+        let orders: Vec<String> = self
+            .orders
+            .iter()
+            .map(|(c, dir)| format!("{} {}", qualify(c), dir))
+            .collect();
+        let order_by = match orders.is_empty() {
+            true => String::default(),
+            false => format!(" ORDER BY {}", orders.join(", ")),
+        };
+
+        match syntax {
+            // string_agg only takes text
+            Syntax::Postgres => {
+                format!(
+                    "string_agg(CAST({} AS TEXT), {}{})",
+                    col, separator, order_by
+                )
+            }
+            Syntax::Sqlite => format!("group_concat({}, {}{})", col, separator, order_by),
+            Syntax::Mysql => {
+                format!("GROUP_CONCAT({}{} SEPARATOR {})", col, order_by, separator)
+            }
+            Syntax::Mssql => {
+                let call = format!("STRING_AGG({}, {})", col, separator);
+                match orders.is_empty() {
+                    true => call,
+                    false => format!("{} WITHIN GROUP ({})", call, order_by.trim_start()),
+                }
+            }
+        }
+    }
+}
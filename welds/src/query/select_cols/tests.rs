@@ -0,0 +1,340 @@
+use super::SelectBuilder;
+use crate::Syntax;
+use crate::WeldsModel;
+
+#[derive(Debug, WeldsModel)]
+#[welds(table = "orders")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Order {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub customer_id: i32,
+    pub status: String,
+    pub price: f64,
+}
+
+#[derive(Debug, WeldsModel)]
+#[welds(table = "customers")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Customer {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub name: String,
+}
+
+#[derive(Debug, WeldsModel)]
+#[welds(table = "people")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Person {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub name: String,
+    pub nickname: Option<String>,
+}
+
+#[test]
+fn should_group_by_a_column_and_select_aggregates() {
+    let sql = Order::all()
+        .group_by(|o| o.status)
+        .select(|o| o.status)
+        .select_count_all("total")
+        .select_sum(|o| o.price, "price_sum")
+        .to_sql(Syntax::Postgres);
+    assert_eq!(
+        sql,
+        "SELECT t1.\"status\", COUNT(*) as \"total\", SUM(t1.\"price\") as \"price_sum\" FROM orders t1  GROUP BY t1.\"status\""
+    );
+}
+
+#[test]
+fn should_read_the_groups_into_tuples() {
+    let q = Order::all()
+        .group_by(|o| o.status)
+        .select(|o| o.status)
+        .select_count_all("total");
+    let client = welds_connections::noop::build(Syntax::Postgres);
+    let totals: Vec<(String, i64)> = futures::executor::block_on(q.run_as_tuple(&client)).unwrap();
+    assert!(totals.is_empty());
+    assert_eq!(
+        client.last_sql().unwrap(),
+        "SELECT t1.\"status\", COUNT(*) as \"total\" FROM orders t1  GROUP BY t1.\"status\""
+    );
+}
+
+#[test]
+fn should_write_group_by_after_where_and_before_order() {
+    let sql = Order::where_col(|o| o.price.gt(1.0))
+        .group_by(|o| o.status)
+        .select(|o| o.status)
+        .select_max(|o| o.price, "top")
+        .order_by_asc(|o| o.status)
+        .to_sql(Syntax::Sqlite);
+    assert_eq!(
+        sql,
+        "SELECT t1.\"status\", MAX(t1.\"price\") as \"top\" FROM orders t1  WHERE ( t1.price > ? ) GROUP BY t1.\"status\" ORDER BY status ASC"
+    );
+}
+
+#[test]
+fn should_filter_groups_with_having() {
+    let sql = Order::where_col(|o| o.price.gt(1.0))
+        .group_by(|o| o.status)
+        .select(|o| o.status)
+        .having(|agg| agg.count().gt(5))
+        .having(|agg| agg.sum(|o| o.price).lte(100.0))
+        .to_sql(Syntax::Postgres);
+    assert_eq!(
+        sql,
+        "SELECT t1.\"status\" FROM orders t1  WHERE ( t1.price > $1 ) GROUP BY t1.\"status\" HAVING ( COUNT(*) > $2 AND SUM(t1.\"price\") <= $3 )"
+    );
+}
+
+#[test]
+fn should_select_a_window_function() {
+    let sql = Order::all()
+        .select(|o| o.id)
+        .select_window(
+            |w| {
+                w.row_number()
+                    .partition_by(|o| o.status)
+                    .order_by_desc(|o| o.price)
+            },
+            "rn",
+        )
+        .select_window(|w| w.sum(|o| o.price).order_by_asc(|o| o.id), "running")
+        .to_sql(Syntax::Postgres);
+    assert_eq!(
+        sql,
+        "SELECT t1.\"id\", ROW_NUMBER() OVER (PARTITION BY t1.\"status\" ORDER BY t1.\"price\" DESC) as \"rn\", SUM(t1.\"price\") OVER (ORDER BY t1.\"id\" ASC) as \"running\" FROM orders t1"
+    );
+}
+
+#[test]
+fn should_give_mssql_an_order_for_ranking_windows() {
+    let sql = Order::all()
+        .select(|o| o.id)
+        .select_window(|w| w.lag(|o| o.price), "prev")
+        .to_sql(Syntax::Mssql);
+    assert!(sql.contains("LAG(t1.\"price\") OVER (ORDER BY (SELECT NULL)) as \"prev\""));
+}
+
+#[test]
+fn should_filter_with_an_in_subquery() {
+    let sql = Customer::where_col(|c| c.name.like("a%"))
+        .where_col(|c| {
+            c.id.in_query(Order::where_col(|o| o.price.gt(10.0)).select(|o| o.customer_id))
+        })
+        .select(|c| c.name)
+        .to_sql(Syntax::Postgres);
+    assert_eq!(
+        sql,
+        "SELECT t1.\"name\" FROM customers t1  WHERE ( t1.name like $1 AND t1.id IN ( SELECT t1.\"customer_id\" FROM orders t1  WHERE ( t1.price > $2 ) ) )"
+    );
+}
+
+#[test]
+fn should_compare_to_a_scalar_subquery() {
+    let sql = Order::where_col(|o| {
+        o.price
+            .gt_query(SelectBuilder::new(Order::all()).select_avg(|x| x.price, "avg_price"))
+    })
+    .select(|o| o.id)
+    .to_sql(Syntax::Mssql);
+    assert_eq!(
+        sql,
+        "SELECT t1.\"id\" FROM orders t1  WHERE ( t1.price > ( SELECT AVG(t1.\"price\") as \"avg_price\" FROM orders t1 ) )"
+    );
+}
+
+#[test]
+fn should_write_a_with_block_before_the_select() {
+    let sql = Customer::where_col(|c| c.name.like("a%"))
+        .where_col(|c| c.id.in_cte("recent_orders", "customer_id"))
+        .select(|c| c.name)
+        .with(
+            "recent_orders",
+            Order::where_col(|o| o.price.gt(10.0)).select(|o| o.customer_id),
+        )
+        .to_sql(Syntax::Postgres);
+    assert_eq!(
+        sql,
+        "WITH \"recent_orders\" AS ( SELECT t1.\"customer_id\" FROM orders t1  WHERE ( t1.price > $1 ) ) SELECT t1.\"name\" FROM customers t1  WHERE ( t1.name like $2 AND t1.id IN ( SELECT \"customer_id\" FROM \"recent_orders\" ) )"
+    );
+}
+
+#[derive(crate::WeldsDto)]
+#[welds(model(Order))]
+#[welds_path(crate)] // needed only within the welds crate.
+#[allow(dead_code)] // only the SQL is tested
+struct OrderPrice {
+    pub id: i32,
+    pub price: f64,
+}
+
+#[test]
+fn should_select_only_the_columns_of_a_dto() {
+    let sql = Order::where_col(|o| o.price.gt(1.0))
+        .select_as::<OrderPrice>()
+        .to_sql(Syntax::Postgres);
+    assert_eq!(
+        sql,
+        "SELECT t1.\"id\", t1.\"price\" FROM orders t1  WHERE ( t1.price > $1 )"
+    );
+}
+
+#[test]
+fn should_select_a_full_text_search_rank_before_the_where_params() {
+    let sql = Customer::where_col(|c| c.name.search("bob"))
+        .select(|c| c.id)
+        .select_search_rank(|c| c.name, "bob", "relevance")
+        .order_by_search_rank("relevance")
+        .to_sql(Syntax::Postgres);
+    assert_eq!(
+        sql,
+        "SELECT t1.\"id\", ts_rank(to_tsvector(t1.name), plainto_tsquery($1)) as \"relevance\" FROM customers t1  WHERE ( to_tsvector(t1.name) @@ plainto_tsquery($2) ) ORDER BY relevance DESC"
+    );
+}
+
+#[test]
+fn should_rank_a_full_text_search_with_bm25_on_sqlite() {
+    let sql = Customer::where_col(|c| c.name.search("bob"))
+        .select(|c| c.id)
+        .select_search_rank(|c| c.name, "bob", "relevance")
+        .to_sql(Syntax::Sqlite);
+    assert_eq!(
+        sql,
+        "SELECT t1.\"id\", -bm25(customers) as \"relevance\" FROM customers t1  WHERE ( t1.name MATCH ? )"
+    );
+}
+
+#[test]
+fn should_select_a_case_before_the_where_params() {
+    use crate::query::clause::Case;
+    let sql = Order::where_col(|o| o.price.gt(10.0))
+        .select(|o| o.id)
+        .select_case("open", |o| {
+            Case::new().when(o.status.equal("open"), 1).otherwise(0)
+        })
+        .to_sql(Syntax::Postgres);
+    assert_eq!(
+        sql,
+        "SELECT t1.\"id\", CASE WHEN t1.status = $1 THEN $2 ELSE $3 END as \"open\" FROM orders t1  WHERE ( t1.price > $4 )"
+    );
+}
+
+#[test]
+fn should_filter_select_and_order_by_a_coalesce() {
+    use crate::query::clause::{Expr, OrderExpr};
+    let sql = Person::where_col(|p| {
+        Expr::col(p.nickname)
+            .coalesce(p.name)
+            .equal("bob".to_owned())
+    })
+    .select(|p| p.id)
+    .select_expr("display_name", |p| Expr::col(p.nickname).ifnull(p.name))
+    .order_by(|p| OrderExpr::expr(Expr::col(p.nickname).coalesce(p.name)))
+    .to_sql(Syntax::Mysql);
+    assert_eq!(
+        sql,
+        "SELECT t1.id, IFNULL(t1.nickname, t1.name) as display_name FROM people t1  WHERE ( COALESCE(t1.nickname, t1.name) = ? ) ORDER BY COALESCE(t1.nickname, t1.name) ASC"
+    );
+}
+
+#[test]
+fn should_filter_on_a_nullif() {
+    use crate::query::clause::Expr;
+    let sql = Person::where_col(|p| Expr::col(p.nickname).nullif(String::new()).not_null())
+        .select(|p| p.id)
+        .to_sql(Syntax::Postgres);
+    assert_eq!(
+        sql,
+        "SELECT t1.\"id\" FROM people t1  WHERE ( NULLIF(t1.nickname, $1) IS NOT NULL )"
+    );
+}
+
+#[test]
+fn should_select_counts_of_filtered_rows() {
+    let q = Order::all()
+        .group_by(|o| o.customer_id)
+        .select(|o| o.customer_id)
+        .select_filtered("open", |agg| agg.count(), |o| o.status.equal("open"))
+        .select_filtered(
+            "open_total",
+            |agg| agg.sum(|o| o.price),
+            |o| o.status.equal("open"),
+        )
+        .where_col(|o| o.price.gt(1.0));
+    assert_eq!(
+        q.to_sql(Syntax::Postgres),
+        "SELECT t1.\"customer_id\", COUNT(*) FILTER (WHERE t1.status = $1) as \"open\", SUM(t1.\"price\") FILTER (WHERE t1.status = $2) as \"open_total\" FROM orders t1  WHERE ( t1.price > $3 ) GROUP BY t1.\"customer_id\""
+    );
+    assert_eq!(
+        q.to_sql(Syntax::Mysql),
+        "SELECT t1.customer_id, COUNT(CASE WHEN t1.status = ? THEN 1 END) as open, SUM(CASE WHEN t1.status = ? THEN t1.price END) as open_total FROM orders t1  WHERE ( t1.price > ? ) GROUP BY t1.customer_id"
+    );
+}
+
+#[test]
+fn should_join_the_text_of_each_group_for_each_database() {
+    let q = Order::all()
+        .group_by(|o| o.customer_id)
+        .select(|o| o.customer_id)
+        .select_string_agg(
+            |agg| agg.string_agg(|o| o.status, ", ").order_by_desc(|o| o.id),
+            "statuses",
+        );
+    let sql = q.to_sql(Syntax::Postgres);
+    assert_eq!(
+        sql,
+        "SELECT t1.\"customer_id\", string_agg(CAST(t1.\"status\" AS TEXT), ', ' ORDER BY t1.\"id\" DESC) as \"statuses\" FROM orders t1  GROUP BY t1.\"customer_id\""
+    );
+    let sql = q.to_sql(Syntax::Mysql);
+    assert_eq!(
+        sql,
+        "SELECT t1.customer_id, GROUP_CONCAT(t1.status ORDER BY t1.id DESC SEPARATOR ', ') as statuses FROM orders t1  GROUP BY t1.customer_id"
+    );
+    let sql = q.to_sql(Syntax::Mssql);
+    assert_eq!(
+        sql,
+        "SELECT t1.\"customer_id\", STRING_AGG(t1.\"status\", ', ') WITHIN GROUP (ORDER BY t1.\"id\" DESC) as \"statuses\" FROM orders t1  GROUP BY t1.\"customer_id\""
+    );
+}
+
+#[test]
+fn should_write_the_separator_of_a_string_agg_as_a_literal() {
+    let sql = Order::all()
+        .select(|o| o.customer_id)
+        .select_string_agg(|agg| agg.string_agg(|o| o.status, "'\\"), "statuses")
+        .to_sql(Syntax::Mysql);
+    assert_eq!(
+        sql,
+        "SELECT t1.customer_id, GROUP_CONCAT(t1.status SEPARATOR '''\\\\') as statuses FROM orders t1"
+    );
+}
+
+#[test]
+fn should_pluck_only_the_column() {
+    futures::executor::block_on(async move {
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        let names: Vec<String> = Customer::where_col(|c| c.id.gt(5))
+            .pluck(|c| c.name, &client)
+            .await
+            .unwrap();
+        assert!(names.is_empty());
+        let expected = r#"SELECT t1."name" FROM customers t1  WHERE ( t1.id > $1 )"#;
+        assert_eq!(expected, client.last_sql().unwrap());
+    });
+}
+
+#[test]
+fn should_pluck_a_tuple_of_columns() {
+    futures::executor::block_on(async move {
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        let _: Vec<(i32, String)> = Order::all()
+            .pluck_tuple(|o| (o.customer_id, o.status), &client)
+            .await
+            .unwrap();
+        let expected = r#"SELECT t1."customer_id", t1."status" FROM orders t1"#;
+        assert_eq!(expected, client.last_sql().unwrap());
+    });
+}
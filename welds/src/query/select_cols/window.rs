@@ -0,0 +1,160 @@
+use crate::query::clause::AsFieldName;
+use crate::writers::ColumnWriter;
+use crate::Syntax;
+use std::marker::PhantomData;
+
+/// Picks the window function to select. I.E. `ROW_NUMBER() OVER (...)`
+pub struct WindowSelector<S> {
+    _s: PhantomData<S>,
+}
+
+impl<S: Default> WindowSelector<S> {
+    pub(crate) fn new() -> Self {
+        Self {
+            _s: Default::default(),
+        }
+    }
+
+    /// The number of the row in its partition, starting at 1
+    pub fn row_number(&self) -> Window<S> {
+        Window::new("ROW_NUMBER", None)
+    }
+
+    /// The rank of the row in its partition, with gaps for ties
+    pub fn rank(&self) -> Window<S> {
+        Window::new("RANK", None)
+    }
+
+    /// The rank of the row in its partition, without gaps for ties
+    pub fn dense_rank(&self) -> Window<S> {
+        Window::new("DENSE_RANK", None)
+    }
+
+    /// The value of a column from the row before this one
+    pub fn lag<V, FN: AsFieldName<V>>(&self, lam: impl Fn(S) -> FN) -> Window<S> {
+        Window::new("LAG", Some(lam(Default::default()).colname()))
+    }
+
+    /// The value of a column from the row after this one
+    pub fn lead<V, FN: AsFieldName<V>>(&self, lam: impl Fn(S) -> FN) -> Window<S> {
+        Window::new("LEAD", Some(lam(Default::default()).colname()))
+    }
+
+    /// The total of a column over the window. With an order this is a running sum
+    pub fn sum<V, FN: AsFieldName<V>>(&self, lam: impl Fn(S) -> FN) -> Window<S> {
+        Window::new("SUM", Some(lam(Default::default()).colname()))
+    }
+
+    /// The average of a column over the window. With an order this is a running average
+    pub fn avg<V, FN: AsFieldName<V>>(&self, lam: impl Fn(S) -> FN) -> Window<S> {
+        Window::new("AVG", Some(lam(Default::default()).colname()))
+    }
+
+    /// The number of rows in the window. With an order this is a running count
+    pub fn count(&self) -> Window<S> {
+        Window::new("COUNT", Some("*"))
+    }
+}
+
+/// A window function and the window (`OVER (...)`) it is run over
+pub struct Window<S> {
+    func: &'static str,
+    col: Option<String>,
+    partitions: Vec<String>,
+    orders: Vec<(String, &'static str)>,
+    _s: PhantomData<S>,
+}
+
+impl<S: Default> Window<S> {
+    fn new(func: &'static str, col: Option<&str>) -> Self {
+        Self {
+            func,
+            col: col.map(|x| x.to_string()),
+            partitions: Vec::default(),
+            orders: Vec::default(),
+            _s: Default::default(),
+        }
+    }
+
+    /// Split the rows into partitions by this column. The function restarts in each partition
+    ///
+    /// multiple calls will partition by multiple columns
+    pub fn partition_by<V, FN: AsFieldName<V>>(mut self, lam: impl Fn(S) -> FN) -> Self {
+        let field = lam(Default::default());
+        self.partitions.push(field.colname().to_string());
+        self
+    }
+
+    /// Order the rows in each partition by this column
+    ///
+    /// multiple calls will result in multiple OrderBys
+    pub fn order_by_asc<V, FN: AsFieldName<V>>(mut self, lam: impl Fn(S) -> FN) -> Self {
+        let field = lam(Default::default());
+        self.orders.push((field.colname().to_string(), "ASC"));
+        self
+    }
+
+    /// Order the rows in each partition by this column
+    ///
+    /// multiple calls will result in multiple OrderBys
+    pub fn order_by_desc<V, FN: AsFieldName<V>>(mut self, lam: impl Fn(S) -> FN) -> Self {
+        let field = lam(Default::default());
+        self.orders.push((field.colname().to_string(), "DESC"));
+        self
+    }
+
+    pub(crate) fn into_expr(self) -> WindowExpr {
+        WindowExpr {
+            func: self.func,
+            col: self.col,
+            partitions: self.partitions,
+            orders: self.orders,
+        }
+    }
+}
+
+/// A window function ready to be written into the select list
+pub(crate) struct WindowExpr {
+    func: &'static str,
+    col: Option<String>,
+    partitions: Vec<String>,
+    orders: Vec<(String, &'static str)>,
+}
+
+impl WindowExpr {
+    /// writes the call and window. I.E. `ROW_NUMBER() OVER (PARTITION BY t1."a" ORDER BY t1."b" ASC)`
+    pub(crate) fn write(&self, syntax: Syntax, writer: &ColumnWriter, alias: &str) -> String {
+        let qualify = |col: &str| format!("{}.{}", alias, writer.excape(col));
+        let arg = match self.col.as_deref() {
+            None => String::default(),
+            Some("*") => "*".to_string(),
+            Some(col) => qualify(col),
+        };
+
+        let mut over: Vec<String> = Vec::default();
+        if !self.partitions.is_empty() {
+            let cols: Vec<String> = self.partitions.iter().map(|c| qualify(c)).collect();
+            over.push(format!("PARTITION BY {}", cols.join(", ")));
+        }
+        if !self.orders.is_empty() {
+            let cols: Vec<String> = self
+                .orders
+                .iter()
+                .map(|(c, dir)| format!("{} {}", qualify(c), dir))
+                .collect();
+            over.push(format!("ORDER BY {}", cols.join(", ")));
+        } else if syntax == Syntax::Mssql && self.needs_order() {
+            // Mssql requires an order for ranking and offset functions
+            over.push("ORDER BY (SELECT NULL)".to_string());
+        }
+
+        format!("{}({}) OVER ({})", self.func, arg, over.join(" "))
+    }
+
+    fn needs_order(&self) -> bool {
+        matches!(
+            self.func,
+            "ROW_NUMBER" | "RANK" | "DENSE_RANK" | "LAG" | "LEAD"
+        )
+    }
+}
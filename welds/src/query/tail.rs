@@ -1,16 +1,89 @@
 use crate::query::clause::orderby;
-use crate::query::clause::OrderBy;
+use crate::query::clause::{OrderBy, ParamArgs};
 use crate::writers::limit_skip::LimitSkipWriter;
+use crate::writers::NextParam;
 use crate::Syntax;
 use std::collections::VecDeque;
 
 /// writes the Limit Skip OrderBy of a statement
-pub(crate) fn write(
+/// Will fill in the args of the orders if provided
+pub(crate) fn write<'o, 'args, 'p>(
+    syntax: Syntax,
+    limit: &Option<i64>,
+    offset: &Option<i64>,
+    orders: &'o [OrderBy],
+    alias: &str,
+    next_params: &NextParam,
+    args: &'args mut Option<ParamArgs<'p>>,
+) -> Option<String>
+where
+    'o: 'p,
+{
+    write_ordered(syntax, limit, offset, orders, &[], alias, next_params, args)
+}
+
+/// writes the Limit Skip OrderBy of a SELECT from a table that has written `top` in its head.
+///
+/// On MSSQL a limit without an order is ordered by the primary keys,
+/// or dropped here if the head has already written it as a TOP
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn write_for_table<'o, 'args, 'p>(
+    syntax: Syntax,
+    limit: &Option<i64>,
+    offset: &Option<i64>,
+    orders: &'o [OrderBy],
+    primary_keys: &[String],
+    alias: &str,
+    next_params: &NextParam,
+    args: &'args mut Option<ParamArgs<'p>>,
+) -> Option<String>
+where
+    'o: 'p,
+{
+    let limit = match top(syntax, limit, offset, orders) {
+        Some(_) => &None,
+        None => limit,
+    };
+    write_ordered(
+        syntax,
+        limit,
+        offset,
+        orders,
+        primary_keys,
+        alias,
+        next_params,
+        args,
+    )
+}
+
+/// On MSSQL a limit without an offset or order is written as `TOP (n)` after the SELECT.
+/// MSSQL can only OFFSET/FETCH rows that are ordered
+pub(crate) fn top(
     syntax: Syntax,
     limit: &Option<i64>,
     offset: &Option<i64>,
     orders: &[OrderBy],
 ) -> Option<String> {
+    if syntax != Syntax::Mssql || offset.is_some() || !orders.is_empty() {
+        return None;
+    }
+    limit.map(|limit| format!("TOP ({})", limit))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn write_ordered<'o, 'args, 'p>(
+    syntax: Syntax,
+    limit: &Option<i64>,
+    offset: &Option<i64>,
+    orders: &'o [OrderBy],
+    primary_keys: &[String],
+    alias: &str,
+    next_params: &NextParam,
+    args: &'args mut Option<ParamArgs<'p>>,
+) -> Option<String>
+where
+    'o: 'p,
+{
     let w = LimitSkipWriter::new(syntax);
     let mut parts = VecDeque::default();
 
@@ -20,11 +93,14 @@ pub(crate) fn write(
 
     // If we are limiting but no order is given force an order (needed for MSSQL)
     if !parts.is_empty() && orders.is_empty() {
-        parts.push_front("ORDER BY 1".to_owned())
+        parts.push_front(fallback_order(syntax, alias, primary_keys))
     }
 
     if !orders.is_empty() {
-        parts.push_front(orderby::to_sql(orders));
+        if let Some(args) = args {
+            orderby::bind(orders, args);
+        }
+        parts.push_front(orderby::to_sql(syntax, alias, next_params, orders));
     }
 
     if parts.is_empty() {
@@ -33,3 +109,16 @@ pub(crate) fn write(
     let parts: Vec<String> = parts.drain(..).collect();
     Some(parts.join(" "))
 }
+
+/// MSSQL pages in the order of the primary keys so each page is the same every time.
+/// The other databases, or rows without keys, are ordered by the first column
+fn fallback_order(syntax: Syntax, alias: &str, primary_keys: &[String]) -> String {
+    if syntax != Syntax::Mssql || primary_keys.is_empty() {
+        return "ORDER BY 1".to_owned();
+    }
+    let keys: Vec<String> = primary_keys
+        .iter()
+        .map(|key| format!("{}.{}", alias, key))
+        .collect();
+    format!("ORDER BY {}", keys.join(", "))
+}
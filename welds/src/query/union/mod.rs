@@ -0,0 +1,261 @@
+use super::builder::QueryBuilder;
+use super::clause::{AsFieldName, OrderBy, OrderExpr, ParamArgs};
+use super::select_cols::cte::SubQuery;
+use super::select_cols::SelectBuilder;
+use crate::errors::Result;
+use crate::model_traits::{HasSchema, TableColumns, TableInfo};
+use crate::query::tail;
+use crate::state::DbState;
+use crate::writers::NextParam;
+use crate::{Syntax, WeldsError};
+use std::marker::PhantomData;
+use welds_connections::{Client, Row};
+
+#[cfg(test)]
+mod tests;
+
+/// An un-executed combination of queries (UNION / UNION ALL)
+///
+/// All the queries must select the same shape of columns.
+/// A query with its own ORDER BY / LIMIT / OFFSET is wrapped so it is
+/// only applied to that query, the ORDER BY / LIMIT / OFFSET of the
+/// UnionBuilder are applied to the combined results.
+pub struct UnionBuilder<T> {
+    _t: PhantomData<T>,
+    parts: Vec<UnionPart>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    orderby: Vec<OrderBy>,
+}
+
+struct UnionPart {
+    all: bool,
+    query: Box<dyn SubQuery>,
+}
+
+impl<T> QueryBuilder<T>
+where
+    T: 'static + Send + Sync + HasSchema,
+    <T as HasSchema>::Schema: TableInfo + TableColumns,
+{
+    /// Combine the results of this query with another, removing duplicate rows (UNION)
+    pub fn union(self, other: QueryBuilder<T>) -> UnionBuilder<T> {
+        self.select_all().union(other.select_all())
+    }
+
+    /// Combine the results of this query with another, keeping duplicate rows (UNION ALL)
+    pub fn union_all(self, other: QueryBuilder<T>) -> UnionBuilder<T> {
+        self.select_all().union_all(other.select_all())
+    }
+}
+
+impl<T> SelectBuilder<T>
+where
+    T: 'static + Send + Sync + HasSchema,
+    <T as HasSchema>::Schema: TableInfo + TableColumns,
+{
+    /// Combine the results of this query with another, removing duplicate rows (UNION)
+    ///
+    /// Both queries must select the same shape of columns
+    pub fn union<R>(self, other: SelectBuilder<R>) -> UnionBuilder<T>
+    where
+        R: 'static + Send + Sync + HasSchema,
+        <R as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        UnionBuilder::new(self).union(other)
+    }
+
+    /// Combine the results of this query with another, keeping duplicate rows (UNION ALL)
+    ///
+    /// Both queries must select the same shape of columns
+    pub fn union_all<R>(self, other: SelectBuilder<R>) -> UnionBuilder<T>
+    where
+        R: 'static + Send + Sync + HasSchema,
+        <R as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        UnionBuilder::new(self).union_all(other)
+    }
+}
+
+impl<T> UnionBuilder<T>
+where
+    T: Send + HasSchema,
+{
+    fn new<F>(first: SelectBuilder<F>) -> Self
+    where
+        F: 'static + Send + Sync + HasSchema,
+        <F as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        Self {
+            _t: Default::default(),
+            parts: vec![UnionPart {
+                all: false,
+                query: Box::new(first),
+            }],
+            limit: None,
+            offset: None,
+            orderby: Vec::default(),
+        }
+    }
+
+    /// Add another query to the results, removing duplicate rows (UNION)
+    pub fn union<R>(mut self, other: SelectBuilder<R>) -> Self
+    where
+        R: 'static + Send + Sync + HasSchema,
+        <R as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        self.parts.push(UnionPart {
+            all: false,
+            query: Box::new(other),
+        });
+        self
+    }
+
+    /// Add another query to the results, keeping duplicate rows (UNION ALL)
+    pub fn union_all<R>(mut self, other: SelectBuilder<R>) -> Self
+    where
+        R: 'static + Send + Sync + HasSchema,
+        <R as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        self.parts.push(UnionPart {
+            all: true,
+            query: Box::new(other),
+        });
+        self
+    }
+
+    /// Limit the number of rows returned by the combined queries
+    pub fn limit(mut self, x: i64) -> Self {
+        self.limit = Some(x);
+        self
+    }
+
+    /// Offset the starting point for the results returned by the combined queries
+    pub fn offset(mut self, x: i64) -> Self {
+        self.offset = Some(x);
+        self
+    }
+
+    /// Order the combined results by a given column
+    ///
+    /// multiple calls will result in multiple OrderBys
+    pub fn order_by_desc<V, FN: AsFieldName<V>>(
+        mut self,
+        lam: impl Fn(<T as HasSchema>::Schema) -> FN,
+    ) -> Self {
+        let field = lam(Default::default());
+        self.orderby.push(OrderBy::new(field.colname(), "DESC"));
+        self
+    }
+
+    /// Order the combined results by a given column
+    ///
+    /// multiple calls will result in multiple OrderBys
+    pub fn order_by_asc<V, FN: AsFieldName<V>>(
+        mut self,
+        lam: impl Fn(<T as HasSchema>::Schema) -> FN,
+    ) -> Self {
+        let field = lam(Default::default());
+        self.orderby.push(OrderBy::new(field.colname(), "ASC"));
+        self
+    }
+
+    /// Order the combined results by an expression.
+    /// NULLS FIRST/LAST is emulated on databases without it
+    ///
+    /// multiple calls will result in multiple OrderBys
+    pub fn order_by(mut self, lam: impl Fn(<T as HasSchema>::Schema) -> OrderExpr) -> Self {
+        self.orderby.push(lam(Default::default()).into());
+        self
+    }
+
+    /// Manually write the order by part of the combined query
+    pub fn order_manual(mut self, sql: &str) -> Self {
+        self.orderby.push(OrderBy::new(sql.to_string(), ""));
+        self
+    }
+
+    fn sql_internal<'s, 'args, 'p>(
+        &'s self,
+        syntax: Syntax,
+        args: &'args mut Option<ParamArgs<'p>>,
+    ) -> String
+    where
+        's: 'p,
+    {
+        let next_params = NextParam::new(syntax);
+        let mut sql = String::default();
+        for (i, part) in self.parts.iter().enumerate() {
+            let inner = part.query.write_sql(syntax, &next_params, args);
+            if i > 0 {
+                sql.push_str(if part.all { " UNION ALL " } else { " UNION " });
+            }
+            // not all databases allow an ORDER BY / LIMIT directly on a part of a UNION
+            if part.query.has_tail() {
+                sql.push_str(&format!("SELECT * FROM ( {} ) u{}", inner, i + 1));
+            } else {
+                sql.push_str(&inner);
+            }
+        }
+        // the parts are wrapped, the orders of a union can only be by the names of the columns
+        let tail = tail::write(
+            syntax,
+            &self.limit,
+            &self.offset,
+            &self.orderby,
+            "",
+            &next_params,
+            args,
+        );
+        if let Some(tail) = tail {
+            sql.push(' ');
+            sql.push_str(&tail);
+        }
+        sql
+    }
+
+    /// Get a copy of the SQL that will be executed when this query runs
+    pub fn to_sql(&self, syntax: Syntax) -> String {
+        self.sql_internal(syntax, &mut None)
+    }
+
+    /// Executes the combined queries in the database returning the rows
+    pub async fn run_rows(&self, client: &dyn Client) -> Result<Vec<Row>> {
+        let syntax = client.syntax();
+        let mut args: Option<ParamArgs> = Some(Vec::default());
+        let sql = self.sql_internal(syntax, &mut args);
+        let args = args.unwrap();
+        let rows = client.fetch_rows(&sql, &args).await?;
+        Ok(rows)
+    }
+
+    /// Executes the combined queries in the database returning the results as models
+    pub async fn run(&self, client: &dyn Client) -> Result<Vec<DbState<T>>>
+    where
+        T: TryFrom<Row>,
+        WeldsError: From<<T as TryFrom<Row>>::Error>,
+    {
+        let rows = self.run_rows(client).await?;
+        let mut objs = Vec::default();
+        for row in rows {
+            let obj: T = T::try_from(row)?;
+            objs.push(DbState::db_loaded(obj));
+        }
+        Ok(objs)
+    }
+
+    /// Executes the combined queries in the database reading each row into a `R`.
+    /// Useful when the queries select a shape other than the model
+    pub async fn run_as<R>(&self, client: &dyn Client) -> Result<Vec<R>>
+    where
+        R: TryFrom<Row>,
+        WeldsError: From<<R as TryFrom<Row>>::Error>,
+    {
+        let rows = self.run_rows(client).await?;
+        let mut objs = Vec::default();
+        for row in rows {
+            objs.push(R::try_from(row)?);
+        }
+        Ok(objs)
+    }
+}
@@ -0,0 +1,46 @@
+use crate::Syntax;
+use crate::WeldsModel;
+
+#[derive(Debug, WeldsModel)]
+#[welds(table = "orders")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Order {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub status: String,
+    pub price: f64,
+}
+
+#[derive(Debug, WeldsModel)]
+#[welds(table = "archived_orders")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct ArchivedOrder {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub status: String,
+    pub price: f64,
+}
+
+#[test]
+fn should_union_two_queries_of_the_same_model() {
+    let sql = Order::where_col(|o| o.price.gt(10.0))
+        .union_all(Order::where_col(|o| o.status.equal("open")))
+        .order_by_desc(|o| o.price)
+        .to_sql(Syntax::Postgres);
+    assert_eq!(
+        sql,
+        "SELECT t1.\"id\", t1.\"status\", t1.\"price\" FROM orders t1  WHERE ( t1.price > $1 ) UNION ALL SELECT t1.\"id\", t1.\"status\", t1.\"price\" FROM orders t1  WHERE ( t1.status = $2 ) ORDER BY price DESC"
+    );
+}
+
+#[test]
+fn should_wrap_parts_with_their_own_limit() {
+    let sql = Order::all()
+        .select(|o| o.id)
+        .union(ArchivedOrder::all().select(|o| o.id).limit(5))
+        .to_sql(Syntax::Sqlite);
+    assert_eq!(
+        sql,
+        "SELECT t1.\"id\" FROM orders t1 UNION SELECT * FROM ( SELECT t1.\"id\" FROM archived_orders t1  ORDER BY 1 LIMIT 5 OFFSET 0 ) u2"
+    );
+}
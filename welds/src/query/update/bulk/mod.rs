@@ -1,26 +1,32 @@
 use crate::errors::Result;
 use crate::model_traits::UniqueIdentifier;
-use crate::model_traits::{HasSchema, TableColumns, TableInfo};
+use crate::model_traits::{HasSchema, TableColumns, TableInfo, Versioned};
 use crate::query::builder::QueryBuilder;
 use crate::query::clause::wherein::WhereIn;
 use crate::query::clause::AsFieldName;
 use crate::query::clause::ClauseAdder;
 use crate::query::clause::ParamArgs;
-use crate::query::helpers::{build_where, join_sql_parts};
+use crate::query::clause::{Expr, SetColExpr};
+use crate::query::helpers::{self, build_where, join_sql_parts};
 use crate::writers::ColumnWriter;
 use crate::writers::NextParam;
 use crate::Client;
 use crate::Syntax;
 use std::marker::PhantomData;
+use std::time::Duration;
 use welds_connections::Param;
 
 /// An un-executed Sql Update.
 ///
+/// On a model with `#[welds(version)]` the version of every updated row is moved forward.
+/// A `#[welds(db_default)]` column is only written when it is set directly
+///
 /// Build out a sql statement that will update the database in bulk
 pub struct UpdateBuilder<T> {
     _t: PhantomData<T>,
     pub(crate) query_builder: QueryBuilder<T>,
     pub(crate) sets: Vec<Box<dyn ClauseAdder>>,
+    set_cols: Vec<String>,
 }
 
 impl<T> UpdateBuilder<T>
@@ -31,6 +37,7 @@ where
         Self {
             _t: Default::default(),
             sets: Vec::default(),
+            set_cols: Vec::default(),
             query_builder,
         }
     }
@@ -50,13 +57,43 @@ where
         let val: V = value.into();
         let field = lam(Default::default());
         let col_raw = field.colname().to_string();
+        self.set_cols.push(col_raw.clone());
         self.sets.push(Box::new(SetColVal { col_raw, val }));
         self
     }
 
+    /// Set a column to an expression of the columns already in the row.
+    /// ```rust,ignore
+    /// .set_expr(|p| p.views, |p| Expr::col(p.views) + 1)
+    /// ```
+    pub fn set_expr<V, FIELD, E>(
+        mut self,
+        lam: impl Fn(<T as HasSchema>::Schema) -> FIELD,
+        expr: impl Fn(<T as HasSchema>::Schema) -> E,
+    ) -> Self
+    where
+        <T as HasSchema>::Schema: Default,
+        FIELD: AsFieldName<V>,
+        E: Into<Expr>,
+    {
+        let field = lam(Default::default());
+        let col_raw = field.colname().to_string();
+        let expr = expr(Default::default()).into();
+        self.set_cols.push(col_raw.clone());
+        self.sets.push(Box::new(SetColExpr { col_raw, expr }));
+        self
+    }
+
+    /// Limit how long the database can spend running the update. See `QueryBuilder::timeout`
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.query_builder.timeout = Some(timeout);
+        self
+    }
+
     /// Get a copy of the SQL that will be executed when this query runs
     pub fn to_sql(&self, syntax: Syntax) -> String
     where
+        T: Versioned,
         <T as HasSchema>::Schema: UniqueIdentifier + TableInfo + TableColumns,
     {
         let mut w_in = WhereIn::new(&self.query_builder);
@@ -73,14 +110,24 @@ where
     where
         'w: 'p,
         's: 'p,
+        T: Versioned,
         <T as HasSchema>::Schema: UniqueIdentifier + TableInfo + TableColumns,
     {
         let next_params = NextParam::new(syntax);
         let sets = self.sets.as_slice();
         let alias = <T as HasSchema>::Schema::identifier().join(".");
+        // the version is moved forward unless it is being set directly
+        let version = T::version_column().filter(|v| !self.set_cols.iter().any(|c| c == v));
 
         join_sql_parts(&[
-            build_head::<<T as HasSchema>::Schema>(syntax, &next_params, &alias, args, sets),
+            build_head::<<T as HasSchema>::Schema>(
+                syntax,
+                &next_params,
+                &alias,
+                args,
+                sets,
+                version,
+            ),
             build_where_update(
                 syntax,
                 w_in,
@@ -93,8 +140,9 @@ where
     }
 
     /// Executes the query in the database Bulk updating the values
-    pub async fn run<'s, 'c>(&'s self, client: &'c dyn Client) -> Result<()>
+    pub async fn run(&self, client: &dyn Client) -> Result<()>
     where
+        T: Versioned,
         <T as HasSchema>::Schema: UniqueIdentifier + TableInfo + TableColumns,
     {
         let syntax = client.syntax();
@@ -102,7 +150,8 @@ where
         let mut w_in = WhereIn::new(&self.query_builder);
         let sql = self.sql_internal(syntax, &mut w_in, &mut args);
         let args = args.unwrap();
-        let _results = client.execute(&sql, &args).await?;
+        let timeout = self.query_builder.timeout;
+        helpers::execute(client, &sql, &args, timeout).await?;
 
         Ok(())
     }
@@ -114,6 +163,7 @@ fn build_head<'s, 'args, 'p, S>(
     alias: &str,
     args: &'args mut Option<ParamArgs<'p>>,
     sets: &'s [Box<dyn ClauseAdder>],
+    version: Option<&str>,
 ) -> Option<String>
 where
     's: 'p,
@@ -131,11 +181,20 @@ where
             set_parts.push(p);
         }
     }
+    if let Some(version) = version {
+        set_parts.push(version_bump(syntax, version));
+    }
     let set_sql = set_parts.join(", ");
 
     Some(format!("UPDATE {tn} SET {sets}", tn = tn, sets = set_sql))
 }
 
+/// the SET moving the version column of a row forward
+pub(crate) fn version_bump(syntax: Syntax, column: &str) -> String {
+    let colname = ColumnWriter::new(syntax).excape(column);
+    format!("{}={} + 1", colname, colname)
+}
+
 pub struct SetColVal<T> {
     pub col_raw: String,
     pub val: T,
@@ -1,4 +1,5 @@
 use crate::query::builder::QueryBuilder;
+use crate::query::clause::Expr;
 use crate::Syntax;
 
 // Testing that the tail end of the SQL is correct
@@ -60,3 +61,102 @@ fn should_be_able_to_write_complex_set_value() {
         assert_eq!(client.args_count().unwrap(), 3);
     });
 }
+
+#[test]
+fn should_be_able_to_set_a_column_from_a_case() {
+    use crate::query::clause::Case;
+    let q = QueryBuilder::<Product>::new().set_expr(
+        |p| p.b,
+        |p| Case::new().when(p.a.gt(10), Expr::col(p.b)).otherwise(0),
+    );
+    let expected = "UPDATE nums SET \"b\"=CASE WHEN nums.a > $1 THEN \"b\" ELSE $2 END";
+    assert_eq!(expected, q.to_sql(Syntax::Postgres));
+}
+
+#[test]
+fn should_be_able_to_set_a_column_from_an_expression() {
+    futures::executor::block_on(async move {
+        let q = QueryBuilder::<Product>::new().where_col(|c| c.id.gt(10));
+        let bulk = q
+            .set_expr(|p| p.a, |p| Expr::col(p.a) + 1)
+            .set_expr(|p| p.b, |p| (Expr::col(p.a) + Expr::col(p.b)) * 2);
+
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        let _ = bulk.run(&client).await;
+
+        let ran_sql = client
+            .last_sql()
+            .expect("Expected to get SQL back from client");
+
+        let expected =
+            "UPDATE nums SET \"a\"=\"a\" + $1, \"b\"=(\"a\" + \"b\") * $2 WHERE ( nums.id > $3 )";
+        assert_eq!(expected, &ran_sql);
+
+        assert_eq!(client.args_count().unwrap(), 3);
+    });
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "posts")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Post {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub title: Option<String>,
+}
+
+#[test]
+fn should_be_able_to_coalesce_and_concat_text() {
+    let q = QueryBuilder::<Post>::new().set_expr(
+        |p| p.title,
+        |p| {
+            Expr::col(p.title)
+                .coalesce(String::new())
+                .concat("!".to_owned())
+        },
+    );
+    let expected = "UPDATE posts SET \"title\"=COALESCE(\"title\", $1) || $2";
+    assert_eq!(expected, q.to_sql(Syntax::Postgres));
+    let expected = "UPDATE posts SET title=CONCAT(COALESCE(title, ?), ?)";
+    assert_eq!(expected, q.to_sql(Syntax::Mysql));
+}
+
+#[test]
+fn should_write_ifnull_for_each_database() {
+    let q = QueryBuilder::<Post>::new().set_expr(
+        |p| p.title,
+        |p| {
+            Expr::col(p.title)
+                .nullif(String::new())
+                .ifnull("none".to_owned())
+        },
+    );
+    let expected = "UPDATE posts SET \"title\"=COALESCE(NULLIF(\"title\", $1), $2)";
+    assert_eq!(expected, q.to_sql(Syntax::Postgres));
+    let expected = "UPDATE posts SET title=IFNULL(NULLIF(title, ?), ?)";
+    assert_eq!(expected, q.to_sql(Syntax::Mysql));
+    let expected = "UPDATE posts SET \"title\"=ISNULL(NULLIF(\"title\", @p1), @p2)";
+    assert_eq!(expected, q.to_sql(Syntax::Mssql));
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "docs")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Doc {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub body: String,
+    #[welds(version)]
+    pub lock_version: i32,
+}
+
+#[test]
+fn should_move_the_version_forward_of_every_updated_row() {
+    let q = QueryBuilder::<Doc>::new().set(|d| d.body, "new".to_owned());
+    let expected = "UPDATE docs SET \"body\"=$1, \"lock_version\"=\"lock_version\" + 1";
+    assert_eq!(expected, q.to_sql(Syntax::Postgres));
+
+    // setting the version directly leaves it alone
+    let q = QueryBuilder::<Doc>::new().set(|d| d.lock_version, 7);
+    assert_eq!("UPDATE docs SET lock_version=?", q.to_sql(Syntax::Mysql));
+}
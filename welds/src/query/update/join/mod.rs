@@ -0,0 +1,248 @@
+use super::bulk::SetColVal;
+use crate::errors::Result;
+use crate::model_traits::UniqueIdentifier;
+use crate::model_traits::{HasSchema, TableColumns, TableInfo};
+use crate::query::builder::QueryBuilder;
+use crate::query::clause::{AsFieldName, ClauseAdder, ParamArgs};
+use crate::query::helpers::{self, build_where_clauses};
+use crate::relations::{HasRelations, Relationship};
+use crate::writers::{ColumnWriter, NextParam};
+use crate::Client;
+use crate::Syntax;
+use std::marker::PhantomData;
+use welds_connections::Param;
+
+// ******************************************************************************************
+// This file contains code to bulk update rows using the columns of a related table
+// joined into the same UPDATE statement
+// ******************************************************************************************
+
+/// An un-executed Sql Update that joins in a related table.
+///
+/// The related table can filter the rows to update and its columns can be copied into them.
+/// ```rust,ignore
+/// // copy the name of the customer onto each of their orders
+/// Order::all()
+///     .update_join(|o| o.customer, Customer::all())
+///     .set_from(|o| o.customer_name, |c| c.name)
+///     .run(client)
+///     .await?;
+/// ```
+///
+/// NOTE: Limits and orders of the query are not used
+pub struct UpdateJoinBuilder<T, R> {
+    _r: PhantomData<R>,
+    qb: QueryBuilder<T>,
+    sets: Vec<Box<dyn ClauseAdder>>,
+    tablename: String,
+    alias: String,
+    outer_column: String,
+    inner_column: String,
+    filter: QueryBuilder<R>,
+}
+
+impl<T> QueryBuilder<T>
+where
+    T: Send + HasSchema,
+{
+    /// Bulk update the rows of this query, joining in a relationship.
+    /// See `UpdateJoinBuilder`
+    pub fn update_join<R, Ship>(
+        self,
+        relationship: impl Fn(<T as HasRelations>::Relation) -> Ship,
+        mut filter: QueryBuilder<R>,
+    ) -> UpdateJoinBuilder<T, R>
+    where
+        T: HasRelations,
+        Ship: Relationship<R>,
+        R: Send + Sync + HasSchema,
+        <R as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasSchema>::Schema: TableInfo + TableColumns + UniqueIdentifier,
+        <T as HasRelations>::Relation: Default,
+    {
+        let ship = relationship(Default::default());
+        filter.set_aliases(&self.alias_asigner);
+        UpdateJoinBuilder {
+            _r: PhantomData,
+            tablename: <R as HasSchema>::Schema::identifier().join("."),
+            alias: filter.alias.clone(),
+            outer_column: ship.my_key::<R::Schema, T::Schema>(),
+            inner_column: ship.their_key::<R::Schema, T::Schema>(),
+            qb: self,
+            sets: Vec::default(),
+            filter,
+        }
+    }
+}
+
+impl<T, R> UpdateJoinBuilder<T, R>
+where
+    T: Send + HasSchema,
+    R: Send + HasSchema,
+{
+    /// Set a column to a value
+    pub fn set<V, FIELD>(
+        mut self,
+        lam: impl Fn(<T as HasSchema>::Schema) -> FIELD,
+        value: impl Into<V>,
+    ) -> Self
+    where
+        FIELD: AsFieldName<V>,
+        V: 'static + Sync + Send + Clone + Param,
+    {
+        let val: V = value.into();
+        let col_raw = lam(Default::default()).colname().to_string();
+        self.sets.push(Box::new(SetColVal { col_raw, val }));
+        self
+    }
+
+    /// Set a column to the value of a column on the joined row
+    pub fn set_from<V, FIELD, FROM>(
+        mut self,
+        lam: impl Fn(<T as HasSchema>::Schema) -> FIELD,
+        from: impl Fn(<R as HasSchema>::Schema) -> FROM,
+    ) -> Self
+    where
+        FIELD: AsFieldName<V>,
+        FROM: AsFieldName<V>,
+    {
+        let col_raw = lam(Default::default()).colname().to_string();
+        let from = format!("{}.{}", self.alias, from(Default::default()).colname());
+        self.sets.push(Box::new(SetColFrom { col_raw, from }));
+        self
+    }
+
+    fn on(&self) -> String {
+        format!(
+            "{}.{} = {}.{}",
+            self.qb.alias, self.outer_column, self.alias, self.inner_column
+        )
+    }
+
+    fn sql_internal<'s, 'args, 'p>(
+        &'s self,
+        syntax: Syntax,
+        args: &'args mut Option<ParamArgs<'p>>,
+    ) -> String
+    where
+        's: 'p,
+        <T as HasSchema>::Schema: TableInfo,
+    {
+        let next_params = NextParam::new(syntax);
+        let tn = <T as HasSchema>::Schema::identifier().join(".");
+        let alias = &self.qb.alias;
+
+        // The SET comes before the joins and filters in all databases
+        let mut sets: Vec<String> = Vec::default();
+        for clause in &self.sets {
+            if let Some(args) = args {
+                clause.bind(args);
+            }
+            if let Some(p) = clause.clause(syntax, alias, &next_params) {
+                // MySQL needs to know which of the joined tables the column is on
+                match syntax {
+                    Syntax::Mysql => sets.push(format!("{}.{}", alias, p)),
+                    _ => sets.push(p),
+                }
+            }
+        }
+        let sets = sets.join(", ");
+
+        let mut wheres: Vec<String> = Vec::default();
+        if matches!(syntax, Syntax::Postgres | Syntax::Sqlite) {
+            wheres.push(self.on());
+        }
+        let filter = &self.filter;
+        wheres.extend(build_where_clauses(
+            syntax,
+            &next_params,
+            &self.alias,
+            &filter.wheres,
+            args,
+            &filter.exist_ins,
+        ));
+        let qb = &self.qb;
+        wheres.extend(build_where_clauses(
+            syntax,
+            &next_params,
+            alias,
+            &qb.wheres,
+            args,
+            &qb.exist_ins,
+        ));
+
+        let joined = format!("{} {}", self.tablename, self.alias);
+        let head = match syntax {
+            Syntax::Postgres => format!("UPDATE {} {} SET {} FROM {}", tn, alias, sets, joined),
+            Syntax::Sqlite => format!(
+                "UPDATE {} AS {} SET {} FROM {} AS {}",
+                tn, alias, sets, self.tablename, self.alias
+            ),
+            Syntax::Mysql => format!(
+                "UPDATE {} {} JOIN {} ON {} SET {}",
+                tn,
+                alias,
+                joined,
+                self.on(),
+                sets
+            ),
+            Syntax::Mssql => format!(
+                "UPDATE {} SET {} FROM {} {} JOIN {} ON {}",
+                alias,
+                sets,
+                tn,
+                alias,
+                joined,
+                self.on()
+            ),
+        };
+
+        if wheres.is_empty() {
+            return head;
+        }
+        format!("{} WHERE ( {} )", head, wheres.join(" AND "))
+    }
+
+    /// Get a copy of the SQL that will be executed when this query runs
+    pub fn to_sql(&self, syntax: Syntax) -> String
+    where
+        <T as HasSchema>::Schema: TableInfo,
+    {
+        self.sql_internal(syntax, &mut None)
+    }
+
+    /// Executes the query in the database Bulk updating the values
+    pub async fn run(&self, client: &dyn Client) -> Result<()>
+    where
+        <T as HasSchema>::Schema: TableInfo,
+    {
+        let syntax = client.syntax();
+        let mut args: Option<ParamArgs> = Some(Vec::default());
+        let sql = self.sql_internal(syntax, &mut args);
+        let args = args.unwrap();
+        helpers::execute(client, &sql, &args, self.qb.timeout).await?;
+        Ok(())
+    }
+}
+
+/// SETs a column to a column of the joined table
+struct SetColFrom {
+    col_raw: String,
+    from: String,
+}
+
+impl ClauseAdder for SetColFrom {
+    fn bind<'lam, 'args, 'p>(&'lam self, _args: &'args mut ParamArgs<'p>)
+    where
+        'lam: 'p,
+    {
+    }
+
+    fn clause(&self, syntax: Syntax, _alias: &str, _next_params: &NextParam) -> Option<String> {
+        let colname = ColumnWriter::new(syntax).excape(&self.col_raw);
+        Some(format!("{}={}", colname, self.from))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,60 @@
+use crate::query::builder::QueryBuilder;
+use crate::Syntax;
+use crate::WeldsModel;
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "customers")]
+#[welds_path(crate)] // needed only within the welds crate.
+#[welds(HasMany(orders, Order, "customer_id"))]
+struct Customer {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub name: String,
+    pub banned: bool,
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "orders")]
+#[welds_path(crate)] // needed only within the welds crate.
+#[welds(BelongsTo(customer, Customer, "customer_id"))]
+struct Order {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub customer_id: i32,
+    pub customer_name: String,
+    pub on_hold: bool,
+}
+
+fn copy_names_of_banned(syntax: Syntax) -> String {
+    let banned = QueryBuilder::<Customer>::new().where_col(|c| c.banned.equal(true));
+    QueryBuilder::<Order>::new()
+        .where_col(|o| o.id.gt(10))
+        .update_join(|o| o.customer, banned)
+        .set_from(|o| o.customer_name, |c| c.name)
+        .set(|o| o.on_hold, true)
+        .to_sql(syntax)
+}
+
+#[test]
+fn should_update_from_a_relationship_on_postgres() {
+    let expected = "UPDATE orders t1 SET \"customer_name\"=t2.name, \"on_hold\"=$1 FROM customers t2 WHERE ( t1.customer_id = t2.id AND t2.banned = $2 AND t1.id > $3 )";
+    assert_eq!(expected, copy_names_of_banned(Syntax::Postgres));
+}
+
+#[test]
+fn should_update_from_a_relationship_on_sqlite() {
+    let expected = "UPDATE orders AS t1 SET \"customer_name\"=t2.name, \"on_hold\"=? FROM customers AS t2 WHERE ( t1.customer_id = t2.id AND t2.banned = ? AND t1.id > ? )";
+    assert_eq!(expected, copy_names_of_banned(Syntax::Sqlite));
+}
+
+#[test]
+fn should_update_a_join_on_mysql() {
+    let expected = "UPDATE orders t1 JOIN customers t2 ON t1.customer_id = t2.id SET t1.customer_name=t2.name, t1.on_hold=? WHERE ( t2.banned = ? AND t1.id > ? )";
+    assert_eq!(expected, copy_names_of_banned(Syntax::Mysql));
+}
+
+#[test]
+fn should_update_from_a_join_on_mssql() {
+    let expected = "UPDATE t1 SET \"customer_name\"=t2.name, \"on_hold\"=@p1 FROM orders t1 JOIN customers t2 ON t1.customer_id = t2.id WHERE ( t2.banned = @p2 AND t1.id > @p3 )";
+    assert_eq!(expected, copy_names_of_banned(Syntax::Mssql));
+}
@@ -1,4 +1,5 @@
 pub mod bulk;
+pub mod join;
 mod single;
 
-pub use single::update_one;
+pub use single::{update_one, update_one_returning};
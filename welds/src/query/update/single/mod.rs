@@ -1,16 +1,19 @@
 use crate::errors::{Result, WeldsError};
 use crate::model_traits::hooks::{AfterUpdate, BeforeUpdate};
-use crate::model_traits::{HasSchema, TableColumns, TableInfo, UpdateFromRow, WriteToArgs};
+use crate::model_traits::{
+    HasSchema, TableColumns, TableInfo, UpdateColumnFromRow, UpdateFromRow, Versioned, WriteToArgs,
+};
 use crate::query::clause::ParamArgs;
 use crate::writers::ColumnWriter;
 use crate::writers::NextParam;
+use crate::{Row, Syntax};
 use welds_connections::Client;
 
 pub async fn update_one<T>(obj: &mut T, client: &dyn Client) -> Result<()>
 where
     T: WriteToArgs + HasSchema,
     <T as HasSchema>::Schema: TableInfo + TableColumns,
-    T: UpdateFromRow,
+    T: UpdateFromRow + Versioned,
     T: AfterUpdate + BeforeUpdate,
 {
     BeforeUpdate::before(obj)?;
@@ -26,13 +29,20 @@ where
     if pks.is_empty() {
         return Err(WeldsError::NoPrimaryKey);
     }
+    let version = T::version_column();
     let mut sets = Vec::default();
 
     for col in columns {
-        if !pks.contains(&col) {
+        // the database fills in the db_default columns, they are never written
+        if !pks.contains(&col) && !col.db_default() {
+            let colname = col_writer.excape(col.name());
+            // the database moves the version forward
+            if Some(col.name()) == version {
+                sets.push(format!("{}={} + 1", colname, colname));
+                continue;
+            }
             obj.bind(col.name(), &mut args)?;
             let p = next_params.next();
-            let colname = col_writer.excape(col.name());
             sets.push(format!("{}={}", colname, p));
         }
     }
@@ -47,13 +57,184 @@ where
         let colname = col_writer.excape(col.name());
         wheres.push(format!("{}={}", colname, p));
     }
+    // only update the row if it is still on the version that was read
+    if let Some(version) = version {
+        obj.bind(version, &mut args)?;
+        let p = next_params.next();
+        wheres.push(format!("{}={}", col_writer.excape(version), p));
+    }
 
     let sets = sets.join(", ");
     let wheres = wheres.join(" AND ");
 
     let sql = format!("UPDATE {} SET {} where {}", identifier, sets, wheres);
 
-    client.execute(&sql, &args).await?;
+    let result = client.execute(&sql, &args).await?;
+    if version.is_some() {
+        if result.rows_affected() == 0 {
+            return Err(WeldsError::StaleObject);
+        }
+        obj.next_version();
+    }
+
+    AfterUpdate::after(obj);
+    Ok(())
+}
+
+/// Updates the model reading back only the `returning` columns into it.
+/// Useful for columns the database changes on update (triggers, versions, etc)
+///
+/// MySQL and Sqlite read the columns with a follow-up SELECT
+pub async fn update_one_returning<T>(
+    obj: &mut T,
+    returning: &[String],
+    client: &dyn Client,
+) -> Result<()>
+where
+    T: WriteToArgs + HasSchema,
+    <T as HasSchema>::Schema: TableInfo + TableColumns,
+    T: UpdateColumnFromRow + Versioned,
+    T: AfterUpdate + BeforeUpdate,
+{
+    BeforeUpdate::before(obj)?;
+
+    let syntax = client.syntax();
+    let mut args: ParamArgs = Vec::default();
+    let mut args2: ParamArgs = Vec::default();
+    let col_writer = ColumnWriter::new(syntax);
+    let next_params = NextParam::new(syntax);
+
+    let identifier = <<T as HasSchema>::Schema>::identifier().join(".");
+    let columns = <<T as HasSchema>::Schema as TableColumns>::columns();
+    let pks = <<T as HasSchema>::Schema as TableColumns>::primary_keys();
+    if pks.is_empty() {
+        return Err(WeldsError::NoPrimaryKey);
+    }
+    let version = T::version_column();
+    let mut sets = Vec::default();
+
+    for col in columns {
+        // the database fills in the db_default columns, they are never written
+        if !pks.contains(&col) && !col.db_default() {
+            let colname = col_writer.excape(col.name());
+            // the database moves the version forward
+            if Some(col.name()) == version {
+                sets.push(format!("{}={} + 1", colname, colname));
+                continue;
+            }
+            obj.bind(col.name(), &mut args)?;
+            let p = next_params.next();
+            sets.push(format!("{}={}", colname, p));
+        }
+    }
+
+    if sets.is_empty() {
+        return Ok(());
+    }
+    let mut wheres = Vec::default();
+    for col in &pks {
+        obj.bind(col.name(), &mut args)?;
+        let p = next_params.next();
+        let colname = col_writer.excape(col.name());
+        wheres.push(format!("{}={}", colname, p));
+    }
+    if let Some(version) = version {
+        obj.bind(version, &mut args)?;
+        let p = next_params.next();
+        wheres.push(format!("{}={}", col_writer.excape(version), p));
+    }
+
+    let sets = sets.join(", ");
+    let wheres = wheres.join(" AND ");
+    // read back the new version, no row means the version had already moved
+    let mut returning = returning.to_vec();
+    if let Some(version) = version {
+        if !returning.iter().any(|c| c == version) {
+            returning.push(version.to_owned());
+        }
+    }
+    let cols: Vec<String> = returning.iter().map(|c| col_writer.excape(c)).collect();
+    let cols = cols.join(", ");
+
+    let (sql, select) = match syntax {
+        _ if returning.is_empty() => (
+            format!("UPDATE {} SET {} where {}", identifier, sets, wheres),
+            None,
+        ),
+        Syntax::Postgres => (
+            format!(
+                "UPDATE {} SET {} where {} RETURNING {}",
+                identifier, sets, wheres, cols
+            ),
+            None,
+        ),
+        Syntax::Mssql => {
+            let outputs: Vec<String> = returning
+                .iter()
+                .map(|c| format!("Inserted.{}", col_writer.excape(c)))
+                .collect();
+            (
+                format!(
+                    "UPDATE {} SET {} OUTPUT {} where {}",
+                    identifier,
+                    sets,
+                    outputs.join(", "),
+                    wheres
+                ),
+                None,
+            )
+        }
+        Syntax::Mysql | Syntax::Sqlite => {
+            // find the row again by its PK
+            let next_params = NextParam::new(syntax);
+            let mut filter = Vec::default();
+            for col in &pks {
+                obj.bind(col.name(), &mut args2)?;
+                let colname = col_writer.excape(col.name());
+                filter.push(format!("{}={}", colname, next_params.next()));
+            }
+            // the row we updated is on the version after the one that was read
+            if let Some(version) = version {
+                obj.bind(version, &mut args2)?;
+                let colname = col_writer.excape(version);
+                filter.push(format!("{}={} + 1", colname, next_params.next()));
+            }
+            let select = format!(
+                "SELECT {} FROM {} where {}",
+                cols,
+                identifier,
+                filter.join(" AND ")
+            );
+            (
+                format!("UPDATE {} SET {} where {}", identifier, sets, wheres),
+                Some(select),
+            )
+        }
+    };
+
+    let mut rows: Vec<Row> = match &select {
+        // The row is only read back when the update found it on the version that was read.
+        // Otherwise the select would find the row someone else moved to the next version
+        Some(select) => {
+            let result = client.execute(&sql, &args).await?;
+            if version.is_some() && result.rows_affected() == 0 {
+                return Err(WeldsError::StaleObject);
+            }
+            client.fetch_rows(select, &args2).await?
+        }
+        None => client.fetch_rows(&sql, &args).await?,
+    };
+
+    if !returning.is_empty() {
+        let missing = match version {
+            Some(_) => WeldsError::StaleObject,
+            None => WeldsError::RowNowFound,
+        };
+        let mut row = rows.pop().ok_or(missing)?;
+        for col in &returning {
+            obj.update_column_from_row(col, &mut row)?;
+        }
+    }
 
     AfterUpdate::after(obj);
     Ok(())
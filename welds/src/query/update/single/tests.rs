@@ -1,6 +1,5 @@
 use crate::state::DbState;
 use crate::Syntax;
-use welds_connections::Row;
 
 // Testing that the tail end of the SQL is correct
 // Limits / skips / orders
@@ -45,3 +44,136 @@ fn changing_a_value_should_update() {
         assert_eq!(expected, &ran_sql);
     });
 }
+
+#[test]
+fn should_be_able_to_pick_the_columns_returned() {
+    futures::executor::block_on(async move {
+        let obj = Product::default();
+        let mut obj = DbState::db_loaded(obj);
+        obj.a = 42;
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        let _ = obj.returning(|p| (p.a, p.b)).save(&client).await;
+        let ran_sql = client
+            .last_sql()
+            .expect("Expected to get SQL back from client");
+        let expected = "UPDATE nums SET \"a\"=$1, \"b\"=$2 where \"id\"=$3 RETURNING \"a\", \"b\"";
+        assert_eq!(expected, &ran_sql);
+    });
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "docs")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Doc {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub body: String,
+    #[welds(version)]
+    pub lock_version: i32,
+}
+
+#[test]
+fn should_only_update_a_versioned_row_on_the_version_read() {
+    futures::executor::block_on(async move {
+        let mut obj = DbState::db_loaded(Doc::default());
+        obj.body = "new".to_owned();
+        let client = welds_connections::noop::build(Syntax::Mysql);
+        let _ = obj.save(&client).await;
+        let ran_sql = client.last_sql().unwrap();
+        let expected =
+            "UPDATE docs SET body=?, lock_version=lock_version + 1 where id=? AND lock_version=?";
+        assert_eq!(expected, &ran_sql);
+        assert_eq!(client.args_count(), Some(3));
+    });
+}
+
+#[test]
+fn should_fail_when_no_row_is_on_the_version_read() {
+    futures::executor::block_on(async move {
+        let mut obj = DbState::db_loaded(Doc::default());
+        obj.body = "new".to_owned();
+        // the noop client never affects any rows
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        let err = obj.save(&client).await.unwrap_err();
+        assert!(matches!(err, crate::WeldsError::StaleObject));
+        assert_eq!(obj.lock_version, 0);
+    });
+}
+
+#[test]
+fn should_read_back_the_version_when_returning() {
+    futures::executor::block_on(async move {
+        let mut obj = DbState::db_loaded(Doc::default());
+        obj.body = "new".to_owned();
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        let err = obj
+            .returning(|d| (d.body,))
+            .save(&client)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::WeldsError::StaleObject));
+        let ran_sql = client.last_sql().unwrap();
+        let expected = "UPDATE docs SET \"body\"=$1, \"lock_version\"=\"lock_version\" + 1 where \"id\"=$2 AND \"lock_version\"=$3 RETURNING \"body\", \"lock_version\"";
+        assert_eq!(expected, &ran_sql);
+    });
+}
+
+#[test]
+fn should_not_read_back_the_row_of_someone_else_on_the_next_version() {
+    use welds_connections::{Client, TransactStart};
+    futures::executor::block_on(async move {
+        let client = welds_connections::sqlite::connect("sqlite::memory:")
+            .await
+            .unwrap();
+        // a transaction keeps everything on the same in memory database
+        let trans = client.begin().await.unwrap();
+        let create = "CREATE TABLE docs (id INTEGER PRIMARY KEY, body TEXT, lock_version INT)";
+        trans.execute(create, &[]).await.unwrap();
+        // someone else has already moved the row from version 0 to 1
+        let theirs = "INSERT INTO docs VALUES (1, 'theirs', 1)";
+        trans.execute(theirs, &[]).await.unwrap();
+
+        let mut obj = DbState::db_loaded(Doc {
+            id: 1,
+            body: "mine".to_owned(),
+            lock_version: 0,
+        });
+        obj.body = "new".to_owned();
+        // the update affects no rows, but a row is on the version after the one read
+        let err = obj.returning(|d| (d.body,)).save(&trans).await.unwrap_err();
+        assert!(matches!(err, crate::WeldsError::StaleObject));
+        assert_eq!(obj.body, "new");
+        assert_eq!(obj.lock_version, 0);
+        trans.rollback().await.unwrap();
+    });
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "events")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Event {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub name: String,
+    #[welds(db_default)]
+    pub created_on: String,
+}
+
+#[test]
+fn should_leave_the_db_default_columns_out_of_updates() {
+    futures::executor::block_on(async move {
+        let mut obj = DbState::db_loaded(Event::default());
+        obj.name = "new".to_owned();
+        let client = welds_connections::noop::build(Syntax::Mysql);
+        obj.save(&client).await.unwrap();
+        let ran_sql = client.last_sql().unwrap();
+        assert_eq!("UPDATE events SET name=? where id=?", &ran_sql);
+
+        obj.name = "newer".to_owned();
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        let _ = obj.returning(|e| (e.created_on,)).save(&client).await;
+        let ran_sql = client.last_sql().unwrap();
+        let expected = "UPDATE events SET \"name\"=$1 where \"id\"=$2 RETURNING \"created_on\"";
+        assert_eq!(expected, &ran_sql);
+    });
+}
@@ -0,0 +1,327 @@
+use crate::errors::Result;
+use crate::errors::WeldsError::InsertFailed;
+use crate::model_traits::hooks::{AfterCreate, BeforeCreate};
+use crate::model_traits::{ColumnDefaultCheck, UpdateFromRow};
+use crate::model_traits::{HasSchema, TableColumns, TableInfo, WriteToArgs};
+use crate::query::clause::{ColumnList, ParamArgs};
+use crate::query::insert;
+use crate::writers::upsert::{UpsertParts, UpsertWriter};
+use crate::writers::{ColumnWriter, NextParam};
+use crate::Row;
+use std::marker::PhantomData;
+use welds_connections::{Client, Fetch};
+
+#[cfg(test)]
+mod tests;
+
+/// How an upsert handles a row that already exists.
+///
+/// By default a conflict is on the primary key, and every other column is updated
+/// ```rust,ignore
+/// user.upsert_with(|on| on.target(|u| (u.email,)).update(|u| (u.name,)), client).await?;
+/// ```
+///
+/// NOTE: MySQL updates on a conflict with any unique key, the target is not used
+pub struct OnConflict<T> {
+    target: Option<Vec<String>>,
+    updates: Option<Vec<String>>,
+    _t: PhantomData<T>,
+}
+
+impl<T> Default for OnConflict<T> {
+    fn default() -> Self {
+        Self {
+            target: None,
+            updates: None,
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<T> OnConflict<T>
+where
+    T: HasSchema,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The columns of the unique index a new row conflicts with
+    pub fn target<V, C>(mut self, lam: impl Fn(<T as HasSchema>::Schema) -> C) -> Self
+    where
+        C: ColumnList<V>,
+    {
+        self.target = Some(lam(Default::default()).colnames());
+        self
+    }
+
+    /// The columns overwritten when the row already exists
+    pub fn update<V, C>(mut self, lam: impl Fn(<T as HasSchema>::Schema) -> C) -> Self
+    where
+        C: ColumnList<V>,
+    {
+        self.updates = Some(lam(Default::default()).colnames());
+        self
+    }
+
+    /// Only "update" the target, so a conflict returns the row that is already there
+    pub(crate) fn or_keep_existing(mut self) -> Self
+    where
+        <T as HasSchema>::Schema: TableColumns,
+    {
+        if self.updates.is_none() {
+            self.updates = Some(self.target_or_pks());
+        }
+        self
+    }
+
+    fn target_or_pks(&self) -> Vec<String>
+    where
+        <T as HasSchema>::Schema: TableColumns,
+    {
+        match &self.target {
+            Some(target) => target.clone(),
+            None => <<T as HasSchema>::Schema as TableColumns>::primary_keys()
+                .iter()
+                .map(|c| c.name().to_string())
+                .collect(),
+        }
+    }
+
+    /// the columns to update, defaults to the written columns that aren't keys or `created_at`.
+    /// When nothing is left the target is "updated" so the row is still returned
+    fn updates_or_rest(&self, written: &[&str], target: &[String]) -> Vec<String>
+    where
+        <T as HasSchema>::Schema: TableColumns,
+    {
+        if let Some(updates) = &self.updates {
+            if !updates.is_empty() {
+                return updates.clone();
+            }
+        }
+        let pks = <<T as HasSchema>::Schema as TableColumns>::primary_keys();
+        // the row keeps the time it was first created
+        let columns = <<T as HasSchema>::Schema as TableColumns>::columns();
+        let created: Vec<&str> = columns
+            .iter()
+            .filter(|c| c.created_at())
+            .map(|c| c.name())
+            .collect();
+        let rest: Vec<String> = written
+            .iter()
+            .filter(|c| !target.iter().any(|t| t == *c))
+            .filter(|c| !pks.iter().any(|p| p.name() == **c))
+            .filter(|c| !created.contains(c))
+            .map(|c| c.to_string())
+            .collect();
+        if rest.is_empty() {
+            return target.to_vec();
+        }
+        rest
+    }
+}
+
+/// Inserts the model, updating the row that is already in the database if there is one.
+/// The row is read back into the model.
+///
+/// A model left with a default primary key (made by the database) can't conflict on it,
+/// it is inserted like normal.
+pub async fn upsert_one<T>(obj: &mut T, on: &OnConflict<T>, client: &dyn Client) -> Result<()>
+where
+    T: WriteToArgs + HasSchema + ColumnDefaultCheck,
+    <T as HasSchema>::Schema: TableInfo + TableColumns,
+    T: UpdateFromRow,
+    T: BeforeCreate + AfterCreate,
+{
+    // the hooks make the uuid keys, they are given to the database like any other key
+    BeforeCreate::before(obj)?;
+
+    let columns = <<T as HasSchema>::Schema as TableColumns>::columns();
+    let pks = <<T as HasSchema>::Schema as TableColumns>::primary_keys();
+    let target = on.target_or_pks();
+
+    let mut id_return_required = false;
+    // the parts of a composite key are always given, even when one of them is 0
+    let composite = pks.len() > 1;
+    for pk in &pks {
+        if !composite && obj.col_is_default(pk.name())? {
+            if target.iter().any(|t| t == pk.name()) {
+                return insert::insert_prepared(obj, client).await;
+            }
+            id_return_required = true;
+        }
+    }
+
+    let syntax = client.syntax();
+    let mut args: ParamArgs = Vec::default();
+    let mut args2: ParamArgs = Vec::default();
+
+    let col_writer = ColumnWriter::new(syntax);
+    let next_params = NextParam::new(syntax);
+    let writer = UpsertWriter::new(syntax);
+    let identifier = <<T as HasSchema>::Schema>::identifier().join(".");
+
+    let mut written = Vec::default();
+    let mut params = Vec::default();
+    for col in &columns {
+        // A PK left as the default value is made by the database
+        if !composite && pks.contains(col) && obj.col_is_default(col.name())? {
+            continue;
+        }
+        // so are the db_default columns, they are read back with the row
+        if !pks.contains(col) && col.db_default() {
+            continue;
+        }
+        obj.bind(col.name(), &mut args)?;
+        written.push(col.name());
+        params.push(next_params.next());
+    }
+
+    // When the PK was given the follow-up select finds the row with the target,
+    // the row that conflicted can have another PK
+    let target_params = if id_return_required {
+        None
+    } else {
+        let next_params = NextParam::new(syntax);
+        let mut params = Vec::default();
+        for col in &target {
+            obj.bind(col, &mut args2)?;
+            params.push(next_params.next());
+        }
+        Some(params)
+    };
+
+    let updates = on.updates_or_rest(&written, &target);
+    let excape = |c: &str| col_writer.excape(c);
+    let parts = UpsertParts {
+        identifier: &identifier,
+        columns: &written.iter().map(|c| excape(c)).collect::<Vec<_>>(),
+        rows: &[format!("({})", params.join(", "))],
+        target: &target.iter().map(|c| excape(c)).collect::<Vec<_>>(),
+        updates: &updates.iter().map(|c| excape(c)).collect::<Vec<_>>(),
+    };
+    let keys = target_params.as_deref().map(|p| (target.as_slice(), p));
+    let (upsert, select) = writer.write_returning(&parts, &columns, &pks, keys)?;
+
+    let mut statements = vec![Fetch {
+        sql: &upsert,
+        params: &args,
+    }];
+    if let Some(select) = &select {
+        statements.push(Fetch {
+            sql: select,
+            params: &args2,
+        })
+    }
+
+    // WARNING: these statements MUST be ran on the same DB connection in the pool
+    let mut datasets = client.fetch_many(&statements).await?;
+    let mut rows: Vec<Row> = datasets.drain(..).flatten().collect();
+    let row = rows.pop();
+    let mut row =
+        row.ok_or_else(|| InsertFailed("Upsert didn't return the upserted Row".to_owned()))?;
+    UpdateFromRow::update_from_row(obj, &mut row)?;
+    AfterCreate::after(obj);
+
+    Ok(())
+}
+
+/// Executes the query in the database Bulk Upserting values.
+///
+/// The primary keys are only written when they are part of the conflict target,
+/// otherwise they are left for the database to make. A composite key is always written
+/// ```rust,ignore
+/// bulk_upsert(client, &users, |on| on.target(|u| (u.email,))).await?;
+/// ```
+///
+/// The hooks of the rows are not ran, use `bulk_upsert_with_hooks` to set their timestamps
+pub async fn bulk_upsert<T>(
+    conn: &dyn Client,
+    data: &[T],
+    on: impl Fn(OnConflict<T>) -> OnConflict<T>,
+) -> Result<()>
+where
+    T: WriteToArgs + HasSchema,
+    <T as HasSchema>::Schema: TableInfo + TableColumns,
+{
+    if data.is_empty() {
+        return Ok(());
+    }
+    let syntax = conn.syntax();
+    let col_writer = ColumnWriter::new(syntax);
+    let writer = UpsertWriter::new(syntax);
+    let identifier = <<T as HasSchema>::Schema>::identifier().join(".");
+    let on = on(OnConflict::new());
+
+    let all_columns = <<T as HasSchema>::Schema as TableColumns>::columns();
+    let pks = <<T as HasSchema>::Schema as TableColumns>::primary_keys();
+    let target = on.target_or_pks();
+    let composite = pks.len() > 1;
+    let columns: Vec<_> = all_columns
+        .iter()
+        .filter(|c| composite || !pks.contains(c) || target.iter().any(|t| t == c.name()))
+        .filter(|c| pks.contains(c) || !c.db_default())
+        .collect();
+
+    let written: Vec<&str> = columns.iter().map(|c| c.name()).collect();
+    let updates = on.updates_or_rest(&written, &target);
+    let excape =
+        |cols: &[String]| -> Vec<String> { cols.iter().map(|c| col_writer.excape(c)).collect() };
+    let colnames: Vec<String> = written.iter().map(|c| col_writer.excape(c)).collect();
+    let target = excape(&target);
+    let updates = excape(&updates);
+
+    // number of rows to upsert per statement
+    let max_params = NextParam::new(syntax).max_params();
+    let chunk_size = (max_params as usize / columns.len()).max(1);
+
+    for chunk in data.chunks(chunk_size) {
+        let next_params = NextParam::new(syntax);
+        let mut args: ParamArgs = Vec::default();
+
+        let mut rows: Vec<String> = Vec::default();
+        for d in chunk {
+            let mut row: Vec<String> = Vec::default();
+            for col in &columns {
+                d.bind(col.name(), &mut args)?;
+                row.push(next_params.next());
+            }
+            rows.push(format!("({})", row.join(",")));
+        }
+        let parts = UpsertParts {
+            identifier: &identifier,
+            columns: &colnames,
+            rows: &rows,
+            target: &target,
+            updates: &updates,
+        };
+        let sql = writer.write(&parts);
+        conn.execute(&sql, &args).await?;
+    }
+    Ok(())
+}
+
+/// Executes the query in the database Bulk Upserting values, running the hooks of each row.
+///
+/// The `BeforeCreate` hooks are ran on each row first, setting its timestamps,
+/// and the `AfterCreate` hooks once all the rows are upserted.
+/// A row that already exists keeps its `created_at`
+pub async fn bulk_upsert_with_hooks<T>(
+    conn: &dyn Client,
+    data: &mut [T],
+    on: impl Fn(OnConflict<T>) -> OnConflict<T>,
+) -> Result<()>
+where
+    T: WriteToArgs + HasSchema,
+    <T as HasSchema>::Schema: TableInfo + TableColumns,
+    T: BeforeCreate + AfterCreate,
+{
+    for d in data.iter_mut() {
+        BeforeCreate::before(d)?;
+    }
+    bulk_upsert(conn, data, on).await?;
+    for d in data.iter() {
+        AfterCreate::after(d);
+    }
+    Ok(())
+}
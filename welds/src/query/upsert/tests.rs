@@ -0,0 +1,263 @@
+use super::{bulk_upsert, bulk_upsert_with_hooks};
+use crate::state::DbState;
+use crate::Syntax;
+use crate::WeldsModel;
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "users")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct User {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub email: String,
+    pub name: String,
+}
+
+fn saved_user() -> DbState<User> {
+    DbState::new_uncreated(User {
+        id: 1,
+        email: "a@example.com".to_owned(),
+        name: "a".to_owned(),
+    })
+}
+
+async fn upsert_sql(syntax: Syntax) -> String {
+    let mut user = saved_user();
+    let client = welds_connections::noop::build(syntax);
+    let _ = user.upsert(&client).await;
+    client.last_sql().unwrap()
+}
+
+#[test]
+fn should_upsert_on_the_primary_key_with_postgres() {
+    futures::executor::block_on(async move {
+        let sql = upsert_sql(Syntax::Postgres).await;
+        let expected = "INSERT INTO users (\"id\", \"email\", \"name\") VALUES ($1, $2, $3) ON CONFLICT (\"id\") DO UPDATE SET \"email\"=EXCLUDED.\"email\", \"name\"=EXCLUDED.\"name\" RETURNING *";
+        assert_eq!(expected, &sql);
+    });
+}
+
+#[test]
+fn should_upsert_on_the_primary_key_with_sqlite() {
+    futures::executor::block_on(async move {
+        let sql = upsert_sql(Syntax::Sqlite).await;
+        let expected = "INSERT INTO users (\"id\", \"email\", \"name\") VALUES (?, ?, ?) ON CONFLICT (\"id\") DO UPDATE SET \"email\"=EXCLUDED.\"email\", \"name\"=EXCLUDED.\"name\" RETURNING *";
+        assert_eq!(expected, &sql);
+    });
+}
+
+#[test]
+fn should_upsert_on_duplicate_key_with_mysql() {
+    futures::executor::block_on(async move {
+        let sql = upsert_sql(Syntax::Mysql).await;
+        let expected = "INSERT INTO users (id, email, name) VALUES (?, ?, ?) ON DUPLICATE KEY UPDATE email=VALUES(email), name=VALUES(name);SELECT * FROM users where id=?";
+        assert_eq!(expected, &sql);
+    });
+}
+
+#[test]
+fn should_upsert_with_merge_on_mssql() {
+    futures::executor::block_on(async move {
+        let sql = upsert_sql(Syntax::Mssql).await;
+        let expected = "MERGE INTO users WITH (HOLDLOCK) AS welds_target USING (VALUES (@p1, @p2, @p3)) AS welds_source (\"id\", \"email\", \"name\") ON welds_target.\"id\"=welds_source.\"id\" WHEN MATCHED THEN UPDATE SET \"email\"=welds_source.\"email\", \"name\"=welds_source.\"name\" WHEN NOT MATCHED THEN INSERT (\"id\", \"email\", \"name\") VALUES (welds_source.\"id\", welds_source.\"email\", welds_source.\"name\") OUTPUT Inserted.\"id\", Inserted.\"email\", Inserted.\"name\";";
+        assert_eq!(expected, &sql);
+    });
+}
+
+#[test]
+fn should_be_able_to_pick_the_target_and_updated_columns() {
+    futures::executor::block_on(async move {
+        let mut user = DbState::new_uncreated(User::default());
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        let _ = user
+            .upsert_with(
+                |on| on.target(|u| (u.email,)).update(|u| (u.name,)),
+                &client,
+            )
+            .await;
+        let sql = client.last_sql().unwrap();
+        let expected = "INSERT INTO users (\"email\", \"name\") VALUES ($1, $2) ON CONFLICT (\"email\") DO UPDATE SET \"name\"=EXCLUDED.\"name\" RETURNING *";
+        assert_eq!(expected, &sql);
+    });
+}
+
+#[test]
+fn mysql_should_read_back_a_made_id_with_last_insert_id() {
+    futures::executor::block_on(async move {
+        let mut user = DbState::new_uncreated(User::default());
+        let client = welds_connections::noop::build(Syntax::Mysql);
+        let _ = user
+            .upsert_with(|on| on.target(|u| (u.email,)), &client)
+            .await;
+        let sql = client.last_sql().unwrap();
+        let expected = "INSERT INTO users (email, name) VALUES (?, ?) ON DUPLICATE KEY UPDATE id=LAST_INSERT_ID(id), name=VALUES(name);SELECT * FROM users where id=LAST_INSERT_ID()";
+        assert_eq!(expected, &sql);
+    });
+}
+
+#[test]
+fn a_made_primary_key_should_be_inserted_like_normal() {
+    futures::executor::block_on(async move {
+        let mut user = DbState::new_uncreated(User::default());
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        let _ = user.upsert(&client).await;
+        let sql = client.last_sql().unwrap();
+        let expected = "INSERT INTO users (\"email\", \"name\") VALUES ($1, $2) RETURNING *";
+        assert_eq!(expected, &sql);
+    });
+}
+
+#[test]
+fn mysql_should_read_back_the_row_on_the_target_when_the_id_is_given() {
+    futures::executor::block_on(async move {
+        let mut user = saved_user();
+        let client = welds_connections::noop::build(Syntax::Mysql);
+        let _ = user
+            .upsert_with(|on| on.target(|u| (u.email,)), &client)
+            .await;
+        let sql = client.last_sql().unwrap();
+        let expected = "INSERT INTO users (id, email, name) VALUES (?, ?, ?) ON DUPLICATE KEY UPDATE name=VALUES(name);SELECT * FROM users where email=?";
+        assert_eq!(expected, &sql);
+    });
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "user_roles")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct UserRole {
+    #[welds(primary_key)]
+    pub user_id: i32,
+    #[welds(primary_key)]
+    pub role_id: i32,
+    pub name: String,
+}
+
+#[test]
+fn should_upsert_a_composite_key_with_a_zero_part() {
+    futures::executor::block_on(async move {
+        let mut role = DbState::new_uncreated(UserRole {
+            user_id: 1,
+            ..Default::default()
+        });
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        let _ = role.upsert(&client).await;
+        let sql = client.last_sql().unwrap();
+        let expected = "INSERT INTO user_roles (\"user_id\", \"role_id\", \"name\") VALUES ($1, $2, $3) ON CONFLICT (\"user_id\", \"role_id\") DO UPDATE SET \"name\"=EXCLUDED.\"name\" RETURNING *";
+        assert_eq!(expected, &sql);
+
+        // the zero part isn't left for the database to make when the target is another column
+        let client = welds_connections::noop::build(Syntax::Mysql);
+        let _ = role
+            .upsert_with(|on| on.target(|r| (r.name,)), &client)
+            .await;
+        let sql = client.last_sql().unwrap();
+        let expected = "INSERT INTO user_roles (user_id, role_id, name) VALUES (?, ?, ?) ON DUPLICATE KEY UPDATE name=VALUES(name);SELECT * FROM user_roles where name=?";
+        assert_eq!(expected, &sql);
+    });
+}
+
+#[test]
+fn should_bulk_upsert_every_part_of_a_composite_key() {
+    futures::executor::block_on(async move {
+        let roles = vec![UserRole::default()];
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        bulk_upsert(&client, &roles, |on| on.target(|r| (r.name,)))
+            .await
+            .unwrap();
+        let sql = client.last_sql().unwrap();
+        let expected = "INSERT INTO user_roles (\"user_id\", \"role_id\", \"name\") VALUES ($1,$2,$3) ON CONFLICT (\"name\") DO UPDATE SET \"name\"=EXCLUDED.\"name\"";
+        assert_eq!(expected, &sql);
+    });
+}
+
+#[test]
+fn should_bulk_upsert_many_rows() {
+    futures::executor::block_on(async move {
+        let users = vec![User::default(), User::default()];
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        bulk_upsert(&client, &users, |on| on.target(|u| (u.email,)))
+            .await
+            .unwrap();
+        let sql = client.last_sql().unwrap();
+        let expected = "INSERT INTO users (\"email\", \"name\") VALUES ($1,$2), ($3,$4) ON CONFLICT (\"email\") DO UPDATE SET \"name\"=EXCLUDED.\"name\"";
+        assert_eq!(expected, &sql);
+    });
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "posts", timestamps)]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Post {
+    #[welds(primary_key)]
+    pub id: i32,
+    pub title: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[test]
+fn should_keep_the_created_at_of_a_row_that_already_exists() {
+    futures::executor::block_on(async move {
+        let mut post = DbState::new_uncreated(Post {
+            id: 1,
+            ..Default::default()
+        });
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        let _ = post.upsert(&client).await;
+        assert_eq!(post.created_at, "now");
+        let sql = client.last_sql().unwrap();
+        let expected = "INSERT INTO posts (\"id\", \"title\", \"created_at\", \"updated_at\") VALUES ($1, $2, $3, $4) ON CONFLICT (\"id\") DO UPDATE SET \"title\"=EXCLUDED.\"title\", \"updated_at\"=EXCLUDED.\"updated_at\" RETURNING *";
+        assert_eq!(expected, &sql);
+    });
+}
+
+#[test]
+fn should_set_the_timestamps_of_bulk_upserted_rows() {
+    futures::executor::block_on(async move {
+        let mut posts = vec![Post::default(), Post::default()];
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        bulk_upsert_with_hooks(&client, &mut posts, |on| on.target(|p| (p.title,)))
+            .await
+            .unwrap();
+        assert!(posts.iter().all(|p| p.created_at == "now"));
+        assert!(posts.iter().all(|p| p.updated_at == "now"));
+        let sql = client.last_sql().unwrap();
+        let expected = "INSERT INTO posts (\"title\", \"created_at\", \"updated_at\") VALUES ($1,$2,$3), ($4,$5,$6) ON CONFLICT (\"title\") DO UPDATE SET \"updated_at\"=EXCLUDED.\"updated_at\"";
+        assert_eq!(expected, &sql);
+    });
+}
+
+#[test]
+fn should_not_run_the_hooks_of_a_bulk_upsert_without_asking() {
+    futures::executor::block_on(async move {
+        let posts = vec![Post::default()];
+        let client = welds_connections::noop::build(Syntax::Postgres);
+        bulk_upsert(&client, &posts, |on| on.target(|p| (p.title,)))
+            .await
+            .unwrap();
+        assert_eq!(posts[0].created_at, "");
+        assert_eq!(posts[0].updated_at, "");
+    });
+}
+
+#[derive(Debug, Default, WeldsModel)]
+#[welds(table = "tasks")]
+#[welds_path(crate)] // needed only within the welds crate.
+struct Task {
+    #[welds(primary_key, uuid_v4)]
+    pub id: String,
+    pub name: String,
+}
+
+#[test]
+fn should_upsert_on_the_uuid_key_made_before_the_upsert() {
+    futures::executor::block_on(async move {
+        let mut task = DbState::new_uncreated(Task::default());
+        let client = welds_connections::noop::build(Syntax::Mysql);
+        let _ = task.upsert(&client).await;
+        assert_eq!(task.id.len(), 36);
+        let sql = client.last_sql().unwrap();
+        let expected = "INSERT INTO tasks (id, name) VALUES (?, ?) ON DUPLICATE KEY UPDATE name=VALUES(name);SELECT * FROM tasks where id=?";
+        assert_eq!(expected, &sql);
+    });
+}
@@ -3,11 +3,14 @@ use crate::model_traits::hooks::{
     AfterCreate, AfterDelete, AfterUpdate, BeforeCreate, BeforeDelete, BeforeUpdate,
 };
 use crate::model_traits::{
-    ColumnDefaultCheck, HasSchema, TableColumns, TableInfo, UpdateFromRow, WriteToArgs,
+    ColumnDefaultCheck, HasSchema, SoftDelete, TableColumns, TableInfo, UpdateColumnFromRow,
+    UpdateFromRow, Versioned, WriteToArgs,
 };
+use crate::query::clause::ColumnList;
 use crate::query::delete;
 use crate::query::insert;
 use crate::query::update;
+use crate::query::upsert::{self, OnConflict};
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 use welds_connections::Client;
@@ -72,7 +75,7 @@ impl<T> DbState<T> {
     where
         T: HasSchema + WriteToArgs + ColumnDefaultCheck,
         <T as HasSchema>::Schema: TableInfo + TableColumns,
-        T: UpdateFromRow,
+        T: UpdateFromRow + Versioned,
         T: BeforeCreate + AfterCreate,
         T: BeforeUpdate + AfterUpdate,
     {
@@ -89,8 +92,83 @@ impl<T> DbState<T> {
         Ok(())
     }
 
+    /// Pick the columns read back from the database into the inner T when it is saved.
+    /// Useful for values the database makes (defaults, triggers, etc)
+    /// ```rust,ignore
+    /// order.returning(|o| (o.id, o.created_at)).save(client).await?;
+    /// ```
+    ///
+    /// MySQL and Sqlite read the columns with a follow-up SELECT
+    pub fn returning<V, C>(
+        &mut self,
+        lam: impl Fn(<T as HasSchema>::Schema) -> C,
+    ) -> Returning<'_, T>
+    where
+        T: HasSchema,
+        C: ColumnList<V>,
+    {
+        let columns = lam(Default::default()).colnames();
+        Returning {
+            state: self,
+            columns,
+        }
+    }
+
+    /// Inserts the inner T, or updates it if the row is already in the database.
+    /// A row already exists when it has the same primary key.
+    /// See `upsert_with` to pick the columns of the conflict
+    pub async fn upsert(&mut self, client: &dyn Client) -> Result<()>
+    where
+        T: HasSchema + WriteToArgs + ColumnDefaultCheck,
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+        T: UpdateFromRow,
+        T: BeforeCreate + AfterCreate,
+    {
+        self.upsert_with(|on| on, client).await
+    }
+
+    /// Inserts the inner T, or updates it if the row conflicts with one already in the database.
+    /// ```rust,ignore
+    /// user.upsert_with(|on| on.target(|u| (u.email,)).update(|u| (u.name,)), client).await?;
+    /// ```
+    pub async fn upsert_with(
+        &mut self,
+        on: impl Fn(OnConflict<T>) -> OnConflict<T>,
+        client: &dyn Client,
+    ) -> Result<()>
+    where
+        T: HasSchema + WriteToArgs + ColumnDefaultCheck,
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+        T: UpdateFromRow,
+        T: BeforeCreate + AfterCreate,
+    {
+        upsert::upsert_one(&mut self.inner, &on(OnConflict::new()), client).await?;
+        self.status = DbStatus::NotModified;
+        Ok(())
+    }
+
     /// Removes the inner T from the database. If T is not in the database no operation will occur
+    ///
+    /// A model with `#[welds(soft_delete = "deleted_at")]` is marked as deleted instead,
+    /// its row stays in the database and can be restored.
     pub async fn delete(&mut self, client: &dyn Client) -> Result<()>
+    where
+        T: HasSchema + WriteToArgs + SoftDelete + Versioned,
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+        T: BeforeDelete + AfterDelete,
+    {
+        if T::soft_delete_column().is_some() && self.status != DbStatus::NotInDatabase {
+            BeforeDelete::before(&self.inner)?;
+            delete::soft_delete_one(&mut self.inner, true, client).await?;
+            AfterDelete::after(&self.inner);
+            self.status = DbStatus::NotModified;
+            return Ok(());
+        }
+        self.force_delete(client).await
+    }
+
+    /// Deletes the model's row from the database, even on a model with soft_delete
+    pub async fn force_delete(&mut self, client: &dyn Client) -> Result<()>
     where
         T: HasSchema + WriteToArgs,
         <T as HasSchema>::Schema: TableInfo + TableColumns,
@@ -109,6 +187,21 @@ impl<T> DbState<T> {
         Ok(())
     }
 
+    /// Brings back a soft deleted model, clearing the time it was deleted
+    /// ```rust,ignore
+    /// let mut post = Post::only_deleted().where_col(|p| p.id.equal(1)).run(client).await?.pop()?;
+    /// post.restore(client).await?;
+    /// ```
+    pub async fn restore(&mut self, client: &dyn Client) -> Result<()>
+    where
+        T: HasSchema + WriteToArgs + SoftDelete + Versioned,
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+    {
+        delete::soft_delete_one(&mut self.inner, false, client).await?;
+        self.status = DbStatus::NotModified;
+        Ok(())
+    }
+
     /// Consumes the DbState, returning the wrapped value. The inner value is nolonger connected to
     /// welds and can nolonger be saved/created/deleted
     pub fn into_inner(self) -> T {
@@ -131,6 +224,38 @@ impl<T> DbState<T> {
     }
 }
 
+/// Saves a DbState reading back only the picked columns. See `DbState::returning`
+pub struct Returning<'s, T> {
+    state: &'s mut DbState<T>,
+    columns: Vec<String>,
+}
+
+impl<T> Returning<'_, T> {
+    /// Saves the inner T to the database, reading back the picked columns.
+    /// If no change has been detected on the inner T, No operation will occur
+    pub async fn save(self, client: &dyn Client) -> Result<()>
+    where
+        T: HasSchema + WriteToArgs + ColumnDefaultCheck,
+        <T as HasSchema>::Schema: TableInfo + TableColumns,
+        T: UpdateColumnFromRow + Versioned,
+        T: BeforeCreate + AfterCreate,
+        T: BeforeUpdate + AfterUpdate,
+    {
+        let state = self.state;
+        match state.status {
+            DbStatus::NotModified => {}
+            DbStatus::Edited => {
+                update::update_one_returning(&mut state.inner, &self.columns, client).await?;
+            }
+            DbStatus::NotInDatabase => {
+                insert::insert_one_returning(&mut state.inner, &self.columns, client).await?;
+            }
+        }
+        state.status = DbStatus::NotModified;
+        Ok(())
+    }
+}
+
 impl<T> Deref for DbState<T> {
     type Target = T;
     fn deref(&self) -> &Self::Target {
@@ -0,0 +1,122 @@
+use crate::query::clause::ParamArgs;
+use crate::Syntax;
+use std::iter::Peekable;
+use std::str::Chars;
+use welds_connections::debug_literal;
+
+/// Writes the params into the SQL in place of their placeholders.
+///
+/// For reading and pasting into a database console only, never run the result.
+/// A param that can't be written out leaves its placeholder in place.
+pub(crate) fn inline_params(syntax: Syntax, sql: &str, params: &ParamArgs) -> String {
+    let literals: Vec<Option<String>> = params.iter().map(|p| debug_literal(syntax, *p)).collect();
+
+    let mut out = String::with_capacity(sql.len());
+    let mut chars = sql.chars().peekable();
+    let mut quote: Option<char> = None;
+    let mut next = 0;
+    while let Some(c) = chars.next() {
+        // placeholders inside strings and quoted names are just text
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+            out.push(c);
+            continue;
+        }
+        if c == '\'' || c == '"' {
+            quote = Some(c);
+            out.push(c);
+            continue;
+        }
+
+        let placeholder = match (syntax, c) {
+            (Syntax::Mysql | Syntax::Sqlite, '?') => {
+                next += 1;
+                Some((next, c.to_string()))
+            }
+            (Syntax::Postgres, '$') => Some(numbered(&mut chars, "$")),
+            (Syntax::Mssql, '@') if chars.peek() == Some(&'p') => {
+                chars.next();
+                Some(numbered(&mut chars, "@p"))
+            }
+            _ => None,
+        };
+        let (n, text) = match placeholder {
+            Some(placeholder) => placeholder,
+            None => {
+                out.push(c);
+                continue;
+            }
+        };
+        let literal = n.checked_sub(1).and_then(|i| literals.get(i)?.as_ref());
+        match literal {
+            Some(literal) => out.push_str(literal),
+            None => out.push_str(&text),
+        }
+    }
+    out
+}
+
+/// reads the number of a placeholder like `$3`. Zero if there isn't one
+fn numbered(chars: &mut Peekable<Chars>, prefix: &str) -> (usize, String) {
+    let mut digits = String::default();
+    while let Some(d) = chars.peek().filter(|d| d.is_ascii_digit()) {
+        digits.push(*d);
+        chars.next();
+    }
+    let n = digits.parse().unwrap_or_default();
+    (n, format!("{}{}", prefix, digits))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_write_params_in_order_for_question_marks() {
+        let id = 5_i64;
+        let name = "it's".to_string();
+        let params: ParamArgs = vec![&id, &name];
+        let sql = "SELECT * FROM t1 WHERE t1.id = ? AND t1.name = ?";
+        let expected = "SELECT * FROM t1 WHERE t1.id = 5 AND t1.name = 'it''s'";
+        assert_eq!(expected, inline_params(Syntax::Sqlite, sql, &params));
+        assert_eq!(expected, inline_params(Syntax::Mysql, sql, &params));
+    }
+
+    #[test]
+    fn should_write_params_by_their_number() {
+        let params: Vec<i32> = (1..=10).collect();
+        let params: ParamArgs = params.iter().map(|p| p as _).collect();
+        let sql = "SELECT $10, $1, $2";
+        assert_eq!(
+            "SELECT 10, 1, 2",
+            inline_params(Syntax::Postgres, sql, &params)
+        );
+        let sql = "SELECT @p10, @p1, @ptr";
+        assert_eq!(
+            "SELECT 10, 1, @ptr",
+            inline_params(Syntax::Mssql, sql, &params)
+        );
+    }
+
+    #[test]
+    fn should_not_write_params_inside_strings() {
+        let id = 5_i64;
+        let params: ParamArgs = vec![&id];
+        let sql = "SELECT '?' as \"what?\" FROM t1 WHERE t1.id = ?";
+        let expected = "SELECT '?' as \"what?\" FROM t1 WHERE t1.id = 5";
+        assert_eq!(expected, inline_params(Syntax::Sqlite, sql, &params));
+    }
+
+    #[test]
+    fn should_write_nulls_and_leave_missing_params() {
+        let missing: Option<i64> = None;
+        let params: ParamArgs = vec![&missing];
+        let sql = "SELECT $1, $2";
+        assert_eq!(
+            "SELECT NULL, $2",
+            inline_params(Syntax::Postgres, sql, &params)
+        );
+    }
+}
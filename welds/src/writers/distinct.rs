@@ -0,0 +1,28 @@
+use super::ColumnWriter;
+use crate::Syntax;
+
+/// How the rows of a SELECT are de-duplicated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Distinct {
+    /// SELECT DISTINCT
+    Rows,
+    /// SELECT DISTINCT ON (...) (Postgres only)
+    On(Vec<String>),
+}
+
+impl Distinct {
+    /// writes the part of the SELECT that goes before the list of columns
+    pub(crate) fn write(&self, syntax: Syntax, alias: &str) -> String {
+        match self {
+            Distinct::Rows => "DISTINCT".to_owned(),
+            Distinct::On(cols) => {
+                let writer = ColumnWriter::new(syntax);
+                let cols: Vec<String> = cols
+                    .iter()
+                    .map(|c| format!("{}.{}", alias, writer.excape(c)))
+                    .collect();
+                format!("DISTINCT ON ({})", cols.join(", "))
+            }
+        }
+    }
+}
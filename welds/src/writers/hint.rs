@@ -0,0 +1,148 @@
+use crate::writers::lock::Lock;
+use crate::Syntax;
+
+/// A hint to the query planner of the database, for the rare query it plans badly.
+///
+/// Each hint is only written for the databases that have it, the others ignore it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Hint {
+    /// Ask the database to use an index.
+    /// `USE INDEX (name)` on MySQL, `WITH (INDEX(name))` on MSSQL, `INDEXED BY name` on Sqlite
+    UseIndex(String),
+    /// Only use this index, never scan the table.
+    /// `FORCE INDEX (name)` on MySQL, `WITH (INDEX(name))` on MSSQL, `INDEXED BY name` on Sqlite
+    ForceIndex(String),
+    /// A query option added to the end of the query. I.E. `OPTION (RECOMPILE)` on MSSQL
+    QueryOption(String),
+    /// An optimizer hint comment after the SELECT. I.E. `/*+ NO_INDEX_MERGE(t1) */`
+    /// Used by MySQL and pg_hint_plan on Postgres, written for every database
+    Comment(String),
+}
+
+impl Hint {
+    pub fn use_index(name: impl Into<String>) -> Self {
+        Hint::UseIndex(name.into())
+    }
+
+    pub fn force_index(name: impl Into<String>) -> Self {
+        Hint::ForceIndex(name.into())
+    }
+
+    pub fn query_option(option: impl Into<String>) -> Self {
+        Hint::QueryOption(option.into())
+    }
+
+    pub fn comment(hint: impl Into<String>) -> Self {
+        Hint::Comment(hint.into())
+    }
+}
+
+/// The SELECT keyword, followed by the hint comments if there are any
+pub(crate) fn select(hints: &[Hint]) -> String {
+    let comments: Vec<&str> = hints
+        .iter()
+        .filter_map(|h| match h {
+            Hint::Comment(c) => Some(c.as_str()),
+            _ => None,
+        })
+        .collect();
+    if comments.is_empty() {
+        return "SELECT".to_owned();
+    }
+    format!("SELECT /*+ {} */", comments.join(" "))
+}
+
+/// The hints written after the table in the FROM.
+/// On MSSQL these are merged with the table hints of the lock into a single `WITH (...)`
+pub(crate) fn table(syntax: Syntax, hints: &[Hint], lock: &Option<Lock>) -> Option<String> {
+    let indexes = hints.iter().filter_map(|h| match h {
+        Hint::UseIndex(name) => Some((false, name)),
+        Hint::ForceIndex(name) => Some((true, name)),
+        _ => None,
+    });
+    let parts: Vec<String> = match syntax {
+        Syntax::Mysql => indexes
+            .map(|(force, name)| match force {
+                true => format!("FORCE INDEX ({})", name),
+                false => format!("USE INDEX ({})", name),
+            })
+            .collect(),
+        Syntax::Sqlite => indexes
+            .map(|(_, name)| format!("INDEXED BY {}", name))
+            .collect(),
+        Syntax::Mssql => {
+            let mut table_hints = lock.map(|l| l.table_hints()).unwrap_or_default();
+            table_hints.extend(indexes.map(|(_, name)| format!("INDEX({})", name)));
+            if table_hints.is_empty() {
+                return None;
+            }
+            vec![format!("WITH ({})", table_hints.join(", "))]
+        }
+        Syntax::Postgres => Vec::default(),
+    };
+    if parts.is_empty() {
+        return None;
+    }
+    Some(parts.join(" "))
+}
+
+/// The query options written at the end of the query. I.E. `OPTION (RECOMPILE, MAXDOP 1)`
+pub(crate) fn options(syntax: Syntax, hints: &[Hint]) -> Option<String> {
+    if syntax != Syntax::Mssql {
+        return None;
+    }
+    let options: Vec<&str> = hints
+        .iter()
+        .filter_map(|h| match h {
+            Hint::QueryOption(o) => Some(o.as_str()),
+            _ => None,
+        })
+        .collect();
+    if options.is_empty() {
+        return None;
+    }
+    Some(format!("OPTION ({})", options.join(", ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writers::LockMode;
+
+    #[test]
+    fn should_write_index_hints_for_each_database() {
+        let hints = vec![Hint::use_index("idx_a"), Hint::force_index("idx_b")];
+        let mysql = table(Syntax::Mysql, &hints, &None);
+        assert_eq!(
+            Some("USE INDEX (idx_a) FORCE INDEX (idx_b)"),
+            mysql.as_deref()
+        );
+        let mssql = table(Syntax::Mssql, &hints, &None);
+        assert_eq!(Some("WITH (INDEX(idx_a), INDEX(idx_b))"), mssql.as_deref());
+        assert_eq!(None, table(Syntax::Postgres, &hints, &None));
+    }
+
+    #[test]
+    fn should_merge_index_hints_with_lock_hints_on_mssql() {
+        let hints = vec![Hint::use_index("idx_a")];
+        let lock = Some(Lock::new(LockMode::ForUpdate));
+        let mssql = table(Syntax::Mssql, &hints, &lock);
+        assert_eq!(
+            Some("WITH (UPDLOCK, ROWLOCK, INDEX(idx_a))"),
+            mssql.as_deref()
+        );
+    }
+
+    #[test]
+    fn should_write_comments_and_options() {
+        let hints = vec![
+            Hint::comment("NO_INDEX_MERGE(t1)"),
+            Hint::query_option("RECOMPILE"),
+            Hint::query_option("MAXDOP 1"),
+        ];
+        assert_eq!("SELECT /*+ NO_INDEX_MERGE(t1) */", select(&hints));
+        assert_eq!("SELECT", select(&[]));
+        let options = options(Syntax::Mssql, &hints);
+        assert_eq!(Some("OPTION (RECOMPILE, MAXDOP 1)"), options.as_deref());
+    }
+}
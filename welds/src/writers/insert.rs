@@ -27,6 +27,118 @@ impl InsertWriter {
             Syntax::Mssql => Mssql::write(identifier, colargs, columns, pks),
         }
     }
+
+    /// Writes an insert that returns only the `returning` columns.
+    ///
+    /// MySQL and Sqlite can't return from the insert, a follow-up select is written.
+    /// It finds the row with `pk_params` when the PK is given, or the last insert id if not.
+    pub fn write_returning(
+        &self,
+        identifier: &str,
+        colargs: &[ColArg],
+        returning: &[String],
+        pks: &[Column],
+        pk_params: Option<&[String]>,
+    ) -> Sql {
+        let col_write = ColumnWriter::new(self.syntax);
+        let cols: Vec<_> = colargs.iter().map(|x| x.0.as_str()).collect();
+        let args: Vec<_> = colargs.iter().map(|x| x.1.as_str()).collect();
+        let col_group = cols.join(", ");
+        let arg_group = args.join(", ");
+        let returning: Vec<String> = returning.iter().map(|c| col_write.excape(c)).collect();
+
+        if returning.is_empty() {
+            let insert = format!(
+                "INSERT INTO {} ({}) VALUES ({})",
+                identifier, col_group, arg_group
+            );
+            return (insert, None);
+        }
+
+        match self.syntax {
+            Syntax::Postgres => (
+                format!(
+                    "INSERT INTO {} ({}) VALUES ({}) RETURNING {}",
+                    identifier,
+                    col_group,
+                    arg_group,
+                    returning.join(", ")
+                ),
+                None,
+            ),
+            Syntax::Mssql => {
+                let outputs: Vec<String> = returning
+                    .iter()
+                    .map(|c| format!("Inserted.{}", c))
+                    .collect();
+                (
+                    format!(
+                        "INSERT INTO {} ({}) OUTPUT {} VALUES ({})",
+                        identifier,
+                        col_group,
+                        outputs.join(", "),
+                        arg_group
+                    ),
+                    None,
+                )
+            }
+            Syntax::Mysql | Syntax::Sqlite => {
+                let insert = format!(
+                    "INSERT INTO {} ({}) VALUES ({})",
+                    identifier, col_group, arg_group
+                );
+                let filter = match pk_params {
+                    Some(params) => pk_filter(&col_write, pks, params),
+                    None => {
+                        assert!(
+                            pks.len() == 1,
+                            "Error: A single primary key is required for insert"
+                        );
+                        let last_id = match self.syntax {
+                            Syntax::Mysql => "LAST_INSERT_ID()",
+                            _ => "last_insert_rowid()",
+                        };
+                        format!("{}={}", pks[0].name(), last_id)
+                    }
+                };
+                let fetch = format!(
+                    "SELECT {} FROM {} where {}",
+                    returning.join(", "),
+                    identifier,
+                    filter
+                );
+                (insert, Some(fetch))
+            }
+        }
+    }
+
+    /// Writes the follow-up select reading back the whole row inserted with the given PK.
+    /// None for the databases that return the row from the insert itself
+    pub fn write_refresh(
+        &self,
+        identifier: &str,
+        pks: &[Column],
+        pk_params: &[String],
+    ) -> Option<String> {
+        match self.syntax {
+            Syntax::Postgres | Syntax::Mssql => None,
+            Syntax::Mysql | Syntax::Sqlite => {
+                let col_write = ColumnWriter::new(self.syntax);
+                let filter = pk_filter(&col_write, pks, pk_params);
+                Some(format!("SELECT * FROM {} where {}", identifier, filter))
+            }
+        }
+    }
+}
+
+/// finds a row by each of its primary keys. I.E. `id=?`
+fn pk_filter(col_write: &ColumnWriter, pks: &[Column], params: &[String]) -> String {
+    let parts: Vec<String> = pks
+        .iter()
+        .zip(params)
+        .map(|(pk, p)| format!("{}={}", col_write.excape(pk.name()), p))
+        .collect();
+    parts.join(" AND ")
 }
 
 struct Postgres;
@@ -0,0 +1,69 @@
+use crate::Syntax;
+
+/// The kind of lock taken on the rows read by a SELECT
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockMode {
+    /// Lock the rows so other transactions can't change or lock them (FOR UPDATE)
+    ForUpdate,
+    /// Lock the rows so other transactions can read but not change them (FOR SHARE)
+    ForShare,
+}
+
+/// What to do when a row is already locked by another transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LockWait {
+    /// Wait for the other transaction (default)
+    Wait,
+    /// Error right away (NOWAIT)
+    NoWait,
+    /// Leave the locked rows out of the results (SKIP LOCKED)
+    SkipLocked,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Lock {
+    pub(crate) mode: LockMode,
+    pub(crate) wait: LockWait,
+}
+
+impl Lock {
+    pub(crate) fn new(mode: LockMode) -> Self {
+        Self {
+            mode,
+            wait: LockWait::Wait,
+        }
+    }
+
+    /// MSSQL locks with table hints after the table in the FROM. I.E. `WITH (UPDLOCK, ROWLOCK)`
+    /// They are written along with any other table hints, see `hint::table`
+    pub(crate) fn table_hints(&self) -> Vec<String> {
+        let mut hints = vec![match self.mode {
+            LockMode::ForUpdate => "UPDLOCK",
+            LockMode::ForShare => "HOLDLOCK",
+        }];
+        hints.push("ROWLOCK");
+        match self.wait {
+            LockWait::Wait => {}
+            LockWait::NoWait => hints.push("NOWAIT"),
+            LockWait::SkipLocked => hints.push("READPAST"),
+        }
+        hints.into_iter().map(|h| h.to_owned()).collect()
+    }
+
+    /// Postgres and MySQL lock at the end of the SELECT. I.E. `FOR UPDATE SKIP LOCKED`
+    /// Sqlite locks the whole database when writing, there are no row locks.
+    pub(crate) fn suffix(&self, syntax: Syntax) -> Option<String> {
+        if syntax != Syntax::Postgres && syntax != Syntax::Mysql {
+            return None;
+        }
+        let mode = match self.mode {
+            LockMode::ForUpdate => "FOR UPDATE",
+            LockMode::ForShare => "FOR SHARE",
+        };
+        Some(match self.wait {
+            LockWait::Wait => mode.to_string(),
+            LockWait::NoWait => format!("{} NOWAIT", mode),
+            LockWait::SkipLocked => format!("{} SKIP LOCKED", mode),
+        })
+    }
+}
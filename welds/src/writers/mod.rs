@@ -1,13 +1,20 @@
 pub(crate) mod alias;
 pub(crate) mod column;
 pub(crate) mod count;
+pub(crate) mod debug;
+pub(crate) mod distinct;
+pub(crate) mod hint;
 pub(crate) mod insert;
 pub(crate) mod limit_skip;
+pub(crate) mod lock;
 pub(crate) mod nextparam;
 pub mod types;
+pub(crate) mod upsert;
 
 pub use column::ColumnWriter;
 pub use count::CountWriter;
+pub use hint::Hint;
 pub use insert::InsertWriter;
 pub use limit_skip::LimitSkipWriter;
+pub use lock::LockMode;
 pub use nextparam::NextParam;
@@ -1,4 +1,11 @@
 use crate::Syntax;
+use std::sync::{Arc, RwLock};
+
+/// Extra pairs registered by the app with `register_pair`
+static CUSTOM_PAIRS: RwLock<Vec<(Syntax, Pair)>> = RwLock::new(Vec::new());
+
+/// The pairs of each syntax built by `get_pairs`, cleared when a pair is registered
+static PAIRS: RwLock<Vec<(Syntax, Arc<[Pair]>)>> = RwLock::new(Vec::new());
 
 #[derive(Debug, Clone)]
 pub struct Pair {
@@ -58,6 +65,12 @@ impl Pair {
         }
     }
 
+    /// A pair for a type welds doesn't know about. I.E. a domain or a type from an extension.
+    /// Register it with `register_pair` so check and migrations know to use it.
+    pub const fn custom(db_type: &'static str, rust_type: &'static str) -> Pair {
+        Pair::new(db_type, rust_type)
+    }
+
     /// returns true is this Pair should ONLY be used in a PK column type.
     pub fn id_only(&self) -> bool {
         self.id_only
@@ -105,7 +118,7 @@ impl Pair {
 
     /// returns true is this Pair matches a db_type and rust_type
     pub fn matches(&self, db: &str, rust: &str) -> bool {
-        self.db_type() == db && self.is_rust_type(rust)
+        self.db_type().eq_ignore_ascii_case(db) && self.is_rust_type(rust)
     }
 
     /// Returns true the Pair matches a given rust type
@@ -131,14 +144,59 @@ impl Pair {
 /// A pair can be assumed to be usable together in welds
 /// I.E.  INT <=> i32
 /// a model with a type i32 will work with a db_type of INT
-pub fn get_pairs(syntax: Syntax) -> Vec<Pair> {
-    let base_pairs = get_basic_type_pairs(syntax);
-    let arrays = base_pairs.iter().map(|p| {
-        let mut a = p.clone();
-        a.array = true;
-        a
-    });
-    base_pairs.iter().cloned().chain(arrays).collect()
+/// The list is built once for each syntax, and again after a `register_pair`
+pub fn get_pairs(syntax: Syntax) -> Arc<[Pair]> {
+    {
+        let cached = PAIRS.read().unwrap_or_else(|e| e.into_inner());
+        if let Some((_, pairs)) = cached.iter().find(|(s, _)| *s == syntax) {
+            return pairs.clone();
+        }
+    }
+    let pairs: Arc<[Pair]> = build_pairs(syntax, &get_custom_pairs(syntax)).into();
+    let mut cached = PAIRS.write().unwrap_or_else(|e| e.into_inner());
+    cached.retain(|(s, _)| *s != syntax);
+    cached.push((syntax, pairs.clone()));
+    pairs
+}
+
+/// The built in pairs followed by the custom pairs, and the array version of each of them
+fn build_pairs(syntax: Syntax, custom: &[Pair]) -> Vec<Pair> {
+    let mut base_pairs = get_basic_type_pairs(syntax).to_vec();
+    base_pairs.extend_from_slice(custom);
+    let arrays: Vec<Pair> = base_pairs
+        .iter()
+        .map(|p| {
+            let mut a = p.clone();
+            a.array = true;
+            a
+        })
+        .collect();
+    base_pairs.into_iter().chain(arrays).collect()
+}
+
+/// Tells welds a DB_TYPE and RUST_TYPE can be used together.
+/// Used for types welds doesn't know about. I.E. domains and types from extensions
+/// ```rust,ignore
+/// register_pair(Syntax::Postgres, Pair::custom("CITEXT", "String"));
+/// ```
+/// Registered pairs are used by `check`, `migrations`, and everywhere else types are compared.
+/// They are only used after the built in pairs, so they will not change the type picked for a built in type.
+pub fn register_pair(syntax: Syntax, pair: Pair) {
+    let mut pairs = CUSTOM_PAIRS.write().unwrap_or_else(|e| e.into_inner());
+    pairs.push((syntax, pair));
+    // the next get_pairs builds the list again with the new pair
+    let mut cached = PAIRS.write().unwrap_or_else(|e| e.into_inner());
+    cached.retain(|(s, _)| *s != syntax);
+}
+
+/// All the pairs that have been registered for a syntax with `register_pair`
+pub fn get_custom_pairs(syntax: Syntax) -> Vec<Pair> {
+    let pairs = CUSTOM_PAIRS.read().unwrap_or_else(|e| e.into_inner());
+    pairs
+        .iter()
+        .filter(|(s, _)| *s == syntax)
+        .map(|(_, p)| p.clone())
+        .collect()
 }
 
 /// Same as get_pairs but doesn't include arrays types
@@ -173,6 +231,7 @@ const MSSQL_PAIRS: &[Pair] = &[
     Pair::sized("VARBINARY", "Vec<u8>", "MAX"),
     Pair::new("UNIQUEIDENTIFIER", "Uuid"),
     Pair::new("JSON", "serde_json::Value"),
+    Pair::sized("NVARCHAR", "serde_json::Value", "MAX"),
 ];
 
 const MYSQL_PAIRS: &[Pair] = &[
@@ -280,6 +339,7 @@ const POSTGRES_PAIRS: &[Pair] = &[
     Pair::new("TIME", "chrono::NaiveTime"),
     Pair::new("TIMETZ", "sqlx::postgres::types::PgTimeTz"),
     Pair::new("JSONB", "serde_json::Value"),
+    Pair::new("JSON", "serde_json::Value"),
 ];
 
 /// Returns true if two types are a match
@@ -324,18 +384,22 @@ pub(crate) fn pk_override(syntax: Syntax, db_type: &str) -> Option<&'static str>
 
 /// Returns the recommenced rust type to use for a given Database type.
 pub fn recommended_rust_type(syntax: Syntax, db_type: &str) -> Option<Pair> {
+    let pairs = get_pairs(syntax);
+    recommended_rust_type_in(&pairs, db_type)
+}
+
+/// Same as recommended_rust_type but only looks in the given pairs
+pub(crate) fn recommended_rust_type_in(pairs: &[Pair], db_type: &str) -> Option<Pair> {
     // find the root of the type VARCHAR from VARCHAR(MAX)
+    let db_type = db_type.trim().to_uppercase();
     let base = match db_type.find('(') {
         Some(index) => &db_type[..index],
-        None => db_type,
+        None => db_type.as_str(),
     };
-
-    let pairs = get_pairs(syntax);
-    let db_type = db_type.trim().to_uppercase();
     for pair in pairs {
         let pair_type = pair.db_type();
         if base == pair_type || db_type == pair_type {
-            return Some(pair);
+            return Some(pair.clone());
         }
     }
     None
@@ -372,6 +436,33 @@ mod tests {
         assert!(are_equivalent_types(&pairs, "MONEY", "PgMoney"));
     }
 
+    #[test]
+    fn should_use_registered_pairs() {
+        // built without registering, the type is never seen by the other tests
+        let custom = [Pair::custom("WELDS_TEST_PAIR_DOMAIN", "String")];
+        let pairs = build_pairs(Syntax::Mysql, &[]);
+        assert!(!are_equivalent_types(
+            &pairs,
+            "WELDS_TEST_PAIR_DOMAIN",
+            "String"
+        ));
+
+        let pairs = build_pairs(Syntax::Mysql, &custom);
+        assert!(are_equivalent_types(
+            &pairs,
+            "welds_test_pair_domain",
+            "String"
+        ));
+        assert!(are_equivalent_types(
+            &pairs,
+            "WELDS_TEST_PAIR_DOMAIN[]",
+            "Vec<String>"
+        ));
+        // built in pairs still come first
+        let first = pairs.iter().find(|p| p.is_rust_type("String")).unwrap();
+        assert_ne!(first.db_type(), "WELDS_TEST_PAIR_DOMAIN");
+    }
+
     #[test]
     fn should_recommend_good_rust_types() {
         let s = Syntax::Postgres;
@@ -0,0 +1,196 @@
+use super::column::ColumnWriter;
+use crate::errors::{Result, WeldsError};
+use crate::model_traits::Column;
+use crate::Syntax;
+
+type Sql = (String, Option<String>);
+
+/// The pieces of an upsert. All the column names are expected to already be excaped
+pub struct UpsertParts<'a> {
+    pub identifier: &'a str,
+    pub columns: &'a [String],
+    /// the params of each row. I.E. `($1, $2)`
+    pub rows: &'a [String],
+    /// the columns that conflict with an existing row
+    pub target: &'a [String],
+    /// the columns overwritten when a row already exists
+    pub updates: &'a [String],
+}
+
+pub struct UpsertWriter {
+    syntax: Syntax,
+}
+
+impl UpsertWriter {
+    pub fn new(syntax: Syntax) -> Self {
+        Self { syntax }
+    }
+
+    /// Writes an upsert that doesn't read back the rows
+    pub fn write(&self, parts: &UpsertParts) -> String {
+        match self.syntax {
+            Syntax::Postgres | Syntax::Sqlite => OnConflict::write(parts, None),
+            Syntax::Mysql => MySql::write(parts, &[]),
+            Syntax::Mssql => Mssql::write(parts, None),
+        }
+    }
+
+    /// Writes an upsert of a single row that reads back the row.
+    ///
+    /// MySQL can't return from the insert, a follow-up select is written.
+    /// It finds the row with `keys` (the columns and their params) when the PK is given,
+    /// or the last insert id of the single PK if not.
+    pub fn write_returning(
+        &self,
+        parts: &UpsertParts,
+        columns: &[Column],
+        pks: &[Column],
+        keys: Option<(&[String], &[String])>,
+    ) -> Result<Sql> {
+        let col_write = ColumnWriter::new(self.syntax);
+        match self.syntax {
+            Syntax::Postgres | Syntax::Sqlite => Ok((OnConflict::write(parts, Some("*")), None)),
+            Syntax::Mssql => {
+                let outputs: Vec<String> = columns
+                    .iter()
+                    .map(|c| col_write.write("Inserted", c))
+                    .collect();
+                Ok((Mssql::write(parts, Some(&outputs.join(", "))), None))
+            }
+            Syntax::Mysql => {
+                let (extra, filter) = match keys {
+                    Some((cols, params)) => {
+                        let parts: Vec<String> = cols
+                            .iter()
+                            .zip(params)
+                            .map(|(c, p)| format!("{}={}", col_write.excape(c), p))
+                            .collect();
+                        (Vec::default(), parts.join(" AND "))
+                    }
+                    None => {
+                        if pks.len() != 1 {
+                            let msg = "A single primary key is required to read back an upsert";
+                            return Err(WeldsError::InsertFailed(msg.to_owned()));
+                        }
+                        // point LAST_INSERT_ID() at the existing row when it is updated
+                        let pk = col_write.excape(pks[0].name());
+                        let extra = vec![format!("{}=LAST_INSERT_ID({})", pk, pk)];
+                        (extra, format!("{}=LAST_INSERT_ID()", pk))
+                    }
+                };
+                let fetch = format!("SELECT * FROM {} where {}", parts.identifier, filter);
+                Ok((MySql::write(parts, &extra), Some(fetch)))
+            }
+        }
+    }
+}
+
+struct OnConflict;
+
+impl OnConflict {
+    fn write(parts: &UpsertParts, returning: Option<&str>) -> String {
+        let sets: Vec<String> = parts
+            .updates
+            .iter()
+            .map(|c| format!("{}=EXCLUDED.{}", c, c))
+            .collect();
+        let mut sql = format!(
+            "INSERT INTO {} ({}) VALUES {} ON CONFLICT ({}) DO UPDATE SET {}",
+            parts.identifier,
+            parts.columns.join(", "),
+            parts.rows.join(", "),
+            parts.target.join(", "),
+            sets.join(", ")
+        );
+        if let Some(returning) = returning {
+            sql.push_str(" RETURNING ");
+            sql.push_str(returning);
+        }
+        sql
+    }
+}
+
+struct MySql;
+
+impl MySql {
+    // MySQL updates on a conflict with any unique key, the target isn't used
+    fn write(parts: &UpsertParts, extra: &[String]) -> String {
+        let sets: Vec<String> = extra
+            .iter()
+            .cloned()
+            .chain(parts.updates.iter().map(|c| format!("{}=VALUES({})", c, c)))
+            .collect();
+        format!(
+            "INSERT INTO {} ({}) VALUES {} ON DUPLICATE KEY UPDATE {}",
+            parts.identifier,
+            parts.columns.join(", "),
+            parts.rows.join(", "),
+            sets.join(", ")
+        )
+    }
+}
+
+struct Mssql;
+
+impl Mssql {
+    fn write(parts: &UpsertParts, output: Option<&str>) -> String {
+        let on: Vec<String> = parts
+            .target
+            .iter()
+            .map(|c| format!("welds_target.{}=welds_source.{}", c, c))
+            .collect();
+        let sets: Vec<String> = parts
+            .updates
+            .iter()
+            .map(|c| format!("{}=welds_source.{}", c, c))
+            .collect();
+        let values: Vec<String> = parts
+            .columns
+            .iter()
+            .map(|c| format!("welds_source.{}", c))
+            .collect();
+        let columns = parts.columns.join(", ");
+        let output = match output {
+            Some(output) => format!(" OUTPUT {}", output),
+            None => String::default(),
+        };
+        // HOLDLOCK keeps another upsert from inserting the same row between the match and insert
+        format!(
+            "MERGE INTO {} WITH (HOLDLOCK) AS welds_target USING (VALUES {}) AS welds_source ({}) ON {} WHEN MATCHED THEN UPDATE SET {} WHEN NOT MATCHED THEN INSERT ({}) VALUES ({}){};",
+            parts.identifier,
+            parts.rows.join(", "),
+            columns,
+            on.join(" AND "),
+            sets.join(", "),
+            columns,
+            values.join(", "),
+            output
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_fail_to_read_back_a_made_composite_key_on_mysql() {
+        let columns = vec!["a".to_owned(), "b".to_owned()];
+        let parts = UpsertParts {
+            identifier: "t",
+            columns: &columns,
+            rows: &["(?, ?)".to_owned()],
+            target: &columns,
+            updates: &columns,
+        };
+        let pks = vec![
+            Column::new("a", "i32", false),
+            Column::new("b", "i32", false),
+        ];
+        let writer = UpsertWriter::new(Syntax::Mysql);
+        let err = writer
+            .write_returning(&parts, &pks, &pks, None)
+            .unwrap_err();
+        assert!(matches!(err, WeldsError::InsertFailed(_)));
+    }
+}